@@ -0,0 +1,347 @@
+//! Off-chain swap quotes for Whirlpools.
+//!
+//! The quote runs the program's own `swap_manager::swap` against account state that was
+//! fetched by the caller, so the returned `PostSwapUpdate` matches the one the program
+//! would compute for the same accounts and timestamp. No RPC client or Anchor runtime is
+//! involved.
+//!
+//! The quote only covers the swap itself. The caller has to apply a `FeeRateSchedule` that
+//! is due before quoting, and the fills of a `RangeOrderBook` crossed by the swap are not
+//! computed. A swap that reaches a tick array synthesized by `SparseTickArrays` is rejected,
+//! as the swap instruction cannot be given an account for it.
+use anchor_lang::prelude::ProgramError;
+use anchor_lang::{AccountDeserialize, Discriminator};
+use whirlpool::{
+    errors::ErrorCode,
+    manager::swap_manager::swap,
+    math::tick_index_from_sqrt_price,
+    state::{DynamicFee, TickArray, Whirlpool, TICK_ARRAY_SIZE},
+    util::SwapTickSequence,
+};
+
+pub use whirlpool::{
+    manager::swap_manager::PostSwapUpdate,
+    util::{SparseTickArrays, TickArrayProvider},
+};
+
+/// Maximum number of tick arrays a single swap can traverse.
+pub const MAX_SWAP_TICK_ARRAYS: usize = 3;
+
+/// Compute the result of a swap against the given Whirlpool state.
+///
+/// # Parameters
+/// - `whirlpool` - The Whirlpool to swap against. A `FeeRateSchedule` that is due at `timestamp`
+///                 has to be applied first, as the swap instruction would.
+/// - `dynamic_fee` - The dynamic fee of the Whirlpool, if it has been initialized
+/// - `tick_arrays` - The tick arrays in the order they would be passed to the swap instruction,
+///                   either as a `Vec<TickArray>` or as `SparseTickArrays`
+/// - `amount` - The amount of input or output token, depending on `amount_specified_is_input`
+/// - `sqrt_price_limit` - The maximum/minimum price the swap will swap to
+/// - `amount_specified_is_input` - Specifies the token type of `amount`
+/// - `a_to_b` - The direction of the swap
+/// - `timestamp` - The unix timestamp used to update the reward growths
+///
+/// # Returns
+/// - `PostSwapUpdate` - The amounts and the Whirlpool state after the swap
+///
+/// # Errors
+/// - `InvalidTickArraySequence` - No tick arrays or more than `MAX_SWAP_TICK_ARRAYS` were provided
+/// - `TickArraySequenceInvalidIndex` - The swap reaches a tick array that was synthesized
+/// - Any error returned by the swap instruction for the same accounts
+pub fn quote_swap<P: TickArrayProvider>(
+    whirlpool: &Whirlpool,
+    dynamic_fee: Option<&DynamicFee>,
+    tick_arrays: P,
+    amount: u64,
+    sqrt_price_limit: u128,
+    amount_specified_is_input: bool,
+    a_to_b: bool,
+    timestamp: u64,
+) -> Result<PostSwapUpdate, ErrorCode> {
+    let tick_array_count = tick_arrays.tick_array_count();
+    if tick_array_count == 0 || tick_array_count > MAX_SWAP_TICK_ARRAYS {
+        return Err(ErrorCode::InvalidTickArraySequence);
+    }
+
+    let mut swap_tick_sequence = SwapTickSequence::from_provider(tick_arrays);
+
+    let post_swap = swap(
+        whirlpool,
+        dynamic_fee,
+        &mut swap_tick_sequence,
+        amount,
+        sqrt_price_limit,
+        amount_specified_is_input,
+        a_to_b,
+        timestamp,
+    )?;
+
+    let end_tick_index = tick_index_from_sqrt_price(&post_swap.next_sqrt_price);
+    let tick_arrays = swap_tick_sequence.into_provider();
+    for array_index in 0..tick_arrays.tick_array_count() {
+        if tick_arrays.is_synthesized(array_index)
+            && is_tick_array_reached(
+                &tick_arrays,
+                array_index,
+                end_tick_index,
+                whirlpool.tick_spacing,
+                a_to_b,
+            )
+        {
+            return Err(ErrorCode::TickArraySequenceInvalidIndex);
+        }
+    }
+
+    Ok(post_swap)
+}
+
+// The first tick array is always passed to the swap instruction. The following ones are only
+// needed once the price of the swap moves into them.
+fn is_tick_array_reached<P: TickArrayProvider>(
+    tick_arrays: &P,
+    array_index: usize,
+    end_tick_index: i32,
+    tick_spacing: u16,
+    a_to_b: bool,
+) -> bool {
+    if array_index == 0 {
+        return true;
+    }
+    match tick_arrays.tick_array(array_index) {
+        Some(array) if a_to_b => {
+            end_tick_index < array.start_tick_index + TICK_ARRAY_SIZE * tick_spacing as i32
+        }
+        Some(array) => end_tick_index >= array.start_tick_index,
+        None => false,
+    }
+}
+
+/// Deserialize a Whirlpool from the raw data of its account.
+pub fn whirlpool_from_account_data(data: &[u8]) -> Result<Whirlpool, ProgramError> {
+    Whirlpool::try_deserialize(&mut &data[..])
+}
+
+/// Deserialize a DynamicFee from the raw data of its account.
+pub fn dynamic_fee_from_account_data(data: &[u8]) -> Result<DynamicFee, ProgramError> {
+    DynamicFee::try_deserialize(&mut &data[..])
+}
+
+/// Deserialize a TickArray from the raw data of its account.
+pub fn tick_array_from_account_data(data: &[u8]) -> Result<TickArray, ProgramError> {
+    if data.len() != TickArray::LEN || data[..8] != TickArray::discriminator() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(*bytemuck::from_bytes::<TickArray>(&data[8..]))
+}
+
+#[cfg(test)]
+mod quote_swap_tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use whirlpool::math::{sqrt_price_from_tick_index, MIN_SQRT_PRICE_X64};
+
+    const TICK_SPACING: u16 = 64;
+
+    fn test_whirlpool() -> Whirlpool {
+        Whirlpool {
+            tick_spacing: TICK_SPACING,
+            fee_rate: 3000,
+            liquidity: 1_000_000_000_000,
+            sqrt_price: sqrt_price_from_tick_index(0),
+            tick_current_index: 0,
+            ..Default::default()
+        }
+    }
+
+    fn test_tick_arrays() -> Vec<TickArray> {
+        let ticks_in_array = TICK_ARRAY_SIZE * TICK_SPACING as i32;
+        (0..3)
+            .map(|i| TickArray {
+                start_tick_index: -i * ticks_in_array,
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_quote_swap_exact_in() {
+        let whirlpool = test_whirlpool();
+        let post_swap = quote_swap(
+            &whirlpool,
+            None,
+            test_tick_arrays(),
+            1_000_000,
+            MIN_SQRT_PRICE_X64,
+            true,
+            true,
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(post_swap.amount_a, 1_000_000);
+        assert!(post_swap.amount_b > 0 && post_swap.amount_b < 1_000_000);
+        assert!(post_swap.next_sqrt_price < whirlpool.sqrt_price);
+        assert_eq!(post_swap.next_liquidity, whirlpool.liquidity);
+    }
+
+    #[test]
+    fn test_quote_swap_sparse_tick_arrays() {
+        let whirlpool = test_whirlpool();
+        // The swap moves into the second array, the third one can be left out
+        let tick_arrays: BTreeMap<i32, TickArray> = test_tick_arrays()
+            .into_iter()
+            .take(2)
+            .map(|array| (array.start_tick_index, array))
+            .collect();
+        let sparse = SparseTickArrays::new(
+            tick_arrays,
+            whirlpool.tick_current_index,
+            whirlpool.tick_spacing,
+            true,
+            MAX_SWAP_TICK_ARRAYS,
+        );
+        let from_sparse = quote_swap(
+            &whirlpool,
+            None,
+            sparse,
+            1_000_000,
+            MIN_SQRT_PRICE_X64,
+            true,
+            true,
+            0,
+        )
+        .unwrap();
+        let from_vec = quote_swap(
+            &whirlpool,
+            None,
+            test_tick_arrays(),
+            1_000_000,
+            MIN_SQRT_PRICE_X64,
+            true,
+            true,
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(from_sparse.amount_b, from_vec.amount_b);
+        assert_eq!(from_sparse.next_sqrt_price, from_vec.next_sqrt_price);
+    }
+
+    #[test]
+    fn test_quote_swap_reaches_synthesized_tick_array() {
+        let whirlpool = test_whirlpool();
+        let tick_arrays: BTreeMap<i32, TickArray> = test_tick_arrays()
+            .into_iter()
+            .take(1)
+            .map(|array| (array.start_tick_index, array))
+            .collect();
+        let sparse = SparseTickArrays::new(
+            tick_arrays,
+            whirlpool.tick_current_index,
+            whirlpool.tick_spacing,
+            true,
+            MAX_SWAP_TICK_ARRAYS,
+        );
+        assert_eq!(
+            quote_swap(
+                &whirlpool,
+                None,
+                sparse,
+                1_000_000,
+                MIN_SQRT_PRICE_X64,
+                true,
+                true,
+                0,
+            )
+            .unwrap_err(),
+            ErrorCode::TickArraySequenceInvalidIndex
+        );
+    }
+
+    #[test]
+    fn test_quote_swap_dynamic_fee() {
+        let whirlpool = test_whirlpool();
+        let dynamic_fee = DynamicFee {
+            base_surcharge_rate: 100,
+            max_surcharge_rate: 5_000,
+            decay_period: 600,
+            volatility_accumulator: 10,
+            last_update_timestamp: 0,
+            ..Default::default()
+        };
+        let with_surcharge = quote_swap(
+            &whirlpool,
+            Some(&dynamic_fee),
+            test_tick_arrays(),
+            1_000_000,
+            MIN_SQRT_PRICE_X64,
+            true,
+            true,
+            0,
+        )
+        .unwrap();
+        let decayed = quote_swap(
+            &whirlpool,
+            Some(&dynamic_fee),
+            test_tick_arrays(),
+            1_000_000,
+            MIN_SQRT_PRICE_X64,
+            true,
+            true,
+            600,
+        )
+        .unwrap();
+
+        assert!(with_surcharge.amount_b < decayed.amount_b);
+    }
+
+    #[test]
+    fn test_quote_swap_zero_amount() {
+        assert_eq!(
+            quote_swap(
+                &test_whirlpool(),
+                None,
+                test_tick_arrays(),
+                0,
+                MIN_SQRT_PRICE_X64,
+                true,
+                true,
+                0,
+            )
+            .unwrap_err(),
+            ErrorCode::ZeroTradableAmount
+        );
+    }
+
+    #[test]
+    fn test_quote_swap_without_tick_arrays() {
+        assert_eq!(
+            quote_swap(
+                &test_whirlpool(),
+                None,
+                Vec::<TickArray>::new(),
+                1_000_000,
+                MIN_SQRT_PRICE_X64,
+                true,
+                true,
+                0,
+            )
+            .unwrap_err(),
+            ErrorCode::InvalidTickArraySequence
+        );
+    }
+
+    #[test]
+    fn test_tick_array_from_account_data() {
+        let tick_array = TickArray {
+            start_tick_index: -5632,
+            ..Default::default()
+        };
+        let mut data = TickArray::discriminator().to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&tick_array));
+
+        let decoded = tick_array_from_account_data(&data).unwrap();
+        assert_eq!({ decoded.start_tick_index }, -5632);
+        assert!(tick_array_from_account_data(&data[..data.len() - 1]).is_err());
+    }
+}
@@ -107,7 +107,74 @@ pub enum ErrorCode {
     InvalidIntermediaryMint, // 0x179a
     #[msg("Duplicate two hop pool")]
     DuplicateTwoHopPool, // 0x179b
-    
+    #[msg("Requested observation is older than the oldest stored oracle observation")]
+    ObservationTooOld, // 0x179c
+    #[msg("Observation look-back periods must be strictly decreasing")]
+    InvalidObservationWindow, // 0x179d
+    #[msg("Invalid observation cardinality")]
+    InvalidObservationCardinality, // 0x179e
+    #[msg("Invalid accounts provided for route swap")]
+    InvalidRouteAccounts, // 0x179f
+    #[msg("Duplicate route pool")]
+    DuplicateRoutePool, // 0x17a0
+    #[msg("Intermediate hop output does not match the next hop input")]
+    IntermediateTokenAmountMismatch, // 0x17a1
+    #[msg("Invalid split swap legs")]
+    InvalidSplitSwap, // 0x17a2
+    #[msg("Invalid dynamic fee parameters")]
+    InvalidDynamicFeeParams, // 0x17a3
+    #[msg("Fee rate change has to be scheduled further in advance")]
+    FeeRateChangeTooEarly, // 0x17a4
+    #[msg("Invalid pool creation mode")]
+    InvalidPoolCreationMode, // 0x17a5
+    #[msg("Pool creation is not allowed by the pool creation policy")]
+    PoolCreationNotAllowed, // 0x17a6
+    #[msg("The allowlist of the pool creation policy is full")]
+    AllowedMintsFull, // 0x17a7
+    #[msg("The fee tier has been disabled")]
+    FeeTierDisabled, // 0x17a8
+    #[msg("Too many tick spacings for the fee tier policy")]
+    TooManyTickSpacings, // 0x17a9
+    #[msg("The operation has been paused")]
+    OperationPaused, // 0x17aa
+    #[msg("Unknown paused operations")]
+    InvalidPausedOperations, // 0x17ab
+    #[msg("Too many pools with paused operations")]
+    PausedPoolsFull, // 0x17ac
+    #[msg("The swap return data was not set by the whirlpool program")]
+    SwapReturnDataMissing, // 0x17ad
+    #[msg("Range orders have to span exactly one tick spacing")]
+    InvalidRangeOrderTickRange, // 0x17ae
+    #[msg("The current price is inside the range of the range order")]
+    RangeOrderInRange, // 0x17af
+    #[msg("The range order book is full")]
+    RangeOrderBookFull, // 0x17b0
+    #[msg("The range order was not found")]
+    RangeOrderNotFound, // 0x17b1
+    #[msg("The position has an open range order")]
+    PositionHasRangeOrder, // 0x17b2
+    #[msg("Position must have no liquidity to change its tick range")]
+    PositionLiquidityNonZero, // 0x17b3
+    #[msg("Position metadata exceeds the length limits of the metadata program")]
+    InvalidPositionMetadata, // 0x17b4
+    #[msg("Invalid metadata update authority")]
+    InvalidMetadataUpdateAuthority, // 0x17b5
+    #[msg("The metadata account does not belong to the position mint")]
+    InvalidPositionMetadataAccount, // 0x17b6
+    #[msg("The account of an extension enabled on the whirlpool is missing or invalid")]
+    InvalidExtensionAccount, // 0x17b7
+    #[msg("The fee rates of the whirlpool can only be changed through its fee rate schedule")]
+    FeeRateScheduleEnabled, // 0x17b8
+    #[msg("The fee tier cannot be closed while it has pools")]
+    FeeTierHasPools, // 0x17b9
+    #[msg("The account is not a fee tier of the config that has to be migrated")]
+    InvalidFeeTierMigration, // 0x17ba
+    #[msg("The liquidity of the range order is below the minimum")]
+    RangeOrderLiquidityTooLow, // 0x17bb
+    #[msg("The token mint has an extension that is not supported")]
+    UnsupportedTokenMint, // 0x17bc
+    #[msg("The transfer fee of the token could not be calculated")]
+    TransferFeeCalculationError, // 0x17bd
 }
 
 impl From<TryFromIntError> for ErrorCode {
@@ -0,0 +1,378 @@
+//! Events emitted by the instructions that change the state of the program.
+use anchor_lang::prelude::*;
+
+use crate::state::NUM_REWARDS;
+
+/// The fee authority of a WhirlpoolsConfig.
+pub const AUTHORITY_FEE: u8 = 0;
+/// The collect protocol fees authority of a WhirlpoolsConfig.
+pub const AUTHORITY_COLLECT_PROTOCOL_FEES: u8 = 1;
+/// The pool creator authority of a WhirlpoolsConfig.
+pub const AUTHORITY_POOL_CREATOR: u8 = 2;
+/// The reward emissions super authority of a WhirlpoolsConfig.
+pub const AUTHORITY_REWARD_EMISSIONS_SUPER: u8 = 3;
+
+#[event]
+pub struct ConfigInitialized {
+    pub whirlpools_config: Pubkey,
+    pub fee_authority: Pubkey,
+    pub collect_protocol_fees_authority: Pubkey,
+    pub reward_emissions_super_authority: Pubkey,
+    pub pool_creator_authority: Pubkey,
+    pub default_protocol_fee_rate: u16,
+}
+
+#[event]
+pub struct FeeTierInitialized {
+    pub whirlpools_config: Pubkey,
+    pub tick_spacing: u16,
+    pub default_fee_rate: u16,
+}
+
+#[event]
+pub struct PoolInitialized {
+    pub whirlpools_config: Pubkey,
+    pub whirlpool: Pubkey,
+    pub token_mint_a: Pubkey,
+    pub token_mint_b: Pubkey,
+    pub tick_spacing: u16,
+    pub sqrt_price: u128,
+}
+
+#[event]
+pub struct RewardInitialized {
+    pub whirlpool: Pubkey,
+    pub reward_index: u8,
+    pub reward_mint: Pubkey,
+}
+
+#[event]
+pub struct OracleInitialized {
+    pub whirlpool: Pubkey,
+}
+
+#[event]
+pub struct ObservationCardinalityIncreased {
+    pub whirlpool: Pubkey,
+    pub observation_cardinality_next: u16,
+}
+
+#[event]
+pub struct FeeRateScheduleInitialized {
+    pub whirlpool: Pubkey,
+}
+
+#[event]
+pub struct RangeOrderBookInitialized {
+    pub whirlpool: Pubkey,
+}
+
+#[event]
+pub struct FeeTierPolicyInitialized {
+    pub whirlpools_config: Pubkey,
+}
+
+#[event]
+pub struct PauseControlsInitialized {
+    pub whirlpools_config: Pubkey,
+}
+
+#[event]
+pub struct PendingAuthoritiesInitialized {
+    pub whirlpools_config: Pubkey,
+}
+
+/// Emitted for every hop of a swap, including the hops of two-hop, route and split swaps.
+#[event]
+pub struct Swapped {
+    pub whirlpool: Pubkey,
+    pub a_to_b: bool,
+    pub amount_a: u64,
+    pub amount_b: u64,
+    // Fee paid in the input token, including the protocol fee
+    pub fee_amount: u64,
+    pub protocol_fee_amount: u64,
+    pub sqrt_price: u128,
+    pub tick_current_index: i32,
+}
+
+#[event]
+pub struct PositionOpened {
+    pub whirlpool: Pubkey,
+    pub position: Pubkey,
+    pub position_mint: Pubkey,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+}
+
+#[event]
+pub struct PositionClosed {
+    pub whirlpool: Pubkey,
+    pub position: Pubkey,
+    pub position_mint: Pubkey,
+}
+
+#[event]
+pub struct LiquidityIncreased {
+    pub whirlpool: Pubkey,
+    pub position: Pubkey,
+    pub liquidity_amount: u128,
+    pub token_amount_a: u64,
+    pub token_amount_b: u64,
+}
+
+#[event]
+pub struct LiquidityDecreased {
+    pub whirlpool: Pubkey,
+    pub position: Pubkey,
+    pub liquidity_amount: u128,
+    pub token_amount_a: u64,
+    pub token_amount_b: u64,
+}
+
+/// The fees collected by the rebalance are included in the removed amounts.
+#[event]
+pub struct PositionRebalanced {
+    pub whirlpool: Pubkey,
+    pub position: Pubkey,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub liquidity_removed: u128,
+    pub liquidity_added: u128,
+    pub token_amount_a_removed: u64,
+    pub token_amount_b_removed: u64,
+    pub token_amount_a_added: u64,
+    pub token_amount_b_added: u64,
+}
+
+/// The amounts are the fees and rewards owed to the position after the update.
+#[event]
+pub struct FeesAndRewardsUpdated {
+    pub whirlpool: Pubkey,
+    pub position: Pubkey,
+    pub fee_owed_a: u64,
+    pub fee_owed_b: u64,
+    pub reward_amounts_owed: [u64; NUM_REWARDS],
+}
+
+#[event]
+pub struct FeesCollected {
+    pub whirlpool: Pubkey,
+    pub position: Pubkey,
+    pub fee_amount_a: u64,
+    pub fee_amount_b: u64,
+}
+
+#[event]
+pub struct RewardCollected {
+    pub whirlpool: Pubkey,
+    pub position: Pubkey,
+    pub reward_index: u8,
+    pub reward_amount: u64,
+}
+
+#[event]
+pub struct ProtocolFeesCollected {
+    pub whirlpool: Pubkey,
+    pub fee_amount_a: u64,
+    pub fee_amount_b: u64,
+}
+
+/// `authority` is one of the AUTHORITY_* constants.
+#[event]
+pub struct AuthorityTransferProposed {
+    pub whirlpools_config: Pubkey,
+    pub authority: u8,
+    pub new_authority: Pubkey,
+}
+
+/// `authority` is one of the AUTHORITY_* constants.
+#[event]
+pub struct AuthorityTransferAccepted {
+    pub whirlpools_config: Pubkey,
+    pub authority: u8,
+    pub new_authority: Pubkey,
+}
+
+/// `authority` is one of the AUTHORITY_* constants.
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub whirlpools_config: Pubkey,
+    pub authority: u8,
+}
+
+#[event]
+pub struct DefaultFeeRateUpdated {
+    pub whirlpools_config: Pubkey,
+    pub tick_spacing: u16,
+    pub default_fee_rate: u16,
+}
+
+#[event]
+pub struct DefaultProtocolFeeRateUpdated {
+    pub whirlpools_config: Pubkey,
+    pub default_protocol_fee_rate: u16,
+}
+
+/// Emitted when the fee rate is set directly, and when a scheduled fee rate change is applied.
+#[event]
+pub struct FeeRateUpdated {
+    pub whirlpool: Pubkey,
+    pub fee_rate: u16,
+}
+
+/// Emitted when the protocol fee rate is set directly, and when a scheduled fee rate change is
+/// applied.
+#[event]
+pub struct ProtocolFeeRateUpdated {
+    pub whirlpool: Pubkey,
+    pub protocol_fee_rate: u16,
+}
+
+#[event]
+pub struct FeeRateChangeScheduled {
+    pub whirlpool: Pubkey,
+    pub fee_rate: u16,
+    pub protocol_fee_rate: u16,
+    pub effective_at: u64,
+}
+
+#[event]
+pub struct FeeRateChangeCancelled {
+    pub whirlpool: Pubkey,
+}
+
+#[event]
+pub struct DynamicFeeParamsUpdated {
+    pub whirlpool: Pubkey,
+    pub base_surcharge_rate: u16,
+    pub max_surcharge_rate: u16,
+    pub decay_period: u64,
+}
+
+#[event]
+pub struct RewardAuthorityUpdated {
+    pub whirlpool: Pubkey,
+    pub reward_index: u8,
+    pub new_reward_authority: Pubkey,
+}
+
+#[event]
+pub struct RewardEmissionsUpdated {
+    pub whirlpool: Pubkey,
+    pub reward_index: u8,
+    pub emissions_per_second_x64: u128,
+}
+
+#[event]
+pub struct EnableFlagUpdated {
+    pub whirlpool: Pubkey,
+    pub is_enabled: bool,
+}
+
+#[event]
+pub struct PoolCreationModeUpdated {
+    pub whirlpools_config: Pubkey,
+    pub mode: u8,
+}
+
+#[event]
+pub struct PositionMetadataUpdated {
+    pub whirlpool: Pubkey,
+    pub position: Pubkey,
+    pub position_mint: Pubkey,
+}
+
+/// Emitted when the metadata settings of a WhirlpoolsConfig are initialized or updated.
+#[event]
+pub struct MetadataSettingsUpdated {
+    pub whirlpools_config: Pubkey,
+    pub update_authority: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri_template: String,
+}
+
+/// Emitted when the collection NFT of the positions of a WhirlpoolsConfig is minted.
+#[event]
+pub struct PositionCollectionInitialized {
+    pub whirlpools_config: Pubkey,
+    pub collection_mint: Pubkey,
+}
+
+#[event]
+pub struct AllowedMintAdded {
+    pub whirlpools_config: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct AllowedMintRemoved {
+    pub whirlpools_config: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct AllowedTickSpacingsUpdated {
+    pub whirlpools_config: Pubkey,
+    pub allowed_tick_spacings: Vec<u16>,
+}
+
+#[event]
+pub struct FeeTierEnabledUpdated {
+    pub whirlpools_config: Pubkey,
+    pub tick_spacing: u16,
+    pub is_enabled: bool,
+}
+
+#[event]
+pub struct FeeTierClosed {
+    pub whirlpools_config: Pubkey,
+    pub tick_spacing: u16,
+}
+
+#[event]
+pub struct FeeTierMigrated {
+    pub whirlpools_config: Pubkey,
+    pub tick_spacing: u16,
+}
+
+#[event]
+pub struct ConfigPausedOperationsUpdated {
+    pub whirlpools_config: Pubkey,
+    pub paused_operations: u8,
+}
+
+#[event]
+pub struct PoolPausedOperationsUpdated {
+    pub whirlpools_config: Pubkey,
+    pub whirlpool: Pubkey,
+    pub paused_operations: u8,
+}
+
+#[event]
+pub struct RangeOrderPlaced {
+    pub whirlpool: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub liquidity: u128,
+    pub sells_token_a: bool,
+}
+
+#[event]
+pub struct RangeOrderFilled {
+    pub whirlpool: Pubkey,
+    pub position: Pubkey,
+}
+
+#[event]
+pub struct RangeOrderWithdrawn {
+    pub whirlpool: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub filled: bool,
+    pub token_amount_a: u64,
+    pub token_amount_b: u64,
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::events::{AuthorityTransferAccepted, AUTHORITY_COLLECT_PROTOCOL_FEES};
+use crate::state::{PendingAuthorities, WhirlpoolsConfig};
+
+#[derive(Accounts)]
+pub struct AcceptCollectProtocolFeesAuthority<'info> {
+    #[account(mut)]
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(
+      mut,
+      seeds = [b"pending_authorities", whirlpools_config.key().as_ref()],
+      bump,
+      has_one = whirlpools_config)]
+    pub pending_authorities: Account<'info, PendingAuthorities>,
+
+    #[account(address = pending_authorities.collect_protocol_fees_authority)]
+    pub new_collect_protocol_fees_authority: Signer<'info>,
+}
+
+/// Accept a pending transfer of the collect protocol fees authority.
+/// Only the proposed collect protocol fees authority has permission to invoke this instruction.
+pub fn handler(ctx: Context<AcceptCollectProtocolFeesAuthority>) -> ProgramResult {
+    ctx.accounts
+        .whirlpools_config
+        .update_collect_protocol_fees_authority(
+            ctx.accounts.new_collect_protocol_fees_authority.key(),
+        );
+    ctx.accounts
+        .pending_authorities
+        .collect_protocol_fees_authority = Pubkey::default();
+    emit!(AuthorityTransferAccepted {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        authority: AUTHORITY_COLLECT_PROTOCOL_FEES,
+        new_authority: ctx.accounts.new_collect_protocol_fees_authority.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::events::{AuthorityTransferAccepted, AUTHORITY_FEE};
+use crate::state::{PendingAuthorities, WhirlpoolsConfig};
+
+#[derive(Accounts)]
+pub struct AcceptFeeAuthority<'info> {
+    #[account(mut)]
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(
+      mut,
+      seeds = [b"pending_authorities", whirlpools_config.key().as_ref()],
+      bump,
+      has_one = whirlpools_config)]
+    pub pending_authorities: Account<'info, PendingAuthorities>,
+
+    #[account(address = pending_authorities.fee_authority)]
+    pub new_fee_authority: Signer<'info>,
+}
+
+/// Accept a pending transfer of the fee authority.
+/// Only the proposed fee authority has permission to invoke this instruction.
+pub fn handler(ctx: Context<AcceptFeeAuthority>) -> ProgramResult {
+    ctx.accounts
+        .whirlpools_config
+        .update_fee_authority(ctx.accounts.new_fee_authority.key());
+    ctx.accounts.pending_authorities.fee_authority = Pubkey::default();
+    emit!(AuthorityTransferAccepted {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        authority: AUTHORITY_FEE,
+        new_authority: ctx.accounts.new_fee_authority.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::events::{AuthorityTransferAccepted, AUTHORITY_POOL_CREATOR};
+use crate::state::{PendingAuthorities, WhirlpoolsConfig};
+
+#[derive(Accounts)]
+pub struct AcceptPoolCreatorAuthority<'info> {
+    #[account(mut)]
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(
+      mut,
+      seeds = [b"pending_authorities", whirlpools_config.key().as_ref()],
+      bump,
+      has_one = whirlpools_config)]
+    pub pending_authorities: Account<'info, PendingAuthorities>,
+
+    #[account(address = pending_authorities.pool_creator_authority)]
+    pub new_pool_creator_authority: Signer<'info>,
+}
+
+/// Accept a pending transfer of the pool creator authority.
+/// Only the proposed pool creator authority has permission to invoke this instruction.
+pub fn handler(ctx: Context<AcceptPoolCreatorAuthority>) -> ProgramResult {
+    ctx.accounts
+        .whirlpools_config
+        .update_pool_creator_authority(ctx.accounts.new_pool_creator_authority.key());
+    ctx.accounts.pending_authorities.pool_creator_authority = Pubkey::default();
+    emit!(AuthorityTransferAccepted {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        authority: AUTHORITY_POOL_CREATOR,
+        new_authority: ctx.accounts.new_pool_creator_authority.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::events::{AuthorityTransferAccepted, AUTHORITY_REWARD_EMISSIONS_SUPER};
+use crate::state::{PendingAuthorities, WhirlpoolsConfig};
+
+#[derive(Accounts)]
+pub struct AcceptRewardEmissionsSuperAuthority<'info> {
+    #[account(mut)]
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(
+      mut,
+      seeds = [b"pending_authorities", whirlpools_config.key().as_ref()],
+      bump,
+      has_one = whirlpools_config)]
+    pub pending_authorities: Account<'info, PendingAuthorities>,
+
+    #[account(address = pending_authorities.reward_emissions_super_authority)]
+    pub new_reward_emissions_super_authority: Signer<'info>,
+}
+
+/// Accept a pending transfer of the reward emissions super authority.
+/// Only the proposed reward emissions super authority has permission to invoke this instruction.
+pub fn handler(ctx: Context<AcceptRewardEmissionsSuperAuthority>) -> ProgramResult {
+    ctx.accounts
+        .whirlpools_config
+        .update_reward_emissions_super_authority(
+            ctx.accounts.new_reward_emissions_super_authority.key(),
+        );
+    ctx.accounts
+        .pending_authorities
+        .reward_emissions_super_authority = Pubkey::default();
+    emit!(AuthorityTransferAccepted {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        authority: AUTHORITY_REWARD_EMISSIONS_SUPER,
+        new_authority: ctx.accounts.new_reward_emissions_super_authority.key(),
+    });
+    Ok(())
+}
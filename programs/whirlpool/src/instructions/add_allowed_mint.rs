@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+use super::ModifyPoolCreationPolicy;
+use crate::events::AllowedMintAdded;
+
+pub fn handler(ctx: Context<ModifyPoolCreationPolicy>, mint: Pubkey) -> ProgramResult {
+    ctx.accounts.pool_creation_policy.add_allowed_mint(mint)?;
+    emit!(AllowedMintAdded {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        mint,
+    });
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::events::{AuthorityTransferCancelled, AUTHORITY_COLLECT_PROTOCOL_FEES};
+use crate::state::{PendingAuthorities, WhirlpoolsConfig};
+
+#[derive(Accounts)]
+pub struct CancelCollectProtocolFeesAuthorityTransfer<'info> {
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(address = whirlpools_config.collect_protocol_fees_authority)]
+    pub collect_protocol_fees_authority: Signer<'info>,
+
+    #[account(
+      mut,
+      seeds = [b"pending_authorities", whirlpools_config.key().as_ref()],
+      bump,
+      has_one = whirlpools_config)]
+    pub pending_authorities: Account<'info, PendingAuthorities>,
+}
+
+/// Cancel a pending transfer of the collect protocol fees authority.
+/// Only the current collect protocol fees authority has permission to invoke this instruction.
+pub fn handler(ctx: Context<CancelCollectProtocolFeesAuthorityTransfer>) -> ProgramResult {
+    ctx.accounts
+        .pending_authorities
+        .collect_protocol_fees_authority = Pubkey::default();
+    emit!(AuthorityTransferCancelled {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        authority: AUTHORITY_COLLECT_PROTOCOL_FEES,
+    });
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::events::{AuthorityTransferCancelled, AUTHORITY_FEE};
+use crate::state::{PendingAuthorities, WhirlpoolsConfig};
+
+#[derive(Accounts)]
+pub struct CancelFeeAuthorityTransfer<'info> {
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(address = whirlpools_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+
+    #[account(
+      mut,
+      seeds = [b"pending_authorities", whirlpools_config.key().as_ref()],
+      bump,
+      has_one = whirlpools_config)]
+    pub pending_authorities: Account<'info, PendingAuthorities>,
+}
+
+/// Cancel a pending transfer of the fee authority.
+/// Only the current fee authority has permission to invoke this instruction.
+pub fn handler(ctx: Context<CancelFeeAuthorityTransfer>) -> ProgramResult {
+    ctx.accounts.pending_authorities.fee_authority = Pubkey::default();
+    emit!(AuthorityTransferCancelled {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        authority: AUTHORITY_FEE,
+    });
+    Ok(())
+}
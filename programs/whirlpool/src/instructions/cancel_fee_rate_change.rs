@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use super::ScheduleFeeRateChange;
+use crate::events::FeeRateChangeCancelled;
+
+pub fn handler(ctx: Context<ScheduleFeeRateChange>) -> ProgramResult {
+    ctx.accounts.fee_rate_schedule.cancel();
+    emit!(FeeRateChangeCancelled {
+        whirlpool: ctx.accounts.whirlpool.key(),
+    });
+    Ok(())
+}
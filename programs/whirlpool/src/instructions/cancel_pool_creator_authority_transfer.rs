@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::events::{AuthorityTransferCancelled, AUTHORITY_POOL_CREATOR};
+use crate::state::{PendingAuthorities, WhirlpoolsConfig};
+
+#[derive(Accounts)]
+pub struct CancelPoolCreatorAuthorityTransfer<'info> {
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(address = whirlpools_config.pool_creator_authority)]
+    pub pool_creator_authority: Signer<'info>,
+
+    #[account(
+      mut,
+      seeds = [b"pending_authorities", whirlpools_config.key().as_ref()],
+      bump,
+      has_one = whirlpools_config)]
+    pub pending_authorities: Account<'info, PendingAuthorities>,
+}
+
+/// Cancel a pending transfer of the pool creator authority.
+/// Only the current pool creator authority has permission to invoke this instruction.
+pub fn handler(ctx: Context<CancelPoolCreatorAuthorityTransfer>) -> ProgramResult {
+    ctx.accounts.pending_authorities.pool_creator_authority = Pubkey::default();
+    emit!(AuthorityTransferCancelled {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        authority: AUTHORITY_POOL_CREATOR,
+    });
+    Ok(())
+}
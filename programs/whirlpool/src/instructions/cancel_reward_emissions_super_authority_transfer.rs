@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::events::{AuthorityTransferCancelled, AUTHORITY_REWARD_EMISSIONS_SUPER};
+use crate::state::{PendingAuthorities, WhirlpoolsConfig};
+
+#[derive(Accounts)]
+pub struct CancelRewardEmissionsSuperAuthorityTransfer<'info> {
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(address = whirlpools_config.reward_emissions_super_authority)]
+    pub reward_emissions_super_authority: Signer<'info>,
+
+    #[account(
+      mut,
+      seeds = [b"pending_authorities", whirlpools_config.key().as_ref()],
+      bump,
+      has_one = whirlpools_config)]
+    pub pending_authorities: Account<'info, PendingAuthorities>,
+}
+
+/// Cancel a pending transfer of the reward emissions super authority.
+/// Only the current reward emissions super authority has permission to invoke this instruction.
+pub fn handler(ctx: Context<CancelRewardEmissionsSuperAuthorityTransfer>) -> ProgramResult {
+    ctx.accounts
+        .pending_authorities
+        .reward_emissions_super_authority = Pubkey::default();
+    emit!(AuthorityTransferCancelled {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        authority: AUTHORITY_REWARD_EMISSIONS_SUPER,
+    });
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::FeeTierClosed;
+use crate::state::{FeeTier, WhirlpoolsConfig};
+
+#[derive(Accounts)]
+pub struct CloseFeeTier<'info> {
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(mut, has_one = whirlpools_config, close = receiver)]
+    pub fee_tier: Account<'info, FeeTier>,
+
+    #[account(address = whirlpools_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+
+    /// CHECK: receives the rent of the closed fee tier
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+}
+
+/*
+   Closes a FeeTier that no Whirlpool has been initialized with. No new pools can be created
+   with the tick spacing until the fee tier is initialized again.
+*/
+pub fn handler(ctx: Context<CloseFeeTier>) -> ProgramResult {
+    if ctx.accounts.fee_tier.pool_count != 0 {
+        return Err(ErrorCode::FeeTierHasPools.into());
+    }
+
+    emit!(FeeTierClosed {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        tick_spacing: ctx.accounts.fee_tier.tick_spacing,
+    });
+    Ok(())
+}
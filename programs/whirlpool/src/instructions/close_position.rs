@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
 
 use crate::errors::ErrorCode;
+use crate::events::PositionClosed;
 use crate::state::*;
 use crate::util::{burn_and_close_user_position_token, verify_position_authority};
 
@@ -49,5 +50,13 @@ pub fn handler(ctx: Context<ClosePosition>) -> ProgramResult {
         &ctx.accounts.position_mint,
         &ctx.accounts.position_token_account,
         &ctx.accounts.token_program,
-    )
+    )?;
+
+    emit!(PositionClosed {
+        whirlpool: ctx.accounts.whirlpool.key(),
+        position: ctx.accounts.position.key(),
+        position_mint: ctx.accounts.position_mint.key(),
+    });
+
+    Ok(())
 }
@@ -2,8 +2,9 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount};
 
 use crate::{
+    events::FeesCollected,
     state::*,
-    util::{transfer_from_vault_to_owner, verify_position_authority},
+    util::{require_not_paused, transfer_from_vault_to_owner, verify_position_authority},
 };
 
 #[derive(Accounts)]
@@ -32,6 +33,10 @@ pub struct CollectFees<'info> {
 
     #[account(address = token::ID)]
     pub token_program: Program<'info, Token>,
+
+    /// CHECK: nothing is paused until the pause controls of the config have been initialized
+    #[account(seeds = [b"pause_controls", whirlpool.whirlpools_config.as_ref()], bump)]
+    pub pause_controls: UncheckedAccount<'info>,
 }
 
 pub fn handler(ctx: Context<CollectFees>) -> ProgramResult {
@@ -39,6 +44,11 @@ pub fn handler(ctx: Context<CollectFees>) -> ProgramResult {
         &ctx.accounts.position_token_account,
         &ctx.accounts.position_authority,
     )?;
+    require_not_paused(
+        &ctx.accounts.pause_controls,
+        &ctx.accounts.whirlpool,
+        PAUSE_COLLECT,
+    )?;
 
     let position = &mut ctx.accounts.position;
 
@@ -64,5 +74,12 @@ pub fn handler(ctx: Context<CollectFees>) -> ProgramResult {
         fee_owed_b,
     )?;
 
+    emit!(FeesCollected {
+        whirlpool: ctx.accounts.whirlpool.key(),
+        position: ctx.accounts.position.key(),
+        fee_amount_a: fee_owed_a,
+        fee_amount_b: fee_owed_b,
+    });
+
     Ok(())
 }
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::{
+    events::FeesCollected,
+    state::*,
+    util::{require_not_paused, transfer_from_vault_to_owner_v2, verify_position_authority},
+};
+
+#[derive(Accounts)]
+pub struct CollectFeesV2<'info> {
+    pub whirlpool: Box<Account<'info, Whirlpool>>,
+
+    pub position_authority: Signer<'info>,
+
+    #[account(mut, has_one = whirlpool)]
+    pub position: Box<Account<'info, Position>>,
+    #[account(
+        constraint = position_token_account.mint == position.position_mint,
+        constraint = position_token_account.amount == 1
+    )]
+    pub position_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: the mints of the whirlpool
+    #[account(address = whirlpool.token_mint_a)]
+    pub token_mint_a: UncheckedAccount<'info>,
+    /// CHECK: the mints of the whirlpool
+    #[account(address = whirlpool.token_mint_b)]
+    pub token_mint_b: UncheckedAccount<'info>,
+
+    /// CHECK: the mint of the token owner accounts is checked by the transfers
+    #[account(mut)]
+    pub token_owner_account_a: UncheckedAccount<'info>,
+    /// CHECK: the vaults of the whirlpool
+    #[account(mut, address = whirlpool.token_vault_a)]
+    pub token_vault_a: UncheckedAccount<'info>,
+
+    /// CHECK: the mint of the token owner accounts is checked by the transfers
+    #[account(mut)]
+    pub token_owner_account_b: UncheckedAccount<'info>,
+    /// CHECK: the vaults of the whirlpool
+    #[account(mut, address = whirlpool.token_vault_b)]
+    pub token_vault_b: UncheckedAccount<'info>,
+
+    /// CHECK: the token program of each mint is the program that owns it
+    #[account(address = *token_mint_a.owner)]
+    pub token_program_a: UncheckedAccount<'info>,
+    /// CHECK: the token program of each mint is the program that owns it
+    #[account(address = *token_mint_b.owner)]
+    pub token_program_b: UncheckedAccount<'info>,
+
+    /// CHECK: nothing is paused until the pause controls of the config have been initialized
+    #[account(seeds = [b"pause_controls", whirlpool.whirlpools_config.as_ref()], bump)]
+    pub pause_controls: UncheckedAccount<'info>,
+}
+
+/*
+  Collects the fees of a Position of a Whirlpool whose mints may belong to the Token-2022 program.
+  The transfer fees are withheld from the fees owed.
+*/
+pub fn handler(ctx: Context<CollectFeesV2>) -> ProgramResult {
+    verify_position_authority(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+    )?;
+    require_not_paused(
+        &ctx.accounts.pause_controls,
+        &ctx.accounts.whirlpool,
+        PAUSE_COLLECT,
+    )?;
+
+    let position = &mut ctx.accounts.position;
+
+    // Store the fees owed to use as transfer amounts.
+    let fee_owed_a = position.fee_owed_a;
+    let fee_owed_b = position.fee_owed_b;
+
+    position.reset_fees_owed();
+
+    transfer_from_vault_to_owner_v2(
+        &ctx.accounts.whirlpool,
+        &ctx.accounts.token_mint_a,
+        &ctx.accounts.token_vault_a,
+        &ctx.accounts.token_owner_account_a,
+        &ctx.accounts.token_program_a,
+        fee_owed_a,
+    )?;
+
+    transfer_from_vault_to_owner_v2(
+        &ctx.accounts.whirlpool,
+        &ctx.accounts.token_mint_b,
+        &ctx.accounts.token_vault_b,
+        &ctx.accounts.token_owner_account_b,
+        &ctx.accounts.token_program_b,
+        fee_owed_b,
+    )?;
+
+    emit!(FeesCollected {
+        whirlpool: ctx.accounts.whirlpool.key(),
+        position: ctx.accounts.position.key(),
+        fee_amount_a: fee_owed_a,
+        fee_amount_b: fee_owed_b,
+    });
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use crate::{state::*, util::transfer_from_vault_to_owner};
+use crate::{events::ProtocolFeesCollected, state::*, util::transfer_from_vault_to_owner};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount};
 
@@ -47,5 +47,11 @@ pub fn handler(ctx: Context<CollectProtocolFees>) -> ProgramResult {
         whirlpool.protocol_fee_owed_b,
     )?;
 
+    emit!(ProtocolFeesCollected {
+        whirlpool: whirlpool.key(),
+        fee_amount_a: whirlpool.protocol_fee_owed_a,
+        fee_amount_b: whirlpool.protocol_fee_owed_b,
+    });
+
     Ok(ctx.accounts.whirlpool.reset_protocol_fees_owed())
 }
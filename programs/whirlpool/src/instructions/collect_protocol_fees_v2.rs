@@ -0,0 +1,73 @@
+use crate::{events::ProtocolFeesCollected, state::*, util::transfer_from_vault_to_owner_v2};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CollectProtocolFeesV2<'info> {
+    pub whirlpools_config: Box<Account<'info, WhirlpoolsConfig>>,
+
+    #[account(mut, has_one = whirlpools_config)]
+    pub whirlpool: Box<Account<'info, Whirlpool>>,
+
+    #[account(address = whirlpools_config.collect_protocol_fees_authority)]
+    pub collect_protocol_fees_authority: Signer<'info>,
+
+    /// CHECK: the mints of the whirlpool
+    #[account(address = whirlpool.token_mint_a)]
+    pub token_mint_a: UncheckedAccount<'info>,
+    /// CHECK: the mints of the whirlpool
+    #[account(address = whirlpool.token_mint_b)]
+    pub token_mint_b: UncheckedAccount<'info>,
+
+    /// CHECK: the vaults of the whirlpool
+    #[account(mut, address = whirlpool.token_vault_a)]
+    pub token_vault_a: UncheckedAccount<'info>,
+
+    /// CHECK: the vaults of the whirlpool
+    #[account(mut, address = whirlpool.token_vault_b)]
+    pub token_vault_b: UncheckedAccount<'info>,
+
+    /// CHECK: the mint of the token destinations is checked by the transfers
+    #[account(mut)]
+    pub token_destination_a: UncheckedAccount<'info>,
+
+    /// CHECK: the mint of the token destinations is checked by the transfers
+    #[account(mut)]
+    pub token_destination_b: UncheckedAccount<'info>,
+
+    /// CHECK: the token program of each mint is the program that owns it
+    #[account(address = *token_mint_a.owner)]
+    pub token_program_a: UncheckedAccount<'info>,
+    /// CHECK: the token program of each mint is the program that owns it
+    #[account(address = *token_mint_b.owner)]
+    pub token_program_b: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CollectProtocolFeesV2>) -> ProgramResult {
+    let whirlpool = &ctx.accounts.whirlpool;
+
+    transfer_from_vault_to_owner_v2(
+        whirlpool,
+        &ctx.accounts.token_mint_a,
+        &ctx.accounts.token_vault_a,
+        &ctx.accounts.token_destination_a,
+        &ctx.accounts.token_program_a,
+        whirlpool.protocol_fee_owed_a,
+    )?;
+
+    transfer_from_vault_to_owner_v2(
+        whirlpool,
+        &ctx.accounts.token_mint_b,
+        &ctx.accounts.token_vault_b,
+        &ctx.accounts.token_destination_b,
+        &ctx.accounts.token_program_b,
+        whirlpool.protocol_fee_owed_b,
+    )?;
+
+    emit!(ProtocolFeesCollected {
+        whirlpool: whirlpool.key(),
+        fee_amount_a: whirlpool.protocol_fee_owed_a,
+        fee_amount_b: whirlpool.protocol_fee_owed_b,
+    });
+
+    Ok(ctx.accounts.whirlpool.reset_protocol_fees_owed())
+}
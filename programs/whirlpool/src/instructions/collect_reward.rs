@@ -2,8 +2,9 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount};
 
 use crate::{
+    events::RewardCollected,
     state::*,
-    util::{transfer_from_vault_to_owner, verify_position_authority},
+    util::{require_not_paused, transfer_from_vault_to_owner, verify_position_authority},
 };
 
 #[derive(Accounts)]
@@ -31,6 +32,10 @@ pub struct CollectReward<'info> {
 
     #[account(address = token::ID)]
     pub token_program: Program<'info, Token>,
+
+    /// CHECK: nothing is paused until the pause controls of the config have been initialized
+    #[account(seeds = [b"pause_controls", whirlpool.whirlpools_config.as_ref()], bump)]
+    pub pause_controls: UncheckedAccount<'info>,
 }
 
 /// Collects all harvestable tokens for a specified reward.
@@ -51,6 +56,11 @@ pub fn handler(ctx: Context<CollectReward>, reward_index: u8) -> ProgramResult {
         &ctx.accounts.position_token_account,
         &ctx.accounts.position_authority,
     )?;
+    require_not_paused(
+        &ctx.accounts.pause_controls,
+        &ctx.accounts.whirlpool,
+        PAUSE_COLLECT,
+    )?;
 
     let index = reward_index as usize;
 
@@ -62,13 +72,22 @@ pub fn handler(ctx: Context<CollectReward>, reward_index: u8) -> ProgramResult {
 
     position.update_reward_owed(index, updated_amount_owed);
 
-    Ok(transfer_from_vault_to_owner(
+    transfer_from_vault_to_owner(
         &ctx.accounts.whirlpool,
         &ctx.accounts.reward_vault,
         &ctx.accounts.reward_owner_account,
         &ctx.accounts.token_program,
         transfer_amount,
-    )?)
+    )?;
+
+    emit!(RewardCollected {
+        whirlpool: ctx.accounts.whirlpool.key(),
+        position: ctx.accounts.position.key(),
+        reward_index,
+        reward_amount: transfer_amount,
+    });
+
+    Ok(())
 }
 
 fn calculate_collect_reward(position_reward: PositionRewardInfo, vault_amount: u64) -> (u64, u64) {
@@ -1,19 +1,24 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::ErrorCode;
+use crate::events::LiquidityDecreased;
 use crate::manager::liquidity_manager::{
     calculate_liquidity_token_deltas, calculate_modify_liquidity, sync_modify_liquidity_values,
 };
 use crate::math::convert_to_liquidity_delta;
-use crate::util::{to_timestamp_u64, transfer_from_vault_to_owner, verify_position_authority};
+use crate::state::PAUSE_DECREASE_LIQUIDITY;
+use crate::util::{
+    apply_fee_rate_schedule, require_not_paused, to_timestamp_u64, transfer_from_vault_to_owner,
+    update_oracle, verify_position_authority, WhirlpoolExtensionAccounts,
+};
 
-use super::ModifyLiquidity;
+use super::{ModifyLiquidity, MODIFY_LIQUIDITY_EXTENSIONS};
 
 /*
   Removes liquidity from an existing Whirlpool Position.
 */
-pub fn handler(
-    ctx: Context<ModifyLiquidity>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ModifyLiquidity<'info>>,
     liquidity_amount: u128,
     token_min_a: u64,
     token_min_b: u64,
@@ -22,8 +27,18 @@ pub fn handler(
         &ctx.accounts.position_token_account,
         &ctx.accounts.position_authority,
     )?;
+    let (extension_accounts, _) = WhirlpoolExtensionAccounts::try_from(
+        &ctx.accounts.whirlpool,
+        ctx.remaining_accounts,
+        MODIFY_LIQUIDITY_EXTENSIONS,
+    )?;
     let whirlpool = &ctx.accounts.whirlpool;
     whirlpool.require_enabled()?;
+    require_not_paused(
+        &ctx.accounts.pause_controls,
+        whirlpool,
+        PAUSE_DECREASE_LIQUIDITY,
+    )?;
     let clock = Clock::get()?;
 
     if liquidity_amount == 0 {
@@ -31,6 +46,11 @@ pub fn handler(
     }
     let liquidity_delta = convert_to_liquidity_delta(liquidity_amount, false)?;
     let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    apply_fee_rate_schedule(
+        &mut ctx.accounts.whirlpool,
+        extension_accounts.fee_rate_schedule.as_ref(),
+        timestamp,
+    )?;
 
     let update = calculate_modify_liquidity(
         &ctx.accounts.whirlpool,
@@ -41,6 +61,15 @@ pub fn handler(
         timestamp,
     )?;
 
+    // The oracle has to accumulate the in-range liquidity up until now before it changes
+    if update.whirlpool_liquidity != ctx.accounts.whirlpool.liquidity {
+        update_oracle(
+            &ctx.accounts.whirlpool,
+            extension_accounts.oracle.as_ref(),
+            timestamp,
+        )?;
+    }
+
     sync_modify_liquidity_values(
         &mut ctx.accounts.whirlpool,
         &mut ctx.accounts.position,
@@ -79,5 +108,13 @@ pub fn handler(
         delta_b,
     )?;
 
+    emit!(LiquidityDecreased {
+        whirlpool: ctx.accounts.whirlpool.key(),
+        position: ctx.accounts.position.key(),
+        liquidity_amount,
+        token_amount_a: delta_a,
+        token_amount_b: delta_b,
+    });
+
     Ok(())
 }
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::LiquidityDecreased;
+use crate::manager::liquidity_manager::{
+    calculate_liquidity_token_deltas, calculate_modify_liquidity, sync_modify_liquidity_values,
+};
+use crate::math::convert_to_liquidity_delta;
+use crate::state::PAUSE_DECREASE_LIQUIDITY;
+use crate::util::{
+    apply_fee_rate_schedule, calculate_transfer_fee_excluded_amount, get_transfer_fee,
+    require_not_paused, to_timestamp_u64, transfer_from_vault_to_owner_v2, update_oracle,
+    verify_position_authority, WhirlpoolExtensionAccounts,
+};
+
+use super::{ModifyLiquidityV2, MODIFY_LIQUIDITY_EXTENSIONS};
+
+/*
+  Removes liquidity from a Position of a Whirlpool whose mints may belong to the Token-2022
+  program. The token mins bound the amounts received by the position authority, net of the
+  transfer fees.
+*/
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ModifyLiquidityV2<'info>>,
+    liquidity_amount: u128,
+    token_min_a: u64,
+    token_min_b: u64,
+) -> ProgramResult {
+    verify_position_authority(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+    )?;
+    let (extension_accounts, _) = WhirlpoolExtensionAccounts::try_from(
+        &ctx.accounts.whirlpool,
+        ctx.remaining_accounts,
+        MODIFY_LIQUIDITY_EXTENSIONS,
+    )?;
+    let whirlpool = &ctx.accounts.whirlpool;
+    whirlpool.require_enabled()?;
+    require_not_paused(
+        &ctx.accounts.pause_controls,
+        whirlpool,
+        PAUSE_DECREASE_LIQUIDITY,
+    )?;
+    let clock = Clock::get()?;
+
+    if liquidity_amount == 0 {
+        return Err(ErrorCode::LiquidityZero.into());
+    }
+    let liquidity_delta = convert_to_liquidity_delta(liquidity_amount, false)?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    apply_fee_rate_schedule(
+        &mut ctx.accounts.whirlpool,
+        extension_accounts.fee_rate_schedule.as_ref(),
+        timestamp,
+    )?;
+
+    let update = calculate_modify_liquidity(
+        &ctx.accounts.whirlpool,
+        &ctx.accounts.position,
+        &ctx.accounts.tick_array_lower,
+        &ctx.accounts.tick_array_upper,
+        liquidity_delta,
+        timestamp,
+    )?;
+
+    // The oracle has to accumulate the in-range liquidity up until now before it changes
+    if update.whirlpool_liquidity != ctx.accounts.whirlpool.liquidity {
+        update_oracle(
+            &ctx.accounts.whirlpool,
+            extension_accounts.oracle.as_ref(),
+            timestamp,
+        )?;
+    }
+
+    sync_modify_liquidity_values(
+        &mut ctx.accounts.whirlpool,
+        &mut ctx.accounts.position,
+        &ctx.accounts.tick_array_lower,
+        &ctx.accounts.tick_array_upper,
+        update,
+        timestamp,
+    )?;
+
+    let (delta_a, delta_b) = calculate_liquidity_token_deltas(
+        ctx.accounts.whirlpool.tick_current_index,
+        ctx.accounts.whirlpool.sqrt_price,
+        &ctx.accounts.position,
+        liquidity_delta,
+    )?;
+
+    let received_amount_a = calculate_transfer_fee_excluded_amount(
+        get_transfer_fee(&ctx.accounts.token_mint_a)?.as_ref(),
+        delta_a,
+    )?;
+    let received_amount_b = calculate_transfer_fee_excluded_amount(
+        get_transfer_fee(&ctx.accounts.token_mint_b)?.as_ref(),
+        delta_b,
+    )?;
+
+    if received_amount_a < token_min_a {
+        return Err(ErrorCode::TokenMinSubceeded.into());
+    } else if received_amount_b < token_min_b {
+        return Err(ErrorCode::TokenMinSubceeded.into());
+    }
+
+    transfer_from_vault_to_owner_v2(
+        &ctx.accounts.whirlpool,
+        &ctx.accounts.token_mint_a,
+        &ctx.accounts.token_vault_a,
+        &ctx.accounts.token_owner_account_a,
+        &ctx.accounts.token_program_a,
+        delta_a,
+    )?;
+
+    transfer_from_vault_to_owner_v2(
+        &ctx.accounts.whirlpool,
+        &ctx.accounts.token_mint_b,
+        &ctx.accounts.token_vault_b,
+        &ctx.accounts.token_owner_account_b,
+        &ctx.accounts.token_program_b,
+        delta_b,
+    )?;
+
+    emit!(LiquidityDecreased {
+        whirlpool: ctx.accounts.whirlpool.key(),
+        position: ctx.accounts.position.key(),
+        liquidity_amount,
+        token_amount_a: delta_a,
+        token_amount_b: delta_b,
+    });
+
+    Ok(())
+}
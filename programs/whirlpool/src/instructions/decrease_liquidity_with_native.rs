@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::util::unwrap_native;
+
+use super::decrease_liquidity;
+use super::ModifyLiquidityWithNative;
+
+/*
+  Removes liquidity from an existing Whirlpool Position into the lamport balance of the position
+  authority. The native tokens are withdrawn into a temporary RENEC account, which is closed back
+  to the position authority.
+*/
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ModifyLiquidityWithNative<'info>>,
+    liquidity_amount: u128,
+    token_min_a: u64,
+    token_min_b: u64,
+) -> ProgramResult {
+    decrease_liquidity::handler(
+        Context::new(
+            ctx.program_id,
+            &mut ctx.accounts.modify_liquidity,
+            ctx.remaining_accounts,
+        ),
+        liquidity_amount,
+        token_min_a,
+        token_min_b,
+    )?;
+
+    unwrap_native(
+        &ctx.accounts.position_authority,
+        &ctx.accounts.native_wrap_account,
+        &ctx.accounts.token_program,
+    )
+}
@@ -2,12 +2,16 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount};
 
 use crate::errors::ErrorCode;
+use crate::events::LiquidityIncreased;
 use crate::manager::liquidity_manager::{
     calculate_liquidity_token_deltas, calculate_modify_liquidity, sync_modify_liquidity_values,
 };
 use crate::math::convert_to_liquidity_delta;
 use crate::state::*;
-use crate::util::{to_timestamp_u64, transfer_from_owner_to_vault, verify_position_authority};
+use crate::util::{
+    apply_fee_rate_schedule, require_not_paused, to_timestamp_u64, transfer_from_owner_to_vault,
+    update_oracle, verify_position_authority, WhirlpoolExtensionAccounts,
+};
 
 #[derive(Accounts)]
 pub struct ModifyLiquidity<'info> {
@@ -41,10 +45,20 @@ pub struct ModifyLiquidity<'info> {
     pub tick_array_lower: AccountLoader<'info, TickArray>,
     #[account(mut, has_one = whirlpool)]
     pub tick_array_upper: AccountLoader<'info, TickArray>,
+
+    /// CHECK: nothing is paused until the pause controls of the config have been initialized
+    #[account(seeds = [b"pause_controls", whirlpool.whirlpools_config.as_ref()], bump)]
+    pub pause_controls: UncheckedAccount<'info>,
+    // remaining_accounts: the side accounts of the MODIFY_LIQUIDITY_EXTENSIONS enabled on the
+    // whirlpool
 }
 
-pub fn handler(
-    ctx: Context<ModifyLiquidity>,
+/// Extensions used to modify the liquidity of a position. The side accounts of the ones enabled on
+/// the Whirlpool are passed in `remaining_accounts`.
+pub const MODIFY_LIQUIDITY_EXTENSIONS: u8 = EXTENSION_ORACLE | EXTENSION_FEE_RATE_SCHEDULE;
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ModifyLiquidity<'info>>,
     liquidity_amount: u128,
     token_max_a: u64,
     token_max_b: u64,
@@ -54,8 +68,18 @@ pub fn handler(
         &ctx.accounts.position_authority,
     )?;
 
+    let (extension_accounts, _) = WhirlpoolExtensionAccounts::try_from(
+        &ctx.accounts.whirlpool,
+        ctx.remaining_accounts,
+        MODIFY_LIQUIDITY_EXTENSIONS,
+    )?;
     let whirlpool = &ctx.accounts.whirlpool;
     whirlpool.require_enabled()?;
+    require_not_paused(
+        &ctx.accounts.pause_controls,
+        whirlpool,
+        PAUSE_INCREASE_LIQUIDITY,
+    )?;
     let clock = Clock::get()?;
 
     if liquidity_amount == 0 {
@@ -63,6 +87,11 @@ pub fn handler(
     }
     let liquidity_delta = convert_to_liquidity_delta(liquidity_amount, true)?;
     let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    apply_fee_rate_schedule(
+        &mut ctx.accounts.whirlpool,
+        extension_accounts.fee_rate_schedule.as_ref(),
+        timestamp,
+    )?;
 
     let update = calculate_modify_liquidity(
         &ctx.accounts.whirlpool,
@@ -73,6 +102,15 @@ pub fn handler(
         timestamp,
     )?;
 
+    // The oracle has to accumulate the in-range liquidity up until now before it changes
+    if update.whirlpool_liquidity != ctx.accounts.whirlpool.liquidity {
+        update_oracle(
+            &ctx.accounts.whirlpool,
+            extension_accounts.oracle.as_ref(),
+            timestamp,
+        )?;
+    }
+
     sync_modify_liquidity_values(
         &mut ctx.accounts.whirlpool,
         &mut ctx.accounts.position,
@@ -111,5 +149,13 @@ pub fn handler(
         delta_b,
     )?;
 
+    emit!(LiquidityIncreased {
+        whirlpool: ctx.accounts.whirlpool.key(),
+        position: ctx.accounts.position.key(),
+        liquidity_amount,
+        token_amount_a: delta_a,
+        token_amount_b: delta_b,
+    });
+
     Ok(())
 }
@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ErrorCode;
+use crate::events::LiquidityIncreased;
+use crate::manager::liquidity_manager::{
+    calculate_liquidity_token_deltas, calculate_modify_liquidity, sync_modify_liquidity_values,
+};
+use crate::math::convert_to_liquidity_delta;
+use crate::state::*;
+use crate::util::{
+    apply_fee_rate_schedule, calculate_transfer_fee_included_amount, get_transfer_fee,
+    require_not_paused, to_timestamp_u64, transfer_from_owner_to_vault_v2, update_oracle,
+    verify_position_authority, WhirlpoolExtensionAccounts,
+};
+
+use super::MODIFY_LIQUIDITY_EXTENSIONS;
+
+#[derive(Accounts)]
+pub struct ModifyLiquidityV2<'info> {
+    #[account(mut)]
+    pub whirlpool: Account<'info, Whirlpool>,
+
+    /// CHECK: the token program of each mint is the program that owns it
+    #[account(address = *token_mint_a.owner)]
+    pub token_program_a: UncheckedAccount<'info>,
+    /// CHECK: the token program of each mint is the program that owns it
+    #[account(address = *token_mint_b.owner)]
+    pub token_program_b: UncheckedAccount<'info>,
+
+    pub position_authority: Signer<'info>,
+
+    #[account(mut, has_one = whirlpool)]
+    pub position: Account<'info, Position>,
+    #[account(
+        constraint = position_token_account.mint == position.position_mint,
+        constraint = position_token_account.amount == 1
+    )]
+    pub position_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: the mints of the whirlpool
+    #[account(address = whirlpool.token_mint_a)]
+    pub token_mint_a: UncheckedAccount<'info>,
+    /// CHECK: the mints of the whirlpool
+    #[account(address = whirlpool.token_mint_b)]
+    pub token_mint_b: UncheckedAccount<'info>,
+
+    /// CHECK: the mint of the token owner accounts is checked by the transfers
+    #[account(mut)]
+    pub token_owner_account_a: UncheckedAccount<'info>,
+    /// CHECK: the mint of the token owner accounts is checked by the transfers
+    #[account(mut)]
+    pub token_owner_account_b: UncheckedAccount<'info>,
+
+    /// CHECK: the vaults of the whirlpool
+    #[account(mut, address = whirlpool.token_vault_a)]
+    pub token_vault_a: UncheckedAccount<'info>,
+    /// CHECK: the vaults of the whirlpool
+    #[account(mut, address = whirlpool.token_vault_b)]
+    pub token_vault_b: UncheckedAccount<'info>,
+
+    #[account(mut, has_one = whirlpool)]
+    pub tick_array_lower: AccountLoader<'info, TickArray>,
+    #[account(mut, has_one = whirlpool)]
+    pub tick_array_upper: AccountLoader<'info, TickArray>,
+
+    /// CHECK: nothing is paused until the pause controls of the config have been initialized
+    #[account(seeds = [b"pause_controls", whirlpool.whirlpools_config.as_ref()], bump)]
+    pub pause_controls: UncheckedAccount<'info>,
+    // remaining_accounts: the side accounts of the MODIFY_LIQUIDITY_EXTENSIONS enabled on the
+    // whirlpool
+}
+
+/*
+  Adds liquidity to a Position of a Whirlpool whose mints may belong to the Token-2022 program.
+  The token maxes bound the amounts sent by the position authority, including the transfer fees.
+*/
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ModifyLiquidityV2<'info>>,
+    liquidity_amount: u128,
+    token_max_a: u64,
+    token_max_b: u64,
+) -> ProgramResult {
+    verify_position_authority(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+    )?;
+
+    let (extension_accounts, _) = WhirlpoolExtensionAccounts::try_from(
+        &ctx.accounts.whirlpool,
+        ctx.remaining_accounts,
+        MODIFY_LIQUIDITY_EXTENSIONS,
+    )?;
+    let whirlpool = &ctx.accounts.whirlpool;
+    whirlpool.require_enabled()?;
+    require_not_paused(
+        &ctx.accounts.pause_controls,
+        whirlpool,
+        PAUSE_INCREASE_LIQUIDITY,
+    )?;
+    let clock = Clock::get()?;
+
+    if liquidity_amount == 0 {
+        return Err(ErrorCode::LiquidityZero.into());
+    }
+    let liquidity_delta = convert_to_liquidity_delta(liquidity_amount, true)?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    apply_fee_rate_schedule(
+        &mut ctx.accounts.whirlpool,
+        extension_accounts.fee_rate_schedule.as_ref(),
+        timestamp,
+    )?;
+
+    let update = calculate_modify_liquidity(
+        &ctx.accounts.whirlpool,
+        &ctx.accounts.position,
+        &ctx.accounts.tick_array_lower,
+        &ctx.accounts.tick_array_upper,
+        liquidity_delta,
+        timestamp,
+    )?;
+
+    // The oracle has to accumulate the in-range liquidity up until now before it changes
+    if update.whirlpool_liquidity != ctx.accounts.whirlpool.liquidity {
+        update_oracle(
+            &ctx.accounts.whirlpool,
+            extension_accounts.oracle.as_ref(),
+            timestamp,
+        )?;
+    }
+
+    sync_modify_liquidity_values(
+        &mut ctx.accounts.whirlpool,
+        &mut ctx.accounts.position,
+        &ctx.accounts.tick_array_lower,
+        &ctx.accounts.tick_array_upper,
+        update,
+        timestamp,
+    )?;
+
+    let (delta_a, delta_b) = calculate_liquidity_token_deltas(
+        ctx.accounts.whirlpool.tick_current_index,
+        ctx.accounts.whirlpool.sqrt_price,
+        &ctx.accounts.position,
+        liquidity_delta,
+    )?;
+
+    // The vaults have to receive the token deltas once the transfer fees have been withheld
+    let transfer_amount_a = calculate_transfer_fee_included_amount(
+        get_transfer_fee(&ctx.accounts.token_mint_a)?.as_ref(),
+        delta_a,
+    )?;
+    let transfer_amount_b = calculate_transfer_fee_included_amount(
+        get_transfer_fee(&ctx.accounts.token_mint_b)?.as_ref(),
+        delta_b,
+    )?;
+
+    if transfer_amount_a > token_max_a {
+        return Err(ErrorCode::TokenMaxExceeded.into());
+    } else if transfer_amount_b > token_max_b {
+        return Err(ErrorCode::TokenMaxExceeded.into());
+    }
+
+    transfer_from_owner_to_vault_v2(
+        &ctx.accounts.position_authority,
+        &ctx.accounts.token_mint_a,
+        &ctx.accounts.token_owner_account_a,
+        &ctx.accounts.token_vault_a,
+        &ctx.accounts.token_program_a,
+        transfer_amount_a,
+    )?;
+
+    transfer_from_owner_to_vault_v2(
+        &ctx.accounts.position_authority,
+        &ctx.accounts.token_mint_b,
+        &ctx.accounts.token_owner_account_b,
+        &ctx.accounts.token_vault_b,
+        &ctx.accounts.token_program_b,
+        transfer_amount_b,
+    )?;
+
+    emit!(LiquidityIncreased {
+        whirlpool: ctx.accounts.whirlpool.key(),
+        position: ctx.accounts.position.key(),
+        liquidity_amount,
+        token_amount_a: transfer_amount_a,
+        token_amount_b: transfer_amount_b,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+use crate::util::{unwrap_native, wrap_native};
+
+use super::increase_liquidity::{self, ModifyLiquidity};
+
+#[derive(Accounts)]
+pub struct ModifyLiquidityWithNative<'info> {
+    #[account(mut)]
+    pub position_authority: Signer<'info>,
+
+    #[account(address = spl_token::native_mint::ID)]
+    pub native_mint: Account<'info, Mint>,
+
+    #[account(init,
+      payer = position_authority,
+      seeds = [b"native_wrap", position_authority.key().as_ref()],
+      bump,
+      token::mint = native_mint,
+      token::authority = position_authority,
+    )]
+    pub native_wrap_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+
+    // The native wrap account is passed as the token owner account of the native mint
+    #[account(
+      constraint = modify_liquidity.position_authority.key() == position_authority.key(),
+      constraint = modify_liquidity.whirlpool.token_mint_a != spl_token::native_mint::ID
+        || modify_liquidity.token_owner_account_a.key() == native_wrap_account.key(),
+      constraint = modify_liquidity.whirlpool.token_mint_b != spl_token::native_mint::ID
+        || modify_liquidity.token_owner_account_b.key() == native_wrap_account.key(),
+    )]
+    pub modify_liquidity: ModifyLiquidity<'info>,
+}
+
+/*
+  Adds liquidity to an existing Whirlpool Position from the lamport balance of the position
+  authority. Up to the token max of the native mint is wrapped into a temporary RENEC account,
+  which is closed back to the position authority once the liquidity has been deposited.
+*/
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ModifyLiquidityWithNative<'info>>,
+    liquidity_amount: u128,
+    token_max_a: u64,
+    token_max_b: u64,
+) -> ProgramResult {
+    let whirlpool = &ctx.accounts.modify_liquidity.whirlpool;
+    let native_amount = if whirlpool.token_mint_a == spl_token::native_mint::ID {
+        token_max_a
+    } else if whirlpool.token_mint_b == spl_token::native_mint::ID {
+        token_max_b
+    } else {
+        0
+    };
+
+    wrap_native(
+        &ctx.accounts.position_authority,
+        &ctx.accounts.native_wrap_account,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        native_amount,
+    )?;
+
+    increase_liquidity::handler(
+        Context::new(
+            ctx.program_id,
+            &mut ctx.accounts.modify_liquidity,
+            ctx.remaining_accounts,
+        ),
+        liquidity_amount,
+        token_max_a,
+        token_max_b,
+    )?;
+
+    unwrap_native(
+        &ctx.accounts.position_authority,
+        &ctx.accounts.native_wrap_account,
+        &ctx.accounts.token_program,
+    )
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{events::ObservationCardinalityIncreased, state::*, util::grow_account};
+
+#[derive(Accounts)]
+pub struct IncreaseObservationCardinality<'info> {
+    pub whirlpool: Account<'info, Whirlpool>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(mut,
+      has_one = whirlpool,
+      seeds = [b"oracle", whirlpool.key().as_ref()],
+      bump)]
+    pub oracle: AccountLoader<'info, Oracle>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<IncreaseObservationCardinality>,
+    observation_cardinality_next: u16,
+) -> ProgramResult {
+    ctx.accounts
+        .oracle
+        .load_mut()?
+        .grow(observation_cardinality_next)?;
+
+    // Make room for the new observations, with the additional rent paid by the funder
+    grow_account(
+        &ctx.accounts.oracle.to_account_info(),
+        Oracle::space(observation_cardinality_next),
+        &ctx.accounts.funder,
+        &ctx.accounts.system_program,
+    )?;
+
+    emit!(ObservationCardinalityIncreased {
+        whirlpool: ctx.accounts.whirlpool.key(),
+        observation_cardinality_next,
+    });
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 
+use crate::events::ConfigInitialized;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -23,11 +24,22 @@ pub fn handler(
 ) -> ProgramResult {
     let config = &mut ctx.accounts.config;
 
-    Ok(config.initialize(
+    config.initialize(
         fee_authority,
         collect_protocol_fees_authority,
         reward_emissions_super_authority,
         pool_creator_authority,
         default_protocol_fee_rate,
-    )?)
+    )?;
+
+    emit!(ConfigInitialized {
+        whirlpools_config: config.key(),
+        fee_authority,
+        collect_protocol_fees_authority,
+        reward_emissions_super_authority,
+        pool_creator_authority,
+        default_protocol_fee_rate,
+    });
+
+    Ok(())
 }
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::DynamicFeeParamsUpdated,
+    state::{DynamicFee, Whirlpool, WhirlpoolsConfig, EXTENSION_DYNAMIC_FEE},
+    util::{enable_whirlpool_extension, to_timestamp_u64},
+};
+
+#[derive(Accounts)]
+pub struct InitializeDynamicFee<'info> {
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(mut, has_one = whirlpools_config)]
+    pub whirlpool: Account<'info, Whirlpool>,
+
+    #[account(address = whirlpools_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+      init,
+      payer = funder,
+      seeds = [b"dynamic_fee", whirlpool.key().as_ref()],
+      bump,
+      space = DynamicFee::LEN)]
+    pub dynamic_fee: Account<'info, DynamicFee>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeDynamicFee>,
+    base_surcharge_rate: u16,
+    max_surcharge_rate: u16,
+    decay_period: u64,
+) -> ProgramResult {
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    ctx.accounts.dynamic_fee.initialize(
+        &ctx.accounts.whirlpool,
+        base_surcharge_rate,
+        max_surcharge_rate,
+        decay_period,
+        timestamp,
+    )?;
+    emit!(DynamicFeeParamsUpdated {
+        whirlpool: ctx.accounts.whirlpool.key(),
+        base_surcharge_rate,
+        max_surcharge_rate,
+        decay_period,
+    });
+
+    enable_whirlpool_extension(
+        ctx.accounts.whirlpool.as_ref(),
+        &ctx.accounts.funder,
+        &ctx.accounts.system_program,
+        EXTENSION_DYNAMIC_FEE,
+    )
+}
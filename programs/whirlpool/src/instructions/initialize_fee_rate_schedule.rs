@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::FeeRateScheduleInitialized,
+    state::{FeeRateSchedule, Whirlpool, WhirlpoolsConfig, EXTENSION_FEE_RATE_SCHEDULE},
+    util::enable_whirlpool_extension,
+};
+
+#[derive(Accounts)]
+pub struct InitializeFeeRateSchedule<'info> {
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(mut, has_one = whirlpools_config)]
+    pub whirlpool: Account<'info, Whirlpool>,
+
+    #[account(address = whirlpools_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+      init,
+      payer = funder,
+      seeds = [b"fee_rate_schedule", whirlpool.key().as_ref()],
+      bump,
+      space = FeeRateSchedule::LEN)]
+    pub fee_rate_schedule: Account<'info, FeeRateSchedule>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeFeeRateSchedule>) -> ProgramResult {
+    ctx.accounts
+        .fee_rate_schedule
+        .initialize(&ctx.accounts.whirlpool);
+
+    emit!(FeeRateScheduleInitialized {
+        whirlpool: ctx.accounts.whirlpool.key(),
+    });
+
+    enable_whirlpool_extension(
+        ctx.accounts.whirlpool.as_ref(),
+        &ctx.accounts.funder,
+        &ctx.accounts.system_program,
+        EXTENSION_FEE_RATE_SCHEDULE,
+    )
+}
@@ -1,3 +1,5 @@
+use crate::errors::ErrorCode;
+use crate::events::FeeTierInitialized;
 use crate::state::*;
 use anchor_lang::prelude::*;
 
@@ -20,6 +22,10 @@ pub struct InitializeFeeTier<'info> {
     #[account(address = config.fee_authority)]
     pub fee_authority: Signer<'info>,
 
+    /// CHECK: the fee tier policy is only enforced once it has been initialized
+    #[account(seeds = [b"fee_tier_policy", config.key().as_ref()], bump)]
+    pub fee_tier_policy: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -28,8 +34,23 @@ pub fn handler(
     tick_spacing: u16,
     default_fee_rate: u16,
 ) -> ProgramResult {
-    Ok(ctx
-        .accounts
+    let fee_tier_policy = &ctx.accounts.fee_tier_policy;
+    if fee_tier_policy.owner == &crate::ID && !fee_tier_policy.data_is_empty() {
+        let fee_tier_policy: Account<FeeTierPolicy> = Account::try_from(fee_tier_policy)?;
+        if !fee_tier_policy.is_tick_spacing_allowed(tick_spacing) {
+            return Err(ErrorCode::InvalidTickSpacing.into());
+        }
+    }
+
+    ctx.accounts
         .fee_tier
-        .initialize(&ctx.accounts.config, tick_spacing, default_fee_rate)?)
+        .initialize(&ctx.accounts.config, tick_spacing, default_fee_rate)?;
+
+    emit!(FeeTierInitialized {
+        whirlpools_config: ctx.accounts.config.key(),
+        tick_spacing,
+        default_fee_rate,
+    });
+
+    Ok(())
 }
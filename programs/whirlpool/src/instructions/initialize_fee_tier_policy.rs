@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::events::FeeTierPolicyInitialized;
+use crate::state::{FeeTierPolicy, WhirlpoolsConfig};
+
+#[derive(Accounts)]
+pub struct InitializeFeeTierPolicy<'info> {
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(address = whirlpools_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+      init,
+      payer = funder,
+      seeds = [b"fee_tier_policy", whirlpools_config.key().as_ref()],
+      bump,
+      space = FeeTierPolicy::LEN)]
+    pub fee_tier_policy: Account<'info, FeeTierPolicy>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeFeeTierPolicy>) -> ProgramResult {
+    ctx.accounts
+        .fee_tier_policy
+        .initialize(&ctx.accounts.whirlpools_config);
+
+    emit!(FeeTierPolicyInitialized {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+    });
+
+    Ok(())
+}
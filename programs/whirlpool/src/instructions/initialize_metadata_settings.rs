@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::events::MetadataSettingsUpdated;
+use crate::state::{MetadataSettings, WhirlpoolsConfig};
+
+#[derive(Accounts)]
+pub struct InitializeMetadataSettings<'info> {
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(address = whirlpools_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+      init,
+      payer = funder,
+      seeds = [b"metadata_settings", whirlpools_config.key().as_ref()],
+      bump,
+      space = MetadataSettings::LEN)]
+    pub metadata_settings: Account<'info, MetadataSettings>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeMetadataSettings>,
+    update_authority: Pubkey,
+    name: String,
+    symbol: String,
+    uri_template: String,
+) -> ProgramResult {
+    // The bump is stored to sign for the collection NFT of the config
+    let (_, bump) = Pubkey::find_program_address(
+        &[
+            b"metadata_settings",
+            ctx.accounts.whirlpools_config.key().as_ref(),
+        ],
+        &crate::ID,
+    );
+
+    ctx.accounts.metadata_settings.initialize(
+        &ctx.accounts.whirlpools_config,
+        bump,
+        update_authority,
+        name,
+        symbol,
+        uri_template,
+    )?;
+
+    let metadata_settings = &ctx.accounts.metadata_settings;
+    emit!(MetadataSettingsUpdated {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        update_authority,
+        name: metadata_settings.name.clone(),
+        symbol: metadata_settings.symbol.clone(),
+        uri_template: metadata_settings.uri_template.clone(),
+    });
+    Ok(())
+}
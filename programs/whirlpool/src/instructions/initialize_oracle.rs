@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::OracleInitialized,
+    state::*,
+    util::{enable_whirlpool_extension, to_timestamp_u64},
+};
+
+#[derive(Accounts)]
+pub struct InitializeOracle<'info> {
+    #[account(mut)]
+    pub whirlpool: Account<'info, Whirlpool>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+      init,
+      payer = funder,
+      seeds = [b"oracle", whirlpool.key().as_ref()],
+      bump,
+      space = Oracle::LEN)]
+    pub oracle: AccountLoader<'info, Oracle>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeOracle>) -> ProgramResult {
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    let oracle_info = ctx.accounts.oracle.to_account_info();
+    {
+        let mut data = oracle_info.try_borrow_mut_data()?;
+        let (oracle, observations) = Oracle::split_data_mut(&mut data);
+        oracle.initialize(observations, &ctx.accounts.whirlpool, timestamp)?;
+    }
+
+    emit!(OracleInitialized {
+        whirlpool: ctx.accounts.whirlpool.key(),
+    });
+
+    enable_whirlpool_extension(
+        ctx.accounts.whirlpool.as_ref(),
+        &ctx.accounts.funder,
+        &ctx.accounts.system_program,
+        EXTENSION_ORACLE,
+    )
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::events::PauseControlsInitialized;
+use crate::state::{PauseControls, WhirlpoolsConfig};
+
+#[derive(Accounts)]
+pub struct InitializePauseControls<'info> {
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(address = whirlpools_config.pool_creator_authority)]
+    pub pool_creator_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+      init,
+      payer = funder,
+      seeds = [b"pause_controls", whirlpools_config.key().as_ref()],
+      bump,
+      space = PauseControls::LEN)]
+    pub pause_controls: Account<'info, PauseControls>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializePauseControls>) -> ProgramResult {
+    ctx.accounts
+        .pause_controls
+        .initialize(&ctx.accounts.whirlpools_config);
+
+    emit!(PauseControlsInitialized {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::events::PendingAuthoritiesInitialized;
+use crate::state::{PendingAuthorities, WhirlpoolsConfig};
+
+#[derive(Accounts)]
+pub struct InitializePendingAuthorities<'info> {
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+      init,
+      payer = funder,
+      seeds = [b"pending_authorities", whirlpools_config.key().as_ref()],
+      bump,
+      space = PendingAuthorities::LEN)]
+    pub pending_authorities: Account<'info, PendingAuthorities>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializePendingAuthorities>) -> ProgramResult {
+    ctx.accounts
+        .pending_authorities
+        .initialize(&ctx.accounts.whirlpools_config);
+
+    emit!(PendingAuthoritiesInitialized {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+    });
+
+    Ok(())
+}
@@ -1,4 +1,6 @@
+use crate::events::PoolInitialized;
 use crate::state::*;
+use crate::util::migrate_legacy_fee_tier;
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
 
@@ -13,6 +15,8 @@ pub struct InitializePool<'info> {
     #[account(mut)]
     pub funder: Signer<'info>,
 
+    pub pool_creator: Signer<'info>,
+
     #[account(init,
       seeds = [
         b"whirlpool".as_ref(),
@@ -23,7 +27,7 @@ pub struct InitializePool<'info> {
       ],
       bump = bumps.whirlpool_bump,
       payer = funder,
-      space = Whirlpool::LEN)]
+      space = Whirlpool::LEN_WITH_EXTENSIONS)]
     pub whirlpool: Box<Account<'info, Whirlpool>>,
 
     #[account(init,
@@ -38,8 +42,19 @@ pub struct InitializePool<'info> {
       token::authority = whirlpool)]
     pub token_vault_b: Box<Account<'info, TokenAccount>>,
 
-    #[account(has_one = whirlpools_config)]
-    pub fee_tier: Account<'info, FeeTier>,
+    /// CHECK: fee tiers created before pools were counted are migrated in the handler
+    #[account(mut,
+      seeds = [b"fee_tier", whirlpools_config.key().as_ref(), tick_spacing.to_le_bytes().as_ref()],
+      bump)]
+    pub fee_tier: UncheckedAccount<'info>,
+
+    /// CHECK: the pool creation policy is only enforced once it has been initialized
+    #[account(seeds = [b"pool_creation_policy", whirlpools_config.key().as_ref()], bump)]
+    pub pool_creation_policy: UncheckedAccount<'info>,
+
+    /// CHECK: the fee tier policy is only enforced once it has been initialized
+    #[account(seeds = [b"fee_tier_policy", whirlpools_config.key().as_ref()], bump)]
+    pub fee_tier_policy: UncheckedAccount<'info>,
 
     #[account(address = token::ID)]
     pub token_program: Program<'info, Token>,
@@ -59,9 +74,20 @@ pub fn handler(
     let whirlpool = &mut ctx.accounts.whirlpool;
     let whirlpools_config = &ctx.accounts.whirlpools_config;
 
-    let default_fee_rate = ctx.accounts.fee_tier.default_fee_rate;
+    let default_fee_rate = register_pool(
+        whirlpools_config,
+        &ctx.accounts.pool_creator,
+        &token_mint_a,
+        &token_mint_b,
+        tick_spacing,
+        &ctx.accounts.fee_tier,
+        &ctx.accounts.pool_creation_policy,
+        &ctx.accounts.fee_tier_policy,
+        &ctx.accounts.funder,
+        &ctx.accounts.system_program,
+    )?;
 
-    Ok(whirlpool.initialize(
+    whirlpool.initialize(
         whirlpools_config,
         bumps.whirlpool_bump,
         tick_spacing,
@@ -71,5 +97,56 @@ pub fn handler(
         ctx.accounts.token_vault_a.key(),
         token_mint_b,
         ctx.accounts.token_vault_b.key(),
-    )?)
+    )?;
+
+    emit!(PoolInitialized {
+        whirlpools_config: whirlpools_config.key(),
+        whirlpool: whirlpool.key(),
+        token_mint_a,
+        token_mint_b,
+        tick_spacing,
+        sqrt_price: initial_sqrt_price,
+    });
+
+    Ok(())
+}
+
+/// Enforce the pool creation and fee tier policies of the config on a new pool and count the pool
+/// in its fee tier. Returns the default fee rate of the fee tier.
+pub fn register_pool<'info>(
+    whirlpools_config: &Account<'info, WhirlpoolsConfig>,
+    pool_creator: &Signer<'info>,
+    token_mint_a: &Pubkey,
+    token_mint_b: &Pubkey,
+    tick_spacing: u16,
+    fee_tier: &AccountInfo<'info>,
+    pool_creation_policy: &AccountInfo<'info>,
+    fee_tier_policy: &AccountInfo<'info>,
+    funder: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<u16, ProgramError> {
+    if pool_creation_policy.owner == &crate::ID && !pool_creation_policy.data_is_empty() {
+        let pool_creation_policy: Account<PoolCreationPolicy> =
+            Account::try_from(pool_creation_policy)?;
+        pool_creation_policy.validate_pool_creation(
+            pool_creator.key,
+            &whirlpools_config.pool_creator_authority,
+            token_mint_a,
+            token_mint_b,
+        )?;
+    }
+
+    if fee_tier_policy.owner == &crate::ID && !fee_tier_policy.data_is_empty() {
+        let fee_tier_policy: Account<FeeTierPolicy> = Account::try_from(fee_tier_policy)?;
+        fee_tier_policy.validate_pool_tick_spacing(tick_spacing)?;
+    }
+
+    if fee_tier.data_len() == FeeTier::LEGACY_LEN {
+        migrate_legacy_fee_tier(fee_tier, &whirlpools_config.key(), funder, system_program)?;
+    }
+    let mut fee_tier: Account<FeeTier> = Account::try_from(fee_tier)?;
+    let default_fee_rate = fee_tier.default_fee_rate;
+    fee_tier.increment_pool_count();
+    fee_tier.exit(&crate::ID)?;
+    Ok(default_fee_rate)
 }
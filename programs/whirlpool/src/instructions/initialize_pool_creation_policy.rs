@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::events::PoolCreationModeUpdated;
+use crate::state::{PoolCreationPolicy, WhirlpoolsConfig};
+
+#[derive(Accounts)]
+pub struct InitializePoolCreationPolicy<'info> {
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(address = whirlpools_config.pool_creator_authority)]
+    pub pool_creator_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+      init,
+      payer = funder,
+      seeds = [b"pool_creation_policy", whirlpools_config.key().as_ref()],
+      bump,
+      space = PoolCreationPolicy::LEN)]
+    pub pool_creation_policy: Account<'info, PoolCreationPolicy>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializePoolCreationPolicy>, mode: u8) -> ProgramResult {
+    ctx.accounts
+        .pool_creation_policy
+        .initialize(&ctx.accounts.whirlpools_config, mode)?;
+    emit!(PoolCreationModeUpdated {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        mode,
+    });
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use crate::events::PoolInitialized;
+use crate::state::*;
+use crate::util::{initialize_token_vault, load_mint, validate_mint_extensions};
+use anchor_lang::prelude::*;
+
+use super::register_pool;
+
+#[derive(Accounts)]
+#[instruction(bumps: WhirlpoolBumps, tick_spacing: u16)]
+pub struct InitializePoolV2<'info> {
+    pub whirlpools_config: Box<Account<'info, WhirlpoolsConfig>>,
+
+    /// CHECK: the mints are loaded and their extensions are checked in the handler
+    pub token_mint_a: UncheckedAccount<'info>,
+    /// CHECK: the mints are loaded and their extensions are checked in the handler
+    pub token_mint_b: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub pool_creator: Signer<'info>,
+
+    #[account(init,
+      seeds = [
+        b"whirlpool".as_ref(),
+        whirlpools_config.key().as_ref(),
+        token_mint_a.key().as_ref(),
+        token_mint_b.key().as_ref(),
+        tick_spacing.to_le_bytes().as_ref()
+      ],
+      bump = bumps.whirlpool_bump,
+      payer = funder,
+      space = Whirlpool::LEN_WITH_EXTENSIONS)]
+    pub whirlpool: Box<Account<'info, Whirlpool>>,
+
+    // The vaults are created in the handler, sized for the account extensions of their mint
+    #[account(mut)]
+    pub token_vault_a: Signer<'info>,
+    #[account(mut)]
+    pub token_vault_b: Signer<'info>,
+
+    /// CHECK: fee tiers created before pools were counted are migrated in the handler
+    #[account(mut,
+      seeds = [b"fee_tier", whirlpools_config.key().as_ref(), tick_spacing.to_le_bytes().as_ref()],
+      bump)]
+    pub fee_tier: UncheckedAccount<'info>,
+
+    /// CHECK: the pool creation policy is only enforced once it has been initialized
+    #[account(seeds = [b"pool_creation_policy", whirlpools_config.key().as_ref()], bump)]
+    pub pool_creation_policy: UncheckedAccount<'info>,
+
+    /// CHECK: the fee tier policy is only enforced once it has been initialized
+    #[account(seeds = [b"fee_tier_policy", whirlpools_config.key().as_ref()], bump)]
+    pub fee_tier_policy: UncheckedAccount<'info>,
+
+    /// CHECK: the token program of each mint is the program that owns it
+    #[account(address = *token_mint_a.owner)]
+    pub token_program_a: UncheckedAccount<'info>,
+    /// CHECK: the token program of each mint is the program that owns it
+    #[account(address = *token_mint_b.owner)]
+    pub token_program_b: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/*
+  Initializes a Whirlpool whose mints belong to the token program or the Token-2022 program.
+*/
+pub fn handler(
+    ctx: Context<InitializePoolV2>,
+    bumps: WhirlpoolBumps,
+    tick_spacing: u16,
+    initial_sqrt_price: u128,
+) -> ProgramResult {
+    let token_mint_a = ctx.accounts.token_mint_a.key();
+    let token_mint_b = ctx.accounts.token_mint_b.key();
+
+    for token_mint in [&ctx.accounts.token_mint_a, &ctx.accounts.token_mint_b] {
+        validate_mint_extensions(token_mint)?;
+        load_mint(token_mint)?;
+    }
+
+    let whirlpools_config = &ctx.accounts.whirlpools_config;
+    let default_fee_rate = register_pool(
+        whirlpools_config,
+        &ctx.accounts.pool_creator,
+        &token_mint_a,
+        &token_mint_b,
+        tick_spacing,
+        &ctx.accounts.fee_tier,
+        &ctx.accounts.pool_creation_policy,
+        &ctx.accounts.fee_tier_policy,
+        &ctx.accounts.funder,
+        &ctx.accounts.system_program,
+    )?;
+
+    let whirlpool = &mut ctx.accounts.whirlpool;
+    whirlpool.initialize(
+        whirlpools_config,
+        bumps.whirlpool_bump,
+        tick_spacing,
+        initial_sqrt_price,
+        default_fee_rate,
+        token_mint_a,
+        ctx.accounts.token_vault_a.key(),
+        token_mint_b,
+        ctx.accounts.token_vault_b.key(),
+    )?;
+
+    initialize_token_vault(
+        whirlpool,
+        &ctx.accounts.token_vault_a,
+        &ctx.accounts.token_mint_a,
+        &ctx.accounts.token_program_a,
+        &ctx.accounts.funder,
+        &ctx.accounts.system_program,
+        &ctx.accounts.rent,
+    )?;
+
+    initialize_token_vault(
+        whirlpool,
+        &ctx.accounts.token_vault_b,
+        &ctx.accounts.token_mint_b,
+        &ctx.accounts.token_program_b,
+        &ctx.accounts.funder,
+        &ctx.accounts.system_program,
+        &ctx.accounts.rent,
+    )?;
+
+    emit!(PoolInitialized {
+        whirlpools_config: whirlpools_config.key(),
+        whirlpool: whirlpool.key(),
+        token_mint_a,
+        token_mint_b,
+        tick_spacing,
+        sqrt_price: initial_sqrt_price,
+    });
+
+    Ok(())
+}
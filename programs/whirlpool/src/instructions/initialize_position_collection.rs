@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+use crate::events::PositionCollectionInitialized;
+use crate::state::{check_metadata_lengths, MetadataSettings, WhirlpoolsConfig};
+use crate::util::mint_position_collection;
+
+#[derive(Accounts)]
+pub struct InitializePositionCollection<'info> {
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(address = whirlpools_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+      mut,
+      seeds = [b"metadata_settings", whirlpools_config.key().as_ref()],
+      bump,
+      has_one = whirlpools_config,
+    )]
+    pub metadata_settings: Box<Account<'info, MetadataSettings>>,
+
+    #[account(init,
+      payer = funder,
+      seeds = [b"position_collection", whirlpools_config.key().as_ref()],
+      bump,
+      space = Mint::LEN,
+      mint::authority = metadata_settings,
+      mint::decimals = 0,
+    )]
+    pub collection_mint: Account<'info, Mint>,
+
+    #[account(init,
+      payer = funder,
+      associated_token::mint = collection_mint,
+      associated_token::authority = metadata_settings,
+    )]
+    pub collection_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: checked via the Metadata CPI call
+    #[account(mut)]
+    pub collection_metadata_account: UncheckedAccount<'info>,
+
+    /// CHECK: checked via the Metadata CPI call
+    #[account(mut)]
+    pub collection_master_edition_account: UncheckedAccount<'info>,
+
+    /// CHECK: checked via account constraints
+    #[account(address = mpl_token_metadata::ID)]
+    pub metadata_program: UncheckedAccount<'info>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/*
+  Mints the collection NFT of the position NFTs of a WhirlpoolsConfig. Positions opened with
+  metadata afterwards are verified as members of the collection.
+*/
+pub fn handler(
+    ctx: Context<InitializePositionCollection>,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> ProgramResult {
+    check_metadata_lengths(&name, &symbol, &uri)?;
+
+    mint_position_collection(
+        &ctx.accounts.metadata_settings,
+        &ctx.accounts.collection_mint,
+        &ctx.accounts.collection_token_account,
+        &ctx.accounts.collection_metadata_account,
+        &ctx.accounts.collection_master_edition_account,
+        &ctx.accounts.funder,
+        &ctx.accounts.metadata_program,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        &ctx.accounts.rent,
+        name,
+        symbol,
+        uri,
+    )?;
+
+    let collection_mint = ctx.accounts.collection_mint.key();
+    ctx.accounts
+        .metadata_settings
+        .set_collection_mint(collection_mint);
+
+    emit!(PositionCollectionInitialized {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        collection_mint,
+    });
+    Ok(())
+}
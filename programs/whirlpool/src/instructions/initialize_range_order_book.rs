@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::RangeOrderBookInitialized,
+    state::{RangeOrderBook, Whirlpool, EXTENSION_RANGE_ORDER_BOOK},
+    util::enable_whirlpool_extension,
+};
+
+#[derive(Accounts)]
+pub struct InitializeRangeOrderBook<'info> {
+    #[account(mut)]
+    pub whirlpool: Account<'info, Whirlpool>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+      init,
+      payer = funder,
+      seeds = [b"range_order_book", whirlpool.key().as_ref()],
+      bump,
+      space = RangeOrderBook::LEN)]
+    pub range_order_book: Account<'info, RangeOrderBook>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeRangeOrderBook>) -> ProgramResult {
+    ctx.accounts
+        .range_order_book
+        .initialize(&ctx.accounts.whirlpool);
+
+    emit!(RangeOrderBookInitialized {
+        whirlpool: ctx.accounts.whirlpool.key(),
+    });
+
+    enable_whirlpool_extension(
+        ctx.accounts.whirlpool.as_ref(),
+        &ctx.accounts.funder,
+        &ctx.accounts.system_program,
+        EXTENSION_RANGE_ORDER_BOOK,
+    )
+}
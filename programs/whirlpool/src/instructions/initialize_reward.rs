@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
 
+use crate::events::RewardInitialized;
 use crate::state::Whirlpool;
 
 #[derive(Accounts)]
@@ -34,9 +35,17 @@ pub struct InitializeReward<'info> {
 pub fn handler(ctx: Context<InitializeReward>, reward_index: u8) -> ProgramResult {
     let whirlpool = &mut ctx.accounts.whirlpool;
 
-    Ok(whirlpool.initialize_reward(
+    whirlpool.initialize_reward(
         reward_index as usize,
         ctx.accounts.reward_mint.key(),
         ctx.accounts.reward_vault.key(),
-    )?)
+    )?;
+
+    emit!(RewardInitialized {
+        whirlpool: whirlpool.key(),
+        reward_index,
+        reward_mint: ctx.accounts.reward_mint.key(),
+    });
+
+    Ok(())
 }
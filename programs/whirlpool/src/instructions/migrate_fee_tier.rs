@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::state::WhirlpoolsConfig;
+use crate::util::migrate_legacy_fee_tier;
+
+#[derive(Accounts)]
+pub struct MigrateFeeTier<'info> {
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    /// CHECK: a fee tier of the config created before pools were counted, validated in the handler
+    #[account(mut)]
+    pub fee_tier: UncheckedAccount<'info>,
+
+    #[account(address = whirlpools_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/*
+   Grows a FeeTier created before pools were counted. The pools initialized with it before can not
+   be counted on-chain, so the fee tier keeps an untracked pool count and can never be closed.
+   initialize_pool migrates legacy fee tiers as well, this lets the fee authority do it up front.
+*/
+pub fn handler(ctx: Context<MigrateFeeTier>) -> ProgramResult {
+    migrate_legacy_fee_tier(
+        ctx.accounts.fee_tier.as_ref(),
+        &ctx.accounts.whirlpools_config.key(),
+        &ctx.accounts.funder,
+        &ctx.accounts.system_program,
+    )
+}
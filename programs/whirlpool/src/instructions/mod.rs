@@ -1,57 +1,159 @@
+pub mod accept_collect_protocol_fees_authority;
+pub mod accept_fee_authority;
+pub mod accept_pool_creator_authority;
+pub mod accept_reward_emissions_super_authority;
+pub mod add_allowed_mint;
+pub mod cancel_collect_protocol_fees_authority_transfer;
+pub mod cancel_fee_authority_transfer;
+pub mod cancel_fee_rate_change;
+pub mod cancel_pool_creator_authority_transfer;
+pub mod cancel_reward_emissions_super_authority_transfer;
+pub mod close_fee_tier;
 pub mod close_position;
 pub mod collect_fees;
+pub mod collect_fees_v2;
 pub mod collect_protocol_fees;
+pub mod collect_protocol_fees_v2;
 pub mod collect_reward;
 pub mod decrease_liquidity;
+pub mod decrease_liquidity_v2;
+pub mod decrease_liquidity_with_native;
 pub mod increase_liquidity;
+pub mod increase_liquidity_v2;
+pub mod increase_liquidity_with_native;
+pub mod increase_observation_cardinality;
 pub mod initialize_config;
+pub mod initialize_dynamic_fee;
+pub mod initialize_fee_rate_schedule;
 pub mod initialize_fee_tier;
+pub mod initialize_fee_tier_policy;
+pub mod initialize_metadata_settings;
+pub mod initialize_oracle;
+pub mod initialize_pause_controls;
+pub mod initialize_pending_authorities;
 pub mod initialize_pool;
+pub mod initialize_pool_v2;
+pub mod initialize_pool_creation_policy;
+pub mod initialize_position_collection;
+pub mod initialize_range_order_book;
 pub mod initialize_reward;
 pub mod initialize_tick_array;
+pub mod migrate_fee_tier;
+pub mod observe;
 pub mod open_position;
 pub mod open_position_with_metadata;
+pub mod place_range_order;
+pub mod remove_allowed_mint;
+pub mod set_allowed_tick_spacings;
 pub mod set_collect_protocol_fees_authority;
+pub mod set_config_paused_operations;
 pub mod set_default_fee_rate;
 pub mod set_default_protocol_fee_rate;
+pub mod set_dynamic_fee_params;
 pub mod set_fee_authority;
 pub mod set_fee_rate;
+pub mod set_fee_tier_enabled;
+pub mod set_metadata_settings;
 pub mod set_pool_creator_authority;
+pub mod set_pool_creation_mode;
+pub mod set_pool_paused_operations;
 pub mod set_protocol_fee_rate;
 pub mod set_reward_authority;
 pub mod set_reward_authority_by_super_authority;
 pub mod set_reward_emissions;
 pub mod set_reward_emissions_super_authority;
+pub mod quote_swap;
+pub mod quote_two_hop_swap;
+pub mod rebalance_position;
+pub mod route_swap;
+pub mod schedule_fee_rate_change;
+pub mod split_swap;
 pub mod swap;
+pub mod swap_v2;
+pub mod swap_with_native;
 pub mod two_hop_swap;
+pub mod two_hop_swap_with_native;
 pub mod update_fees_and_rewards;
+pub mod update_position_metadata;
+pub mod withdraw_range_order;
 pub mod set_enable_flag;
 
+pub use accept_collect_protocol_fees_authority::*;
+pub use accept_fee_authority::*;
+pub use accept_pool_creator_authority::*;
+pub use accept_reward_emissions_super_authority::*;
+pub use add_allowed_mint::*;
+pub use cancel_collect_protocol_fees_authority_transfer::*;
+pub use cancel_fee_authority_transfer::*;
+pub use cancel_fee_rate_change::*;
+pub use cancel_pool_creator_authority_transfer::*;
+pub use cancel_reward_emissions_super_authority_transfer::*;
+pub use close_fee_tier::*;
 pub use close_position::*;
 pub use collect_fees::*;
+pub use collect_fees_v2::*;
 pub use collect_protocol_fees::*;
+pub use collect_protocol_fees_v2::*;
 pub use collect_reward::*;
 pub use decrease_liquidity::*;
+pub use decrease_liquidity_v2::*;
+pub use decrease_liquidity_with_native::*;
 pub use increase_liquidity::*;
+pub use increase_liquidity_v2::*;
+pub use increase_liquidity_with_native::*;
+pub use increase_observation_cardinality::*;
 pub use initialize_config::*;
+pub use initialize_dynamic_fee::*;
+pub use initialize_fee_rate_schedule::*;
 pub use initialize_fee_tier::*;
+pub use initialize_fee_tier_policy::*;
+pub use initialize_metadata_settings::*;
+pub use initialize_oracle::*;
+pub use initialize_pause_controls::*;
+pub use initialize_pending_authorities::*;
 pub use initialize_pool::*;
+pub use initialize_pool_v2::*;
+pub use initialize_pool_creation_policy::*;
+pub use initialize_position_collection::*;
+pub use initialize_range_order_book::*;
 pub use initialize_reward::*;
 pub use initialize_tick_array::*;
+pub use migrate_fee_tier::*;
+pub use observe::*;
 pub use open_position::*;
 pub use open_position_with_metadata::*;
+pub use place_range_order::*;
+pub use remove_allowed_mint::*;
+pub use set_allowed_tick_spacings::*;
 pub use set_collect_protocol_fees_authority::*;
+pub use set_config_paused_operations::*;
 pub use set_default_fee_rate::*;
 pub use set_default_protocol_fee_rate::*;
+pub use set_dynamic_fee_params::*;
 pub use set_fee_authority::*;
 pub use set_pool_creator_authority::*;
+pub use set_pool_creation_mode::*;
+pub use set_pool_paused_operations::*;
 pub use set_fee_rate::*;
+pub use set_fee_tier_enabled::*;
+pub use set_metadata_settings::*;
 pub use set_protocol_fee_rate::*;
 pub use set_reward_authority::*;
 pub use set_reward_authority_by_super_authority::*;
 pub use set_reward_emissions::*;
 pub use set_reward_emissions_super_authority::*;
+pub use quote_swap::*;
+pub use quote_two_hop_swap::*;
+pub use rebalance_position::*;
+pub use route_swap::*;
+pub use schedule_fee_rate_change::*;
+pub use split_swap::*;
 pub use swap::*;
+pub use swap_v2::*;
+pub use swap_with_native::*;
 pub use two_hop_swap::*;
+pub use two_hop_swap_with_native::*;
 pub use update_fees_and_rewards::*;
+pub use update_position_metadata::*;
+pub use withdraw_range_order::*;
 pub use set_enable_flag::*;
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use solana_program::program::set_return_data;
+
+use crate::{state::*, util::to_timestamp_u64};
+
+#[derive(Accounts)]
+pub struct Observe<'info> {
+    pub whirlpool: Account<'info, Whirlpool>,
+
+    #[account(has_one = whirlpool)]
+    pub oracle: AccountLoader<'info, Oracle>,
+}
+
+/*
+  Reads the oracle of a Whirlpool and returns a Borsh-encoded ObserveResult as return data.
+*/
+pub fn handler(ctx: Context<Observe>, seconds_agos: Vec<u32>) -> ProgramResult {
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    let whirlpool = &ctx.accounts.whirlpool;
+
+    let oracle_info = ctx.accounts.oracle.to_account_info();
+    let data = oracle_info.try_borrow_data()?;
+    let (oracle, observations) = Oracle::split_data(&data);
+    let result = oracle.observe(
+        observations,
+        timestamp,
+        &seconds_agos,
+        whirlpool.tick_current_index,
+        whirlpool.liquidity,
+    )?;
+
+    set_return_data(
+        &result
+            .try_to_vec()
+            .map_err(|e| ProgramError::BorshIoError(e.to_string()))?,
+    );
+    Ok(())
+}
@@ -2,7 +2,13 @@ use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
 
-use crate::{state::*, util::mint_position_token_and_remove_authority};
+use crate::{
+    events::PositionOpened,
+    state::*,
+    util::{
+        mint_position_token_and_remove_authority, require_not_paused, require_tick_spacing_allowed,
+    },
+};
 
 #[derive(Accounts)]
 #[instruction(bumps: OpenPositionBumps)]
@@ -42,6 +48,14 @@ pub struct OpenPosition<'info> {
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// CHECK: nothing is paused until the pause controls of the config have been initialized
+    #[account(seeds = [b"pause_controls", whirlpool.whirlpools_config.as_ref()], bump)]
+    pub pause_controls: UncheckedAccount<'info>,
+
+    /// CHECK: every tick spacing is allowed until the fee tier policy has been initialized
+    #[account(seeds = [b"fee_tier_policy", whirlpool.whirlpools_config.as_ref()], bump)]
+    pub fee_tier_policy: UncheckedAccount<'info>,
 }
 
 /*
@@ -55,6 +69,8 @@ pub fn handler(
 ) -> ProgramResult {
     let whirlpool = &ctx.accounts.whirlpool;
     whirlpool.require_enabled()?;
+    require_not_paused(&ctx.accounts.pause_controls, whirlpool, PAUSE_OPEN_POSITION)?;
+    require_tick_spacing_allowed(&ctx.accounts.fee_tier_policy, whirlpool)?;
     let position_mint = &ctx.accounts.position_mint;
     let position = &mut ctx.accounts.position;
 
@@ -70,5 +86,15 @@ pub fn handler(
         position_mint,
         &ctx.accounts.position_token_account,
         &ctx.accounts.token_program,
-    )
+    )?;
+
+    emit!(PositionOpened {
+        whirlpool: whirlpool.key(),
+        position: position.key(),
+        position_mint: position_mint.key(),
+        tick_lower_index,
+        tick_upper_index,
+    });
+
+    Ok(())
 }
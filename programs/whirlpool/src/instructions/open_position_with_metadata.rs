@@ -2,7 +2,15 @@ use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
 
-use crate::{state::*, util::mint_position_token_with_metadata_and_remove_authority};
+use crate::{
+    errors::ErrorCode,
+    events::PositionOpened,
+    state::*,
+    util::{
+        load_metadata_settings, mint_position_token_with_metadata_and_remove_authority,
+        require_not_paused, require_tick_spacing_allowed, verify_position_collection,
+    },
+};
 
 use whirlpool_nft_update_auth::ID as WP_NFT_UPDATE_AUTH;
 mod whirlpool_nft_update_auth {
@@ -58,13 +66,36 @@ pub struct OpenPositionWithMetadata<'info> {
     #[account(address = mpl_token_metadata::ID)]
     pub metadata_program: UncheckedAccount<'info>,
 
-    /// CHECK: checked via account constraints
-    #[account(address = WP_NFT_UPDATE_AUTH)]
+    /// CHECK: checked against the update authority of the metadata settings
     pub metadata_update_auth: UncheckedAccount<'info>,
+
+    /// CHECK: the default metadata is used until the metadata settings have been initialized
+    #[account(seeds = [b"metadata_settings", whirlpool.whirlpools_config.as_ref()], bump)]
+    pub metadata_settings: UncheckedAccount<'info>,
+
+    /// CHECK: the position is not part of a collection until the collection has been initialized
+    #[account(seeds = [b"position_collection", whirlpool.whirlpools_config.as_ref()], bump)]
+    pub collection_mint: UncheckedAccount<'info>,
+
+    /// CHECK: checked via the Metadata CPI call
+    pub collection_metadata_account: UncheckedAccount<'info>,
+
+    /// CHECK: checked via the Metadata CPI call
+    pub collection_master_edition_account: UncheckedAccount<'info>,
+
+    /// CHECK: nothing is paused until the pause controls of the config have been initialized
+    #[account(seeds = [b"pause_controls", whirlpool.whirlpools_config.as_ref()], bump)]
+    pub pause_controls: UncheckedAccount<'info>,
+
+    /// CHECK: every tick spacing is allowed until the fee tier policy has been initialized
+    #[account(seeds = [b"fee_tier_policy", whirlpool.whirlpools_config.as_ref()], bump)]
+    pub fee_tier_policy: UncheckedAccount<'info>,
 }
 
 /*
   Opens a new Whirlpool Position with Metadata account.
+  Once the collection of the WhirlpoolsConfig has been initialized, the position is verified as a
+  member of the collection.
 */
 pub fn handler(
     ctx: Context<OpenPositionWithMetadata>,
@@ -74,6 +105,8 @@ pub fn handler(
 ) -> ProgramResult {
     let whirlpool = &ctx.accounts.whirlpool;
     whirlpool.require_enabled()?;
+    require_not_paused(&ctx.accounts.pause_controls, whirlpool, PAUSE_OPEN_POSITION)?;
+    require_tick_spacing_allowed(&ctx.accounts.fee_tier_policy, whirlpool)?;
     let position_mint = &ctx.accounts.position_mint;
     let position = &mut ctx.accounts.position;
 
@@ -84,16 +117,51 @@ pub fn handler(
         tick_upper_index,
     )?;
 
+    let metadata_settings = load_metadata_settings(&ctx.accounts.metadata_settings)?;
+    let update_authority = match &metadata_settings {
+        Some(metadata_settings) => metadata_settings.update_authority,
+        None => WP_NFT_UPDATE_AUTH,
+    };
+    if ctx.accounts.metadata_update_auth.key() != update_authority {
+        return Err(ErrorCode::InvalidMetadataUpdateAuthority.into());
+    }
+
     mint_position_token_with_metadata_and_remove_authority(
         whirlpool,
+        position,
         position_mint,
         &ctx.accounts.position_token_account,
         &ctx.accounts.position_metadata_account,
         &ctx.accounts.metadata_update_auth,
+        metadata_settings.as_deref(),
         &ctx.accounts.funder,
         &ctx.accounts.metadata_program,
         &ctx.accounts.token_program,
         &ctx.accounts.system_program,
         &ctx.accounts.rent,
-    )
+    )?;
+
+    if let Some(metadata_settings) = &metadata_settings {
+        if metadata_settings.collection().is_some() {
+            verify_position_collection(
+                &ctx.accounts.position_metadata_account,
+                metadata_settings,
+                &ctx.accounts.funder,
+                &ctx.accounts.collection_mint,
+                &ctx.accounts.collection_metadata_account,
+                &ctx.accounts.collection_master_edition_account,
+                &ctx.accounts.metadata_program,
+            )?;
+        }
+    }
+
+    emit!(PositionOpened {
+        whirlpool: whirlpool.key(),
+        position: position.key(),
+        position_mint: position_mint.key(),
+        tick_lower_index,
+        tick_upper_index,
+    });
+
+    Ok(())
 }
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+use crate::{
+    events::RangeOrderPlaced,
+    state::*,
+    util::{transfer_from_owner_to_vault, verify_position_authority},
+};
+
+#[derive(Accounts)]
+pub struct PlaceRangeOrder<'info> {
+    pub whirlpool: Box<Account<'info, Whirlpool>>,
+
+    #[account(mut)]
+    pub position_authority: Signer<'info>,
+
+    #[account(has_one = whirlpool)]
+    pub position: Box<Account<'info, Position>>,
+    #[account(address = position.position_mint)]
+    pub position_mint: Account<'info, Mint>,
+    #[account(mut,
+        constraint = position_token_account.mint == position.position_mint,
+        constraint = position_token_account.amount == 1
+    )]
+    pub position_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(init,
+      payer = position_authority,
+      seeds = [b"range_order_escrow".as_ref(), position.key().as_ref()],
+      bump,
+      token::mint = position_mint,
+      token::authority = whirlpool)]
+    pub range_order_escrow: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, has_one = whirlpool)]
+    pub range_order_book: Box<Account<'info, RangeOrderBook>>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/*
+  Places a range order for all of the liquidity of a Position spanning a single tick-spacing.
+  The position token is held in escrow until the order is withdrawn, so the liquidity cannot be
+  modified in the meantime.
+*/
+pub fn handler(ctx: Context<PlaceRangeOrder>) -> ProgramResult {
+    verify_position_authority(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+    )?;
+
+    let whirlpool = &ctx.accounts.whirlpool;
+    let position = &ctx.accounts.position;
+    // The order belongs to the owner of the position token, even if it is placed by a delegate
+    let owner = ctx.accounts.position_token_account.owner;
+    ctx.accounts.range_order_book.place(
+        position.key(),
+        position,
+        owner,
+        whirlpool.tick_spacing,
+        whirlpool.tick_current_index,
+    )?;
+
+    transfer_from_owner_to_vault(
+        &ctx.accounts.position_authority,
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.range_order_escrow,
+        &ctx.accounts.token_program,
+        1,
+    )?;
+
+    emit!(RangeOrderPlaced {
+        whirlpool: whirlpool.key(),
+        position: position.key(),
+        owner,
+        tick_lower_index: position.tick_lower_index,
+        tick_upper_index: position.tick_upper_index,
+        liquidity: position.liquidity,
+        sells_token_a: whirlpool.tick_current_index < position.tick_lower_index,
+    });
+
+    Ok(())
+}
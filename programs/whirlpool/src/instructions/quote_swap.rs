@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    manager::swap_manager::*,
+    return_data::{set_swap_return_data, SwapReturnData},
+    state::{TickArray, Whirlpool, PAUSE_SWAP},
+    util::{
+        load_dynamic_fee, load_read_only_tick_sequence, preview_fee_rate_schedule,
+        require_not_paused, to_timestamp_u64, WhirlpoolExtensionAccounts,
+    },
+};
+
+use super::SWAP_EXTENSIONS;
+
+#[derive(Accounts)]
+pub struct QuoteSwap<'info> {
+    pub whirlpool: Box<Account<'info, Whirlpool>>,
+
+    #[account(has_one = whirlpool)]
+    pub tick_array_0: AccountLoader<'info, TickArray>,
+
+    #[account(has_one = whirlpool)]
+    pub tick_array_1: AccountLoader<'info, TickArray>,
+
+    #[account(has_one = whirlpool)]
+    pub tick_array_2: AccountLoader<'info, TickArray>,
+
+    /// CHECK: nothing is paused until the pause controls of the config have been initialized
+    #[account(seeds = [b"pause_controls", whirlpool.whirlpools_config.as_ref()], bump)]
+    pub pause_controls: UncheckedAccount<'info>,
+    // remaining_accounts: the side accounts of the SWAP_EXTENSIONS enabled on the whirlpool
+}
+
+/*
+  Runs the swap calculation against the current state of a Whirlpool without persisting it,
+  and returns a Borsh-encoded SwapReturnData as return data.
+*/
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, QuoteSwap<'info>>,
+    amount: u64,
+    sqrt_price_limit: u128,
+    amount_specified_is_input: bool,
+    a_to_b: bool,
+) -> ProgramResult {
+    let (extension_accounts, _) = WhirlpoolExtensionAccounts::try_from(
+        &ctx.accounts.whirlpool,
+        ctx.remaining_accounts,
+        SWAP_EXTENSIONS,
+    )?;
+    let whirlpool = &ctx.accounts.whirlpool;
+    whirlpool.require_enabled()?;
+    require_not_paused(&ctx.accounts.pause_controls, whirlpool, PAUSE_SWAP)?;
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    let whirlpool = preview_fee_rate_schedule(
+        whirlpool,
+        extension_accounts.fee_rate_schedule.as_ref(),
+        timestamp,
+    )?;
+    let dynamic_fee = load_dynamic_fee(extension_accounts.dynamic_fee.as_ref())?;
+    let mut swap_tick_sequence = load_read_only_tick_sequence(
+        &ctx.accounts.tick_array_0,
+        &ctx.accounts.tick_array_1,
+        &ctx.accounts.tick_array_2,
+    )?;
+
+    let swap_update = swap(
+        &whirlpool,
+        dynamic_fee.as_deref(),
+        &mut swap_tick_sequence,
+        amount,
+        sqrt_price_limit,
+        amount_specified_is_input,
+        a_to_b,
+        timestamp,
+    )?;
+
+    set_swap_return_data(&SwapReturnData::from(&swap_update))
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ErrorCode,
+    manager::swap_manager::*,
+    return_data::{set_swap_return_data, SwapReturnData, TwoHopSwapReturnData},
+    state::PAUSE_SWAP,
+    util::{
+        load_dynamic_fee, load_read_only_tick_sequence, preview_fee_rate_schedule,
+        require_not_paused, to_timestamp_u64, WhirlpoolExtensionAccounts,
+    },
+};
+
+use super::{quote_swap::*, SWAP_EXTENSIONS};
+
+#[derive(Accounts)]
+pub struct QuoteTwoHopSwap<'info> {
+    pub swap_one: QuoteSwap<'info>,
+
+    pub swap_two: QuoteSwap<'info>,
+    // remaining_accounts: the side accounts of the SWAP_EXTENSIONS enabled on the whirlpool of
+    // swap_one, followed by the ones enabled on the whirlpool of swap_two
+}
+
+/*
+  Runs the two-hop swap calculation against the current state of both Whirlpools without
+  persisting it, and returns a Borsh-encoded TwoHopSwapReturnData as return data.
+*/
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, QuoteTwoHopSwap<'info>>,
+    amount: u64,
+    amount_specified_is_input: bool,
+    a_to_b_one: bool,
+    a_to_b_two: bool,
+    sqrt_price_limit_one: u128,
+    sqrt_price_limit_two: u128,
+) -> ProgramResult {
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    let swap_one = &ctx.accounts.swap_one;
+    let swap_two = &ctx.accounts.swap_two;
+    let (extension_accounts_one, extension_accounts_one_count) =
+        WhirlpoolExtensionAccounts::try_from(
+            &swap_one.whirlpool,
+            ctx.remaining_accounts,
+            SWAP_EXTENSIONS,
+        )?;
+    let (extension_accounts_two, _) = WhirlpoolExtensionAccounts::try_from(
+        &swap_two.whirlpool,
+        &ctx.remaining_accounts[extension_accounts_one_count..],
+        SWAP_EXTENSIONS,
+    )?;
+    let dynamic_fee_one = load_dynamic_fee(extension_accounts_one.dynamic_fee.as_ref())?;
+    let dynamic_fee_two = load_dynamic_fee(extension_accounts_two.dynamic_fee.as_ref())?;
+
+    let whirlpool_one = &swap_one.whirlpool;
+    let whirlpool_two = &swap_two.whirlpool;
+
+    // Don't allow swaps on the same whirlpool
+    if whirlpool_one.key() == whirlpool_two.key() {
+        return Err(ErrorCode::DuplicateTwoHopPool.into());
+    }
+
+    whirlpool_one.require_enabled()?;
+    whirlpool_two.require_enabled()?;
+    require_not_paused(&swap_one.pause_controls, whirlpool_one, PAUSE_SWAP)?;
+    require_not_paused(&swap_two.pause_controls, whirlpool_two, PAUSE_SWAP)?;
+
+    let swap_one_output_mint = if a_to_b_one {
+        whirlpool_one.token_mint_b
+    } else {
+        whirlpool_one.token_mint_a
+    };
+
+    let swap_two_input_mint = if a_to_b_two {
+        whirlpool_two.token_mint_a
+    } else {
+        whirlpool_two.token_mint_b
+    };
+    if swap_one_output_mint != swap_two_input_mint {
+        return Err(ErrorCode::InvalidIntermediaryMint.into());
+    }
+
+    let whirlpool_one = preview_fee_rate_schedule(
+        whirlpool_one,
+        extension_accounts_one.fee_rate_schedule.as_ref(),
+        timestamp,
+    )?;
+    let whirlpool_two = preview_fee_rate_schedule(
+        whirlpool_two,
+        extension_accounts_two.fee_rate_schedule.as_ref(),
+        timestamp,
+    )?;
+
+    let mut swap_tick_sequence_one = load_read_only_tick_sequence(
+        &swap_one.tick_array_0,
+        &swap_one.tick_array_1,
+        &swap_one.tick_array_2,
+    )?;
+
+    let mut swap_tick_sequence_two = load_read_only_tick_sequence(
+        &swap_two.tick_array_0,
+        &swap_two.tick_array_1,
+        &swap_two.tick_array_2,
+    )?;
+
+    let (swap_update_one, swap_update_two) = two_hop_swap(
+        &whirlpool_one,
+        &whirlpool_two,
+        dynamic_fee_one.as_deref(),
+        dynamic_fee_two.as_deref(),
+        &mut swap_tick_sequence_one,
+        &mut swap_tick_sequence_two,
+        amount,
+        amount_specified_is_input,
+        a_to_b_one,
+        a_to_b_two,
+        sqrt_price_limit_one,
+        sqrt_price_limit_two,
+        timestamp,
+    )?;
+
+    set_swap_return_data(&TwoHopSwapReturnData {
+        swap_one: SwapReturnData::from(&swap_update_one),
+        swap_two: SwapReturnData::from(&swap_update_two),
+    })
+}
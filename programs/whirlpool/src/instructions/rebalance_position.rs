@@ -0,0 +1,281 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::errors::ErrorCode;
+use crate::events::PositionRebalanced;
+use crate::manager::liquidity_manager::{
+    calculate_liquidity_token_deltas, calculate_modify_liquidity, sync_modify_liquidity_values,
+};
+use crate::math::convert_to_liquidity_delta;
+use crate::state::*;
+use crate::util::{
+    apply_fee_rate_schedule, require_not_paused, require_tick_spacing_allowed, to_timestamp_u64,
+    transfer_from_owner_to_vault, transfer_from_vault_to_owner, update_oracle,
+    verify_position_authority, WhirlpoolExtensionAccounts,
+};
+
+use super::MODIFY_LIQUIDITY_EXTENSIONS;
+
+#[derive(Accounts)]
+pub struct RebalancePosition<'info> {
+    #[account(mut)]
+    pub whirlpool: Box<Account<'info, Whirlpool>>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub position_authority: Signer<'info>,
+
+    #[account(mut, has_one = whirlpool)]
+    pub position: Box<Account<'info, Position>>,
+    #[account(
+        constraint = position_token_account.mint == position.position_mint,
+        constraint = position_token_account.amount == 1
+    )]
+    pub position_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, constraint = token_owner_account_a.mint == whirlpool.token_mint_a)]
+    pub token_owner_account_a: Box<Account<'info, TokenAccount>>,
+    #[account(mut, constraint = token_owner_account_b.mint == whirlpool.token_mint_b)]
+    pub token_owner_account_b: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, constraint = token_vault_a.key() == whirlpool.token_vault_a)]
+    pub token_vault_a: Box<Account<'info, TokenAccount>>,
+    #[account(mut, constraint = token_vault_b.key() == whirlpool.token_vault_b)]
+    pub token_vault_b: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, has_one = whirlpool)]
+    pub tick_array_lower: AccountLoader<'info, TickArray>,
+    #[account(mut, has_one = whirlpool)]
+    pub tick_array_upper: AccountLoader<'info, TickArray>,
+
+    #[account(mut, has_one = whirlpool)]
+    pub new_tick_array_lower: AccountLoader<'info, TickArray>,
+    #[account(mut, has_one = whirlpool)]
+    pub new_tick_array_upper: AccountLoader<'info, TickArray>,
+
+    /// CHECK: nothing is paused until the pause controls of the config have been initialized
+    #[account(seeds = [b"pause_controls", whirlpool.whirlpools_config.as_ref()], bump)]
+    pub pause_controls: UncheckedAccount<'info>,
+
+    /// CHECK: every tick spacing is allowed until the fee tier policy has been initialized
+    #[account(seeds = [b"fee_tier_policy", whirlpool.whirlpools_config.as_ref()], bump)]
+    pub fee_tier_policy: UncheckedAccount<'info>,
+    // remaining_accounts: the side accounts of the MODIFY_LIQUIDITY_EXTENSIONS enabled on the
+    // whirlpool
+}
+
+/*
+  Moves all of the liquidity of a Position to a new tick range.
+  The liquidity is withdrawn together with the fees owed, and the position is reopened in the new
+  range with `liquidity_amount`. Only the difference between the withdrawn and the deposited
+  amounts is transferred. Rewards owed remain collectable from the position.
+  Positions without liquidity skip the withdrawal and are only moved to the new range.
+*/
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RebalancePosition<'info>>,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+    liquidity_amount: u128,
+    token_min_a: u64,
+    token_min_b: u64,
+    token_max_a: u64,
+    token_max_b: u64,
+) -> ProgramResult {
+    let (extension_accounts, _) = WhirlpoolExtensionAccounts::try_from(
+        &ctx.accounts.whirlpool,
+        ctx.remaining_accounts,
+        MODIFY_LIQUIDITY_EXTENSIONS,
+    )?;
+    verify_position_authority(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+    )?;
+
+    let whirlpool = &ctx.accounts.whirlpool;
+    whirlpool.require_enabled()?;
+    require_not_paused(
+        &ctx.accounts.pause_controls,
+        whirlpool,
+        PAUSE_DECREASE_LIQUIDITY | PAUSE_COLLECT | PAUSE_INCREASE_LIQUIDITY,
+    )?;
+    require_tick_spacing_allowed(&ctx.accounts.fee_tier_policy, whirlpool)?;
+    let clock = Clock::get()?;
+
+    if liquidity_amount == 0 {
+        return Err(ErrorCode::LiquidityZero.into());
+    }
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    apply_fee_rate_schedule(
+        &mut ctx.accounts.whirlpool,
+        extension_accounts.fee_rate_schedule.as_ref(),
+        timestamp,
+    )?;
+
+    // Withdraw all of the liquidity from the current range
+    let liquidity_removed = ctx.accounts.position.liquidity;
+    let (removed_a, removed_b) = if liquidity_removed == 0 {
+        (0, 0)
+    } else {
+        let removal_delta = convert_to_liquidity_delta(liquidity_removed, false)?;
+        let update = calculate_modify_liquidity(
+            &ctx.accounts.whirlpool,
+            &ctx.accounts.position,
+            &ctx.accounts.tick_array_lower,
+            &ctx.accounts.tick_array_upper,
+            removal_delta,
+            timestamp,
+        )?;
+
+        // The oracle has to accumulate the in-range liquidity up until now before it changes
+        if update.whirlpool_liquidity != ctx.accounts.whirlpool.liquidity {
+            update_oracle(
+                &ctx.accounts.whirlpool,
+                extension_accounts.oracle.as_ref(),
+                timestamp,
+            )?;
+        }
+
+        sync_modify_liquidity_values(
+            &mut ctx.accounts.whirlpool,
+            &mut ctx.accounts.position,
+            &ctx.accounts.tick_array_lower,
+            &ctx.accounts.tick_array_upper,
+            update,
+            timestamp,
+        )?;
+
+        calculate_liquidity_token_deltas(
+            ctx.accounts.whirlpool.tick_current_index,
+            ctx.accounts.whirlpool.sqrt_price,
+            &ctx.accounts.position,
+            removal_delta,
+        )?
+    };
+
+    let position = &mut ctx.accounts.position;
+    let withdrawn_a = removed_a
+        .checked_add(position.fee_owed_a)
+        .ok_or(ErrorCode::AmountCalcOverflow)?;
+    let withdrawn_b = removed_b
+        .checked_add(position.fee_owed_b)
+        .ok_or(ErrorCode::AmountCalcOverflow)?;
+    position.reset_fees_owed();
+
+    if withdrawn_a < token_min_a {
+        return Err(ErrorCode::TokenMinSubceeded.into());
+    } else if withdrawn_b < token_min_b {
+        return Err(ErrorCode::TokenMinSubceeded.into());
+    }
+
+    // Reopen the position in the new range
+    position.reset_position_range(
+        tick_lower_index,
+        tick_upper_index,
+        ctx.accounts.whirlpool.tick_spacing,
+    )?;
+
+    let deposit_delta = convert_to_liquidity_delta(liquidity_amount, true)?;
+    let update = calculate_modify_liquidity(
+        &ctx.accounts.whirlpool,
+        &ctx.accounts.position,
+        &ctx.accounts.new_tick_array_lower,
+        &ctx.accounts.new_tick_array_upper,
+        deposit_delta,
+        timestamp,
+    )?;
+
+    if update.whirlpool_liquidity != ctx.accounts.whirlpool.liquidity {
+        update_oracle(
+            &ctx.accounts.whirlpool,
+            extension_accounts.oracle.as_ref(),
+            timestamp,
+        )?;
+    }
+
+    sync_modify_liquidity_values(
+        &mut ctx.accounts.whirlpool,
+        &mut ctx.accounts.position,
+        &ctx.accounts.new_tick_array_lower,
+        &ctx.accounts.new_tick_array_upper,
+        update,
+        timestamp,
+    )?;
+
+    let (deposited_a, deposited_b) = calculate_liquidity_token_deltas(
+        ctx.accounts.whirlpool.tick_current_index,
+        ctx.accounts.whirlpool.sqrt_price,
+        &ctx.accounts.position,
+        deposit_delta,
+    )?;
+
+    if deposited_a > token_max_a {
+        return Err(ErrorCode::TokenMaxExceeded.into());
+    } else if deposited_b > token_max_b {
+        return Err(ErrorCode::TokenMaxExceeded.into());
+    }
+
+    settle_token_amounts(
+        &ctx.accounts.whirlpool,
+        &ctx.accounts.position_authority,
+        &ctx.accounts.token_owner_account_a,
+        &ctx.accounts.token_vault_a,
+        &ctx.accounts.token_program,
+        withdrawn_a,
+        deposited_a,
+    )?;
+
+    settle_token_amounts(
+        &ctx.accounts.whirlpool,
+        &ctx.accounts.position_authority,
+        &ctx.accounts.token_owner_account_b,
+        &ctx.accounts.token_vault_b,
+        &ctx.accounts.token_program,
+        withdrawn_b,
+        deposited_b,
+    )?;
+
+    emit!(PositionRebalanced {
+        whirlpool: ctx.accounts.whirlpool.key(),
+        position: ctx.accounts.position.key(),
+        tick_lower_index,
+        tick_upper_index,
+        liquidity_removed,
+        liquidity_added: liquidity_amount,
+        token_amount_a_removed: withdrawn_a,
+        token_amount_b_removed: withdrawn_b,
+        token_amount_a_added: deposited_a,
+        token_amount_b_added: deposited_b,
+    });
+
+    Ok(())
+}
+
+// Transfers only the difference between the withdrawn and the deposited amounts of a token.
+fn settle_token_amounts<'info>(
+    whirlpool: &Account<'info, Whirlpool>,
+    position_authority: &Signer<'info>,
+    token_owner_account: &Account<'info, TokenAccount>,
+    token_vault: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    withdrawn: u64,
+    deposited: u64,
+) -> ProgramResult {
+    if deposited > withdrawn {
+        transfer_from_owner_to_vault(
+            position_authority,
+            token_owner_account,
+            token_vault,
+            token_program,
+            deposited - withdrawn,
+        )
+    } else {
+        transfer_from_vault_to_owner(
+            whirlpool,
+            token_vault,
+            token_owner_account,
+            token_program,
+            withdrawn - deposited,
+        )
+    }
+}
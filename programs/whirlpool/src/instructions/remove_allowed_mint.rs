@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+use super::ModifyPoolCreationPolicy;
+use crate::events::AllowedMintRemoved;
+
+pub fn handler(ctx: Context<ModifyPoolCreationPolicy>, mint: Pubkey) -> ProgramResult {
+    ctx.accounts.pool_creation_policy.remove_allowed_mint(&mint);
+    emit!(AllowedMintRemoved {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        mint,
+    });
+    Ok(())
+}
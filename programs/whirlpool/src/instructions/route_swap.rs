@@ -0,0 +1,296 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::{
+    errors::ErrorCode,
+    manager::swap_manager::*,
+    state::{
+        TickArray, Whirlpool, EXTENSION_DYNAMIC_FEE, EXTENSION_FEE_RATE_SCHEDULE, EXTENSION_ORACLE,
+        EXTENSION_RANGE_ORDER_BOOK, PAUSE_SWAP,
+    },
+    util::{
+        apply_fee_rate_schedule, find_pause_controls, load_dynamic_fee, require_not_paused,
+        to_timestamp_u64, update_and_swap_whirlpool, update_range_order_book, SwapTickSequence,
+        WhirlpoolExtensionAccounts,
+    },
+};
+
+/// Number of `remaining_accounts` that describe a single hop of a route, before the side accounts
+/// of its extensions.
+pub const ROUTE_HOP_ACCOUNTS_LEN: usize = 8;
+
+/// Extensions whose side accounts follow the accounts of each hop. The pause controls, which every
+/// hop requires, are passed once per config after the last hop instead.
+pub const ROUTE_HOP_EXTENSIONS: u8 = EXTENSION_ORACLE
+    | EXTENSION_DYNAMIC_FEE
+    | EXTENSION_FEE_RATE_SCHEDULE
+    | EXTENSION_RANGE_ORDER_BOOK;
+
+#[derive(Accounts)]
+pub struct RouteSwap<'info> {
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub token_authority: Signer<'info>,
+    // remaining_accounts: for each hop, ROUTE_HOP_ACCOUNTS_LEN accounts in the order of `RouteHop`
+    // followed by the side accounts of the ROUTE_HOP_EXTENSIONS enabled on its whirlpool. The pause
+    // controls of the configs of the hops follow the last hop, whether or not they are initialized.
+}
+
+/// The accounts of a single hop of a route, parsed from `remaining_accounts`.
+/// Validates the same constraints that `Swap` declares for its accounts.
+pub struct RouteHop<'info> {
+    pub whirlpool: Box<Account<'info, Whirlpool>>,
+    pub token_owner_account_a: Box<Account<'info, TokenAccount>>,
+    pub token_vault_a: Box<Account<'info, TokenAccount>>,
+    pub token_owner_account_b: Box<Account<'info, TokenAccount>>,
+    pub token_vault_b: Box<Account<'info, TokenAccount>>,
+    pub tick_array_0: AccountLoader<'info, TickArray>,
+    pub tick_array_1: AccountLoader<'info, TickArray>,
+    pub tick_array_2: AccountLoader<'info, TickArray>,
+    pub extension_accounts: WhirlpoolExtensionAccounts<'info>,
+}
+
+impl<'info> RouteHop<'info> {
+    /// Parse a hop from the front of `accounts`.
+    /// Returns the hop along with the number of accounts it took.
+    pub fn try_from(accounts: &[AccountInfo<'info>]) -> Result<(Self, usize), ProgramError> {
+        if accounts.len() < ROUTE_HOP_ACCOUNTS_LEN {
+            return Err(ErrorCode::InvalidRouteAccounts.into());
+        }
+
+        let whirlpool: Box<Account<Whirlpool>> = Box::new(Account::try_from(&accounts[0])?);
+        let (extension_accounts, extension_accounts_count) = WhirlpoolExtensionAccounts::try_from(
+            &whirlpool,
+            &accounts[ROUTE_HOP_ACCOUNTS_LEN..],
+            ROUTE_HOP_EXTENSIONS,
+        )?;
+
+        let hop = RouteHop {
+            whirlpool,
+            token_owner_account_a: Box::new(Account::try_from(&accounts[1])?),
+            token_vault_a: Box::new(Account::try_from(&accounts[2])?),
+            token_owner_account_b: Box::new(Account::try_from(&accounts[3])?),
+            token_vault_b: Box::new(Account::try_from(&accounts[4])?),
+            tick_array_0: AccountLoader::try_from(&accounts[5])?,
+            tick_array_1: AccountLoader::try_from(&accounts[6])?,
+            tick_array_2: AccountLoader::try_from(&accounts[7])?,
+            extension_accounts,
+        };
+
+        let whirlpool = &hop.whirlpool;
+        if hop.token_owner_account_a.mint != whirlpool.token_mint_a
+            || hop.token_vault_a.key() != whirlpool.token_vault_a
+            || hop.token_owner_account_b.mint != whirlpool.token_mint_b
+            || hop.token_vault_b.key() != whirlpool.token_vault_b
+        {
+            return Err(ErrorCode::InvalidRouteAccounts.into());
+        }
+
+        for tick_array in [&hop.tick_array_0, &hop.tick_array_1, &hop.tick_array_2] {
+            if tick_array.load()?.whirlpool != whirlpool.key() {
+                return Err(ErrorCode::InvalidRouteAccounts.into());
+            }
+        }
+
+        Ok((hop, ROUTE_HOP_ACCOUNTS_LEN + extension_accounts_count))
+    }
+
+    fn input_mint(&self, a_to_b: bool) -> Pubkey {
+        if a_to_b {
+            self.whirlpool.token_mint_a
+        } else {
+            self.whirlpool.token_mint_b
+        }
+    }
+
+    fn output_mint(&self, a_to_b: bool) -> Pubkey {
+        if a_to_b {
+            self.whirlpool.token_mint_b
+        } else {
+            self.whirlpool.token_mint_a
+        }
+    }
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RouteSwap<'info>>,
+    amount: u64,
+    other_amount_threshold: u64,
+    amount_specified_is_input: bool,
+    a_to_b: Vec<bool>,
+    sqrt_price_limits: Vec<u128>,
+) -> ProgramResult {
+    let clock = Clock::get()?;
+    // Update the global reward growth which increases as a function of time.
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    let hop_count = a_to_b.len();
+    if hop_count == 0 || sqrt_price_limits.len() != hop_count {
+        return Err(ErrorCode::InvalidRouteAccounts.into());
+    }
+
+    let mut remaining_accounts = ctx.remaining_accounts;
+    let mut hops = Vec::with_capacity(hop_count);
+    for _ in 0..hop_count {
+        let (hop, hop_accounts_len) = RouteHop::try_from(remaining_accounts)?;
+        remaining_accounts = &remaining_accounts[hop_accounts_len..];
+        hop.whirlpool.require_enabled()?;
+
+        // Don't allow a route to visit the same whirlpool twice
+        if hops
+            .iter()
+            .any(|prev: &RouteHop| prev.whirlpool.key() == hop.whirlpool.key())
+        {
+            return Err(ErrorCode::DuplicateRoutePool.into());
+        }
+        hops.push(hop);
+    }
+
+    // The accounts left after the last hop are the pause controls of the configs of the hops
+    let whirlpools: Vec<&Account<Whirlpool>> = hops.iter().map(|hop| &*hop.whirlpool).collect();
+    let pause_controls = find_pause_controls(&whirlpools, remaining_accounts)?;
+    for (hop, pause_controls) in hops.iter().zip(pause_controls) {
+        require_not_paused(&pause_controls, &hop.whirlpool, PAUSE_SWAP)?;
+    }
+
+    for i in 1..hop_count {
+        if hops[i - 1].output_mint(a_to_b[i - 1]) != hops[i].input_mint(a_to_b[i]) {
+            return Err(ErrorCode::InvalidIntermediaryMint.into());
+        }
+    }
+
+    for hop in hops.iter_mut() {
+        apply_fee_rate_schedule(
+            &mut hop.whirlpool,
+            hop.extension_accounts.fee_rate_schedule.as_ref(),
+            timestamp,
+        )?;
+    }
+
+    let swap_updates = {
+        let dynamic_fees = hops
+            .iter()
+            .map(|hop| load_dynamic_fee(hop.extension_accounts.dynamic_fee.as_ref()))
+            .collect::<Result<Vec<_>, ProgramError>>()?;
+        let mut swap_tick_sequences = Vec::with_capacity(hop_count);
+        for hop in hops.iter() {
+            swap_tick_sequences.push(SwapTickSequence::new(
+                hop.tick_array_0.load_mut()?,
+                hop.tick_array_1.load_mut().ok(),
+                hop.tick_array_2.load_mut().ok(),
+            ));
+        }
+
+        let mut swap_updates: Vec<PostSwapUpdate> = Vec::with_capacity(hop_count);
+        if amount_specified_is_input {
+            // Exact-in: the calculations run from the first to the last hop and the output
+            // of each hop is the input of the next one.
+            let mut hop_amount = amount;
+            for i in 0..hop_count {
+                let swap_update = swap(
+                    &hops[i].whirlpool,
+                    dynamic_fees[i].as_deref(),
+                    &mut swap_tick_sequences[i],
+                    hop_amount,
+                    sqrt_price_limits[i],
+                    amount_specified_is_input, // true
+                    a_to_b[i],
+                    timestamp,
+                )?;
+                hop_amount = if a_to_b[i] {
+                    swap_update.amount_b
+                } else {
+                    swap_update.amount_a
+                };
+                swap_updates.push(swap_update);
+            }
+
+            // The slippage we care about is the output of the last hop.
+            if other_amount_threshold > hop_amount {
+                return Err(ErrorCode::AmountOutBelowMinimum.into());
+            }
+        } else {
+            // Exact-out: the calculations run from the last to the first hop and the input
+            // of each hop is the output required from the previous one.
+            let mut hop_amount = amount;
+            for i in (0..hop_count).rev() {
+                let swap_update = swap(
+                    &hops[i].whirlpool,
+                    dynamic_fees[i].as_deref(),
+                    &mut swap_tick_sequences[i],
+                    hop_amount,
+                    sqrt_price_limits[i],
+                    amount_specified_is_input, // false
+                    a_to_b[i],
+                    timestamp,
+                )?;
+
+                // Every hop has to provide its full output. Otherwise the next hop would be
+                // funded from the user's existing intermediate balance.
+                let output_amount = if a_to_b[i] {
+                    swap_update.amount_b
+                } else {
+                    swap_update.amount_a
+                };
+                if output_amount != hop_amount {
+                    return Err(if i == hop_count - 1 {
+                        ErrorCode::AmountOutBelowMinimum.into()
+                    } else {
+                        ErrorCode::IntermediateTokenAmountMismatch.into()
+                    });
+                }
+
+                hop_amount = if a_to_b[i] {
+                    swap_update.amount_a
+                } else {
+                    swap_update.amount_b
+                };
+                swap_updates.push(swap_update);
+            }
+            swap_updates.reverse();
+
+            // The slippage we care about is the input of the first hop.
+            if other_amount_threshold < hop_amount {
+                return Err(ErrorCode::AmountInAboveMaximum.into());
+            }
+        }
+        swap_updates
+    };
+
+    // The swaps occur from the first to the last hop to ensure that the intermediate tokens
+    // exist in the user's accounts.
+    for (i, (hop, swap_update)) in hops.iter_mut().zip(swap_updates).enumerate() {
+        update_and_swap_whirlpool(
+            &mut hop.whirlpool,
+            &ctx.accounts.token_authority,
+            &hop.token_owner_account_a,
+            &hop.token_owner_account_b,
+            &hop.token_vault_a,
+            &hop.token_vault_b,
+            &ctx.accounts.token_program,
+            hop.extension_accounts.oracle.as_ref(),
+            hop.extension_accounts.dynamic_fee.as_ref(),
+            swap_update,
+            a_to_b[i],
+            timestamp,
+        )?;
+
+        // The tick sequences of the calculations have been released, so they are loaded again
+        let mut swap_tick_sequence = SwapTickSequence::new(
+            hop.tick_array_0.load_mut()?,
+            hop.tick_array_1.load_mut().ok(),
+            hop.tick_array_2.load_mut().ok(),
+        );
+        update_range_order_book(
+            &hop.whirlpool,
+            hop.extension_accounts.range_order_book.as_ref(),
+            &mut swap_tick_sequence,
+        )?;
+
+        // Accounts parsed from remaining_accounts are not persisted by Anchor
+        hop.whirlpool.exit(&crate::ID)?;
+    }
+
+    Ok(())
+}
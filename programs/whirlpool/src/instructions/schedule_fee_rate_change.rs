@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::FeeRateChangeScheduled,
+    state::{FeeRateSchedule, Whirlpool, WhirlpoolsConfig},
+    util::to_timestamp_u64,
+};
+
+#[derive(Accounts)]
+pub struct ScheduleFeeRateChange<'info> {
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(has_one = whirlpools_config)]
+    pub whirlpool: Account<'info, Whirlpool>,
+
+    #[account(address = whirlpools_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+
+    #[account(mut, has_one = whirlpool)]
+    pub fee_rate_schedule: Account<'info, FeeRateSchedule>,
+}
+
+pub fn handler(
+    ctx: Context<ScheduleFeeRateChange>,
+    fee_rate: u16,
+    protocol_fee_rate: u16,
+    effective_at: u64,
+) -> ProgramResult {
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    ctx.accounts.fee_rate_schedule.schedule(
+        fee_rate,
+        protocol_fee_rate,
+        effective_at,
+        timestamp,
+    )?;
+    emit!(FeeRateChangeScheduled {
+        whirlpool: ctx.accounts.whirlpool.key(),
+        fee_rate,
+        protocol_fee_rate,
+        effective_at,
+    });
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::events::AllowedTickSpacingsUpdated;
+use crate::state::{FeeTierPolicy, WhirlpoolsConfig};
+
+#[derive(Accounts)]
+pub struct ModifyFeeTierPolicy<'info> {
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(address = whirlpools_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+
+    #[account(
+      mut,
+      seeds = [b"fee_tier_policy", whirlpools_config.key().as_ref()],
+      bump,
+      has_one = whirlpools_config)]
+    pub fee_tier_policy: Account<'info, FeeTierPolicy>,
+}
+
+pub fn handler(
+    ctx: Context<ModifyFeeTierPolicy>,
+    allowed_tick_spacings: Vec<u16>,
+) -> ProgramResult {
+    ctx.accounts
+        .fee_tier_policy
+        .update_allowed_tick_spacings(allowed_tick_spacings)?;
+    emit!(AllowedTickSpacingsUpdated {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        allowed_tick_spacings: ctx.accounts.fee_tier_policy.allowed_tick_spacings.clone(),
+    });
+    Ok(())
+}
@@ -1,23 +1,36 @@
 use anchor_lang::prelude::*;
 
-use crate::state::WhirlpoolsConfig;
+use crate::events::{AuthorityTransferProposed, AUTHORITY_COLLECT_PROTOCOL_FEES};
+use crate::state::{PendingAuthorities, WhirlpoolsConfig};
 
 #[derive(Accounts)]
 pub struct SetCollectProtocolFeesAuthority<'info> {
-    #[account(mut)]
     pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
 
     #[account(address = whirlpools_config.collect_protocol_fees_authority)]
     pub collect_protocol_fees_authority: Signer<'info>,
 
     pub new_collect_protocol_fees_authority: UncheckedAccount<'info>,
+
+    #[account(
+      mut,
+      seeds = [b"pending_authorities", whirlpools_config.key().as_ref()],
+      bump,
+      has_one = whirlpools_config)]
+    pub pending_authorities: Account<'info, PendingAuthorities>,
 }
 
+/// Propose a new collect protocol fees authority.
+/// The transfer takes effect once the new authority accepts it.
+/// Only the current collect protocol fees authority has permission to invoke this instruction.
 pub fn handler(ctx: Context<SetCollectProtocolFeesAuthority>) -> ProgramResult {
-    Ok(ctx
-        .accounts
-        .whirlpools_config
-        .update_collect_protocol_fees_authority(
-            ctx.accounts.new_collect_protocol_fees_authority.key(),
-        ))
+    ctx.accounts
+        .pending_authorities
+        .collect_protocol_fees_authority = ctx.accounts.new_collect_protocol_fees_authority.key();
+    emit!(AuthorityTransferProposed {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        authority: AUTHORITY_COLLECT_PROTOCOL_FEES,
+        new_authority: ctx.accounts.new_collect_protocol_fees_authority.key(),
+    });
+    Ok(())
 }
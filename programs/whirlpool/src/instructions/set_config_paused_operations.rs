@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::events::ConfigPausedOperationsUpdated;
+use crate::state::{PauseControls, WhirlpoolsConfig};
+
+#[derive(Accounts)]
+pub struct SetConfigPausedOperations<'info> {
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(address = whirlpools_config.pool_creator_authority)]
+    pub pool_creator_authority: Signer<'info>,
+
+    #[account(
+      mut,
+      seeds = [b"pause_controls", whirlpools_config.key().as_ref()],
+      bump,
+      has_one = whirlpools_config)]
+    pub pause_controls: Account<'info, PauseControls>,
+}
+
+pub fn handler(ctx: Context<SetConfigPausedOperations>, paused_operations: u8) -> ProgramResult {
+    ctx.accounts
+        .pause_controls
+        .update_paused_operations(paused_operations)?;
+    emit!(ConfigPausedOperationsUpdated {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        paused_operations,
+    });
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 
+use crate::events::DefaultFeeRateUpdated;
 use crate::state::{FeeTier, WhirlpoolsConfig};
 
 #[derive(Accounts)]
@@ -17,8 +18,13 @@ pub struct SetDefaultFeeRate<'info> {
    Updates the default fee rate on a FeeTier object.
 */
 pub fn handler(ctx: Context<SetDefaultFeeRate>, default_fee_rate: u16) -> ProgramResult {
-    Ok(ctx
-        .accounts
+    ctx.accounts
         .fee_tier
-        .update_default_fee_rate(default_fee_rate)?)
+        .update_default_fee_rate(default_fee_rate)?;
+    emit!(DefaultFeeRateUpdated {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        tick_spacing: ctx.accounts.fee_tier.tick_spacing,
+        default_fee_rate,
+    });
+    Ok(())
 }
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 
+use crate::events::DefaultProtocolFeeRateUpdated;
 use crate::state::WhirlpoolsConfig;
 
 #[derive(Accounts)]
@@ -15,8 +16,12 @@ pub fn handler(
     ctx: Context<SetDefaultProtocolFeeRate>,
     default_protocol_fee_rate: u16,
 ) -> ProgramResult {
-    Ok(ctx
-        .accounts
+    ctx.accounts
         .whirlpools_config
-        .update_default_protocol_fee_rate(default_protocol_fee_rate)?)
+        .update_default_protocol_fee_rate(default_protocol_fee_rate)?;
+    emit!(DefaultProtocolFeeRateUpdated {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        default_protocol_fee_rate,
+    });
+    Ok(())
 }
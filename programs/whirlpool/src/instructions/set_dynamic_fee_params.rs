@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::events::DynamicFeeParamsUpdated;
+use crate::state::{DynamicFee, Whirlpool, WhirlpoolsConfig};
+
+#[derive(Accounts)]
+pub struct SetDynamicFeeParams<'info> {
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(has_one = whirlpools_config)]
+    pub whirlpool: Account<'info, Whirlpool>,
+
+    #[account(address = whirlpools_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+
+    #[account(mut, has_one = whirlpool)]
+    pub dynamic_fee: Account<'info, DynamicFee>,
+}
+
+pub fn handler(
+    ctx: Context<SetDynamicFeeParams>,
+    base_surcharge_rate: u16,
+    max_surcharge_rate: u16,
+    decay_period: u64,
+) -> ProgramResult {
+    ctx.accounts.dynamic_fee.update_params(
+        base_surcharge_rate,
+        max_surcharge_rate,
+        decay_period,
+    )?;
+    emit!(DynamicFeeParamsUpdated {
+        whirlpool: ctx.accounts.whirlpool.key(),
+        base_surcharge_rate,
+        max_surcharge_rate,
+        decay_period,
+    });
+    Ok(())
+}
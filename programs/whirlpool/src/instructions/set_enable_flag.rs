@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 
+use crate::events::EnableFlagUpdated;
 use crate::state::{Whirlpool, WhirlpoolsConfig};
 
 #[derive(Accounts)]
@@ -15,5 +16,9 @@ pub struct SetEnableFlag<'info> {
 
 pub fn handler(ctx: Context<SetEnableFlag>, is_enabled: bool) -> ProgramResult {
     ctx.accounts.whirlpool.set_enable_flag(is_enabled);
+    emit!(EnableFlagUpdated {
+        whirlpool: ctx.accounts.whirlpool.key(),
+        is_enabled,
+    });
     Ok(())
 }
@@ -1,22 +1,34 @@
 use anchor_lang::prelude::*;
 
-use crate::state::WhirlpoolsConfig;
+use crate::events::{AuthorityTransferProposed, AUTHORITY_FEE};
+use crate::state::{PendingAuthorities, WhirlpoolsConfig};
 
 #[derive(Accounts)]
 pub struct SetFeeAuthority<'info> {
-    #[account(mut)]
     pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
 
     #[account(address = whirlpools_config.fee_authority)]
     pub fee_authority: Signer<'info>,
 
     pub new_fee_authority: UncheckedAccount<'info>,
+
+    #[account(
+      mut,
+      seeds = [b"pending_authorities", whirlpools_config.key().as_ref()],
+      bump,
+      has_one = whirlpools_config)]
+    pub pending_authorities: Account<'info, PendingAuthorities>,
 }
 
-/// Set the fee authority. Only the current fee authority has permission to invoke this instruction.
+/// Propose a new fee authority.
+/// The transfer takes effect once the new authority accepts it.
+/// Only the current fee authority has permission to invoke this instruction.
 pub fn handler(ctx: Context<SetFeeAuthority>) -> ProgramResult {
-    Ok(ctx
-        .accounts
-        .whirlpools_config
-        .update_fee_authority(ctx.accounts.new_fee_authority.key()))
+    ctx.accounts.pending_authorities.fee_authority = ctx.accounts.new_fee_authority.key();
+    emit!(AuthorityTransferProposed {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        authority: AUTHORITY_FEE,
+        new_authority: ctx.accounts.new_fee_authority.key(),
+    });
+    Ok(())
 }
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::events::FeeTierEnabledUpdated;
+use crate::state::{FeeTier, FeeTierPolicy, WhirlpoolsConfig};
+
+#[derive(Accounts)]
+pub struct SetFeeTierEnabled<'info> {
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(has_one = whirlpools_config)]
+    pub fee_tier: Account<'info, FeeTier>,
+
+    #[account(address = whirlpools_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+
+    #[account(
+      mut,
+      seeds = [b"fee_tier_policy", whirlpools_config.key().as_ref()],
+      bump,
+      has_one = whirlpools_config)]
+    pub fee_tier_policy: Account<'info, FeeTierPolicy>,
+}
+
+/*
+   Enables or disables pool creation on a FeeTier. Existing pools of the fee tier are not affected.
+*/
+pub fn handler(ctx: Context<SetFeeTierEnabled>, is_enabled: bool) -> ProgramResult {
+    let tick_spacing = ctx.accounts.fee_tier.tick_spacing;
+    ctx.accounts
+        .fee_tier_policy
+        .set_fee_tier_enabled(tick_spacing, is_enabled)?;
+    emit!(FeeTierEnabledUpdated {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        tick_spacing,
+        is_enabled,
+    });
+    Ok(())
+}
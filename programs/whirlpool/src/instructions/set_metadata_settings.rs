@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::events::MetadataSettingsUpdated;
+use crate::state::{MetadataSettings, WhirlpoolsConfig};
+
+#[derive(Accounts)]
+pub struct SetMetadataSettings<'info> {
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(address = whirlpools_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+
+    #[account(
+      mut,
+      seeds = [b"metadata_settings", whirlpools_config.key().as_ref()],
+      bump,
+      has_one = whirlpools_config)]
+    pub metadata_settings: Account<'info, MetadataSettings>,
+}
+
+/*
+  Updates the metadata of the position NFTs opened from now on.
+  The metadata of positions that are already open is not changed.
+*/
+pub fn handler(
+    ctx: Context<SetMetadataSettings>,
+    update_authority: Pubkey,
+    name: String,
+    symbol: String,
+    uri_template: String,
+) -> ProgramResult {
+    ctx.accounts
+        .metadata_settings
+        .update(update_authority, name, symbol, uri_template)?;
+
+    let metadata_settings = &ctx.accounts.metadata_settings;
+    emit!(MetadataSettingsUpdated {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        update_authority,
+        name: metadata_settings.name.clone(),
+        symbol: metadata_settings.symbol.clone(),
+        uri_template: metadata_settings.uri_template.clone(),
+    });
+    Ok(())
+}
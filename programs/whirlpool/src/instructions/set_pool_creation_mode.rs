@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::events::PoolCreationModeUpdated;
+use crate::state::{PoolCreationPolicy, WhirlpoolsConfig};
+
+#[derive(Accounts)]
+pub struct ModifyPoolCreationPolicy<'info> {
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(address = whirlpools_config.pool_creator_authority)]
+    pub pool_creator_authority: Signer<'info>,
+
+    #[account(
+      mut,
+      seeds = [b"pool_creation_policy", whirlpools_config.key().as_ref()],
+      bump,
+      has_one = whirlpools_config)]
+    pub pool_creation_policy: Account<'info, PoolCreationPolicy>,
+}
+
+pub fn handler(ctx: Context<ModifyPoolCreationPolicy>, mode: u8) -> ProgramResult {
+    ctx.accounts.pool_creation_policy.update_mode(mode)?;
+    emit!(PoolCreationModeUpdated {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        mode,
+    });
+    Ok(())
+}
@@ -1,22 +1,35 @@
 use anchor_lang::prelude::*;
 
-use crate::state::WhirlpoolsConfig;
+use crate::events::{AuthorityTransferProposed, AUTHORITY_POOL_CREATOR};
+use crate::state::{PendingAuthorities, WhirlpoolsConfig};
 
 #[derive(Accounts)]
 pub struct SetPoolCreatorAuthority<'info> {
-    #[account(mut)]
     pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
 
     #[account(address = whirlpools_config.pool_creator_authority)]
     pub pool_creator_authority: Signer<'info>,
 
     pub new_pool_creator_authority: UncheckedAccount<'info>,
+
+    #[account(
+      mut,
+      seeds = [b"pending_authorities", whirlpools_config.key().as_ref()],
+      bump,
+      has_one = whirlpools_config)]
+    pub pending_authorities: Account<'info, PendingAuthorities>,
 }
 
-/// Set the pool creator authority. Only the current pool creator authority has permission to invoke this instruction.
+/// Propose a new pool creator authority.
+/// The transfer takes effect once the new authority accepts it.
+/// Only the current pool creator authority has permission to invoke this instruction.
 pub fn handler(ctx: Context<SetPoolCreatorAuthority>) -> ProgramResult {
-    Ok(ctx
-        .accounts
-        .whirlpools_config
-        .update_pool_creator_authority(ctx.accounts.new_pool_creator_authority.key()))
+    ctx.accounts.pending_authorities.pool_creator_authority =
+        ctx.accounts.new_pool_creator_authority.key();
+    emit!(AuthorityTransferProposed {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        authority: AUTHORITY_POOL_CREATOR,
+        new_authority: ctx.accounts.new_pool_creator_authority.key(),
+    });
+    Ok(())
 }
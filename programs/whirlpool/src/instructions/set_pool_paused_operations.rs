@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::events::PoolPausedOperationsUpdated;
+use crate::state::{PauseControls, Whirlpool, WhirlpoolsConfig};
+
+#[derive(Accounts)]
+pub struct SetPoolPausedOperations<'info> {
+    pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
+
+    #[account(has_one = whirlpools_config)]
+    pub whirlpool: Account<'info, Whirlpool>,
+
+    #[account(address = whirlpools_config.pool_creator_authority)]
+    pub pool_creator_authority: Signer<'info>,
+
+    #[account(
+      mut,
+      seeds = [b"pause_controls", whirlpools_config.key().as_ref()],
+      bump,
+      has_one = whirlpools_config)]
+    pub pause_controls: Account<'info, PauseControls>,
+}
+
+pub fn handler(ctx: Context<SetPoolPausedOperations>, paused_operations: u8) -> ProgramResult {
+    let whirlpool = ctx.accounts.whirlpool.key();
+    ctx.accounts
+        .pause_controls
+        .update_pool_paused_operations(whirlpool, paused_operations)?;
+    emit!(PoolPausedOperationsUpdated {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        whirlpool,
+        paused_operations,
+    });
+
+    Ok(())
+}
@@ -1,21 +1,40 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Whirlpool, WhirlpoolsConfig};
+use crate::events::FeeRateChangeScheduled;
+use crate::state::{FeeRateSchedule, Whirlpool, WhirlpoolsConfig, MIN_FEE_RATE_CHANGE_DELAY};
+use crate::util::to_timestamp_u64;
 
 #[derive(Accounts)]
 pub struct SetProtocolFeeRate<'info> {
     pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
 
-    #[account(mut, has_one = whirlpools_config)]
+    #[account(has_one = whirlpools_config)]
     pub whirlpool: Account<'info, Whirlpool>,
 
     #[account(address = whirlpools_config.fee_authority)]
     pub fee_authority: Signer<'info>,
+
+    #[account(mut, has_one = whirlpool)]
+    pub fee_rate_schedule: Account<'info, FeeRateSchedule>,
 }
 
+/*
+  Schedules the new protocol fee rate through the fee rate schedule of the Whirlpool, so that it
+  only applies after MIN_FEE_RATE_CHANGE_DELAY. The fee rate of a pending change is kept.
+*/
 pub fn handler(ctx: Context<SetProtocolFeeRate>, protocol_fee_rate: u16) -> ProgramResult {
-    Ok(ctx
-        .accounts
-        .whirlpool
-        .update_protocol_fee_rate(protocol_fee_rate)?)
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    let effective_at = timestamp.saturating_add(MIN_FEE_RATE_CHANGE_DELAY);
+
+    let fee_rate_schedule = &mut ctx.accounts.fee_rate_schedule;
+    let (fee_rate, _) = fee_rate_schedule.next_fee_rates(&ctx.accounts.whirlpool);
+    fee_rate_schedule.schedule(fee_rate, protocol_fee_rate, effective_at, timestamp)?;
+    emit!(FeeRateChangeScheduled {
+        whirlpool: ctx.accounts.whirlpool.key(),
+        fee_rate,
+        protocol_fee_rate,
+        effective_at,
+    });
+    Ok(())
 }
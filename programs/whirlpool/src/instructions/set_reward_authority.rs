@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 
+use crate::events::RewardAuthorityUpdated;
 use crate::state::Whirlpool;
 
 #[derive(Accounts)]
@@ -15,8 +16,14 @@ pub struct SetRewardAuthority<'info> {
 }
 
 pub fn handler(ctx: Context<SetRewardAuthority>, reward_index: u8) -> ProgramResult {
-    Ok(ctx.accounts.whirlpool.update_reward_authority(
+    ctx.accounts.whirlpool.update_reward_authority(
         reward_index as usize,
         ctx.accounts.new_reward_authority.key(),
-    )?)
+    )?;
+    emit!(RewardAuthorityUpdated {
+        whirlpool: ctx.accounts.whirlpool.key(),
+        reward_index,
+        new_reward_authority: ctx.accounts.new_reward_authority.key(),
+    });
+    Ok(())
 }
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 
+use crate::events::RewardAuthorityUpdated;
 use crate::state::{Whirlpool, WhirlpoolsConfig};
 
 #[derive(Accounts)]
@@ -22,8 +23,14 @@ pub fn handler(
     ctx: Context<SetRewardAuthorityBySuperAuthority>,
     reward_index: u8,
 ) -> ProgramResult {
-    Ok(ctx.accounts.whirlpool.update_reward_authority(
+    ctx.accounts.whirlpool.update_reward_authority(
         reward_index as usize,
         ctx.accounts.new_reward_authority.key(),
-    )?)
+    )?;
+    emit!(RewardAuthorityUpdated {
+        whirlpool: ctx.accounts.whirlpool.key(),
+        reward_index,
+        new_reward_authority: ctx.accounts.new_reward_authority.key(),
+    });
+    Ok(())
 }
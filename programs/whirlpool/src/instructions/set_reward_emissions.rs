@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::TokenAccount;
 
 use crate::errors::ErrorCode;
+use crate::events::RewardEmissionsUpdated;
 use crate::manager::whirlpool_manager::next_whirlpool_reward_infos;
 use crate::math::checked_mul_shift_right;
 use crate::state::Whirlpool;
@@ -39,10 +40,16 @@ pub fn handler(
     let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
     let next_reward_infos = next_whirlpool_reward_infos(whirlpool, timestamp)?;
 
-    Ok(ctx.accounts.whirlpool.update_emissions(
+    ctx.accounts.whirlpool.update_emissions(
         reward_index as usize,
         next_reward_infos,
         timestamp,
         emissions_per_second_x64,
-    )?)
+    )?;
+    emit!(RewardEmissionsUpdated {
+        whirlpool: ctx.accounts.whirlpool.key(),
+        reward_index,
+        emissions_per_second_x64,
+    });
+    Ok(())
 }
@@ -1,23 +1,36 @@
 use anchor_lang::prelude::*;
 
-use crate::state::WhirlpoolsConfig;
+use crate::events::{AuthorityTransferProposed, AUTHORITY_REWARD_EMISSIONS_SUPER};
+use crate::state::{PendingAuthorities, WhirlpoolsConfig};
 
 #[derive(Accounts)]
 pub struct SetRewardEmissionsSuperAuthority<'info> {
-    #[account(mut)]
     pub whirlpools_config: Account<'info, WhirlpoolsConfig>,
 
     #[account(address = whirlpools_config.reward_emissions_super_authority)]
     pub reward_emissions_super_authority: Signer<'info>,
 
     pub new_reward_emissions_super_authority: UncheckedAccount<'info>,
+
+    #[account(
+      mut,
+      seeds = [b"pending_authorities", whirlpools_config.key().as_ref()],
+      bump,
+      has_one = whirlpools_config)]
+    pub pending_authorities: Account<'info, PendingAuthorities>,
 }
 
+/// Propose a new reward emissions super authority.
+/// The transfer takes effect once the new authority accepts it.
+/// Only the current reward emissions super authority has permission to invoke this instruction.
 pub fn handler(ctx: Context<SetRewardEmissionsSuperAuthority>) -> ProgramResult {
-    Ok(ctx
-        .accounts
-        .whirlpools_config
-        .update_reward_emissions_super_authority(
-            ctx.accounts.new_reward_emissions_super_authority.key(),
-        ))
+    ctx.accounts
+        .pending_authorities
+        .reward_emissions_super_authority = ctx.accounts.new_reward_emissions_super_authority.key();
+    emit!(AuthorityTransferProposed {
+        whirlpools_config: ctx.accounts.whirlpools_config.key(),
+        authority: AUTHORITY_REWARD_EMISSIONS_SUPER,
+        new_authority: ctx.accounts.new_reward_emissions_super_authority.key(),
+    });
+    Ok(())
 }
@@ -0,0 +1,308 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::{
+    errors::ErrorCode,
+    manager::swap_manager::*,
+    state::{
+        TickArray, Whirlpool, EXTENSION_DYNAMIC_FEE, EXTENSION_FEE_RATE_SCHEDULE, EXTENSION_ORACLE,
+        EXTENSION_RANGE_ORDER_BOOK, PAUSE_SWAP,
+    },
+    util::{
+        apply_fee_rate_schedule, find_pause_controls, load_dynamic_fee, require_not_paused,
+        to_timestamp_u64, update_and_swap_whirlpool, update_range_order_book, SwapTickSequence,
+        WhirlpoolExtensionAccounts,
+    },
+};
+
+/// Number of `remaining_accounts` that describe a single leg of a split swap, before the side
+/// accounts of its extensions.
+pub const SPLIT_SWAP_LEG_ACCOUNTS_LEN: usize = 6;
+
+/// Extensions whose side accounts follow the accounts of each leg. The pause controls, which every
+/// leg requires, are passed once per config after the last leg instead.
+pub const SPLIT_SWAP_LEG_EXTENSIONS: u8 = EXTENSION_ORACLE
+    | EXTENSION_DYNAMIC_FEE
+    | EXTENSION_FEE_RATE_SCHEDULE
+    | EXTENSION_RANGE_ORDER_BOOK;
+
+/// The split of each leg is stored as basis points of the total amount.
+pub const SPLIT_BPS_MUL_VALUE: u128 = 10_000;
+
+#[derive(Accounts)]
+pub struct SplitSwap<'info> {
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub token_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub token_owner_account_a: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub token_owner_account_b: Box<Account<'info, TokenAccount>>,
+    // remaining_accounts: for each leg, SPLIT_SWAP_LEG_ACCOUNTS_LEN accounts in the order of
+    // `SplitSwapLeg` followed by the side accounts of the SPLIT_SWAP_LEG_EXTENSIONS enabled on its
+    // whirlpool. The pause controls of the configs of the legs follow the last leg, whether or not
+    // they have been initialized.
+}
+
+/// The accounts of a single leg of a split swap, parsed from `remaining_accounts`.
+pub struct SplitSwapLeg<'info> {
+    pub whirlpool: Box<Account<'info, Whirlpool>>,
+    pub token_vault_a: Box<Account<'info, TokenAccount>>,
+    pub token_vault_b: Box<Account<'info, TokenAccount>>,
+    pub tick_array_0: AccountLoader<'info, TickArray>,
+    pub tick_array_1: AccountLoader<'info, TickArray>,
+    pub tick_array_2: AccountLoader<'info, TickArray>,
+    pub extension_accounts: WhirlpoolExtensionAccounts<'info>,
+}
+
+impl<'info> SplitSwapLeg<'info> {
+    /// Parse a leg from the front of `accounts`.
+    /// Returns the leg along with the number of accounts it took.
+    pub fn try_from(accounts: &[AccountInfo<'info>]) -> Result<(Self, usize), ProgramError> {
+        if accounts.len() < SPLIT_SWAP_LEG_ACCOUNTS_LEN {
+            return Err(ErrorCode::InvalidSplitSwap.into());
+        }
+
+        let whirlpool: Box<Account<Whirlpool>> = Box::new(Account::try_from(&accounts[0])?);
+        let (extension_accounts, extension_accounts_count) = WhirlpoolExtensionAccounts::try_from(
+            &whirlpool,
+            &accounts[SPLIT_SWAP_LEG_ACCOUNTS_LEN..],
+            SPLIT_SWAP_LEG_EXTENSIONS,
+        )?;
+
+        let leg = SplitSwapLeg {
+            whirlpool,
+            token_vault_a: Box::new(Account::try_from(&accounts[1])?),
+            token_vault_b: Box::new(Account::try_from(&accounts[2])?),
+            tick_array_0: AccountLoader::try_from(&accounts[3])?,
+            tick_array_1: AccountLoader::try_from(&accounts[4])?,
+            tick_array_2: AccountLoader::try_from(&accounts[5])?,
+            extension_accounts,
+        };
+
+        let whirlpool = &leg.whirlpool;
+        if leg.token_vault_a.key() != whirlpool.token_vault_a
+            || leg.token_vault_b.key() != whirlpool.token_vault_b
+        {
+            return Err(ErrorCode::InvalidSplitSwap.into());
+        }
+
+        for tick_array in [&leg.tick_array_0, &leg.tick_array_1, &leg.tick_array_2] {
+            if tick_array.load()?.whirlpool != whirlpool.key() {
+                return Err(ErrorCode::InvalidSplitSwap.into());
+            }
+        }
+
+        Ok((leg, SPLIT_SWAP_LEG_ACCOUNTS_LEN + extension_accounts_count))
+    }
+}
+
+/// Split `amount` into one amount per leg according to `split_bps`.
+/// The last leg receives the remainder so that the leg amounts always sum up to `amount`.
+///
+/// # Errors
+/// - `InvalidSplitSwap` - `split_bps` is empty or its values do not sum up to 100%
+pub fn split_amount(amount: u64, split_bps: &[u16]) -> Result<Vec<u64>, ErrorCode> {
+    let total_bps: u128 = split_bps.iter().map(|bps| *bps as u128).sum();
+    if split_bps.is_empty() || total_bps != SPLIT_BPS_MUL_VALUE {
+        return Err(ErrorCode::InvalidSplitSwap);
+    }
+
+    let mut amounts = Vec::with_capacity(split_bps.len());
+    let mut amount_remaining = amount;
+    for bps in &split_bps[..split_bps.len() - 1] {
+        // Cannot exceed the amount since the bps of a single leg is at most SPLIT_BPS_MUL_VALUE
+        let leg_amount = ((amount as u128) * (*bps as u128) / SPLIT_BPS_MUL_VALUE) as u64;
+        amount_remaining -= leg_amount;
+        amounts.push(leg_amount);
+    }
+    amounts.push(amount_remaining);
+
+    Ok(amounts)
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, SplitSwap<'info>>,
+    amount: u64,
+    other_amount_threshold: u64,
+    amount_specified_is_input: bool,
+    a_to_b: bool,
+    split_bps: Vec<u16>,
+    sqrt_price_limits: Vec<u128>,
+) -> ProgramResult {
+    let clock = Clock::get()?;
+    // Update the global reward growth which increases as a function of time.
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    let leg_count = split_bps.len();
+    if sqrt_price_limits.len() != leg_count {
+        return Err(ErrorCode::InvalidSplitSwap.into());
+    }
+    let leg_amounts = split_amount(amount, &split_bps)?;
+
+    let token_mint_a = ctx.accounts.token_owner_account_a.mint;
+    let token_mint_b = ctx.accounts.token_owner_account_b.mint;
+
+    let mut remaining_accounts = ctx.remaining_accounts;
+    let mut legs = Vec::with_capacity(leg_count);
+    for _ in 0..leg_count {
+        let (leg, leg_accounts_len) = SplitSwapLeg::try_from(remaining_accounts)?;
+        remaining_accounts = &remaining_accounts[leg_accounts_len..];
+        leg.whirlpool.require_enabled()?;
+
+        // Every leg has to trade the same pair of tokens
+        if leg.whirlpool.token_mint_a != token_mint_a || leg.whirlpool.token_mint_b != token_mint_b
+        {
+            return Err(ErrorCode::InvalidSplitSwap.into());
+        }
+
+        if legs
+            .iter()
+            .any(|prev: &SplitSwapLeg| prev.whirlpool.key() == leg.whirlpool.key())
+        {
+            return Err(ErrorCode::DuplicateRoutePool.into());
+        }
+        legs.push(leg);
+    }
+
+    // The accounts left after the last leg are the pause controls of the configs of the legs
+    let whirlpools: Vec<&Account<Whirlpool>> = legs.iter().map(|leg| &*leg.whirlpool).collect();
+    let pause_controls = find_pause_controls(&whirlpools, remaining_accounts)?;
+    for (leg, pause_controls) in legs.iter().zip(pause_controls) {
+        require_not_paused(&pause_controls, &leg.whirlpool, PAUSE_SWAP)?;
+    }
+
+    let mut swap_updates: Vec<Option<PostSwapUpdate>> = Vec::with_capacity(leg_count);
+    let mut total_amount_a: u64 = 0;
+    let mut total_amount_b: u64 = 0;
+    for (i, leg) in legs.iter_mut().enumerate() {
+        // Legs that receive nothing from the split are skipped
+        if leg_amounts[i] == 0 {
+            swap_updates.push(None);
+            continue;
+        }
+
+        apply_fee_rate_schedule(
+            &mut leg.whirlpool,
+            leg.extension_accounts.fee_rate_schedule.as_ref(),
+            timestamp,
+        )?;
+
+        let mut swap_tick_sequence = SwapTickSequence::new(
+            leg.tick_array_0.load_mut()?,
+            leg.tick_array_1.load_mut().ok(),
+            leg.tick_array_2.load_mut().ok(),
+        );
+
+        let dynamic_fee = load_dynamic_fee(leg.extension_accounts.dynamic_fee.as_ref())?;
+        let swap_update = swap(
+            &leg.whirlpool,
+            dynamic_fee.as_deref(),
+            &mut swap_tick_sequence,
+            leg_amounts[i],
+            sqrt_price_limits[i],
+            amount_specified_is_input,
+            a_to_b,
+            timestamp,
+        )?;
+
+        total_amount_a = total_amount_a
+            .checked_add(swap_update.amount_a)
+            .ok_or(ErrorCode::AmountCalcOverflow)?;
+        total_amount_b = total_amount_b
+            .checked_add(swap_update.amount_b)
+            .ok_or(ErrorCode::AmountCalcOverflow)?;
+        swap_updates.push(Some(swap_update));
+    }
+
+    // The slippage threshold applies to the combined amounts of all legs
+    if amount_specified_is_input {
+        if (a_to_b && other_amount_threshold > total_amount_b)
+            || (!a_to_b && other_amount_threshold > total_amount_a)
+        {
+            return Err(ErrorCode::AmountOutBelowMinimum.into());
+        }
+    } else {
+        if (a_to_b && other_amount_threshold < total_amount_a)
+            || (!a_to_b && other_amount_threshold < total_amount_b)
+        {
+            return Err(ErrorCode::AmountInAboveMaximum.into());
+        }
+    }
+
+    for (leg, swap_update) in legs.iter_mut().zip(swap_updates) {
+        let swap_update = match swap_update {
+            Some(swap_update) => swap_update,
+            None => continue,
+        };
+
+        update_and_swap_whirlpool(
+            &mut leg.whirlpool,
+            &ctx.accounts.token_authority,
+            &ctx.accounts.token_owner_account_a,
+            &ctx.accounts.token_owner_account_b,
+            &leg.token_vault_a,
+            &leg.token_vault_b,
+            &ctx.accounts.token_program,
+            leg.extension_accounts.oracle.as_ref(),
+            leg.extension_accounts.dynamic_fee.as_ref(),
+            swap_update,
+            a_to_b,
+            timestamp,
+        )?;
+
+        // The tick sequence of the calculation has been released, so it is loaded again
+        let mut swap_tick_sequence = SwapTickSequence::new(
+            leg.tick_array_0.load_mut()?,
+            leg.tick_array_1.load_mut().ok(),
+            leg.tick_array_2.load_mut().ok(),
+        );
+        update_range_order_book(
+            &leg.whirlpool,
+            leg.extension_accounts.range_order_book.as_ref(),
+            &mut swap_tick_sequence,
+        )?;
+
+        // Accounts parsed from remaining_accounts are not persisted by Anchor
+        leg.whirlpool.exit(&crate::ID)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod split_amount_tests {
+    use super::*;
+
+    #[test]
+    fn test_split_amount_even() {
+        assert_eq!(split_amount(1000, &[5000, 5000]).unwrap(), vec![500, 500]);
+    }
+
+    #[test]
+    fn test_split_amount_remainder_to_last_leg() {
+        assert_eq!(
+            split_amount(10, &[3333, 3333, 3334]).unwrap(),
+            vec![3, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_split_amount_single_leg() {
+        assert_eq!(split_amount(u64::MAX, &[10_000]).unwrap(), vec![u64::MAX]);
+    }
+
+    #[test]
+    fn test_split_amount_invalid_total() {
+        assert_eq!(
+            split_amount(1000, &[5000, 4999]).unwrap_err(),
+            ErrorCode::InvalidSplitSwap
+        );
+        assert_eq!(
+            split_amount(1000, &[]).unwrap_err(),
+            ErrorCode::InvalidSplitSwap
+        );
+    }
+}
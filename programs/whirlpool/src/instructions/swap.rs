@@ -4,14 +4,33 @@ use anchor_spl::token::{self, Token, TokenAccount};
 use crate::{
     errors::ErrorCode,
     manager::swap_manager::*,
-    state::{TickArray, Whirlpool},
+    return_data::{set_swap_return_data, SwapReturnData},
+    state::{
+        TickArray,
+        Whirlpool,
+        EXTENSION_DYNAMIC_FEE,
+        EXTENSION_FEE_RATE_SCHEDULE,
+        EXTENSION_RANGE_ORDER_BOOK,
+        PAUSE_SWAP
+    },
     util::{
+        apply_fee_rate_schedule,
+        load_dynamic_fee,
+        require_not_paused,
         to_timestamp_u64,
         SwapTickSequence,
-        update_and_swap_whirlpool
+        update_and_swap_whirlpool,
+        update_range_order_book,
+        WhirlpoolExtensionAccounts
     },
 };
 
+/// Extensions used by a swap. The side accounts of the ones enabled on the Whirlpool are passed
+/// in `remaining_accounts`, the oracle has its own account.
+pub const SWAP_EXTENSIONS: u8 = EXTENSION_DYNAMIC_FEE
+    | EXTENSION_FEE_RATE_SCHEDULE
+    | EXTENSION_RANGE_ORDER_BOOK;
+
 #[derive(Accounts)]
 pub struct Swap<'info> {
     #[account(address = token::ID)]
@@ -41,24 +60,42 @@ pub struct Swap<'info> {
     #[account(mut, has_one = whirlpool)]
     pub tick_array_2: AccountLoader<'info, TickArray>,
 
-    #[account(seeds = [b"oracle", whirlpool.key().as_ref()],bump)]
-    /// Oracle is currently unused and will be enabled on subsequent updates
+    /// CHECK: the oracle is only written to once it has been initialized, which requires it to be
+    /// passed as writable
+    #[account(seeds = [b"oracle", whirlpool.key().as_ref()], bump)]
     pub oracle: UncheckedAccount<'info>,
+
+    /// CHECK: nothing is paused until the pause controls of the config have been initialized
+    #[account(seeds = [b"pause_controls", whirlpool.whirlpools_config.as_ref()], bump)]
+    pub pause_controls: UncheckedAccount<'info>,
+    // remaining_accounts: the side accounts of the SWAP_EXTENSIONS enabled on the whirlpool
 }
 
-pub fn handler(
-    ctx: Context<Swap>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
     amount: u64,
     other_amount_threshold: u64,
     sqrt_price_limit: u128,
     amount_specified_is_input: bool,
     a_to_b: bool, // Zero for one
 ) -> ProgramResult {
+    let (extension_accounts, _) = WhirlpoolExtensionAccounts::try_from(
+        &ctx.accounts.whirlpool,
+        ctx.remaining_accounts,
+        SWAP_EXTENSIONS,
+    )?;
     let whirlpool = &mut ctx.accounts.whirlpool;
     whirlpool.require_enabled()?;
+    require_not_paused(&ctx.accounts.pause_controls, whirlpool, PAUSE_SWAP)?;
     let clock = Clock::get()?;
     // Update the global reward growth which increases as a function of time.
     let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    apply_fee_rate_schedule(
+        whirlpool,
+        extension_accounts.fee_rate_schedule.as_ref(),
+        timestamp,
+    )?;
+    let dynamic_fee = load_dynamic_fee(extension_accounts.dynamic_fee.as_ref())?;
     let mut swap_tick_sequence = SwapTickSequence::new(
         ctx.accounts.tick_array_0.load_mut().unwrap(),
         ctx.accounts.tick_array_1.load_mut().ok(),
@@ -67,6 +104,7 @@ pub fn handler(
 
     let swap_update = swap(
         &whirlpool,
+        dynamic_fee.as_deref(),
         &mut swap_tick_sequence,
         amount,
         sqrt_price_limit,
@@ -89,6 +127,7 @@ pub fn handler(
         }
     }
 
+    let return_data = SwapReturnData::from(&swap_update);
     update_and_swap_whirlpool(
         whirlpool,
         &ctx.accounts.token_authority,
@@ -97,8 +136,18 @@ pub fn handler(
         &ctx.accounts.token_vault_a,
         &ctx.accounts.token_vault_b,
         &ctx.accounts.token_program,
+        Some(ctx.accounts.oracle.as_ref()),
+        extension_accounts.dynamic_fee.as_ref(),
         swap_update,
         a_to_b,
         timestamp,
-    )
+    )?;
+
+    update_range_order_book(
+        whirlpool,
+        extension_accounts.range_order_book.as_ref(),
+        &mut swap_tick_sequence,
+    )?;
+
+    set_swap_return_data(&return_data)
 }
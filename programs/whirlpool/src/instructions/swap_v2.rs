@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ErrorCode,
+    return_data::{set_swap_return_data, SwapReturnData},
+    state::{TickArray, Whirlpool, PAUSE_SWAP},
+    util::{
+        apply_fee_rate_schedule, calculate_transfer_fee_excluded_amount, get_transfer_fee,
+        load_dynamic_fee, require_not_paused, swap_with_transfer_fee, to_timestamp_u64,
+        update_and_swap_whirlpool_v2, update_range_order_book, SwapTickSequence,
+        WhirlpoolExtensionAccounts,
+    },
+};
+
+use super::SWAP_EXTENSIONS;
+
+#[derive(Accounts)]
+pub struct SwapV2<'info> {
+    /// CHECK: the token program of each mint is the program that owns it
+    #[account(address = *token_mint_a.owner)]
+    pub token_program_a: UncheckedAccount<'info>,
+    /// CHECK: the token program of each mint is the program that owns it
+    #[account(address = *token_mint_b.owner)]
+    pub token_program_b: UncheckedAccount<'info>,
+
+    pub token_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub whirlpool: Box<Account<'info, Whirlpool>>,
+
+    /// CHECK: the mints of the whirlpool
+    #[account(address = whirlpool.token_mint_a)]
+    pub token_mint_a: UncheckedAccount<'info>,
+    /// CHECK: the mints of the whirlpool
+    #[account(address = whirlpool.token_mint_b)]
+    pub token_mint_b: UncheckedAccount<'info>,
+
+    /// CHECK: the mint of the token owner accounts is checked by the transfers
+    #[account(mut)]
+    pub token_owner_account_a: UncheckedAccount<'info>,
+    /// CHECK: the vaults of the whirlpool
+    #[account(mut, address = whirlpool.token_vault_a)]
+    pub token_vault_a: UncheckedAccount<'info>,
+
+    /// CHECK: the mint of the token owner accounts is checked by the transfers
+    #[account(mut)]
+    pub token_owner_account_b: UncheckedAccount<'info>,
+    /// CHECK: the vaults of the whirlpool
+    #[account(mut, address = whirlpool.token_vault_b)]
+    pub token_vault_b: UncheckedAccount<'info>,
+
+    #[account(mut, has_one = whirlpool)]
+    pub tick_array_0: AccountLoader<'info, TickArray>,
+
+    #[account(mut, has_one = whirlpool)]
+    pub tick_array_1: AccountLoader<'info, TickArray>,
+
+    #[account(mut, has_one = whirlpool)]
+    pub tick_array_2: AccountLoader<'info, TickArray>,
+
+    /// CHECK: the oracle is only written to once it has been initialized, which requires it to be
+    /// passed as writable
+    #[account(seeds = [b"oracle", whirlpool.key().as_ref()], bump)]
+    pub oracle: UncheckedAccount<'info>,
+
+    /// CHECK: nothing is paused until the pause controls of the config have been initialized
+    #[account(seeds = [b"pause_controls", whirlpool.whirlpools_config.as_ref()], bump)]
+    pub pause_controls: UncheckedAccount<'info>,
+    // remaining_accounts: the side accounts of the SWAP_EXTENSIONS enabled on the whirlpool
+}
+
+/*
+  Swaps on a Whirlpool whose mints may belong to the Token-2022 program. The amount and the
+  threshold are what the token authority sends and receives, net of the transfer fees.
+*/
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, SwapV2<'info>>,
+    amount: u64,
+    other_amount_threshold: u64,
+    sqrt_price_limit: u128,
+    amount_specified_is_input: bool,
+    a_to_b: bool, // Zero for one
+) -> ProgramResult {
+    let (extension_accounts, _) = WhirlpoolExtensionAccounts::try_from(
+        &ctx.accounts.whirlpool,
+        ctx.remaining_accounts,
+        SWAP_EXTENSIONS,
+    )?;
+    let whirlpool = &mut ctx.accounts.whirlpool;
+    whirlpool.require_enabled()?;
+    require_not_paused(&ctx.accounts.pause_controls, whirlpool, PAUSE_SWAP)?;
+    let clock = Clock::get()?;
+    // Update the global reward growth which increases as a function of time.
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    apply_fee_rate_schedule(
+        whirlpool,
+        extension_accounts.fee_rate_schedule.as_ref(),
+        timestamp,
+    )?;
+    let dynamic_fee = load_dynamic_fee(extension_accounts.dynamic_fee.as_ref())?;
+    let transfer_fee_a = get_transfer_fee(&ctx.accounts.token_mint_a)?;
+    let transfer_fee_b = get_transfer_fee(&ctx.accounts.token_mint_b)?;
+    let mut swap_tick_sequence = SwapTickSequence::new(
+        ctx.accounts.tick_array_0.load_mut().unwrap(),
+        ctx.accounts.tick_array_1.load_mut().ok(),
+        ctx.accounts.tick_array_2.load_mut().ok(),
+    );
+
+    let swap_update = swap_with_transfer_fee(
+        &whirlpool,
+        dynamic_fee.as_deref(),
+        &mut swap_tick_sequence,
+        transfer_fee_a.as_ref(),
+        transfer_fee_b.as_ref(),
+        amount,
+        sqrt_price_limit,
+        amount_specified_is_input,
+        a_to_b,
+        timestamp,
+    )?;
+
+    if amount_specified_is_input {
+        let amount_out = if a_to_b {
+            calculate_transfer_fee_excluded_amount(transfer_fee_b.as_ref(), swap_update.amount_b)?
+        } else {
+            calculate_transfer_fee_excluded_amount(transfer_fee_a.as_ref(), swap_update.amount_a)?
+        };
+        if other_amount_threshold > amount_out {
+            return Err(ErrorCode::AmountOutBelowMinimum.into());
+        }
+    } else {
+        if (a_to_b && other_amount_threshold < swap_update.amount_a)
+            || (!a_to_b && other_amount_threshold < swap_update.amount_b)
+        {
+            return Err(ErrorCode::AmountInAboveMaximum.into());
+        }
+    }
+
+    let return_data = SwapReturnData::from(&swap_update);
+    update_and_swap_whirlpool_v2(
+        whirlpool,
+        &ctx.accounts.token_authority,
+        &ctx.accounts.token_mint_a,
+        &ctx.accounts.token_mint_b,
+        &ctx.accounts.token_owner_account_a,
+        &ctx.accounts.token_owner_account_b,
+        &ctx.accounts.token_vault_a,
+        &ctx.accounts.token_vault_b,
+        &ctx.accounts.token_program_a,
+        &ctx.accounts.token_program_b,
+        Some(ctx.accounts.oracle.as_ref()),
+        extension_accounts.dynamic_fee.as_ref(),
+        swap_update,
+        a_to_b,
+        timestamp,
+    )?;
+
+    update_range_order_book(
+        whirlpool,
+        extension_accounts.range_order_book.as_ref(),
+        &mut swap_tick_sequence,
+    )?;
+
+    set_swap_return_data(&return_data)
+}
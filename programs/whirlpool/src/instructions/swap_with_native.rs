@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+use crate::util::{unwrap_native, wrap_native};
+
+use super::swap::{self, Swap};
+
+#[derive(Accounts)]
+pub struct SwapWithNative<'info> {
+    #[account(mut)]
+    pub token_authority: Signer<'info>,
+
+    #[account(address = spl_token::native_mint::ID)]
+    pub native_mint: Account<'info, Mint>,
+
+    #[account(init,
+      payer = token_authority,
+      seeds = [b"native_wrap", token_authority.key().as_ref()],
+      bump,
+      token::mint = native_mint,
+      token::authority = token_authority,
+    )]
+    pub native_wrap_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+
+    // The native wrap account is passed as the token owner account of the native mint
+    #[account(
+      constraint = swap.token_authority.key() == token_authority.key(),
+      constraint = swap.whirlpool.token_mint_a != spl_token::native_mint::ID
+        || swap.token_owner_account_a.key() == native_wrap_account.key(),
+      constraint = swap.whirlpool.token_mint_b != spl_token::native_mint::ID
+        || swap.token_owner_account_b.key() == native_wrap_account.key(),
+    )]
+    pub swap: Swap<'info>,
+}
+
+/*
+  Swaps on a Whirlpool from and/or into the lamport balance of the token authority.
+  The input is wrapped into a temporary RENEC account, which is closed back to the token authority
+  together with the output once the swap is done.
+*/
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, SwapWithNative<'info>>,
+    amount: u64,
+    other_amount_threshold: u64,
+    sqrt_price_limit: u128,
+    amount_specified_is_input: bool,
+    a_to_b: bool,
+) -> ProgramResult {
+    let whirlpool = &ctx.accounts.swap.whirlpool;
+    let input_mint = if a_to_b {
+        whirlpool.token_mint_a
+    } else {
+        whirlpool.token_mint_b
+    };
+    // Wrap the most that the swap can take from the token authority
+    let native_amount = if input_mint != spl_token::native_mint::ID {
+        0
+    } else if amount_specified_is_input {
+        amount
+    } else {
+        other_amount_threshold
+    };
+
+    wrap_native(
+        &ctx.accounts.token_authority,
+        &ctx.accounts.native_wrap_account,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        native_amount,
+    )?;
+
+    swap::handler(
+        Context::new(
+            ctx.program_id,
+            &mut ctx.accounts.swap,
+            ctx.remaining_accounts,
+        ),
+        amount,
+        other_amount_threshold,
+        sqrt_price_limit,
+        amount_specified_is_input,
+        a_to_b,
+    )?;
+
+    unwrap_native(
+        &ctx.accounts.token_authority,
+        &ctx.accounts.native_wrap_account,
+        &ctx.accounts.token_program,
+    )
+}
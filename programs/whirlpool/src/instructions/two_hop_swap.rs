@@ -4,14 +4,22 @@ use anchor_spl::token::{self, Token, TokenAccount};
 use crate::{
     errors::ErrorCode,
     manager::swap_manager::*,
-    state::{TickArray, Whirlpool},
+    return_data::{set_swap_return_data, SwapReturnData, TwoHopSwapReturnData},
+    state::{TickArray, Whirlpool, PAUSE_SWAP},
     util::{
+        apply_fee_rate_schedule,
+        load_dynamic_fee,
+        require_not_paused,
         to_timestamp_u64,
         SwapTickSequence,
         update_and_swap_whirlpool,
+        update_range_order_book,
+        WhirlpoolExtensionAccounts,
     },
 };
 
+use super::SWAP_EXTENSIONS;
+
 #[derive(Accounts)]
 pub struct TwoHopSwap<'info> {
     #[account(address = token::ID)]
@@ -63,17 +71,29 @@ pub struct TwoHopSwap<'info> {
     #[account(mut, constraint = tick_array_two_2.load()?.whirlpool == whirlpool_two.key())]
     pub tick_array_two_2: AccountLoader<'info, TickArray>,
 
-    #[account(seeds = [b"oracle", whirlpool_one.key().as_ref()],bump)]
-    /// Oracle is currently unused and will be enabled on subsequent updates
+    /// CHECK: the oracle is only written to once it has been initialized, which requires it to be
+    /// passed as writable
+    #[account(seeds = [b"oracle", whirlpool_one.key().as_ref()], bump)]
     pub oracle_one: UncheckedAccount<'info>,
 
-    #[account(seeds = [b"oracle", whirlpool_two.key().as_ref()],bump)]
-    /// Oracle is currently unused and will be enabled on subsequent updates
+    /// CHECK: the oracle is only written to once it has been initialized, which requires it to be
+    /// passed as writable
+    #[account(seeds = [b"oracle", whirlpool_two.key().as_ref()], bump)]
     pub oracle_two: UncheckedAccount<'info>,
+
+    /// CHECK: nothing is paused until the pause controls of the config have been initialized
+    #[account(seeds = [b"pause_controls", whirlpool_one.whirlpools_config.as_ref()], bump)]
+    pub pause_controls_one: UncheckedAccount<'info>,
+
+    /// CHECK: nothing is paused until the pause controls of the config have been initialized
+    #[account(seeds = [b"pause_controls", whirlpool_two.whirlpools_config.as_ref()], bump)]
+    pub pause_controls_two: UncheckedAccount<'info>,
+    // remaining_accounts: the side accounts of the SWAP_EXTENSIONS enabled on whirlpool_one,
+    // followed by the ones enabled on whirlpool_two
 }
 
-pub fn handler(
-    ctx: Context<TwoHopSwap>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, TwoHopSwap<'info>>,
     amount: u64,
     other_amount_threshold: u64,
     amount_specified_is_input: bool,
@@ -85,6 +105,19 @@ pub fn handler(
     let clock = Clock::get()?;
     // Update the global reward growth which increases as a function of time.
     let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    let (extension_accounts_one, extension_accounts_one_count) =
+        WhirlpoolExtensionAccounts::try_from(
+            &ctx.accounts.whirlpool_one,
+            ctx.remaining_accounts,
+            SWAP_EXTENSIONS,
+        )?;
+    let (extension_accounts_two, _) = WhirlpoolExtensionAccounts::try_from(
+        &ctx.accounts.whirlpool_two,
+        &ctx.remaining_accounts[extension_accounts_one_count..],
+        SWAP_EXTENSIONS,
+    )?;
+    let dynamic_fee_one = load_dynamic_fee(extension_accounts_one.dynamic_fee.as_ref())?;
+    let dynamic_fee_two = load_dynamic_fee(extension_accounts_two.dynamic_fee.as_ref())?;
 
     let whirlpool_one = &mut ctx.accounts.whirlpool_one;
     let whirlpool_two = &mut ctx.accounts.whirlpool_two;
@@ -94,6 +127,11 @@ pub fn handler(
         return Err(ErrorCode::DuplicateTwoHopPool.into());
     }
 
+    whirlpool_one.require_enabled()?;
+    whirlpool_two.require_enabled()?;
+    require_not_paused(&ctx.accounts.pause_controls_one, whirlpool_one, PAUSE_SWAP)?;
+    require_not_paused(&ctx.accounts.pause_controls_two, whirlpool_two, PAUSE_SWAP)?;
+
     let swap_one_output_mint = if a_to_b_one {
         whirlpool_one.token_mint_b
     } else {
@@ -109,6 +147,17 @@ pub fn handler(
         return Err(ErrorCode::InvalidIntermediaryMint.into());
     }
 
+    apply_fee_rate_schedule(
+        whirlpool_one,
+        extension_accounts_one.fee_rate_schedule.as_ref(),
+        timestamp,
+    )?;
+    apply_fee_rate_schedule(
+        whirlpool_two,
+        extension_accounts_two.fee_rate_schedule.as_ref(),
+        timestamp,
+    )?;
+
     let mut swap_tick_sequence_one = SwapTickSequence::new(
         ctx.accounts.tick_array_one_0.load_mut().unwrap(),
         ctx.accounts.tick_array_one_1.load_mut().ok(),
@@ -123,69 +172,21 @@ pub fn handler(
 
     // TODO: WLOG, we could extend this to N-swaps, but the account inputs to the instruction would
     // need to be jankier and we may need to programatically map/verify rather than using anchor constraints
-    let (swap_update_one, swap_update_two) = if amount_specified_is_input {
-        // If the amount specified is input, this means we are doing exact-in
-        // and the swap calculations occur from Swap 1 => Swap 2
-        // and the swaps occur from Swap 1 => Swap 2
-        let swap_calc_one = swap(
-            &whirlpool_one,
-            &mut swap_tick_sequence_one,
-            amount,
-            sqrt_price_limit_one,
-            amount_specified_is_input, // true
-            a_to_b_one,
-            timestamp,
-        )?;
-
-        // Swap two input is the output of swap one
-        let swap_two_input_amount = if a_to_b_one {
-            swap_calc_one.amount_b
-        } else {
-            swap_calc_one.amount_a
-        };
-
-        let swap_calc_two = swap(
-            &whirlpool_two,
-            &mut swap_tick_sequence_two,
-            swap_two_input_amount,
-            sqrt_price_limit_two,
-            amount_specified_is_input, // true
-            a_to_b_two,
-            timestamp,
-        )?;
-        (swap_calc_one, swap_calc_two)
-    } else {
-        // If the amount specified is output, this means we need to invert the ordering of the calculations
-        // and the swap calculations occur from Swap 2 => Swap 1
-        // but the actual swaps occur from Swap 1 => Swap 2 (to ensure that the intermediate token exists in the account)
-        let swap_calc_two = swap(
-            &whirlpool_two,
-            &mut swap_tick_sequence_two,
-            amount,
-            sqrt_price_limit_two,
-            amount_specified_is_input, // false
-            a_to_b_two,
-            timestamp,
-        )?;
-
-        // The output of swap 1 is input of swap_calc_two
-        let swap_one_output_amount = if a_to_b_two {
-            swap_calc_two.amount_a
-        } else {
-            swap_calc_two.amount_b
-        };
-
-        let swap_calc_one = swap(
-            &whirlpool_one,
-            &mut swap_tick_sequence_one,
-            swap_one_output_amount,
-            sqrt_price_limit_one,
-            amount_specified_is_input, // false
-            a_to_b_one,
-            timestamp,
-        )?;
-        (swap_calc_one, swap_calc_two)
-    };
+    let (swap_update_one, swap_update_two) = two_hop_swap(
+        &whirlpool_one,
+        &whirlpool_two,
+        dynamic_fee_one.as_deref(),
+        dynamic_fee_two.as_deref(),
+        &mut swap_tick_sequence_one,
+        &mut swap_tick_sequence_two,
+        amount,
+        amount_specified_is_input,
+        a_to_b_one,
+        a_to_b_two,
+        sqrt_price_limit_one,
+        sqrt_price_limit_two,
+        timestamp,
+    )?;
 
     if amount_specified_is_input {
         // If amount_specified_is_input == true, then we have a variable amount of output
@@ -213,6 +214,11 @@ pub fn handler(
         }
     }
 
+    let return_data = TwoHopSwapReturnData {
+        swap_one: SwapReturnData::from(&swap_update_one),
+        swap_two: SwapReturnData::from(&swap_update_two),
+    };
+
     update_and_swap_whirlpool(
         whirlpool_one,
         &ctx.accounts.token_authority,
@@ -221,6 +227,8 @@ pub fn handler(
         &ctx.accounts.token_vault_one_a,
         &ctx.accounts.token_vault_one_b,
         &ctx.accounts.token_program,
+        Some(ctx.accounts.oracle_one.as_ref()),
+        extension_accounts_one.dynamic_fee.as_ref(),
         swap_update_one,
         a_to_b_one,
         timestamp,
@@ -234,8 +242,24 @@ pub fn handler(
         &ctx.accounts.token_vault_two_a,
         &ctx.accounts.token_vault_two_b,
         &ctx.accounts.token_program,
+        Some(ctx.accounts.oracle_two.as_ref()),
+        extension_accounts_two.dynamic_fee.as_ref(),
         swap_update_two,
         a_to_b_two,
         timestamp,
-    )
+    )?;
+
+    update_range_order_book(
+        whirlpool_one,
+        extension_accounts_one.range_order_book.as_ref(),
+        &mut swap_tick_sequence_one,
+    )?;
+
+    update_range_order_book(
+        whirlpool_two,
+        extension_accounts_two.range_order_book.as_ref(),
+        &mut swap_tick_sequence_two,
+    )?;
+
+    set_swap_return_data(&return_data)
 }
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+use crate::util::{unwrap_native, wrap_native};
+
+use super::two_hop_swap::{self, TwoHopSwap};
+
+#[derive(Accounts)]
+pub struct TwoHopSwapWithNative<'info> {
+    #[account(mut)]
+    pub token_authority: Signer<'info>,
+
+    #[account(address = spl_token::native_mint::ID)]
+    pub native_mint: Account<'info, Mint>,
+
+    #[account(init,
+      payer = token_authority,
+      seeds = [b"native_wrap", token_authority.key().as_ref()],
+      bump,
+      token::mint = native_mint,
+      token::authority = token_authority,
+    )]
+    pub native_wrap_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+
+    // The native wrap account is passed as the token owner accounts of the native mint
+    #[account(
+      constraint = two_hop_swap.token_authority.key() == token_authority.key(),
+      constraint = two_hop_swap.whirlpool_one.token_mint_a != spl_token::native_mint::ID
+        || two_hop_swap.token_owner_account_one_a.key() == native_wrap_account.key(),
+      constraint = two_hop_swap.whirlpool_one.token_mint_b != spl_token::native_mint::ID
+        || two_hop_swap.token_owner_account_one_b.key() == native_wrap_account.key(),
+      constraint = two_hop_swap.whirlpool_two.token_mint_a != spl_token::native_mint::ID
+        || two_hop_swap.token_owner_account_two_a.key() == native_wrap_account.key(),
+      constraint = two_hop_swap.whirlpool_two.token_mint_b != spl_token::native_mint::ID
+        || two_hop_swap.token_owner_account_two_b.key() == native_wrap_account.key(),
+    )]
+    pub two_hop_swap: TwoHopSwap<'info>,
+}
+
+/*
+  Swaps through two Whirlpools from and/or into the lamport balance of the token authority.
+  The input is wrapped into a temporary RENEC account, which is closed back to the token authority
+  together with the output once the swaps are done.
+*/
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, TwoHopSwapWithNative<'info>>,
+    amount: u64,
+    other_amount_threshold: u64,
+    amount_specified_is_input: bool,
+    a_to_b_one: bool,
+    a_to_b_two: bool,
+    sqrt_price_limit_one: u128,
+    sqrt_price_limit_two: u128,
+) -> ProgramResult {
+    let whirlpool_one = &ctx.accounts.two_hop_swap.whirlpool_one;
+    let input_mint = if a_to_b_one {
+        whirlpool_one.token_mint_a
+    } else {
+        whirlpool_one.token_mint_b
+    };
+    // Wrap the most that the first swap can take from the token authority
+    let native_amount = if input_mint != spl_token::native_mint::ID {
+        0
+    } else if amount_specified_is_input {
+        amount
+    } else {
+        other_amount_threshold
+    };
+
+    wrap_native(
+        &ctx.accounts.token_authority,
+        &ctx.accounts.native_wrap_account,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        native_amount,
+    )?;
+
+    two_hop_swap::handler(
+        Context::new(
+            ctx.program_id,
+            &mut ctx.accounts.two_hop_swap,
+            ctx.remaining_accounts,
+        ),
+        amount,
+        other_amount_threshold,
+        amount_specified_is_input,
+        a_to_b_one,
+        a_to_b_two,
+        sqrt_price_limit_one,
+        sqrt_price_limit_two,
+    )?;
+
+    unwrap_native(
+        &ctx.accounts.token_authority,
+        &ctx.accounts.native_wrap_account,
+        &ctx.accounts.token_program,
+    )
+}
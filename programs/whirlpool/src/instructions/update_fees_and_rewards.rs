@@ -3,7 +3,11 @@ use anchor_lang::prelude::ProgramResult;
 use anchor_lang::prelude::*;
 
 use crate::{
-    manager::liquidity_manager::calculate_fee_and_reward_growths, state::*, util::to_timestamp_u64,
+    errors::ErrorCode,
+    events::FeesAndRewardsUpdated,
+    manager::liquidity_manager::calculate_fee_and_reward_growths,
+    state::*,
+    util::{load_range_order_book, to_timestamp_u64, WhirlpoolExtensionAccounts},
 };
 
 #[derive(Accounts)]
@@ -18,9 +22,27 @@ pub struct UpdateFeesAndRewards<'info> {
     pub tick_array_lower: AccountLoader<'info, TickArray>,
     #[account(has_one = whirlpool)]
     pub tick_array_upper: AccountLoader<'info, TickArray>,
+    // remaining_accounts: the range order book of the whirlpool, once the extension is enabled
 }
 
-pub fn handler(ctx: Context<UpdateFeesAndRewards>) -> ProgramResult {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateFeesAndRewards<'info>>,
+) -> ProgramResult {
+    let (extension_accounts, _) = WhirlpoolExtensionAccounts::try_from(
+        &ctx.accounts.whirlpool,
+        ctx.remaining_accounts,
+        EXTENSION_RANGE_ORDER_BOOK,
+    )?;
+
+    // The fees and rewards of a range order stop at its fill, which the ticks no longer reflect.
+    // They are accrued when the range order is withdrawn instead.
+    let range_order_book = load_range_order_book(extension_accounts.range_order_book.as_ref())?;
+    if let Some(range_order_book) = range_order_book {
+        if range_order_book.contains(&ctx.accounts.position.key()) {
+            return Err(ErrorCode::PositionHasRangeOrder.into());
+        }
+    }
+
     let whirlpool = &mut ctx.accounts.whirlpool;
     let position = &mut ctx.accounts.position;
     let clock = Clock::get()?;
@@ -37,5 +59,21 @@ pub fn handler(ctx: Context<UpdateFeesAndRewards>) -> ProgramResult {
     whirlpool.update_rewards(reward_infos, timestamp);
     position.update(&position_update);
 
+    let mut reward_amounts_owed = [0; NUM_REWARDS];
+    for (amount_owed, reward_info) in reward_amounts_owed
+        .iter_mut()
+        .zip(position.reward_infos.iter())
+    {
+        *amount_owed = reward_info.amount_owed;
+    }
+
+    emit!(FeesAndRewardsUpdated {
+        whirlpool: whirlpool.key(),
+        position: position.key(),
+        fee_owed_a: position.fee_owed_a,
+        fee_owed_b: position.fee_owed_b,
+        reward_amounts_owed,
+    });
+
     Ok(())
 }
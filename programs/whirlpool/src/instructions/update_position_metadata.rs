@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ErrorCode, events::PositionMetadataUpdated, state::*, util::update_position_metadata,
+};
+
+#[derive(Accounts)]
+pub struct UpdatePositionMetadata<'info> {
+    pub whirlpool: Box<Account<'info, Whirlpool>>,
+
+    #[account(has_one = whirlpool)]
+    pub position: Box<Account<'info, Position>>,
+
+    /// CHECK: checked against the metadata address of the position mint
+    #[account(mut)]
+    pub position_metadata_account: UncheckedAccount<'info>,
+
+    // Checked against the update authority stored in the position metadata
+    pub metadata_update_auth: Signer<'info>,
+
+    #[account(seeds = [b"metadata_settings", whirlpool.whirlpools_config.as_ref()], bump)]
+    pub metadata_settings: Box<Account<'info, MetadataSettings>>,
+
+    /// CHECK: checked via account constraints
+    #[account(address = mpl_token_metadata::ID)]
+    pub metadata_program: UncheckedAccount<'info>,
+}
+
+/*
+  Rewrites the metadata of a position from the metadata settings of its WhirlpoolsConfig,
+  e.g. after the tick range of the position or the URI template has changed. Positions opened
+  before the update authority of the metadata settings was rotated are updated by the previous
+  update authority, which hands their metadata over to the current one.
+*/
+pub fn handler(ctx: Context<UpdatePositionMetadata>) -> ProgramResult {
+    let position = &ctx.accounts.position;
+    let (metadata_address, _) = Pubkey::find_program_address(
+        &[
+            b"metadata",
+            mpl_token_metadata::ID.as_ref(),
+            position.position_mint.as_ref(),
+        ],
+        &mpl_token_metadata::ID,
+    );
+    if ctx.accounts.position_metadata_account.key() != metadata_address {
+        return Err(ErrorCode::InvalidPositionMetadataAccount.into());
+    }
+
+    update_position_metadata(
+        &ctx.accounts.whirlpool,
+        position,
+        &ctx.accounts.position_metadata_account,
+        &ctx.accounts.metadata_update_auth,
+        &ctx.accounts.metadata_settings,
+        &ctx.accounts.metadata_program,
+    )?;
+
+    emit!(PositionMetadataUpdated {
+        whirlpool: ctx.accounts.whirlpool.key(),
+        position: position.key(),
+        position_mint: position.position_mint,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,189 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::errors::ErrorCode;
+use crate::events::RangeOrderWithdrawn;
+use crate::manager::liquidity_manager::{
+    calculate_liquidity_token_deltas, calculate_modify_liquidity, sync_modify_liquidity_values,
+};
+use crate::manager::range_order_manager::calculate_filled_range_order_update;
+use crate::math::convert_to_liquidity_delta;
+use crate::state::*;
+use crate::util::{
+    apply_fee_rate_schedule, close_range_order_escrow, require_not_paused, to_timestamp_u64,
+    transfer_from_vault_to_owner, update_oracle, WhirlpoolExtensionAccounts,
+};
+
+use super::MODIFY_LIQUIDITY_EXTENSIONS;
+
+#[derive(Accounts)]
+pub struct WithdrawRangeOrder<'info> {
+    #[account(mut)]
+    pub whirlpool: Box<Account<'info, Whirlpool>>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut, has_one = whirlpool)]
+    pub position: Box<Account<'info, Position>>,
+    #[account(mut, constraint = position_token_account.mint == position.position_mint)]
+    pub position_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [b"range_order_escrow".as_ref(), position.key().as_ref()], bump)]
+    pub range_order_escrow: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, has_one = whirlpool)]
+    pub range_order_book: Box<Account<'info, RangeOrderBook>>,
+
+    #[account(mut, constraint = token_owner_account_a.mint == whirlpool.token_mint_a)]
+    pub token_owner_account_a: Box<Account<'info, TokenAccount>>,
+    #[account(mut, constraint = token_owner_account_b.mint == whirlpool.token_mint_b)]
+    pub token_owner_account_b: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, constraint = token_vault_a.key() == whirlpool.token_vault_a)]
+    pub token_vault_a: Box<Account<'info, TokenAccount>>,
+    #[account(mut, constraint = token_vault_b.key() == whirlpool.token_vault_b)]
+    pub token_vault_b: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, has_one = whirlpool)]
+    pub tick_array_lower: AccountLoader<'info, TickArray>,
+    #[account(mut, has_one = whirlpool)]
+    pub tick_array_upper: AccountLoader<'info, TickArray>,
+
+    /// CHECK: nothing is paused until the pause controls of the config have been initialized
+    #[account(seeds = [b"pause_controls", whirlpool.whirlpools_config.as_ref()], bump)]
+    pub pause_controls: UncheckedAccount<'info>,
+    // remaining_accounts: the side accounts of the MODIFY_LIQUIDITY_EXTENSIONS enabled on the
+    // whirlpool
+}
+
+/*
+  Withdraws a range order and returns the position token to its owner.
+  A filled order withdraws the token it was converted to, while an order that has not been filled
+  yet withdraws its liquidity like decrease_liquidity. The fees and rewards earned by the order
+  remain collectable from the position.
+*/
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawRangeOrder<'info>>,
+    token_min_a: u64,
+    token_min_b: u64,
+) -> ProgramResult {
+    let (extension_accounts, _) = WhirlpoolExtensionAccounts::try_from(
+        &ctx.accounts.whirlpool,
+        ctx.remaining_accounts,
+        MODIFY_LIQUIDITY_EXTENSIONS,
+    )?;
+    let whirlpool = &ctx.accounts.whirlpool;
+    whirlpool.require_enabled()?;
+    require_not_paused(
+        &ctx.accounts.pause_controls,
+        whirlpool,
+        PAUSE_DECREASE_LIQUIDITY,
+    )?;
+
+    let range_order = ctx
+        .accounts
+        .range_order_book
+        .remove(&ctx.accounts.position.key(), &ctx.accounts.owner.key())?;
+
+    let (delta_a, delta_b) = if range_order.filled {
+        let (position_update, delta_a, delta_b) =
+            calculate_filled_range_order_update(&ctx.accounts.position, &range_order)?;
+        ctx.accounts.position.update(&position_update);
+        (delta_a, delta_b)
+    } else {
+        let clock = Clock::get()?;
+        let liquidity_delta = convert_to_liquidity_delta(range_order.liquidity, false)?;
+        let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+        apply_fee_rate_schedule(
+            &mut ctx.accounts.whirlpool,
+            extension_accounts.fee_rate_schedule.as_ref(),
+            timestamp,
+        )?;
+
+        let update = calculate_modify_liquidity(
+            &ctx.accounts.whirlpool,
+            &ctx.accounts.position,
+            &ctx.accounts.tick_array_lower,
+            &ctx.accounts.tick_array_upper,
+            liquidity_delta,
+            timestamp,
+        )?;
+
+        // The oracle has to accumulate the in-range liquidity up until now before it changes
+        if update.whirlpool_liquidity != ctx.accounts.whirlpool.liquidity {
+            update_oracle(
+                &ctx.accounts.whirlpool,
+                extension_accounts.oracle.as_ref(),
+                timestamp,
+            )?;
+        }
+
+        sync_modify_liquidity_values(
+            &mut ctx.accounts.whirlpool,
+            &mut ctx.accounts.position,
+            &ctx.accounts.tick_array_lower,
+            &ctx.accounts.tick_array_upper,
+            update,
+            timestamp,
+        )?;
+
+        calculate_liquidity_token_deltas(
+            ctx.accounts.whirlpool.tick_current_index,
+            ctx.accounts.whirlpool.sqrt_price,
+            &ctx.accounts.position,
+            liquidity_delta,
+        )?
+    };
+
+    if delta_a < token_min_a {
+        return Err(ErrorCode::TokenMinSubceeded.into());
+    } else if delta_b < token_min_b {
+        return Err(ErrorCode::TokenMinSubceeded.into());
+    }
+
+    transfer_from_vault_to_owner(
+        &ctx.accounts.whirlpool,
+        &ctx.accounts.token_vault_a,
+        &ctx.accounts.token_owner_account_a,
+        &ctx.accounts.token_program,
+        delta_a,
+    )?;
+
+    transfer_from_vault_to_owner(
+        &ctx.accounts.whirlpool,
+        &ctx.accounts.token_vault_b,
+        &ctx.accounts.token_owner_account_b,
+        &ctx.accounts.token_program,
+        delta_b,
+    )?;
+
+    transfer_from_vault_to_owner(
+        &ctx.accounts.whirlpool,
+        &ctx.accounts.range_order_escrow,
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.token_program,
+        1,
+    )?;
+
+    close_range_order_escrow(
+        &ctx.accounts.whirlpool,
+        &ctx.accounts.range_order_escrow,
+        &ctx.accounts.owner,
+        &ctx.accounts.token_program,
+    )?;
+
+    emit!(RangeOrderWithdrawn {
+        whirlpool: ctx.accounts.whirlpool.key(),
+        position: ctx.accounts.position.key(),
+        owner: ctx.accounts.owner.key(),
+        filled: range_order.filled,
+        token_amount_a: delta_a,
+        token_amount_b: delta_b,
+    });
+
+    Ok(())
+}
@@ -7,12 +7,14 @@ declare_id!("EwGzCLvNKeLGAP9QymNgneJBd1gmJH75WnhZKo5Chy4W");
 pub mod constants;
 #[doc(hidden)]
 pub mod errors;
+pub mod events;
 #[doc(hidden)]
 pub mod instructions;
 #[doc(hidden)]
 pub mod manager;
 #[doc(hidden)]
 pub mod math;
+pub mod return_data;
 pub mod state;
 #[doc(hidden)]
 pub mod tests;
@@ -53,6 +55,8 @@ pub mod whirlpool {
 
     /// Initializes a Whirlpool account.
     /// Fee rate is set to the default values on the config and supplied fee_tier.
+    /// Fee tiers created before their pools were counted are migrated as in `migrate_fee_tier`,
+    /// with the additional rent paid by the funder.
     ///
     /// ### Parameters
     /// - `bumps` - The bump value when deriving the PDA of the Whirlpool address.
@@ -62,6 +66,13 @@ pub mod whirlpool {
     /// #### Special Errors
     /// `InvalidTokenMintOrder` - The order of mints have to be ordered by
     /// `SqrtPriceOutOfBounds` - provided initial_sqrt_price is not between 2^-64 to 2^64
+    /// `ConstraintSeeds` - The provided fee_tier is not the fee tier of the tick_spacing
+    /// `InvalidFeeTierMigration` - The fee_tier was created before pools were counted and is
+    ///                             not a fee tier of the config
+    /// `InvalidTickSpacing` - The tick_spacing is not allowed by the fee tier policy of the config
+    /// `PoolCreationNotAllowed` - The pool creation policy of the config does not allow the
+    ///                            pool_creator to create this pool
+    /// `FeeTierDisabled` - The fee tier has been disabled by the fee tier policy of the config
     ///
     pub fn initialize_pool(
         ctx: Context<InitializePool>,
@@ -88,6 +99,52 @@ pub mod whirlpool {
         return instructions::initialize_tick_array::handler(ctx, start_tick_index);
     }
 
+    /// Initializes the oracle account of a Whirlpool. Once initialized, every swap records
+    /// an observation of the pool's tick & liquidity that can be used to derive a
+    /// time-weighted average price.
+    /// The oracle starts off with room for a single observation.
+    /// Enables the oracle extension of the Whirlpool, after which the oracle has to be passed
+    /// writable to every swap of the pool.
+    pub fn initialize_oracle(ctx: Context<InitializeOracle>) -> ProgramResult {
+        return instructions::initialize_oracle::handler(ctx);
+    }
+
+    /// Returns the oracle accumulators of a Whirlpool as of each of the provided look-back
+    /// periods, along with the time-weighted average tick & geometric mean sqrt-price between
+    /// each consecutive pair of periods. The Borsh-encoded `ObserveResult` is written to the
+    /// transaction return data.
+    ///
+    /// ### Parameters
+    /// - `seconds_agos` - Strictly decreasing list of look-back periods in seconds.
+    ///
+    /// #### Special Errors
+    /// - `ObservationTooOld` - A look-back period reaches past the oldest stored observation.
+    /// - `InvalidObservationWindow` - The look-back periods are not strictly decreasing.
+    pub fn observe(ctx: Context<Observe>, seconds_agos: Vec<u32>) -> ProgramResult {
+        return instructions::observe::handler(ctx, seconds_agos);
+    }
+
+    /// Grows the number of observations stored by the oracle of a Whirlpool.
+    /// Anyone can pay the rent for the additional observations, which the oracle account is
+    /// reallocated for. The new observation slots are used once the current observation ring
+    /// buffer wraps around.
+    ///
+    /// ### Parameters
+    /// - `observation_cardinality_next` - The number of observations the oracle should store.
+    ///
+    /// #### Special Errors
+    /// - `InvalidObservationCardinality` - The new cardinality does not exceed the current one or
+    ///                                     exceeds `MAX_OBSERVATION_CARDINALITY`.
+    pub fn increase_observation_cardinality(
+        ctx: Context<IncreaseObservationCardinality>,
+        observation_cardinality_next: u16
+    ) -> ProgramResult {
+        return instructions::increase_observation_cardinality::handler(
+            ctx,
+            observation_cardinality_next
+        );
+    }
+
     /// Initializes a fee_tier account usable by Whirlpools in a WhirlpoolConfig space.
     ///
     /// ### Authority
@@ -100,6 +157,7 @@ pub mod whirlpool {
     ///
     /// #### Special Errors
     /// - `FeeRateMaxExceeded` - If the provided default_fee_rate exceeds MAX_FEE_RATE.
+    /// - `InvalidTickSpacing` - If the fee tier policy of the config does not allow tick_spacing.
     pub fn initialize_fee_tier(
         ctx: Context<InitializeFeeTier>,
         tick_spacing: u16,
@@ -164,6 +222,8 @@ pub mod whirlpool {
     /// #### Special Errors
     /// - `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of
     ///                        the tick-spacing in this pool.
+    /// - `InvalidTickSpacing` - If the fee tier policy of the config does not allow the
+    ///                          tick-spacing of this pool.
     pub fn open_position(
         ctx: Context<OpenPosition>,
         bumps: OpenPositionBumps,
@@ -175,6 +235,9 @@ pub mod whirlpool {
 
     /// Open a position in a Whirlpool. A unique token will be minted to represent the position
     /// in the users wallet. Additional Metaplex metadata is appended to identify the token.
+    /// The metadata is taken from the metadata settings of the WhirlpoolsConfig, if initialized.
+    /// Once the position collection of the WhirlpoolsConfig has been initialized, the token is
+    /// verified as a member of the collection.
     /// The position will start off with 0 liquidity.
     ///
     /// ### Parameters
@@ -184,6 +247,11 @@ pub mod whirlpool {
     /// #### Special Errors
     /// - `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of
     ///                        the tick-spacing in this pool.
+    /// - `InvalidTickSpacing` - If the fee tier policy of the config does not allow the
+    ///                          tick-spacing of this pool.
+    /// - `InvalidMetadataUpdateAuthority` - If the metadata update authority does not match the
+    ///                                      metadata settings.
+    /// - `InvalidPositionMetadata` - If the expanded metadata URI is too long.
     pub fn open_position_with_metadata(
         ctx: Context<OpenPositionWithMetadata>,
         bumps: OpenPositionWithMetadataBumps,
@@ -199,6 +267,7 @@ pub mod whirlpool {
     }
 
     /// Add liquidity to a position in the Whirlpool. This call also updates the position's accrued fees and rewards.
+    /// A change of the in-range liquidity first records an observation in the Whirlpool's oracle.
     ///
     /// ### Authority
     /// - `position_authority` - authority that owns the token corresponding to this desired position.
@@ -212,8 +281,9 @@ pub mod whirlpool {
     /// - `LiquidityZero` - Provided liquidity amount is zero.
     /// - `LiquidityTooHigh` - Provided liquidity exceeds u128::max.
     /// - `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.
-    pub fn increase_liquidity(
-        ctx: Context<ModifyLiquidity>,
+    /// - `InvalidExtensionAccount` - The side account of an extension enabled on the Whirlpool is missing from the remaining accounts.
+    pub fn increase_liquidity<'info>(
+        ctx: Context<'_, '_, '_, 'info, ModifyLiquidity<'info>>,
         liquidity_amount: u128,
         token_max_a: u64,
         token_max_b: u64
@@ -227,6 +297,7 @@ pub mod whirlpool {
     }
 
     /// Withdraw liquidity from a position in the Whirlpool. This call also updates the position's accrued fees and rewards.
+    /// A change of the in-range liquidity first records an observation in the Whirlpool's oracle.
     ///
     /// ### Authority
     /// - `position_authority` - authority that owns the token corresponding to this desired position.
@@ -240,8 +311,9 @@ pub mod whirlpool {
     /// - `LiquidityZero` - Provided liquidity amount is zero.
     /// - `LiquidityTooHigh` - Provided liquidity exceeds u128::max.
     /// - `TokenMinSubceeded` - The required token to perform this operation subceeds the user defined amount.
-    pub fn decrease_liquidity(
-        ctx: Context<ModifyLiquidity>,
+    /// - `InvalidExtensionAccount` - The side account of an extension enabled on the Whirlpool is missing from the remaining accounts.
+    pub fn decrease_liquidity<'info>(
+        ctx: Context<'_, '_, '_, 'info, ModifyLiquidity<'info>>,
         liquidity_amount: u128,
         token_min_a: u64,
         token_min_b: u64
@@ -259,7 +331,11 @@ pub mod whirlpool {
     /// #### Special Errors
     /// - `TickNotFound` - Provided tick array account does not contain the tick for this position.
     /// - `LiquidityZero` - Position has zero liquidity and therefore already has the most updated fees and reward values.
-    pub fn update_fees_and_rewards(ctx: Context<UpdateFeesAndRewards>) -> ProgramResult {
+    /// - `PositionHasRangeOrder` - The position has an open range order, its fees and rewards are accrued when it is withdrawn.
+    /// - `InvalidExtensionAccount` - The side account of an extension enabled on the Whirlpool is missing from the remaining accounts.
+    pub fn update_fees_and_rewards<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateFeesAndRewards<'info>>
+    ) -> ProgramResult {
         return instructions::update_fees_and_rewards::handler(ctx);
     }
 
@@ -288,6 +364,7 @@ pub mod whirlpool {
     }
 
     /// Perform a swap in this Whirlpool
+    /// The Borsh-encoded `SwapReturnData` is written to the transaction return data.
     ///
     /// ### Authority
     /// - "token_authority" - The authority to withdraw tokens from the input token account.
@@ -308,8 +385,9 @@ pub mod whirlpool {
     /// - `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.
     /// - `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.
     /// - `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.
-    pub fn swap(
-        ctx: Context<Swap>,
+    /// - `InvalidExtensionAccount` - The side account of an extension enabled on the Whirlpool is missing from the remaining accounts.
+    pub fn swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
         amount: u64,
         other_amount_threshold: u64,
         sqrt_price_limit: u128,
@@ -377,6 +455,9 @@ pub mod whirlpool {
 
     /// Sets the fee rate for a Whirlpool.
     /// Fee rate is represented as hundredths of a basis point.
+    /// The change is scheduled through the fee rate schedule of the Whirlpool, which has to be
+    /// initialized first, and applies MIN_FEE_RATE_CHANGE_DELAY seconds later. The protocol fee
+    /// rate of a pending change is kept.
     /// Only the current fee authority has permission to invoke this instruction.
     ///
     /// ### Authority
@@ -393,6 +474,9 @@ pub mod whirlpool {
 
     /// Sets the protocol fee rate for a Whirlpool.
     /// Protocol fee rate is represented as a basis point.
+    /// The change is scheduled through the fee rate schedule of the Whirlpool, which has to be
+    /// initialized first, and applies MIN_FEE_RATE_CHANGE_DELAY seconds later. The fee rate of a
+    /// pending change is kept.
     /// Only the current fee authority has permission to invoke this instruction.
     ///
     /// ### Authority
@@ -410,9 +494,122 @@ pub mod whirlpool {
         return instructions::set_protocol_fee_rate::handler(ctx, protocol_fee_rate);
     }
 
-    /// Sets the fee authority for a WhirlpoolConfig.
+    /// Initializes the dynamic fee of a Whirlpool. Once initialized, swaps are charged the
+    /// pool's fee rate plus a surcharge that grows with recent tick movement and decays over time.
+    /// Enables the dynamic fee extension of the Whirlpool, after which the dynamic fee has to be
+    /// passed in the remaining accounts of every swap of the pool.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority that can modify pool fees in the WhirlpoolConfig
+    ///
+    /// ### Parameters
+    /// - `base_surcharge_rate` - The surcharge fee rate added per tick-spacing of accumulated volatility.
+    /// - `max_surcharge_rate` - The maximum surcharge fee rate.
+    /// - `decay_period` - The number of seconds in which accumulated volatility decays to zero.
+    ///
+    /// #### Special Errors
+    /// - `FeeRateMaxExceeded` - If the provided max_surcharge_rate exceeds MAX_FEE_RATE.
+    /// - `InvalidDynamicFeeParams` - If the provided decay_period is zero.
+    pub fn initialize_dynamic_fee(
+        ctx: Context<InitializeDynamicFee>,
+        base_surcharge_rate: u16,
+        max_surcharge_rate: u16,
+        decay_period: u64
+    ) -> ProgramResult {
+        return instructions::initialize_dynamic_fee::handler(
+            ctx,
+            base_surcharge_rate,
+            max_surcharge_rate,
+            decay_period
+        );
+    }
+
+    /// Sets the dynamic fee parameters of a Whirlpool.
+    /// Setting max_surcharge_rate to zero reverts the pool to its static fee rate.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority that can modify pool fees in the WhirlpoolConfig
+    ///
+    /// ### Parameters
+    /// - `base_surcharge_rate` - The surcharge fee rate added per tick-spacing of accumulated volatility.
+    /// - `max_surcharge_rate` - The maximum surcharge fee rate.
+    /// - `decay_period` - The number of seconds in which accumulated volatility decays to zero.
+    ///
+    /// #### Special Errors
+    /// - `FeeRateMaxExceeded` - If the provided max_surcharge_rate exceeds MAX_FEE_RATE.
+    /// - `InvalidDynamicFeeParams` - If the provided decay_period is zero.
+    pub fn set_dynamic_fee_params(
+        ctx: Context<SetDynamicFeeParams>,
+        base_surcharge_rate: u16,
+        max_surcharge_rate: u16,
+        decay_period: u64
+    ) -> ProgramResult {
+        return instructions::set_dynamic_fee_params::handler(
+            ctx,
+            base_surcharge_rate,
+            max_surcharge_rate,
+            decay_period
+        );
+    }
+
+    /// Initializes the fee rate schedule of a Whirlpool, which holds fee rate changes
+    /// scheduled ahead of time by the fee authority and is required to change its fee rates.
+    /// Enables the fee rate schedule extension of the Whirlpool, after which the schedule has to be
+    /// passed in the remaining accounts of every swap and liquidity change of the pool.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority that can modify pool fees in the WhirlpoolConfig
+    pub fn initialize_fee_rate_schedule(ctx: Context<InitializeFeeRateSchedule>) -> ProgramResult {
+        return instructions::initialize_fee_rate_schedule::handler(ctx);
+    }
+
+    /// Schedules a change of the fee rate & protocol fee rate of a Whirlpool.
+    /// The new rates are applied by the first swap or liquidity change at or after `effective_at`.
+    /// Scheduling a change replaces any change that is still pending.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority that can modify pool fees in the WhirlpoolConfig
+    ///
+    /// ### Parameters
+    /// - `fee_rate` - The fee rate that the pool will use from `effective_at`.
+    /// - `protocol_fee_rate` - The protocol fee rate that the pool will use from `effective_at`.
+    /// - `effective_at` - The unix timestamp from which the new rates apply.
+    ///
+    /// #### Special Errors
+    /// - `FeeRateMaxExceeded` - If the provided fee_rate exceeds MAX_FEE_RATE.
+    /// - `ProtocolFeeRateMaxExceeded` - If the provided protocol_fee_rate exceeds MAX_PROTOCOL_FEE_RATE.
+    /// - `FeeRateChangeTooEarly` - If `effective_at` is less than MIN_FEE_RATE_CHANGE_DELAY seconds away.
+    pub fn schedule_fee_rate_change(
+        ctx: Context<ScheduleFeeRateChange>,
+        fee_rate: u16,
+        protocol_fee_rate: u16,
+        effective_at: u64
+    ) -> ProgramResult {
+        return instructions::schedule_fee_rate_change::handler(
+            ctx,
+            fee_rate,
+            protocol_fee_rate,
+            effective_at
+        );
+    }
+
+    /// Cancels the pending fee rate change of a Whirlpool.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority that can modify pool fees in the WhirlpoolConfig
+    pub fn cancel_fee_rate_change(ctx: Context<ScheduleFeeRateChange>) -> ProgramResult {
+        return instructions::cancel_fee_rate_change::handler(ctx);
+    }
+
+    /// Proposes a new fee authority for a WhirlpoolConfig.
     /// The fee authority can set the fee & protocol fee rate for individual pools or
     /// set the default fee rate for newly minted pools.
+    /// The transfer takes effect once the new fee authority invokes `accept_fee_authority`.
     /// Only the current fee authority has permission to invoke this instruction.
     ///
     /// ### Authority
@@ -421,7 +618,8 @@ pub mod whirlpool {
         return instructions::set_fee_authority::handler(ctx);
     }
 
-    /// Sets the fee authority to collect protocol fees for a WhirlpoolConfig.
+    /// Proposes a new authority to collect protocol fees for a WhirlpoolConfig.
+    /// The transfer takes effect once the new authority invokes `accept_collect_protocol_fees_authority`.
     /// Only the current collect protocol fee authority has permission to invoke this instruction.
     ///
     /// ### Authority
@@ -449,7 +647,8 @@ pub mod whirlpool {
         return instructions::set_reward_authority::handler(ctx, reward_index);
     }
 
-    /// Sets the pool creator authority for a WhirlpoolConfig.
+    /// Proposes a new pool creator authority for a WhirlpoolConfig.
+    /// The transfer takes effect once the new authority invokes `accept_pool_creator_authority`.
     /// Only the current pool creator authority has permission to invoke this instruction.
     ///
     /// ### Authority
@@ -475,7 +674,8 @@ pub mod whirlpool {
         return instructions::set_reward_authority_by_super_authority::handler(ctx, reward_index);
     }
 
-    /// Set the whirlpool reward super authority for a WhirlpoolConfig
+    /// Propose a new whirlpool reward super authority for a WhirlpoolConfig
+    /// The transfer takes effect once the new authority invokes `accept_reward_emissions_super_authority`.
     /// Only the current reward super authority has permission to invoke this instruction.
     /// This instruction will not change the authority on any `WhirlpoolRewardInfo` whirlpool rewards.
     ///
@@ -487,13 +687,287 @@ pub mod whirlpool {
         return instructions::set_reward_emissions_super_authority::handler(ctx);
     }
 
+    /// Initializes the account that holds the pending authority transfers of a WhirlpoolConfig.
+    /// Has to be invoked once before any authority of the config can be transferred.
+    pub fn initialize_pending_authorities(
+        ctx: Context<InitializePendingAuthorities>
+    ) -> ProgramResult {
+        return instructions::initialize_pending_authorities::handler(ctx);
+    }
+
+    /// Accepts the pending transfer of the fee authority of a WhirlpoolConfig.
+    ///
+    /// ### Authority
+    /// - "new_fee_authority" - The fee authority proposed by `set_fee_authority`
+    pub fn accept_fee_authority(ctx: Context<AcceptFeeAuthority>) -> ProgramResult {
+        return instructions::accept_fee_authority::handler(ctx);
+    }
+
+    /// Accepts the pending transfer of the collect protocol fees authority of a WhirlpoolConfig.
+    ///
+    /// ### Authority
+    /// - "new_collect_protocol_fees_authority" - The authority proposed by `set_collect_protocol_fees_authority`
+    pub fn accept_collect_protocol_fees_authority(
+        ctx: Context<AcceptCollectProtocolFeesAuthority>
+    ) -> ProgramResult {
+        return instructions::accept_collect_protocol_fees_authority::handler(ctx);
+    }
+
+    /// Accepts the pending transfer of the pool creator authority of a WhirlpoolConfig.
+    ///
+    /// ### Authority
+    /// - "new_pool_creator_authority" - The authority proposed by `set_pool_creator_authority`
+    pub fn accept_pool_creator_authority(
+        ctx: Context<AcceptPoolCreatorAuthority>
+    ) -> ProgramResult {
+        return instructions::accept_pool_creator_authority::handler(ctx);
+    }
+
+    /// Accepts the pending transfer of the reward emissions super authority of a WhirlpoolConfig.
+    ///
+    /// ### Authority
+    /// - "new_reward_emissions_super_authority" - The authority proposed by `set_reward_emissions_super_authority`
+    pub fn accept_reward_emissions_super_authority(
+        ctx: Context<AcceptRewardEmissionsSuperAuthority>
+    ) -> ProgramResult {
+        return instructions::accept_reward_emissions_super_authority::handler(ctx);
+    }
+
+    /// Cancels the pending transfer of the fee authority of a WhirlpoolConfig.
+    /// Only the current fee authority has permission to invoke this instruction.
+    pub fn cancel_fee_authority_transfer(
+        ctx: Context<CancelFeeAuthorityTransfer>
+    ) -> ProgramResult {
+        return instructions::cancel_fee_authority_transfer::handler(ctx);
+    }
+
+    /// Cancels the pending transfer of the collect protocol fees authority of a WhirlpoolConfig.
+    /// Only the current collect protocol fees authority has permission to invoke this instruction.
+    pub fn cancel_collect_protocol_fees_authority_transfer(
+        ctx: Context<CancelCollectProtocolFeesAuthorityTransfer>
+    ) -> ProgramResult {
+        return instructions::cancel_collect_protocol_fees_authority_transfer::handler(ctx);
+    }
+
+    /// Cancels the pending transfer of the pool creator authority of a WhirlpoolConfig.
+    /// Only the current pool creator authority has permission to invoke this instruction.
+    pub fn cancel_pool_creator_authority_transfer(
+        ctx: Context<CancelPoolCreatorAuthorityTransfer>
+    ) -> ProgramResult {
+        return instructions::cancel_pool_creator_authority_transfer::handler(ctx);
+    }
+
+    /// Cancels the pending transfer of the reward emissions super authority of a WhirlpoolConfig.
+    /// Only the current reward emissions super authority has permission to invoke this instruction.
+    pub fn cancel_reward_emissions_super_authority_transfer(
+        ctx: Context<CancelRewardEmissionsSuperAuthorityTransfer>
+    ) -> ProgramResult {
+        return instructions::cancel_reward_emissions_super_authority_transfer::handler(ctx);
+    }
+
+    /// Initializes the pool creation policy of a WhirlpoolConfig, which restricts who can
+    /// create pools in the config. Configs without a policy allow anyone to create pools.
+    /// Only the current pool creator authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "pool_creator_authority" - Set authority that can disable a pool in the WhirlpoolConfig
+    ///
+    /// ### Parameters
+    /// - `mode` - POOL_CREATION_PERMISSIONLESS, POOL_CREATION_CREATOR_ONLY or
+    ///            POOL_CREATION_ALLOWLISTED_MINTS.
+    ///
+    /// #### Special Errors
+    /// - `InvalidPoolCreationMode` - If the provided mode is unknown.
+    pub fn initialize_pool_creation_policy(
+        ctx: Context<InitializePoolCreationPolicy>,
+        mode: u8
+    ) -> ProgramResult {
+        return instructions::initialize_pool_creation_policy::handler(ctx, mode);
+    }
+
+    /// Sets the mode of the pool creation policy of a WhirlpoolConfig.
+    /// Only the current pool creator authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "pool_creator_authority" - Set authority that can disable a pool in the WhirlpoolConfig
+    ///
+    /// ### Parameters
+    /// - `mode` - POOL_CREATION_PERMISSIONLESS, POOL_CREATION_CREATOR_ONLY or
+    ///            POOL_CREATION_ALLOWLISTED_MINTS.
+    ///
+    /// #### Special Errors
+    /// - `InvalidPoolCreationMode` - If the provided mode is unknown.
+    pub fn set_pool_creation_mode(ctx: Context<ModifyPoolCreationPolicy>, mode: u8) -> ProgramResult {
+        return instructions::set_pool_creation_mode::handler(ctx, mode);
+    }
+
+    /// Adds a token mint to the allowlist of the pool creation policy of a WhirlpoolConfig.
+    /// Only the current pool creator authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "pool_creator_authority" - Set authority that can disable a pool in the WhirlpoolConfig
+    ///
+    /// #### Special Errors
+    /// - `AllowedMintsFull` - If the allowlist already holds MAX_ALLOWED_MINTS mints.
+    pub fn add_allowed_mint(ctx: Context<ModifyPoolCreationPolicy>, mint: Pubkey) -> ProgramResult {
+        return instructions::add_allowed_mint::handler(ctx, mint);
+    }
+
+    /// Removes a token mint from the allowlist of the pool creation policy of a WhirlpoolConfig.
+    /// Existing pools of the mint are not affected.
+    /// Only the current pool creator authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "pool_creator_authority" - Set authority that can disable a pool in the WhirlpoolConfig
+    pub fn remove_allowed_mint(
+        ctx: Context<ModifyPoolCreationPolicy>,
+        mint: Pubkey
+    ) -> ProgramResult {
+        return instructions::remove_allowed_mint::handler(ctx, mint);
+    }
+
+    /// Initializes the fee tier policy of a WhirlpoolConfig, which restricts the tick spacings
+    /// of fee tiers and new pools. Configs without a policy allow any tick spacing.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority in the WhirlpoolConfig
+    pub fn initialize_fee_tier_policy(ctx: Context<InitializeFeeTierPolicy>) -> ProgramResult {
+        return instructions::initialize_fee_tier_policy::handler(ctx);
+    }
+
+    /// Sets the tick spacings that fee tiers can be initialized for in a WhirlpoolConfig.
+    /// An empty list allows every tick spacing. Existing fee tiers are not affected, but no new
+    /// pools can be created and no positions can be opened or rebalanced on the pools of a tick
+    /// spacing that is not allowed.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority in the WhirlpoolConfig
+    ///
+    /// ### Parameters
+    /// - `allowed_tick_spacings` - The allowed tick spacings, up to MAX_POLICY_TICK_SPACINGS.
+    ///
+    /// #### Special Errors
+    /// - `InvalidTickSpacing` - If a provided tick spacing is zero.
+    /// - `TooManyTickSpacings` - If more than MAX_POLICY_TICK_SPACINGS tick spacings are provided.
+    pub fn set_allowed_tick_spacings(
+        ctx: Context<ModifyFeeTierPolicy>,
+        allowed_tick_spacings: Vec<u16>
+    ) -> ProgramResult {
+        return instructions::set_allowed_tick_spacings::handler(ctx, allowed_tick_spacings);
+    }
+
+    /// Enables or disables the creation of new pools on a FeeTier.
+    /// Existing pools of the fee tier are not affected.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority in the WhirlpoolConfig
+    ///
+    /// ### Parameters
+    /// - `is_enabled` - Whether new pools can be created on the fee tier.
+    ///
+    /// #### Special Errors
+    /// - `TooManyTickSpacings` - If MAX_POLICY_TICK_SPACINGS fee tiers are already disabled.
+    pub fn set_fee_tier_enabled(
+        ctx: Context<SetFeeTierEnabled>,
+        is_enabled: bool
+    ) -> ProgramResult {
+        return instructions::set_fee_tier_enabled::handler(ctx, is_enabled);
+    }
+
+    /// Closes a FeeTier and returns its rent to the receiver. Only fee tiers that no Whirlpool
+    /// has been initialized with can be closed, which excludes the migrated fee tiers whose pools
+    /// were not counted.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority in the WhirlpoolConfig
+    ///
+    /// #### Special Errors
+    /// - `FeeTierHasPools` - If Whirlpools have been initialized with the fee tier, or the fee tier
+    ///                       was migrated with an untracked pool count.
+    pub fn close_fee_tier(ctx: Context<CloseFeeTier>) -> ProgramResult {
+        return instructions::close_fee_tier::handler(ctx);
+    }
+
+    /// Grows a FeeTier created before the pools of fee tiers were counted. The Whirlpools that
+    /// have been initialized with it can not be counted, so the pool count of the fee tier is
+    /// left untracked and the fee tier can never be closed. Fee tiers have to be migrated before
+    /// their default fee rate can be set, `initialize_pool` migrates them as well.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority in the WhirlpoolConfig
+    ///
+    /// #### Special Errors
+    /// - `InvalidFeeTierMigration` - If the account is not a fee tier of the WhirlpoolConfig, or
+    ///                               the fee tier has already been migrated.
+    pub fn migrate_fee_tier(ctx: Context<MigrateFeeTier>) -> ProgramResult {
+        return instructions::migrate_fee_tier::handler(ctx);
+    }
+
     /// Sets `enable` flag of the pool to enable or disable this pool.
     /// Only the current pool creator authority has permission to invoke this instruction.
     pub fn set_enable_flag(ctx: Context<SetEnableFlag>, is_enabled: bool) -> ProgramResult {
         return instructions::set_enable_flag::handler(ctx, is_enabled);
     }
 
+    /// Initializes the pause controls of a WhirlpoolConfig, which pause individual operations
+    /// of its pools. Nothing is paused in configs without pause controls.
+    /// Only the current pool creator authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "pool_creator_authority" - Set authority that can disable a pool in the WhirlpoolConfig
+    pub fn initialize_pause_controls(ctx: Context<InitializePauseControls>) -> ProgramResult {
+        return instructions::initialize_pause_controls::handler(ctx);
+    }
+
+    /// Sets the operations that are paused on every pool of a WhirlpoolConfig.
+    /// Setting PAUSE_ALL acts as an emergency pause of the whole config.
+    /// Only the current pool creator authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "pool_creator_authority" - Set authority that can disable a pool in the WhirlpoolConfig
+    ///
+    /// ### Parameters
+    /// - `paused_operations` - Bitmask of PAUSE_SWAP, PAUSE_INCREASE_LIQUIDITY,
+    ///                         PAUSE_DECREASE_LIQUIDITY, PAUSE_COLLECT and PAUSE_OPEN_POSITION.
+    ///
+    /// #### Special Errors
+    /// - `InvalidPausedOperations` - If the bitmask contains unknown operations.
+    pub fn set_config_paused_operations(
+        ctx: Context<SetConfigPausedOperations>,
+        paused_operations: u8
+    ) -> ProgramResult {
+        return instructions::set_config_paused_operations::handler(ctx, paused_operations);
+    }
+
+    /// Sets the operations that are paused on a single Whirlpool, in addition to the ones
+    /// paused on its WhirlpoolConfig.
+    /// Only the current pool creator authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "pool_creator_authority" - Set authority that can disable a pool in the WhirlpoolConfig
+    ///
+    /// ### Parameters
+    /// - `paused_operations` - Bitmask of PAUSE_SWAP, PAUSE_INCREASE_LIQUIDITY,
+    ///                         PAUSE_DECREASE_LIQUIDITY, PAUSE_COLLECT and PAUSE_OPEN_POSITION.
+    ///                         Zero resumes every operation of the pool.
+    ///
+    /// #### Special Errors
+    /// - `InvalidPausedOperations` - If the bitmask contains unknown operations.
+    /// - `PausedPoolsFull` - If MAX_PAUSED_POOLS pools already have paused operations.
+    pub fn set_pool_paused_operations(
+        ctx: Context<SetPoolPausedOperations>,
+        paused_operations: u8
+    ) -> ProgramResult {
+        return instructions::set_pool_paused_operations::handler(ctx, paused_operations);
+    }
+
     /// Perform a two-hop swap in this Whirlpool
+    /// The Borsh-encoded `TwoHopSwapReturnData` is written to the transaction return data.
     ///
     /// ### Authority
     /// - "token_authority" - The authority to withdraw tokens from the input token account.
@@ -518,8 +992,11 @@ pub mod whirlpool {
     /// - `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.
     /// - `InvalidIntermediaryMint` - Error if the intermediary mint between hop one and two do not equal.
     /// - `DuplicateTwoHopPool` - Error if whirlpool one & two are the same pool.
-    pub fn two_hop_swap(
-        ctx: Context<TwoHopSwap>,
+    /// - `AmountOutBelowMinimum` - In exact-out mode, hop two cannot provide the full specified output amount.
+    /// - `IntermediateTokenAmountMismatch` - In exact-out mode, hop one cannot provide the full input required by hop two.
+    /// - `InvalidExtensionAccount` - The side account of an extension enabled on the Whirlpool is missing from the remaining accounts.
+    pub fn two_hop_swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, TwoHopSwap<'info>>,
         amount: u64,
         other_amount_threshold: u64,
         amount_specified_is_input: bool,
@@ -539,4 +1016,627 @@ pub mod whirlpool {
             sqrt_price_limit_two
         );
     }
+
+    /// Perform a swap across an ordered route of Whirlpools. The output of each hop is used
+    /// as the input of the next hop.
+    ///
+    /// ### Authority
+    /// - "token_authority" - The authority to withdraw tokens from the input token account.
+    ///
+    /// ### Accounts
+    /// Each hop is described by `ROUTE_HOP_ACCOUNTS_LEN` remaining accounts, in order:
+    /// whirlpool, token_owner_account_a, token_vault_a, token_owner_account_b, token_vault_b,
+    /// tick_array_0, tick_array_1, tick_array_2. They are followed by the side accounts of the
+    /// `ROUTE_HOP_EXTENSIONS` enabled on the whirlpool, in the order of the extension flags.
+    /// The pause controls of the configs of the hops follow the last hop, once per config.
+    ///
+    /// ### Parameters
+    /// - `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).
+    /// - `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).
+    /// - `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the first hop.
+    /// - `a_to_b` - The direction of the swap of each hop. True if swapping from A to B. False if swapping from B to A.
+    /// - `sqrt_price_limits` - The maximum/minimum price each hop will swap to.
+    ///
+    /// #### Special Errors
+    /// - `InvalidRouteAccounts` - The remaining accounts do not describe one valid hop per direction & price limit.
+    /// - `InvalidIntermediaryMint` - Error if the output mint of a hop does not equal the input mint of the next hop.
+    /// - `DuplicateRoutePool` - Error if a whirlpool is visited more than once.
+    /// - `InvalidExtensionAccount` - The side account of an extension enabled on a whirlpool is missing from the remaining accounts.
+    /// - `AmountOutBelowMinimum` - In exact-out mode, the last hop cannot provide the full specified output amount.
+    /// - `IntermediateTokenAmountMismatch` - In exact-out mode, a hop cannot provide the full input required by the next hop.
+    pub fn route_swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, RouteSwap<'info>>,
+        amount: u64,
+        other_amount_threshold: u64,
+        amount_specified_is_input: bool,
+        a_to_b: Vec<bool>,
+        sqrt_price_limits: Vec<u128>
+    ) -> ProgramResult {
+        return instructions::route_swap::handler(
+            ctx,
+            amount,
+            other_amount_threshold,
+            amount_specified_is_input,
+            a_to_b,
+            sqrt_price_limits
+        );
+    }
+
+    /// Perform a swap that splits a single amount across several Whirlpools of the same token pair,
+    /// e.g. pools of different fee tiers. The slippage threshold applies to the combined amounts of all legs.
+    ///
+    /// ### Authority
+    /// - "token_authority" - The authority to withdraw tokens from the input token account.
+    ///
+    /// ### Accounts
+    /// Each leg is described by `SPLIT_SWAP_LEG_ACCOUNTS_LEN` remaining accounts, in order:
+    /// whirlpool, token_vault_a, token_vault_b, tick_array_0, tick_array_1, tick_array_2. They are
+    /// followed by the side accounts of the `SPLIT_SWAP_LEG_EXTENSIONS` enabled on the whirlpool, in
+    /// the order of the extension flags. The pause controls of the configs of the legs follow the
+    /// last leg, once per config.
+    ///
+    /// ### Parameters
+    /// - `amount` - The total amount of input or output token to swap from (depending on amount_specified_is_input).
+    /// - `other_amount_threshold` - The maximum/minimum of combined input/output token to swap into (depending on amount_specified_is_input).
+    /// - `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.
+    /// - `a_to_b` - The direction of the swap. True if swapping from A to B. False if swapping from B to A.
+    /// - `split_bps` - The share of `amount` routed through each leg in basis points. Has to sum up to 10_000.
+    /// - `sqrt_price_limits` - The maximum/minimum price each leg will swap to.
+    ///
+    /// #### Special Errors
+    /// - `InvalidSplitSwap` - The legs do not trade the same token pair, the remaining accounts do not match
+    ///                        the legs or `split_bps` does not sum up to 10_000.
+    /// - `DuplicateRoutePool` - Error if a whirlpool is used by more than one leg.
+    /// - `InvalidExtensionAccount` - The side account of an extension enabled on a whirlpool is missing from the remaining accounts.
+    pub fn split_swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, SplitSwap<'info>>,
+        amount: u64,
+        other_amount_threshold: u64,
+        amount_specified_is_input: bool,
+        a_to_b: bool,
+        split_bps: Vec<u16>,
+        sqrt_price_limits: Vec<u128>
+    ) -> ProgramResult {
+        return instructions::split_swap::handler(
+            ctx,
+            amount,
+            other_amount_threshold,
+            amount_specified_is_input,
+            a_to_b,
+            split_bps,
+            sqrt_price_limits
+        );
+    }
+
+    /// Quote a swap in this Whirlpool without executing it.
+    /// The swap is calculated against the current state of the Whirlpool, including its
+    /// fee rate schedule and dynamic fee, but nothing is persisted. The Borsh-encoded
+    /// `SwapReturnData` is written to the transaction return data, so clients can
+    /// simulate this instruction to get the exact result of a swap.
+    ///
+    /// ### Parameters
+    /// - `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).
+    /// - `sqrt_price_limit` - The maximum/minimum price the swap will swap to.
+    /// - `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.
+    /// - `a_to_b` - The direction of the swap. True if swapping from A to B. False if swapping from B to A.
+    ///
+    /// #### Special Errors
+    /// Same as `swap`.
+    pub fn quote_swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, QuoteSwap<'info>>,
+        amount: u64,
+        sqrt_price_limit: u128,
+        amount_specified_is_input: bool,
+        a_to_b: bool,
+    ) -> ProgramResult {
+        return instructions::quote_swap::handler(
+            ctx,
+            amount,
+            sqrt_price_limit,
+            amount_specified_is_input,
+            a_to_b,
+        );
+    }
+
+    /// Quote a two-hop swap without executing it.
+    /// The Borsh-encoded `TwoHopSwapReturnData` is written to the transaction return data.
+    /// Each hop takes the accounts of `quote_swap`, the ones of hop one before the ones of hop two.
+    ///
+    /// ### Parameters
+    /// - `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).
+    /// - `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.
+    /// - `a_to_b_one` - The direction of the swap of hop one. True if swapping from A to B. False if swapping from B to A.
+    /// - `a_to_b_two` - The direction of the swap of hop two. True if swapping from A to B. False if swapping from B to A.
+    /// - `sqrt_price_limit_one` - The maximum/minimum price the swap will swap to in the first hop.
+    /// - `sqrt_price_limit_two` - The maximum/minimum price the swap will swap to in the second hop.
+    ///
+    /// #### Special Errors
+    /// Same as `two_hop_swap`.
+    pub fn quote_two_hop_swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, QuoteTwoHopSwap<'info>>,
+        amount: u64,
+        amount_specified_is_input: bool,
+        a_to_b_one: bool,
+        a_to_b_two: bool,
+        sqrt_price_limit_one: u128,
+        sqrt_price_limit_two: u128,
+    ) -> ProgramResult {
+        return instructions::quote_two_hop_swap::handler(
+            ctx,
+            amount,
+            amount_specified_is_input,
+            a_to_b_one,
+            a_to_b_two,
+            sqrt_price_limit_one,
+            sqrt_price_limit_two,
+        );
+    }
+
+    /// Initializes the range order book of a Whirlpool. Once initialized, positions in the pool
+    /// can be placed as range orders that are filled by the swaps crossing them.
+    /// Enables the range order book extension of the Whirlpool, after which the book has to be
+    /// passed in the remaining accounts of every swap and fee update of the pool.
+    pub fn initialize_range_order_book(ctx: Context<InitializeRangeOrderBook>) -> ProgramResult {
+        return instructions::initialize_range_order_book::handler(ctx);
+    }
+
+    /// Place a position spanning a single tick-spacing as a range order.
+    /// Once a swap moves the price across the range, the liquidity of the position is taken out
+    /// of the pool, so that it holds only the converted token and stops earning fees and rewards.
+    /// The position token is held in escrow until the order is withdrawn.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    ///
+    /// #### Special Errors
+    /// - `InvalidRangeOrderTickRange` - The position does not span exactly one tick-spacing.
+    /// - `LiquidityZero` - The position has no liquidity.
+    /// - `RangeOrderLiquidityTooLow` - The position has less than MIN_RANGE_ORDER_LIQUIDITY.
+    /// - `RangeOrderInRange` - The current price of the Whirlpool is inside the range of the position.
+    /// - `RangeOrderBookFull` - The range order book already holds MAX_RANGE_ORDERS orders.
+    pub fn place_range_order(ctx: Context<PlaceRangeOrder>) -> ProgramResult {
+        return instructions::place_range_order::handler(ctx);
+    }
+
+    /// Withdraw a range order and return the position token to its owner.
+    /// A filled order withdraws the token it was converted to. An order that has not been
+    /// filled yet withdraws all of its liquidity like `decrease_liquidity`.
+    /// The fees and rewards earned by the order can be collected from the position afterwards.
+    ///
+    /// ### Authority
+    /// - `owner` - the owner of the position token when the order was placed.
+    ///
+    /// ### Parameters
+    /// - `token_min_a` - The minimum amount of tokenA the user is willing to withdraw.
+    /// - `token_min_b` - The minimum amount of tokenB the user is willing to withdraw.
+    ///
+    /// #### Special Errors
+    /// - `RangeOrderNotFound` - The position has no range order placed by the owner.
+    /// - `TokenMinSubceeded` - The required token to perform this operation subceeds the user defined amount.
+    /// - `InvalidExtensionAccount` - The side account of an extension enabled on the Whirlpool is missing from the remaining accounts.
+    pub fn withdraw_range_order<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawRangeOrder<'info>>,
+        token_min_a: u64,
+        token_min_b: u64,
+    ) -> ProgramResult {
+        return instructions::withdraw_range_order::handler(ctx, token_min_a, token_min_b);
+    }
+
+    /// Move all of the liquidity of a position to a new tick range on the same Whirlpool.
+    /// The liquidity and the fees owed are withdrawn, and the position is reopened in the new
+    /// range with `liquidity_amount`. Only the difference between the withdrawn and the
+    /// deposited amounts of each token is transferred. Rewards owed remain collectable.
+    /// Positions without liquidity are only moved to the new range.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    ///
+    /// ### Parameters
+    /// - `tick_lower_index` - The new lower tick index of the position.
+    /// - `tick_upper_index` - The new upper tick index of the position.
+    /// - `liquidity_amount` - The amount of Liquidity the user desires to deposit in the new range.
+    /// - `token_min_a` - The minimum amount of tokenA the user is willing to withdraw, including fees.
+    /// - `token_min_b` - The minimum amount of tokenB the user is willing to withdraw, including fees.
+    /// - `token_max_a` - The maximum amount of tokenA the user is willing to deposit.
+    /// - `token_max_b` - The maximum amount of tokenB the user is willing to deposit.
+    ///
+    /// #### Special Errors
+    /// - `LiquidityZero` - The provided liquidity amount is zero.
+    /// - `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of
+    ///                        the tick-spacing in this pool.
+    /// - `InvalidTickSpacing` - If the fee tier policy of the config does not allow the
+    ///                          tick-spacing of this pool.
+    /// - `TokenMinSubceeded` - The withdrawn tokens subceed the user defined amount.
+    /// - `TokenMaxExceeded` - The deposited tokens exceed the user defined amount.
+    /// - `InvalidExtensionAccount` - The side account of an extension enabled on the Whirlpool is missing from the remaining accounts.
+    pub fn rebalance_position<'info>(
+        ctx: Context<'_, '_, '_, 'info, RebalancePosition<'info>>,
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+        liquidity_amount: u128,
+        token_min_a: u64,
+        token_min_b: u64,
+        token_max_a: u64,
+        token_max_b: u64,
+    ) -> ProgramResult {
+        return instructions::rebalance_position::handler(
+            ctx,
+            tick_lower_index,
+            tick_upper_index,
+            liquidity_amount,
+            token_min_a,
+            token_min_b,
+            token_max_a,
+            token_max_b,
+        );
+    }
+
+    /// Initializes the metadata settings of a WhirlpoolsConfig, which set the metadata of the
+    /// position NFTs opened with `open_position_with_metadata`.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority in the WhirlpoolConfig
+    ///
+    /// ### Parameters
+    /// - `update_authority` - The update authority of the position metadata accounts.
+    /// - `name` - The name of the position NFTs.
+    /// - `symbol` - The symbol of the position NFTs.
+    /// - `uri_template` - The URI of the position NFTs. `{position_mint}`, `{whirlpool}`,
+    ///                    `{token_mint_a}`, `{token_mint_b}`, `{tick_lower_index}` and
+    ///                    `{tick_upper_index}` are replaced by the values of the position.
+    ///
+    /// #### Special Errors
+    /// - `InvalidPositionMetadata` - If a value exceeds the length limits of the metadata program,
+    ///                               or if the URI template can expand to a URI that exceeds it.
+    pub fn initialize_metadata_settings(
+        ctx: Context<InitializeMetadataSettings>,
+        update_authority: Pubkey,
+        name: String,
+        symbol: String,
+        uri_template: String,
+    ) -> ProgramResult {
+        return instructions::initialize_metadata_settings::handler(
+            ctx,
+            update_authority,
+            name,
+            symbol,
+            uri_template,
+        );
+    }
+
+    /// Sets the metadata settings of a WhirlpoolsConfig. Positions that are already open keep
+    /// their metadata.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority in the WhirlpoolConfig
+    ///
+    /// ### Parameters
+    /// - `update_authority` - The update authority of the position metadata accounts.
+    /// - `name` - The name of the position NFTs.
+    /// - `symbol` - The symbol of the position NFTs.
+    /// - `uri_template` - The URI of the position NFTs. `{position_mint}`, `{whirlpool}`,
+    ///                    `{token_mint_a}`, `{token_mint_b}`, `{tick_lower_index}` and
+    ///                    `{tick_upper_index}` are replaced by the values of the position.
+    ///
+    /// #### Special Errors
+    /// - `InvalidPositionMetadata` - If a value exceeds the length limits of the metadata program,
+    ///                               or if the URI template can expand to a URI that exceeds it.
+    pub fn set_metadata_settings(
+        ctx: Context<SetMetadataSettings>,
+        update_authority: Pubkey,
+        name: String,
+        symbol: String,
+        uri_template: String,
+    ) -> ProgramResult {
+        return instructions::set_metadata_settings::handler(
+            ctx,
+            update_authority,
+            name,
+            symbol,
+            uri_template,
+        );
+    }
+
+    /// Rewrite the metadata of a position from the metadata settings of its WhirlpoolsConfig,
+    /// so that its URI reflects the current tick range of the position and URI template.
+    /// The metadata of positions opened before the update authority of the metadata settings was
+    /// rotated is handed over to the current update authority.
+    ///
+    /// ### Authority
+    /// - "metadata_update_auth" - The update authority stored in the position metadata, which is
+    ///                            the update authority of the metadata settings once migrated.
+    ///
+    /// #### Special Errors
+    /// - `InvalidMetadataUpdateAuthority` - If the signer is not the update authority stored in the position metadata.
+    /// - `InvalidPositionMetadataAccount` - If the metadata account does not belong to the position mint.
+    /// - `InvalidPositionMetadata` - If the expanded metadata URI is too long.
+    pub fn update_position_metadata(ctx: Context<UpdatePositionMetadata>) -> ProgramResult {
+        return instructions::update_position_metadata::handler(ctx);
+    }
+
+    /// Mint the collection NFT of the positions of a WhirlpoolsConfig. Positions opened with
+    /// metadata afterwards are verified members of the collection, which lets wallets tell them
+    /// apart from look-alike tokens. The metadata settings of the config are the update authority
+    /// of the collection.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority in the WhirlpoolConfig
+    ///
+    /// ### Parameters
+    /// - `name` - The name of the collection NFT.
+    /// - `symbol` - The symbol of the collection NFT.
+    /// - `uri` - The URI of the collection NFT.
+    ///
+    /// #### Special Errors
+    /// - `InvalidPositionMetadata` - If a value exceeds the length limits of the metadata program.
+    pub fn initialize_position_collection(
+        ctx: Context<InitializePositionCollection>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> ProgramResult {
+        return instructions::initialize_position_collection::handler(ctx, name, symbol, uri);
+    }
+
+    /// Perform a swap in this Whirlpool from and/or into the lamport balance of the token
+    /// authority. The native side of the swap goes through a temporary wrapped RENEC account,
+    /// which is passed as the token owner account of the native mint and closed back to the token
+    /// authority once the swap is done.
+    ///
+    /// ### Authority
+    /// - "token_authority" - The authority to withdraw tokens from the input token account.
+    ///
+    /// ### Parameters
+    /// Same as `swap`. Up to `amount` (exact input) or `other_amount_threshold` (exact output)
+    /// lamports are wrapped if the input token is RENEC.
+    ///
+    /// #### Special Errors
+    /// Same as `swap`.
+    /// - `ConstraintRaw` - If the token authority of the nested accounts is not the signer, or if the
+    ///                     token owner account of the native mint is not the native wrap account.
+    pub fn swap_with_native<'info>(
+        ctx: Context<'_, '_, '_, 'info, SwapWithNative<'info>>,
+        amount: u64,
+        other_amount_threshold: u64,
+        sqrt_price_limit: u128,
+        amount_specified_is_input: bool,
+        a_to_b: bool,
+    ) -> ProgramResult {
+        return instructions::swap_with_native::handler(
+            ctx,
+            amount,
+            other_amount_threshold,
+            sqrt_price_limit,
+            amount_specified_is_input,
+            a_to_b,
+        );
+    }
+
+    /// Perform a two-hop swap from and/or into the lamport balance of the token authority.
+    /// The native side of the swap goes through a temporary wrapped RENEC account, which is passed
+    /// as the token owner account of the native mint and closed back to the token authority once
+    /// the swaps are done.
+    ///
+    /// ### Authority
+    /// - "token_authority" - The authority to withdraw tokens from the input token account.
+    ///
+    /// ### Parameters
+    /// Same as `two_hop_swap`. Up to `amount` (exact input) or `other_amount_threshold`
+    /// (exact output) lamports are wrapped if the input token of hop one is RENEC.
+    ///
+    /// #### Special Errors
+    /// Same as `two_hop_swap`.
+    /// - `ConstraintRaw` - If the token authority of the nested accounts is not the signer, or if the
+    ///                     token owner account of the native mint is not the native wrap account.
+    pub fn two_hop_swap_with_native<'info>(
+        ctx: Context<'_, '_, '_, 'info, TwoHopSwapWithNative<'info>>,
+        amount: u64,
+        other_amount_threshold: u64,
+        amount_specified_is_input: bool,
+        a_to_b_one: bool,
+        a_to_b_two: bool,
+        sqrt_price_limit_one: u128,
+        sqrt_price_limit_two: u128,
+    ) -> ProgramResult {
+        return instructions::two_hop_swap_with_native::handler(
+            ctx,
+            amount,
+            other_amount_threshold,
+            amount_specified_is_input,
+            a_to_b_one,
+            a_to_b_two,
+            sqrt_price_limit_one,
+            sqrt_price_limit_two,
+        );
+    }
+
+    /// Add liquidity to a position in the Whirlpool from the lamport balance of the position
+    /// authority. Up to the token max of RENEC is wrapped into a temporary account, which is
+    /// passed as the token owner account of the native mint and closed back to the position
+    /// authority once the liquidity has been deposited.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    ///
+    /// ### Parameters
+    /// Same as `increase_liquidity`.
+    ///
+    /// #### Special Errors
+    /// Same as `increase_liquidity`.
+    /// - `ConstraintRaw` - If the position authority of the nested accounts is not the signer, or if the
+    ///                     token owner account of the native mint is not the native wrap account.
+    pub fn increase_liquidity_with_native<'info>(
+        ctx: Context<'_, '_, '_, 'info, ModifyLiquidityWithNative<'info>>,
+        liquidity_amount: u128,
+        token_max_a: u64,
+        token_max_b: u64,
+    ) -> ProgramResult {
+        return instructions::increase_liquidity_with_native::handler(
+            ctx,
+            liquidity_amount,
+            token_max_a,
+            token_max_b,
+        );
+    }
+
+    /// Withdraw liquidity from a position in the Whirlpool into the lamport balance of the
+    /// position authority. RENEC is withdrawn into a temporary account, which is passed as the
+    /// token owner account of the native mint and closed back to the position authority.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    ///
+    /// ### Parameters
+    /// Same as `decrease_liquidity`.
+    ///
+    /// #### Special Errors
+    /// Same as `decrease_liquidity`.
+    /// - `ConstraintRaw` - If the position authority of the nested accounts is not the signer, or if the
+    ///                     token owner account of the native mint is not the native wrap account.
+    pub fn decrease_liquidity_with_native<'info>(
+        ctx: Context<'_, '_, '_, 'info, ModifyLiquidityWithNative<'info>>,
+        liquidity_amount: u128,
+        token_min_a: u64,
+        token_min_b: u64,
+    ) -> ProgramResult {
+        return instructions::decrease_liquidity_with_native::handler(
+            ctx,
+            liquidity_amount,
+            token_min_a,
+            token_min_b,
+        );
+    }
+
+    /// Initializes a Whirlpool account whose mints belong to the token program or the Token-2022
+    /// program. The token vaults are created with the account extensions of their mint.
+    /// Only the Whirlpools initialized by this instruction can hold Token-2022 mints, which are
+    /// traded and provided with the other `_v2` instructions.
+    ///
+    /// ### Parameters
+    /// Same as `initialize_pool`.
+    ///
+    /// #### Special Errors
+    /// Same as `initialize_pool`.
+    /// - `UnsupportedTokenMint` - A mint does not belong to a token program or has an extension
+    ///                            other than the transfer fee, the metadata pointer and the metadata.
+    pub fn initialize_pool_v2(
+        ctx: Context<InitializePoolV2>,
+        bumps: WhirlpoolBumps,
+        tick_spacing: u16,
+        initial_sqrt_price: u128,
+    ) -> ProgramResult {
+        return instructions::initialize_pool_v2::handler(
+            ctx,
+            bumps,
+            tick_spacing,
+            initial_sqrt_price,
+        );
+    }
+
+    /// Same as `swap` for Whirlpools whose mints may belong to the Token-2022 program.
+    /// An exact input `amount` and a maximum input `other_amount_threshold` include the transfer
+    /// fee of the input token, an exact output `amount` and a minimum output
+    /// `other_amount_threshold` exclude the transfer fee of the output token.
+    /// The amounts of the `SwapReturnData` are the amounts transferred, the output before its
+    /// transfer fee is withheld.
+    ///
+    /// ### Authority
+    /// - "token_authority" - The authority to withdraw tokens from the input token account.
+    ///
+    /// ### Parameters
+    /// Same as `swap`.
+    ///
+    /// #### Special Errors
+    /// Same as `swap`.
+    /// - `TransferFeeCalculationError` - The transfer fee of an amount could not be calculated.
+    pub fn swap_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, SwapV2<'info>>,
+        amount: u64,
+        other_amount_threshold: u64,
+        sqrt_price_limit: u128,
+        amount_specified_is_input: bool,
+        a_to_b: bool,
+    ) -> ProgramResult {
+        return instructions::swap_v2::handler(
+            ctx,
+            amount,
+            other_amount_threshold,
+            sqrt_price_limit,
+            amount_specified_is_input,
+            a_to_b,
+        );
+    }
+
+    /// Same as `increase_liquidity` for Whirlpools whose mints may belong to the Token-2022
+    /// program. The token maxes include the transfer fees of the deposits.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    ///
+    /// ### Parameters
+    /// Same as `increase_liquidity`.
+    ///
+    /// #### Special Errors
+    /// Same as `increase_liquidity`.
+    /// - `TransferFeeCalculationError` - The transfer fee of a deposit could not be calculated.
+    pub fn increase_liquidity_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, ModifyLiquidityV2<'info>>,
+        liquidity_amount: u128,
+        token_max_a: u64,
+        token_max_b: u64,
+    ) -> ProgramResult {
+        return instructions::increase_liquidity_v2::handler(
+            ctx,
+            liquidity_amount,
+            token_max_a,
+            token_max_b,
+        );
+    }
+
+    /// Same as `decrease_liquidity` for Whirlpools whose mints may belong to the Token-2022
+    /// program. The token mins exclude the transfer fees of the withdrawals.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    ///
+    /// ### Parameters
+    /// Same as `decrease_liquidity`.
+    ///
+    /// #### Special Errors
+    /// Same as `decrease_liquidity`.
+    /// - `TransferFeeCalculationError` - The transfer fee of a withdrawal could not be calculated.
+    pub fn decrease_liquidity_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, ModifyLiquidityV2<'info>>,
+        liquidity_amount: u128,
+        token_min_a: u64,
+        token_min_b: u64,
+    ) -> ProgramResult {
+        return instructions::decrease_liquidity_v2::handler(
+            ctx,
+            liquidity_amount,
+            token_min_a,
+            token_min_b,
+        );
+    }
+
+    /// Same as `collect_fees` for Whirlpools whose mints may belong to the Token-2022 program.
+    /// The transfer fees are withheld from the fees collected.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    pub fn collect_fees_v2(ctx: Context<CollectFeesV2>) -> ProgramResult {
+        return instructions::collect_fees_v2::handler(ctx);
+    }
+
+    /// Same as `collect_protocol_fees` for Whirlpools whose mints may belong to the Token-2022
+    /// program. The transfer fees are withheld from the fees collected.
+    ///
+    /// ### Authority
+    /// - `collect_protocol_fees_authority` - assigned authority in the WhirlpoolConfig that can collect protocol fees
+    pub fn collect_protocol_fees_v2(ctx: Context<CollectProtocolFeesV2>) -> ProgramResult {
+        return instructions::collect_protocol_fees_v2::handler(ctx);
+    }
 }
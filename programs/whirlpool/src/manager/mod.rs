@@ -1,5 +1,6 @@
 pub mod liquidity_manager;
 pub mod position_manager;
+pub mod range_order_manager;
 pub mod swap_manager;
 pub mod tick_manager;
 pub mod whirlpool_manager;
@@ -0,0 +1,402 @@
+use anchor_lang::prelude::Pubkey;
+
+use super::{
+    position_manager::next_position_modify_liquidity_update,
+    tick_manager::{
+        next_fee_growths_inside, next_reward_growths_inside, next_tick_modify_liquidity_update,
+    },
+};
+use crate::{
+    errors::ErrorCode,
+    math::{
+        convert_to_liquidity_delta, get_amount_delta_a, get_amount_delta_b,
+        sqrt_price_from_tick_index,
+    },
+    state::*,
+    util::{SwapTickSequence, TickArrayProvider},
+};
+
+/// Fill the pending range orders whose range has been crossed by a swap.
+///
+/// Swaps only move the price in one direction, so the orders that are fully crossed are the
+/// ones the price has moved past. Their liquidity is removed from the ticks, so that they stop
+/// earning fees and rewards and are not converted back once the price returns to their range.
+/// Since the price is outside of their range, the active liquidity of the Whirlpool is unchanged.
+///
+/// Orders with a tick outside of the tick arrays of the swap, e.g. when the swap ends exactly at
+/// the start of the next tick array, are left pending and are filled by a later swap or fee
+/// update that passes their tick arrays, as long as the price is still past their range.
+///
+/// # Parameters
+/// - `whirlpool` - The Whirlpool after the swap has been applied to it
+/// - `range_order_book` - The range orders of the Whirlpool
+/// - `swap_tick_sequence` - The tick arrays traversed by the swap
+///
+/// # Returns
+/// - `Vec<Pubkey>`: The positions of the range orders that were filled
+pub fn fill_range_orders<P: TickArrayProvider>(
+    whirlpool: &Whirlpool,
+    range_order_book: &mut RangeOrderBook,
+    swap_tick_sequence: &mut SwapTickSequence<P>,
+) -> Result<Vec<Pubkey>, ErrorCode> {
+    let tick_spacing = whirlpool.tick_spacing;
+    let tick_current_index = whirlpool.tick_current_index;
+    let mut filled_positions = Vec::new();
+
+    for range_order in range_order_book.range_orders.iter_mut() {
+        if !range_order.is_crossed(tick_current_index) {
+            continue;
+        }
+
+        let tick_lower_index = range_order.tick_lower_index;
+        let tick_upper_index = range_order.tick_upper_index;
+        let (lower_array_index, upper_array_index) = match (
+            swap_tick_sequence.find_array_index(tick_lower_index, tick_spacing),
+            swap_tick_sequence.find_array_index(tick_upper_index, tick_spacing),
+        ) {
+            (Some(lower_array_index), Some(upper_array_index)) => {
+                (lower_array_index, upper_array_index)
+            }
+            _ => continue,
+        };
+
+        let tick_lower =
+            *swap_tick_sequence.get_tick(lower_array_index, tick_lower_index, tick_spacing)?;
+        let tick_upper =
+            *swap_tick_sequence.get_tick(upper_array_index, tick_upper_index, tick_spacing)?;
+
+        let (fee_growth_inside_a, fee_growth_inside_b) = next_fee_growths_inside(
+            tick_current_index,
+            &tick_lower,
+            tick_lower_index,
+            &tick_upper,
+            tick_upper_index,
+            whirlpool.fee_growth_global_a,
+            whirlpool.fee_growth_global_b,
+        );
+        let reward_growths_inside = next_reward_growths_inside(
+            tick_current_index,
+            &tick_lower,
+            tick_lower_index,
+            &tick_upper,
+            tick_upper_index,
+            &whirlpool.reward_infos,
+        );
+
+        let liquidity_delta = convert_to_liquidity_delta(range_order.liquidity, false)?;
+        let tick_lower_update = next_tick_modify_liquidity_update(
+            &tick_lower,
+            tick_lower_index,
+            tick_current_index,
+            whirlpool.fee_growth_global_a,
+            whirlpool.fee_growth_global_b,
+            &whirlpool.reward_infos,
+            liquidity_delta,
+            false,
+        )?;
+        let tick_upper_update = next_tick_modify_liquidity_update(
+            &tick_upper,
+            tick_upper_index,
+            tick_current_index,
+            whirlpool.fee_growth_global_a,
+            whirlpool.fee_growth_global_b,
+            &whirlpool.reward_infos,
+            liquidity_delta,
+            true,
+        )?;
+
+        swap_tick_sequence.update_tick(
+            lower_array_index,
+            tick_lower_index,
+            tick_spacing,
+            &tick_lower_update,
+        )?;
+        swap_tick_sequence.update_tick(
+            upper_array_index,
+            tick_upper_index,
+            tick_spacing,
+            &tick_upper_update,
+        )?;
+
+        range_order.filled = true;
+        range_order.fee_growth_inside_a = fee_growth_inside_a;
+        range_order.fee_growth_inside_b = fee_growth_inside_b;
+        range_order.reward_growths_inside = reward_growths_inside;
+        filled_positions.push(range_order.position);
+    }
+
+    Ok(filled_positions)
+}
+
+/// Calculate the position update and the converted token amounts of a filled range order.
+/// The fees and rewards of the position are accrued up to the growths snapshotted at the fill,
+/// and all of its liquidity is removed.
+///
+/// # Returns
+/// - `PositionUpdate`: The update of the position
+/// - `u64`: The amount of token A to withdraw
+/// - `u64`: The amount of token B to withdraw
+pub fn calculate_filled_range_order_update(
+    position: &Position,
+    range_order: &RangeOrder,
+) -> Result<(PositionUpdate, u64, u64), ErrorCode> {
+    let liquidity_delta = convert_to_liquidity_delta(range_order.liquidity, false)?;
+    let position_update = next_position_modify_liquidity_update(
+        position,
+        liquidity_delta,
+        range_order.fee_growth_inside_a,
+        range_order.fee_growth_inside_b,
+        &range_order.reward_growths_inside,
+    )?;
+
+    let lower_price = sqrt_price_from_tick_index(range_order.tick_lower_index);
+    let upper_price = sqrt_price_from_tick_index(range_order.tick_upper_index);
+    let (delta_a, delta_b) = if range_order.sells_token_a {
+        (
+            0,
+            get_amount_delta_b(lower_price, upper_price, range_order.liquidity, false)?,
+        )
+    } else {
+        (
+            get_amount_delta_a(lower_price, upper_price, range_order.liquidity, false)?,
+            0,
+        )
+    };
+
+    Ok((position_update, delta_a, delta_b))
+}
+
+#[cfg(test)]
+mod range_order_manager_tests {
+    use super::*;
+    use crate::state::position_builder::PositionBuilder;
+
+    const TS_64: u16 = 64;
+
+    fn build_tick_arrays(ticks: Vec<(usize, Tick)>) -> Vec<TickArray> {
+        let mut array = TickArray::default();
+        for (offset, tick) in ticks {
+            array.ticks[offset] = tick;
+        }
+        vec![array]
+    }
+
+    fn build_tick(liquidity_net: i128, liquidity_gross: u128, fee_growth_outside: u128) -> Tick {
+        Tick {
+            initialized: true,
+            liquidity_net,
+            liquidity_gross,
+            fee_growth_outside_a: fee_growth_outside,
+            fee_growth_outside_b: fee_growth_outside,
+            ..Default::default()
+        }
+    }
+
+    fn build_book(sells_token_a: bool) -> RangeOrderBook {
+        RangeOrderBook {
+            range_orders: vec![RangeOrder {
+                position: Pubkey::new_unique(),
+                tick_lower_index: 128,
+                tick_upper_index: 192,
+                liquidity: 1000,
+                sells_token_a,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_fill_crossed_range_order() {
+        let whirlpool = Whirlpool {
+            tick_spacing: TS_64,
+            tick_current_index: 192,
+            fee_growth_global_a: 500,
+            fee_growth_global_b: 500,
+            ..Default::default()
+        };
+        let mut book = build_book(true);
+        // The upper tick was crossed by the swap, the lower tick is shared with another position
+        let mut swap_tick_sequence = SwapTickSequence::from_provider(build_tick_arrays(vec![
+            (2, build_tick(1500, 1500, 100)),
+            (3, build_tick(-1000, 1000, 400)),
+        ]));
+
+        let filled = fill_range_orders(&whirlpool, &mut book, &mut swap_tick_sequence).unwrap();
+        assert_eq!(filled, vec![book.range_orders[0].position]);
+
+        let range_order = book.range_orders[0];
+        assert!(range_order.filled);
+        assert_eq!(range_order.fee_growth_inside_a, 300);
+        assert_eq!(range_order.fee_growth_inside_b, 300);
+
+        let tick_lower = swap_tick_sequence.get_tick(0, 128, TS_64).unwrap();
+        assert_eq!({ tick_lower.liquidity_net }, 500);
+        assert_eq!({ tick_lower.liquidity_gross }, 500);
+        assert_eq!({ tick_lower.fee_growth_outside_a }, 100);
+        let tick_upper = swap_tick_sequence.get_tick(0, 192, TS_64).unwrap();
+        assert!(!tick_upper.initialized);
+
+        // A filled order is only filled once
+        let filled = fill_range_orders(&whirlpool, &mut book, &mut swap_tick_sequence).unwrap();
+        assert!(filled.is_empty());
+    }
+
+    #[test]
+    fn test_fill_ignores_uncrossed_range_order() {
+        let whirlpool = Whirlpool {
+            tick_spacing: TS_64,
+            tick_current_index: 160,
+            ..Default::default()
+        };
+        let mut book = build_book(true);
+        let mut swap_tick_sequence = SwapTickSequence::from_provider(build_tick_arrays(vec![
+            (2, build_tick(1000, 1000, 0)),
+            (3, build_tick(-1000, 1000, 0)),
+        ]));
+
+        let filled = fill_range_orders(&whirlpool, &mut book, &mut swap_tick_sequence).unwrap();
+        assert!(filled.is_empty());
+        assert!(!book.range_orders[0].filled);
+        let tick_upper = swap_tick_sequence.get_tick(0, 192, TS_64).unwrap();
+        assert_eq!({ tick_upper.liquidity_gross }, 1000);
+    }
+
+    #[test]
+    fn test_fill_range_order_below_price() {
+        let whirlpool = Whirlpool {
+            tick_spacing: TS_64,
+            tick_current_index: 127,
+            ..Default::default()
+        };
+        let mut book = build_book(false);
+        let mut swap_tick_sequence = SwapTickSequence::from_provider(build_tick_arrays(vec![
+            (2, build_tick(1000, 1000, 0)),
+            (3, build_tick(-1000, 1000, 0)),
+        ]));
+
+        let filled = fill_range_orders(&whirlpool, &mut book, &mut swap_tick_sequence).unwrap();
+        assert_eq!(filled.len(), 1);
+        assert!(
+            !swap_tick_sequence
+                .get_tick(0, 128, TS_64)
+                .unwrap()
+                .initialized
+        );
+        assert!(
+            !swap_tick_sequence
+                .get_tick(0, 192, TS_64)
+                .unwrap()
+                .initialized
+        );
+    }
+
+    #[test]
+    fn test_fill_defers_range_order_outside_sequence() {
+        let whirlpool = Whirlpool {
+            tick_spacing: TS_64,
+            tick_current_index: 5696,
+            ..Default::default()
+        };
+        let mut book = build_book(true);
+        book.range_orders[0].tick_lower_index = 5632;
+        book.range_orders[0].tick_upper_index = 5696;
+        let mut swap_tick_sequence = SwapTickSequence::from_provider(build_tick_arrays(vec![]));
+
+        let filled = fill_range_orders(&whirlpool, &mut book, &mut swap_tick_sequence).unwrap();
+        assert!(filled.is_empty());
+        assert!(!book.range_orders[0].filled);
+    }
+
+    #[test]
+    fn test_fill_defers_range_order_at_tick_array_boundary() {
+        // The swap ended exactly at the start of the next tick array, which hosts the upper tick
+        // of the order but is not part of the sequence
+        let whirlpool = Whirlpool {
+            tick_spacing: TS_64,
+            tick_current_index: 5632,
+            ..Default::default()
+        };
+        let mut book = build_book(true);
+        book.range_orders[0].tick_lower_index = 5568;
+        book.range_orders[0].tick_upper_index = 5632;
+        let mut swap_tick_sequence = SwapTickSequence::from_provider(build_tick_arrays(vec![(
+            87,
+            build_tick(1000, 1000, 0),
+        )]));
+
+        let filled = fill_range_orders(&whirlpool, &mut book, &mut swap_tick_sequence).unwrap();
+        assert!(filled.is_empty());
+        assert!(!book.range_orders[0].filled);
+        let tick_lower = swap_tick_sequence.get_tick(0, 5568, TS_64).unwrap();
+        assert_eq!({ tick_lower.liquidity_gross }, 1000);
+
+        // The order is filled once its tick arrays are passed while the price is past its range
+        let mut next_tick_array = TickArray::default();
+        next_tick_array.start_tick_index = 5632;
+        next_tick_array.ticks[0] = build_tick(-1000, 1000, 0);
+        let mut tick_arrays = build_tick_arrays(vec![(87, build_tick(1000, 1000, 0))]);
+        tick_arrays.push(next_tick_array);
+        let mut swap_tick_sequence = SwapTickSequence::from_provider(tick_arrays);
+
+        let filled = fill_range_orders(&whirlpool, &mut book, &mut swap_tick_sequence).unwrap();
+        assert_eq!(filled, vec![book.range_orders[0].position]);
+        assert!(
+            !swap_tick_sequence
+                .get_tick(0, 5568, TS_64)
+                .unwrap()
+                .initialized
+        );
+        assert!(
+            !swap_tick_sequence
+                .get_tick(1, 5632, TS_64)
+                .unwrap()
+                .initialized
+        );
+    }
+
+    #[test]
+    fn test_calculate_filled_range_order_update() {
+        let position = PositionBuilder::new(128, 192)
+            .liquidity(1_000_000)
+            .fee_growth_checkpoint_a(0)
+            .fee_growth_checkpoint_b(0)
+            .build();
+        let range_order = RangeOrder {
+            tick_lower_index: 128,
+            tick_upper_index: 192,
+            liquidity: 1_000_000,
+            sells_token_a: true,
+            filled: true,
+            fee_growth_inside_a: 1 << 64,
+            fee_growth_inside_b: 0,
+            ..Default::default()
+        };
+
+        let (position_update, delta_a, delta_b) =
+            calculate_filled_range_order_update(&position, &range_order).unwrap();
+        assert_eq!(position_update.liquidity, 0);
+        assert_eq!(position_update.fee_owed_a, 1_000_000);
+        assert_eq!(position_update.fee_growth_checkpoint_a, 1 << 64);
+        assert_eq!(delta_a, 0);
+        assert_eq!(
+            delta_b,
+            get_amount_delta_b(
+                sqrt_price_from_tick_index(128),
+                sqrt_price_from_tick_index(192),
+                1_000_000,
+                false
+            )
+            .unwrap()
+        );
+
+        let range_order = RangeOrder {
+            sells_token_a: false,
+            ..range_order
+        };
+        let (_, delta_a, delta_b) =
+            calculate_filled_range_order_update(&position, &range_order).unwrap();
+        assert!(delta_a > 0);
+        assert_eq!(delta_b, 0);
+    }
+}
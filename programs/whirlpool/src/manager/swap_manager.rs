@@ -5,7 +5,7 @@ use crate::{
     },
     math::*,
     state::*,
-    util::SwapTickSequence,
+    util::{SwapTickSequence, TickArrayProvider},
 };
 use std::convert::TryInto;
 
@@ -19,11 +19,13 @@ pub struct PostSwapUpdate {
     pub next_fee_growth_global: u128,
     pub next_reward_infos: [WhirlpoolRewardInfo; NUM_REWARDS],
     pub next_protocol_fee: u64,
+    pub fee_amount: u64,
 }
 
-pub fn swap(
+pub fn swap<P: TickArrayProvider>(
     whirlpool: &Whirlpool,
-    swap_tick_sequence: &mut SwapTickSequence,
+    dynamic_fee: Option<&DynamicFee>,
+    swap_tick_sequence: &mut SwapTickSequence<P>,
     amount: u64,
     sqrt_price_limit: u128,
     amount_specified_is_input: bool,
@@ -45,7 +47,10 @@ pub fn swap(
     }
 
     let tick_spacing = whirlpool.tick_spacing;
-    let fee_rate = whirlpool.fee_rate;
+    let fee_rate = match dynamic_fee {
+        Some(dynamic_fee) => dynamic_fee.effective_fee_rate(whirlpool.fee_rate, timestamp),
+        None => whirlpool.fee_rate,
+    };
     let protocol_fee_rate = whirlpool.protocol_fee_rate;
     let next_reward_infos = next_whirlpool_reward_infos(whirlpool, timestamp)?;
 
@@ -55,6 +60,7 @@ pub fn swap(
     let mut curr_tick_index = whirlpool.tick_current_index;
     let mut curr_liquidity = whirlpool.liquidity;
     let mut curr_protocol_fee: u64 = 0;
+    let mut curr_fee_amount: u64 = 0;
     let mut curr_array_index: usize = 0;
     let mut curr_fee_growth_global_input = if a_to_b {
         whirlpool.fee_growth_global_a
@@ -117,6 +123,9 @@ pub fn swap(
         );
         curr_protocol_fee = next_protocol_fee;
         curr_fee_growth_global_input = next_fee_growth_global_input;
+        curr_fee_amount = curr_fee_amount
+            .checked_add(swap_computation.fee_amount)
+            .ok_or(ErrorCode::AmountCalcOverflow)?;
 
         if swap_computation.next_price == next_tick_sqrt_price {
             let (next_tick, next_tick_initialized) = swap_tick_sequence
@@ -194,9 +203,124 @@ pub fn swap(
         next_fee_growth_global: curr_fee_growth_global_input,
         next_reward_infos,
         next_protocol_fee: curr_protocol_fee,
+        fee_amount: curr_fee_amount,
     })
 }
 
+/// Calculate both hops of a two-hop swap.
+///
+/// In exact-in mode the calculation runs from hop one to hop two, feeding the output of hop
+/// one into hop two. In exact-out mode it runs backwards from hop two, and hop one has to
+/// provide the full input of hop two.
+///
+/// # Errors
+/// - `AmountOutBelowMinimum` - In exact-out mode, hop two cannot provide the full specified output amount.
+/// - `IntermediateTokenAmountMismatch` - In exact-out mode, hop one cannot provide the full input required by hop two.
+pub fn two_hop_swap<P: TickArrayProvider>(
+    whirlpool_one: &Whirlpool,
+    whirlpool_two: &Whirlpool,
+    dynamic_fee_one: Option<&DynamicFee>,
+    dynamic_fee_two: Option<&DynamicFee>,
+    swap_tick_sequence_one: &mut SwapTickSequence<P>,
+    swap_tick_sequence_two: &mut SwapTickSequence<P>,
+    amount: u64,
+    amount_specified_is_input: bool,
+    a_to_b_one: bool,
+    a_to_b_two: bool,
+    sqrt_price_limit_one: u128,
+    sqrt_price_limit_two: u128,
+    timestamp: u64,
+) -> Result<(PostSwapUpdate, PostSwapUpdate), ErrorCode> {
+    if amount_specified_is_input {
+        // If the amount specified is input, this means we are doing exact-in
+        // and the swap calculations occur from Swap 1 => Swap 2
+        // and the swaps occur from Swap 1 => Swap 2
+        let swap_calc_one = swap(
+            whirlpool_one,
+            dynamic_fee_one,
+            swap_tick_sequence_one,
+            amount,
+            sqrt_price_limit_one,
+            amount_specified_is_input, // true
+            a_to_b_one,
+            timestamp,
+        )?;
+
+        // Swap two input is the output of swap one
+        let swap_two_input_amount = if a_to_b_one {
+            swap_calc_one.amount_b
+        } else {
+            swap_calc_one.amount_a
+        };
+
+        let swap_calc_two = swap(
+            whirlpool_two,
+            dynamic_fee_two,
+            swap_tick_sequence_two,
+            swap_two_input_amount,
+            sqrt_price_limit_two,
+            amount_specified_is_input, // true
+            a_to_b_two,
+            timestamp,
+        )?;
+        Ok((swap_calc_one, swap_calc_two))
+    } else {
+        // If the amount specified is output, this means we need to invert the ordering of the calculations
+        // and the swap calculations occur from Swap 2 => Swap 1
+        // but the actual swaps occur from Swap 1 => Swap 2 (to ensure that the intermediate token exists in the account)
+        let swap_calc_two = swap(
+            whirlpool_two,
+            dynamic_fee_two,
+            swap_tick_sequence_two,
+            amount,
+            sqrt_price_limit_two,
+            amount_specified_is_input, // false
+            a_to_b_two,
+            timestamp,
+        )?;
+
+        // The merchant must receive exactly the specified amount of the final token
+        let swap_two_output_amount = if a_to_b_two {
+            swap_calc_two.amount_b
+        } else {
+            swap_calc_two.amount_a
+        };
+        if swap_two_output_amount != amount {
+            return Err(ErrorCode::AmountOutBelowMinimum);
+        }
+
+        // The output of swap 1 is input of swap_calc_two
+        let swap_one_output_amount = if a_to_b_two {
+            swap_calc_two.amount_a
+        } else {
+            swap_calc_two.amount_b
+        };
+
+        let swap_calc_one = swap(
+            whirlpool_one,
+            dynamic_fee_one,
+            swap_tick_sequence_one,
+            swap_one_output_amount,
+            sqrt_price_limit_one,
+            amount_specified_is_input, // false
+            a_to_b_one,
+            timestamp,
+        )?;
+
+        // Swap 1 has to provide the full input of swap 2. Otherwise swap 2 would be funded
+        // from the user's existing intermediate balance.
+        let swap_one_filled_amount = if a_to_b_one {
+            swap_calc_one.amount_b
+        } else {
+            swap_calc_one.amount_a
+        };
+        if swap_one_filled_amount != swap_one_output_amount {
+            return Err(ErrorCode::IntermediateTokenAmountMismatch);
+        }
+        Ok((swap_calc_one, swap_calc_two))
+    }
+}
+
 fn calculate_fees(
     fee_amount: u64,
     protocol_fee_rate: u16,
@@ -2535,3 +2659,158 @@ mod swap_error_tests {
     }
 
 }
+
+#[cfg(test)]
+mod swap_fee_tests {
+    use super::*;
+    use crate::util::test_utils::swap_test_fixture::*;
+
+    #[test]
+    /// An exact input swap within a single tick range.
+    ///
+    /// Expectation:
+    /// The fee amount is the fee rate of the input amount, and includes the protocol fee.
+    fn fee_amount_includes_protocol_fee() {
+        let swap_test_info = SwapTestFixture::new(SwapTestFixtureInfo {
+            tick_spacing: TS_128,
+            liquidity: 1_000_000_000_000,
+            curr_tick_index: 5000,
+            start_tick_index: 0,
+            trade_amount: 1_000_000,
+            sqrt_price_limit: sqrt_price_from_tick_index(0),
+            amount_specified_is_input: true,
+            a_to_b: true,
+            array_1_ticks: &vec![],
+            array_2_ticks: Some(&vec![]),
+            array_3_ticks: Some(&vec![]),
+            fee_rate: 3000,
+            protocol_fee_rate: 300,
+            ..Default::default()
+        });
+        let mut tick_sequence = SwapTickSequence::new(
+            swap_test_info.tick_arrays[0].borrow_mut(),
+            Some(swap_test_info.tick_arrays[1].borrow_mut()),
+            Some(swap_test_info.tick_arrays[2].borrow_mut()),
+        );
+        let post_swap = swap_test_info.run(&mut tick_sequence, 100);
+
+        assert_eq!(post_swap.amount_a, 1_000_000);
+        assert!(post_swap.fee_amount >= 3_000 && post_swap.fee_amount < 3_010);
+        assert_eq!(
+            post_swap.next_protocol_fee,
+            post_swap.fee_amount * 300 / 10_000
+        );
+    }
+}
+
+#[cfg(test)]
+mod two_hop_swap_tests {
+    use super::*;
+    use crate::util::test_utils::swap_test_fixture::*;
+
+    // Both hops swap B for A on a pool with enough liquidity to fill the amounts below between
+    // tick 0 and tick 576.
+    fn build_hop() -> SwapTestFixture {
+        SwapTestFixture::new(SwapTestFixtureInfo {
+            tick_spacing: TS_8,
+            liquidity: 5_000_000_000,
+            curr_tick_index: 0,
+            start_tick_index: 0,
+            a_to_b: false,
+            ..Default::default()
+        })
+    }
+
+    fn run_exact_out(
+        hop_one: &SwapTestFixture,
+        hop_two: &SwapTestFixture,
+        sqrt_price_limit_one: u128,
+        sqrt_price_limit_two: u128,
+    ) -> Result<(PostSwapUpdate, PostSwapUpdate), ErrorCode> {
+        let mut tick_sequence_one = SwapTickSequence::new(
+            hop_one.tick_arrays[0].borrow_mut(),
+            Some(hop_one.tick_arrays[1].borrow_mut()),
+            Some(hop_one.tick_arrays[2].borrow_mut()),
+        );
+        let mut tick_sequence_two = SwapTickSequence::new(
+            hop_two.tick_arrays[0].borrow_mut(),
+            Some(hop_two.tick_arrays[1].borrow_mut()),
+            Some(hop_two.tick_arrays[2].borrow_mut()),
+        );
+        two_hop_swap(
+            &hop_one.whirlpool,
+            &hop_two.whirlpool,
+            None,
+            None,
+            &mut tick_sequence_one,
+            &mut tick_sequence_two,
+            1_000_000,
+            false,
+            false,
+            false,
+            sqrt_price_limit_one,
+            sqrt_price_limit_two,
+            100,
+        )
+    }
+
+    #[test]
+    /// An exact output two-hop swap where both hops can provide their full output.
+    ///
+    /// Expectation:
+    /// The second hop provides the specified amount, and the first hop provides the full input of
+    /// the second hop.
+    fn exact_out_full_fill() {
+        let hop_one = build_hop();
+        let hop_two = build_hop();
+        let (swap_one, swap_two) = run_exact_out(
+            &hop_one,
+            &hop_two,
+            sqrt_price_from_tick_index(576),
+            sqrt_price_from_tick_index(576),
+        )
+        .unwrap();
+
+        assert_eq!(swap_two.amount_a, 1_000_000);
+        assert_eq!(swap_one.amount_a, swap_two.amount_b);
+    }
+
+    #[test]
+    /// An exact output two-hop swap where the price limit of the second hop is reached before
+    /// it provides the specified amount.
+    ///
+    /// Expectation:
+    /// Fail on AmountOutBelowMinimum instead of settling for a partial fill.
+    fn exact_out_partial_fill_on_hop_two() {
+        let hop_one = build_hop();
+        let hop_two = build_hop();
+        let result = run_exact_out(
+            &hop_one,
+            &hop_two,
+            sqrt_price_from_tick_index(576),
+            sqrt_price_from_tick_index(1),
+        );
+
+        assert_eq!(result.unwrap_err(), ErrorCode::AmountOutBelowMinimum);
+    }
+
+    #[test]
+    /// An exact output two-hop swap where the price limit of the first hop is reached before it
+    /// provides the full input of the second hop.
+    ///
+    /// Expectation:
+    /// Fail on IntermediateTokenAmountMismatch, as the second hop would otherwise be funded from
+    /// the user's existing intermediate balance.
+    fn exact_out_partial_fill_on_hop_one() {
+        let hop_one = build_hop();
+        let hop_two = build_hop();
+        let result = run_exact_out(
+            &hop_one,
+            &hop_two,
+            sqrt_price_from_tick_index(1),
+            sqrt_price_from_tick_index(576),
+        );
+
+        assert_eq!(result.unwrap_err(), ErrorCode::IntermediateTokenAmountMismatch);
+    }
+}
@@ -0,0 +1,124 @@
+//! Return data written by the swap instructions for programs that invoke them through CPI.
+use anchor_lang::prelude::*;
+#[cfg(feature = "cpi")]
+use solana_program::program::get_return_data;
+use solana_program::program::set_return_data;
+
+use crate::errors::ErrorCode;
+use crate::manager::swap_manager::PostSwapUpdate;
+
+/// The Borsh-encoded return data of `swap`.
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Default, Debug, PartialEq)]
+pub struct SwapReturnData {
+    pub amount_a: u64,
+    pub amount_b: u64,
+    // Fee paid in the input token, including the protocol fee
+    pub fee_amount: u64,
+    pub protocol_fee_amount: u64,
+    // Q64.64, sqrt-price of the Whirlpool after the swap
+    pub sqrt_price: u128,
+    pub tick_current_index: i32,
+}
+
+impl From<&PostSwapUpdate> for SwapReturnData {
+    fn from(swap_update: &PostSwapUpdate) -> Self {
+        SwapReturnData {
+            amount_a: swap_update.amount_a,
+            amount_b: swap_update.amount_b,
+            fee_amount: swap_update.fee_amount,
+            protocol_fee_amount: swap_update.next_protocol_fee,
+            sqrt_price: swap_update.next_sqrt_price,
+            tick_current_index: swap_update.next_tick_index,
+        }
+    }
+}
+
+/// The Borsh-encoded return data of `two_hop_swap`, one entry per hop.
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Default, Debug, PartialEq)]
+pub struct TwoHopSwapReturnData {
+    pub swap_one: SwapReturnData,
+    pub swap_two: SwapReturnData,
+}
+
+pub fn set_swap_return_data<T: AnchorSerialize>(return_data: &T) -> ProgramResult {
+    set_return_data(
+        &return_data
+            .try_to_vec()
+            .map_err(|e| ProgramError::BorshIoError(e.to_string()))?,
+    );
+    Ok(())
+}
+
+/// Decode the return data of the last instruction invoked through CPI.
+/// Fails if the return data was not set by this program.
+pub fn decode_swap_return_data<T: AnchorDeserialize>(
+    return_data: Option<(Pubkey, Vec<u8>)>,
+) -> Result<T, ProgramError> {
+    match return_data {
+        Some((program_id, data)) if program_id == crate::ID => {
+            T::try_from_slice(&data).map_err(|e| ProgramError::BorshIoError(e.to_string()))
+        }
+        _ => Err(ErrorCode::SwapReturnDataMissing.into()),
+    }
+}
+
+/// Read the `SwapReturnData` of a `swap` that was just invoked through CPI.
+#[cfg(feature = "cpi")]
+pub fn get_swap_return_data() -> Result<SwapReturnData, ProgramError> {
+    decode_swap_return_data(get_return_data())
+}
+
+/// Read the `TwoHopSwapReturnData` of a `two_hop_swap` that was just invoked through CPI.
+#[cfg(feature = "cpi")]
+pub fn get_two_hop_swap_return_data() -> Result<TwoHopSwapReturnData, ProgramError> {
+    decode_swap_return_data(get_return_data())
+}
+
+#[cfg(test)]
+mod return_data_tests {
+    use super::*;
+
+    fn return_data() -> SwapReturnData {
+        SwapReturnData {
+            amount_a: 1_000_000,
+            amount_b: 995_000,
+            fee_amount: 3000,
+            protocol_fee_amount: 90,
+            sqrt_price: 18446744073709551616,
+            tick_current_index: -12,
+        }
+    }
+
+    #[test]
+    fn test_decode_swap_return_data() {
+        let data = return_data().try_to_vec().unwrap();
+        let decoded: SwapReturnData = decode_swap_return_data(Some((crate::ID, data))).unwrap();
+        assert_eq!(decoded, return_data());
+    }
+
+    #[test]
+    fn test_decode_two_hop_swap_return_data() {
+        let expected = TwoHopSwapReturnData {
+            swap_one: return_data(),
+            swap_two: SwapReturnData::default(),
+        };
+        let data = expected.try_to_vec().unwrap();
+        let decoded: TwoHopSwapReturnData =
+            decode_swap_return_data(Some((crate::ID, data))).unwrap();
+        assert_eq!(decoded, expected);
+    }
+
+    #[test]
+    fn test_decode_swap_return_data_from_other_program() {
+        let data = return_data().try_to_vec().unwrap();
+        let result: Result<SwapReturnData, ProgramError> =
+            decode_swap_return_data(Some((Pubkey::new_unique(), data)));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_decode_missing_swap_return_data() {
+        let result: Result<SwapReturnData, ProgramError> = decode_swap_return_data(None);
+        assert!(result.is_err());
+    }
+}
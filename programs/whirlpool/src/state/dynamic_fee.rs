@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, math::MAX_FEE_RATE};
+
+use super::Whirlpool;
+
+/// Tracks the recent price movement of a Whirlpool to charge a volatility surcharge on top of
+/// its static fee rate. Pools without this account only charge their `fee_rate`.
+#[account]
+#[derive(Default)]
+pub struct DynamicFee {
+    pub whirlpool: Pubkey, // 32
+
+    // Surcharge fee rate added per tick-spacing of accumulated volatility,
+    // stored as hundredths of a basis point
+    pub base_surcharge_rate: u16, // 2
+    // Upper bound of the surcharge fee rate, stored as hundredths of a basis point
+    pub max_surcharge_rate: u16, // 2
+    // Number of seconds in which the volatility accumulator decays linearly to zero
+    pub decay_period: u64, // 8
+
+    // Tick-spacings crossed by recent swaps, before decay
+    pub volatility_accumulator: u32, // 4
+    pub last_update_timestamp: u64,  // 8
+}
+
+impl DynamicFee {
+    pub const LEN: usize = 8 + 32 + 2 + 2 + 8 + 4 + 8;
+
+    pub fn initialize(
+        &mut self,
+        whirlpool: &Account<Whirlpool>,
+        base_surcharge_rate: u16,
+        max_surcharge_rate: u16,
+        decay_period: u64,
+        timestamp: u64,
+    ) -> Result<(), ErrorCode> {
+        self.whirlpool = whirlpool.key();
+        self.update_params(base_surcharge_rate, max_surcharge_rate, decay_period)?;
+        self.volatility_accumulator = 0;
+        self.last_update_timestamp = timestamp;
+
+        Ok(())
+    }
+
+    /// Update the surcharge parameters. The accumulated volatility is kept.
+    ///
+    /// # Errors
+    /// - `FeeRateMaxExceeded` - The max surcharge rate exceeds MAX_FEE_RATE
+    /// - `InvalidDynamicFeeParams` - The decay period is zero
+    pub fn update_params(
+        &mut self,
+        base_surcharge_rate: u16,
+        max_surcharge_rate: u16,
+        decay_period: u64,
+    ) -> Result<(), ErrorCode> {
+        if max_surcharge_rate > MAX_FEE_RATE {
+            return Err(ErrorCode::FeeRateMaxExceeded);
+        }
+        if decay_period == 0 {
+            return Err(ErrorCode::InvalidDynamicFeeParams);
+        }
+
+        self.base_surcharge_rate = base_surcharge_rate;
+        self.max_surcharge_rate = max_surcharge_rate;
+        self.decay_period = decay_period;
+
+        Ok(())
+    }
+
+    /// Returns the volatility accumulator after decaying it up until `timestamp`.
+    pub fn decayed_volatility(&self, timestamp: u64) -> u32 {
+        let elapsed = timestamp.saturating_sub(self.last_update_timestamp);
+        if elapsed >= self.decay_period {
+            return 0;
+        }
+
+        // Cannot exceed the accumulator since decay_period - elapsed < decay_period
+        ((self.volatility_accumulator as u128) * ((self.decay_period - elapsed) as u128)
+            / (self.decay_period as u128)) as u32
+    }
+
+    /// Returns the fee rate a swap at `timestamp` is charged: `fee_rate` plus the volatility
+    /// surcharge, bounded by MAX_FEE_RATE.
+    pub fn effective_fee_rate(&self, fee_rate: u16, timestamp: u64) -> u16 {
+        let surcharge = (self.decayed_volatility(timestamp) as u64)
+            .saturating_mul(self.base_surcharge_rate as u64)
+            .min(self.max_surcharge_rate as u64);
+
+        (fee_rate as u64 + surcharge).min(MAX_FEE_RATE as u64) as u16
+    }
+
+    /// Accumulate the tick movement of a swap into the volatility accumulator.
+    pub fn update_volatility(
+        &mut self,
+        timestamp: u64,
+        tick_index_before: i32,
+        tick_index_after: i32,
+        tick_spacing: u16,
+    ) {
+        let tick_spacings_crossed = ((tick_index_after as i64 - tick_index_before as i64).abs()
+            / tick_spacing.max(1) as i64) as u32;
+
+        self.volatility_accumulator = self
+            .decayed_volatility(timestamp)
+            .saturating_add(tick_spacings_crossed);
+        self.last_update_timestamp = timestamp;
+    }
+}
+
+#[cfg(test)]
+mod dynamic_fee_tests {
+    use super::*;
+
+    fn test_dynamic_fee() -> DynamicFee {
+        DynamicFee {
+            base_surcharge_rate: 100,
+            max_surcharge_rate: 5_000,
+            decay_period: 100,
+            volatility_accumulator: 20,
+            last_update_timestamp: 1_000,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_decayed_volatility() {
+        let dynamic_fee = test_dynamic_fee();
+        assert_eq!(dynamic_fee.decayed_volatility(1_000), 20);
+        assert_eq!(dynamic_fee.decayed_volatility(1_050), 10);
+        assert_eq!(dynamic_fee.decayed_volatility(1_100), 0);
+        assert_eq!(dynamic_fee.decayed_volatility(u64::MAX), 0);
+    }
+
+    #[test]
+    fn test_effective_fee_rate() {
+        let dynamic_fee = test_dynamic_fee();
+        assert_eq!(dynamic_fee.effective_fee_rate(3_000, 1_000), 5_000);
+        assert_eq!(dynamic_fee.effective_fee_rate(3_000, 1_100), 3_000);
+    }
+
+    #[test]
+    fn test_effective_fee_rate_bounds() {
+        let mut dynamic_fee = test_dynamic_fee();
+        dynamic_fee.volatility_accumulator = u32::MAX;
+        // Bounded by the max surcharge
+        assert_eq!(dynamic_fee.effective_fee_rate(3_000, 1_000), 8_000);
+        // Bounded by MAX_FEE_RATE
+        assert_eq!(
+            dynamic_fee.effective_fee_rate(MAX_FEE_RATE, 1_000),
+            MAX_FEE_RATE
+        );
+    }
+
+    #[test]
+    fn test_update_volatility() {
+        let mut dynamic_fee = test_dynamic_fee();
+        dynamic_fee.update_volatility(1_050, 0, -640, 64);
+        assert_eq!(dynamic_fee.volatility_accumulator, 20);
+        assert_eq!(dynamic_fee.last_update_timestamp, 1_050);
+
+        dynamic_fee.update_volatility(1_050, -640, 0, 64);
+        assert_eq!(dynamic_fee.volatility_accumulator, 30);
+    }
+
+    #[test]
+    fn test_update_params() {
+        let mut dynamic_fee = test_dynamic_fee();
+        assert_eq!(
+            dynamic_fee.update_params(100, MAX_FEE_RATE + 1, 100),
+            Err(ErrorCode::FeeRateMaxExceeded)
+        );
+        assert_eq!(
+            dynamic_fee.update_params(100, 1_000, 0),
+            Err(ErrorCode::InvalidDynamicFeeParams)
+        );
+        dynamic_fee.update_params(50, 1_000, 600).unwrap();
+        assert_eq!(dynamic_fee.max_surcharge_rate, 1_000);
+        assert_eq!(dynamic_fee.volatility_accumulator, 20);
+    }
+}
@@ -0,0 +1,208 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ErrorCode,
+    math::{MAX_FEE_RATE, MAX_PROTOCOL_FEE_RATE},
+};
+
+use super::Whirlpool;
+
+/// Minimum number of seconds between scheduling a fee rate change and the change taking effect.
+#[cfg(not(feature = "short-fee-rate-change-delay"))]
+pub const MIN_FEE_RATE_CHANGE_DELAY: u64 = 86_400;
+#[cfg(feature = "short-fee-rate-change-delay")]
+pub const MIN_FEE_RATE_CHANGE_DELAY: u64 = 2;
+
+/// Holds a fee rate change of a Whirlpool that was scheduled by the fee authority.
+/// The change is applied by the first swap or liquidity change once it is due.
+#[account]
+#[derive(Default)]
+pub struct FeeRateSchedule {
+    pub whirlpool: Pubkey, // 32
+
+    // Stored as hundredths of a basis point
+    pub pending_fee_rate: u16, // 2
+    // Stored as basis points
+    pub pending_protocol_fee_rate: u16, // 2
+
+    // Unix timestamp from which the pending rates apply, zero if no change is pending
+    pub effective_at: u64, // 8
+}
+
+impl FeeRateSchedule {
+    pub const LEN: usize = 8 + 32 + 2 + 2 + 8;
+
+    pub fn initialize(&mut self, whirlpool: &Account<Whirlpool>) {
+        self.whirlpool = whirlpool.key();
+        self.cancel();
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.effective_at != 0
+    }
+
+    /// Schedule new fee rates for the Whirlpool. Replaces any change that is already pending.
+    ///
+    /// # Parameters
+    /// - `fee_rate` - The fee rate that applies from `effective_at`
+    /// - `protocol_fee_rate` - The protocol fee rate that applies from `effective_at`
+    /// - `effective_at` - The unix timestamp from which the new rates apply
+    /// - `timestamp` - The current unix timestamp
+    ///
+    /// # Errors
+    /// - `FeeRateMaxExceeded` - The fee rate exceeds MAX_FEE_RATE
+    /// - `ProtocolFeeRateMaxExceeded` - The protocol fee rate exceeds MAX_PROTOCOL_FEE_RATE
+    /// - `FeeRateChangeTooEarly` - `effective_at` is less than MIN_FEE_RATE_CHANGE_DELAY away
+    pub fn schedule(
+        &mut self,
+        fee_rate: u16,
+        protocol_fee_rate: u16,
+        effective_at: u64,
+        timestamp: u64,
+    ) -> Result<(), ErrorCode> {
+        if fee_rate > MAX_FEE_RATE {
+            return Err(ErrorCode::FeeRateMaxExceeded);
+        }
+        if protocol_fee_rate > MAX_PROTOCOL_FEE_RATE {
+            return Err(ErrorCode::ProtocolFeeRateMaxExceeded);
+        }
+        if effective_at < timestamp.saturating_add(MIN_FEE_RATE_CHANGE_DELAY) {
+            return Err(ErrorCode::FeeRateChangeTooEarly);
+        }
+
+        self.pending_fee_rate = fee_rate;
+        self.pending_protocol_fee_rate = protocol_fee_rate;
+        self.effective_at = effective_at;
+
+        Ok(())
+    }
+
+    /// The fee rates the Whirlpool uses once the pending change applies, or its current rates if
+    /// no change is pending.
+    ///
+    /// # Returns
+    /// - `u16`: The fee rate
+    /// - `u16`: The protocol fee rate
+    pub fn next_fee_rates(&self, whirlpool: &Whirlpool) -> (u16, u16) {
+        if self.is_pending() {
+            (self.pending_fee_rate, self.pending_protocol_fee_rate)
+        } else {
+            (whirlpool.fee_rate, whirlpool.protocol_fee_rate)
+        }
+    }
+
+    pub fn cancel(&mut self) {
+        self.pending_fee_rate = 0;
+        self.pending_protocol_fee_rate = 0;
+        self.effective_at = 0;
+    }
+
+    /// Apply the pending fee rates to the Whirlpool if they are due at `timestamp`.
+    ///
+    /// # Returns
+    /// - `true` if the pending rates were applied
+    pub fn apply(&mut self, whirlpool: &mut Whirlpool, timestamp: u64) -> Result<bool, ErrorCode> {
+        if !self.is_pending() || timestamp < self.effective_at {
+            return Ok(false);
+        }
+
+        whirlpool.update_fee_rate(self.pending_fee_rate)?;
+        whirlpool.update_protocol_fee_rate(self.pending_protocol_fee_rate)?;
+        self.cancel();
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod fee_rate_schedule_tests {
+    use super::*;
+
+    #[test]
+    fn test_schedule_and_apply() {
+        let mut schedule = FeeRateSchedule::default();
+        let mut whirlpool = Whirlpool {
+            fee_rate: 3000,
+            protocol_fee_rate: 300,
+            ..Default::default()
+        };
+
+        schedule
+            .schedule(500, 100, 1_000 + MIN_FEE_RATE_CHANGE_DELAY, 1_000)
+            .unwrap();
+        assert!(schedule.is_pending());
+
+        // Not due yet
+        assert!(!schedule
+            .apply(&mut whirlpool, MIN_FEE_RATE_CHANGE_DELAY)
+            .unwrap());
+        assert_eq!(whirlpool.fee_rate, 3000);
+
+        assert!(schedule
+            .apply(&mut whirlpool, 1_000 + MIN_FEE_RATE_CHANGE_DELAY)
+            .unwrap());
+        assert_eq!(whirlpool.fee_rate, 500);
+        assert_eq!(whirlpool.protocol_fee_rate, 100);
+        assert!(!schedule.is_pending());
+
+        // Only applied once
+        whirlpool.fee_rate = 3000;
+        assert!(!schedule.apply(&mut whirlpool, u64::MAX).unwrap());
+        assert_eq!(whirlpool.fee_rate, 3000);
+    }
+
+    #[test]
+    fn test_schedule_too_early() {
+        let mut schedule = FeeRateSchedule::default();
+        assert_eq!(
+            schedule.schedule(500, 100, 1_000 + MIN_FEE_RATE_CHANGE_DELAY - 1, 1_000),
+            Err(ErrorCode::FeeRateChangeTooEarly)
+        );
+        assert!(!schedule.is_pending());
+    }
+
+    #[test]
+    fn test_schedule_rates_exceed_max() {
+        let mut schedule = FeeRateSchedule::default();
+        assert_eq!(
+            schedule.schedule(MAX_FEE_RATE + 1, 100, MIN_FEE_RATE_CHANGE_DELAY, 0),
+            Err(ErrorCode::FeeRateMaxExceeded)
+        );
+        assert_eq!(
+            schedule.schedule(500, MAX_PROTOCOL_FEE_RATE + 1, MIN_FEE_RATE_CHANGE_DELAY, 0),
+            Err(ErrorCode::ProtocolFeeRateMaxExceeded)
+        );
+    }
+
+    #[test]
+    fn test_next_fee_rates() {
+        let mut schedule = FeeRateSchedule::default();
+        let whirlpool = Whirlpool {
+            fee_rate: 3000,
+            protocol_fee_rate: 300,
+            ..Default::default()
+        };
+        assert_eq!(schedule.next_fee_rates(&whirlpool), (3000, 300));
+
+        schedule
+            .schedule(500, 100, MIN_FEE_RATE_CHANGE_DELAY, 0)
+            .unwrap();
+        assert_eq!(schedule.next_fee_rates(&whirlpool), (500, 100));
+    }
+
+    #[test]
+    fn test_cancel() {
+        let mut schedule = FeeRateSchedule::default();
+        schedule
+            .schedule(500, 100, MIN_FEE_RATE_CHANGE_DELAY, 0)
+            .unwrap();
+        schedule.cancel();
+
+        let mut whirlpool = Whirlpool {
+            fee_rate: 3000,
+            ..Default::default()
+        };
+        assert!(!schedule.apply(&mut whirlpool, u64::MAX).unwrap());
+        assert_eq!(whirlpool.fee_rate, 3000);
+    }
+}
@@ -7,10 +7,18 @@ pub struct FeeTier {
     pub whirlpools_config: Pubkey,
     pub tick_spacing: u16,
     pub default_fee_rate: u16,
+
+    // Number of Whirlpools initialized with this fee tier, UNTRACKED_POOL_COUNT for the fee tiers
+    // created before their pools were counted
+    pub pool_count: u64,
 }
 
 impl FeeTier {
-    pub const LEN: usize = 8 + 32 + 4;
+    pub const LEN: usize = 8 + 32 + 4 + 8;
+    /// Size of the fee tiers created before their pools were counted, see `migrate_fee_tier`.
+    pub const LEGACY_LEN: usize = 8 + 32 + 4;
+    /// Pool count of the migrated legacy fee tiers, whose pools can not be counted on-chain.
+    pub const UNTRACKED_POOL_COUNT: u64 = u64::MAX;
 
     pub fn initialize(
         &mut self,
@@ -20,10 +28,15 @@ impl FeeTier {
     ) -> Result<(), ErrorCode> {
         self.whirlpools_config = whirlpools_config.key();
         self.tick_spacing = tick_spacing;
+        self.pool_count = 0;
         self.update_default_fee_rate(default_fee_rate)?;
         Ok(())
     }
 
+    pub fn increment_pool_count(&mut self) {
+        self.pool_count = self.pool_count.saturating_add(1);
+    }
+
     pub fn update_default_fee_rate(&mut self, default_fee_rate: u16) -> Result<(), ErrorCode> {
         if default_fee_rate > MAX_FEE_RATE {
             return Err(ErrorCode::FeeRateMaxExceeded.into());
@@ -33,3 +46,24 @@ impl FeeTier {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod fee_tier_tests {
+    use super::*;
+
+    #[test]
+    fn test_increment_pool_count() {
+        let mut fee_tier = FeeTier {
+            whirlpools_config: Pubkey::default(),
+            tick_spacing: 64,
+            default_fee_rate: 3000,
+            pool_count: 0,
+        };
+        fee_tier.increment_pool_count();
+        assert_eq!(fee_tier.pool_count, 1);
+
+        fee_tier.pool_count = FeeTier::UNTRACKED_POOL_COUNT;
+        fee_tier.increment_pool_count();
+        assert_eq!(fee_tier.pool_count, FeeTier::UNTRACKED_POOL_COUNT);
+    }
+}
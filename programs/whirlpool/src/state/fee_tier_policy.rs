@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+use super::WhirlpoolsConfig;
+
+pub const MAX_POLICY_TICK_SPACINGS: usize = 16;
+
+/// Restricts the fee tiers of a WhirlpoolsConfig that can be used to create new pools.
+/// Configs without this account allow fee tiers & pools of any tick spacing.
+/// Positions can only be opened on pools of allowed tick spacings, disabled fee tiers only stop
+/// new pools from being created.
+#[account]
+#[derive(Default)]
+pub struct FeeTierPolicy {
+    pub whirlpools_config: Pubkey, // 32
+
+    // Tick spacings that fee tiers can be created for, every tick spacing if empty
+    pub allowed_tick_spacings: Vec<u16>, // 4 + 2 * MAX_POLICY_TICK_SPACINGS
+    // Tick spacings of the fee tiers that can not be used to create new pools
+    pub disabled_tick_spacings: Vec<u16>, // 4 + 2 * MAX_POLICY_TICK_SPACINGS
+}
+
+impl FeeTierPolicy {
+    pub const LEN: usize = 8 + 32 + 2 * (4 + 2 * MAX_POLICY_TICK_SPACINGS);
+
+    pub fn initialize(&mut self, whirlpools_config: &Account<WhirlpoolsConfig>) {
+        self.whirlpools_config = whirlpools_config.key();
+        self.allowed_tick_spacings = Vec::new();
+        self.disabled_tick_spacings = Vec::new();
+    }
+
+    /// Replace the allowlist of tick spacings. An empty allowlist allows every tick spacing.
+    ///
+    /// # Errors
+    /// - `InvalidTickSpacing` - A tick spacing is zero
+    /// - `TooManyTickSpacings` - More than MAX_POLICY_TICK_SPACINGS tick spacings are provided
+    pub fn update_allowed_tick_spacings(
+        &mut self,
+        allowed_tick_spacings: Vec<u16>,
+    ) -> Result<(), ErrorCode> {
+        if allowed_tick_spacings.len() > MAX_POLICY_TICK_SPACINGS {
+            return Err(ErrorCode::TooManyTickSpacings);
+        }
+        if allowed_tick_spacings.contains(&0) {
+            return Err(ErrorCode::InvalidTickSpacing);
+        }
+        self.allowed_tick_spacings = allowed_tick_spacings;
+        Ok(())
+    }
+
+    /// Enable or disable the fee tier of the given tick spacing for new pools.
+    pub fn set_fee_tier_enabled(
+        &mut self,
+        tick_spacing: u16,
+        is_enabled: bool,
+    ) -> Result<(), ErrorCode> {
+        if is_enabled {
+            self.disabled_tick_spacings
+                .retain(|disabled| *disabled != tick_spacing);
+        } else if !self.disabled_tick_spacings.contains(&tick_spacing) {
+            if self.disabled_tick_spacings.len() >= MAX_POLICY_TICK_SPACINGS {
+                return Err(ErrorCode::TooManyTickSpacings);
+            }
+            self.disabled_tick_spacings.push(tick_spacing);
+        }
+        Ok(())
+    }
+
+    pub fn is_tick_spacing_allowed(&self, tick_spacing: u16) -> bool {
+        self.allowed_tick_spacings.is_empty() || self.allowed_tick_spacings.contains(&tick_spacing)
+    }
+
+    /// Check whether a new pool can be created with the fee tier of the given tick spacing.
+    ///
+    /// # Errors
+    /// - `InvalidTickSpacing` - The tick spacing is not in the allowlist
+    /// - `FeeTierDisabled` - The fee tier of the tick spacing has been disabled
+    pub fn validate_pool_tick_spacing(&self, tick_spacing: u16) -> Result<(), ErrorCode> {
+        if !self.is_tick_spacing_allowed(tick_spacing) {
+            return Err(ErrorCode::InvalidTickSpacing);
+        }
+        if self.disabled_tick_spacings.contains(&tick_spacing) {
+            return Err(ErrorCode::FeeTierDisabled);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod fee_tier_policy_tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_policy_allows_every_tick_spacing() {
+        let policy = FeeTierPolicy::default();
+        assert!(policy.is_tick_spacing_allowed(1));
+        assert!(policy.validate_pool_tick_spacing(128).is_ok());
+    }
+
+    #[test]
+    fn test_allowed_tick_spacings() {
+        let mut policy = FeeTierPolicy::default();
+        policy.update_allowed_tick_spacings(vec![8, 64]).unwrap();
+        assert!(policy.is_tick_spacing_allowed(64));
+        assert!(!policy.is_tick_spacing_allowed(128));
+        assert_eq!(
+            policy.validate_pool_tick_spacing(128),
+            Err(ErrorCode::InvalidTickSpacing)
+        );
+
+        assert_eq!(
+            policy.update_allowed_tick_spacings(vec![0]),
+            Err(ErrorCode::InvalidTickSpacing)
+        );
+        assert_eq!(
+            policy.update_allowed_tick_spacings(vec![1; MAX_POLICY_TICK_SPACINGS + 1]),
+            Err(ErrorCode::TooManyTickSpacings)
+        );
+    }
+
+    #[test]
+    fn test_disable_fee_tier() {
+        let mut policy = FeeTierPolicy::default();
+        policy.set_fee_tier_enabled(64, false).unwrap();
+        policy.set_fee_tier_enabled(64, false).unwrap();
+        assert_eq!(policy.disabled_tick_spacings, vec![64]);
+        assert_eq!(
+            policy.validate_pool_tick_spacing(64),
+            Err(ErrorCode::FeeTierDisabled)
+        );
+        assert!(policy.validate_pool_tick_spacing(8).is_ok());
+
+        policy.set_fee_tier_enabled(64, true).unwrap();
+        assert!(policy.validate_pool_tick_spacing(64).is_ok());
+    }
+
+    #[test]
+    fn test_disable_too_many_fee_tiers() {
+        let mut policy = FeeTierPolicy::default();
+        for tick_spacing in 1..=MAX_POLICY_TICK_SPACINGS as u16 {
+            policy.set_fee_tier_enabled(tick_spacing, false).unwrap();
+        }
+        assert_eq!(
+            policy.set_fee_tier_enabled(128, false),
+            Err(ErrorCode::TooManyTickSpacings)
+        );
+    }
+}
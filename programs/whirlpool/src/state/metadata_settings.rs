@@ -0,0 +1,338 @@
+use anchor_lang::prelude::*;
+use mpl_token_metadata::state::{MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH};
+
+use crate::errors::ErrorCode;
+
+use super::{Position, Whirlpool, WhirlpoolsConfig};
+
+// Placeholders of the URI template, replaced by the values of the position
+pub const URI_TEMPLATE_POSITION_MINT: &str = "{position_mint}";
+pub const URI_TEMPLATE_WHIRLPOOL: &str = "{whirlpool}";
+pub const URI_TEMPLATE_TOKEN_MINT_A: &str = "{token_mint_a}";
+pub const URI_TEMPLATE_TOKEN_MINT_B: &str = "{token_mint_b}";
+pub const URI_TEMPLATE_TICK_LOWER_INDEX: &str = "{tick_lower_index}";
+pub const URI_TEMPLATE_TICK_UPPER_INDEX: &str = "{tick_upper_index}";
+
+// Longest values of the placeholders, a base58 encoded public key and an i32
+const MAX_PUBKEY_LENGTH: usize = 44;
+const MAX_TICK_INDEX_LENGTH: usize = 11;
+
+const URI_TEMPLATE_PLACEHOLDERS: [(&str, usize); 6] = [
+    (URI_TEMPLATE_POSITION_MINT, MAX_PUBKEY_LENGTH),
+    (URI_TEMPLATE_WHIRLPOOL, MAX_PUBKEY_LENGTH),
+    (URI_TEMPLATE_TOKEN_MINT_A, MAX_PUBKEY_LENGTH),
+    (URI_TEMPLATE_TOKEN_MINT_B, MAX_PUBKEY_LENGTH),
+    (URI_TEMPLATE_TICK_LOWER_INDEX, MAX_TICK_INDEX_LENGTH),
+    (URI_TEMPLATE_TICK_UPPER_INDEX, MAX_TICK_INDEX_LENGTH),
+];
+
+/// The metadata of the position NFTs opened in a WhirlpoolsConfig.
+/// Configs without this account use the default Whirlpool position metadata.
+///
+/// The metadata settings are also the update authority of the collection NFT of the config,
+/// so that the program can verify the position NFTs as members of the collection.
+#[account]
+#[derive(Default)]
+pub struct MetadataSettings {
+    pub whirlpools_config: Pubkey,       // 32
+    pub metadata_settings_bump: [u8; 1], // 1
+    pub update_authority: Pubkey,        // 32
+    pub collection_mint: Pubkey,         // 32
+
+    pub name: String,         // 4 + MAX_NAME_LENGTH
+    pub symbol: String,       // 4 + MAX_SYMBOL_LENGTH
+    pub uri_template: String, // 4 + MAX_URI_LENGTH
+}
+
+impl MetadataSettings {
+    pub const LEN: usize =
+        8 + 32 + 1 + 32 + 32 + 4 + MAX_NAME_LENGTH + 4 + MAX_SYMBOL_LENGTH + 4 + MAX_URI_LENGTH;
+
+    pub fn seeds(&self) -> [&[u8]; 3] {
+        [
+            &b"metadata_settings"[..],
+            self.whirlpools_config.as_ref(),
+            self.metadata_settings_bump.as_ref(),
+        ]
+    }
+
+    pub fn initialize(
+        &mut self,
+        whirlpools_config: &Account<WhirlpoolsConfig>,
+        bump: u8,
+        update_authority: Pubkey,
+        name: String,
+        symbol: String,
+        uri_template: String,
+    ) -> Result<(), ErrorCode> {
+        self.whirlpools_config = whirlpools_config.key();
+        self.metadata_settings_bump = [bump];
+        self.update(update_authority, name, symbol, uri_template)
+    }
+
+    /// # Errors
+    /// - `InvalidPositionMetadata` - A field exceeds the length limit of the metadata program, or
+    ///                               the URI template can expand to a URI that exceeds it
+    pub fn update(
+        &mut self,
+        update_authority: Pubkey,
+        name: String,
+        symbol: String,
+        uri_template: String,
+    ) -> Result<(), ErrorCode> {
+        check_metadata_lengths(&name, &symbol, &uri_template)?;
+        // Positions could not be opened with metadata if the URI of some of them is too long
+        if max_expanded_uri_length(&uri_template) > MAX_URI_LENGTH {
+            return Err(ErrorCode::InvalidPositionMetadata);
+        }
+
+        self.update_authority = update_authority;
+        self.name = name;
+        self.symbol = symbol;
+        self.uri_template = uri_template;
+        Ok(())
+    }
+
+    /// The mint of the collection NFT of the positions, if the collection has been initialized.
+    pub fn collection(&self) -> Option<Pubkey> {
+        if self.collection_mint == Pubkey::default() {
+            None
+        } else {
+            Some(self.collection_mint)
+        }
+    }
+
+    pub fn set_collection_mint(&mut self, collection_mint: Pubkey) {
+        self.collection_mint = collection_mint;
+    }
+
+    /// Expand the URI template for a position of the Whirlpool.
+    ///
+    /// # Errors
+    /// - `InvalidPositionMetadata` - The expanded URI exceeds the length limit of the metadata program
+    pub fn uri(
+        &self,
+        whirlpool_key: &Pubkey,
+        whirlpool: &Whirlpool,
+        position: &Position,
+    ) -> Result<String, ErrorCode> {
+        let uri = self
+            .uri_template
+            .replace(
+                URI_TEMPLATE_POSITION_MINT,
+                &position.position_mint.to_string(),
+            )
+            .replace(URI_TEMPLATE_WHIRLPOOL, &whirlpool_key.to_string())
+            .replace(
+                URI_TEMPLATE_TOKEN_MINT_A,
+                &whirlpool.token_mint_a.to_string(),
+            )
+            .replace(
+                URI_TEMPLATE_TOKEN_MINT_B,
+                &whirlpool.token_mint_b.to_string(),
+            )
+            .replace(
+                URI_TEMPLATE_TICK_LOWER_INDEX,
+                &position.tick_lower_index.to_string(),
+            )
+            .replace(
+                URI_TEMPLATE_TICK_UPPER_INDEX,
+                &position.tick_upper_index.to_string(),
+            );
+        if uri.len() > MAX_URI_LENGTH {
+            return Err(ErrorCode::InvalidPositionMetadata);
+        }
+        Ok(uri)
+    }
+}
+
+/// The length of the longest URI that the URI template can expand to.
+fn max_expanded_uri_length(uri_template: &str) -> usize {
+    URI_TEMPLATE_PLACEHOLDERS.iter().fold(
+        uri_template.len(),
+        |length, (placeholder, max_length)| {
+            let count = uri_template.matches(placeholder).count();
+            length - count * placeholder.len() + count * max_length
+        },
+    )
+}
+
+/// # Errors
+/// - `InvalidPositionMetadata` - A field exceeds the length limit of the metadata program
+pub fn check_metadata_lengths(name: &str, symbol: &str, uri: &str) -> Result<(), ErrorCode> {
+    if name.len() > MAX_NAME_LENGTH
+        || symbol.len() > MAX_SYMBOL_LENGTH
+        || uri.len() > MAX_URI_LENGTH
+    {
+        return Err(ErrorCode::InvalidPositionMetadata);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod metadata_settings_tests {
+    use super::*;
+    use crate::state::position_builder::PositionBuilder;
+
+    #[test]
+    fn test_update() {
+        let mut settings = MetadataSettings::default();
+        let update_authority = Pubkey::new_unique();
+        settings
+            .update(
+                update_authority,
+                "RENEC Position".to_string(),
+                "RNP".to_string(),
+                "https://example.com/position.json".to_string(),
+            )
+            .unwrap();
+        assert_eq!(settings.update_authority, update_authority);
+        assert_eq!(settings.name, "RENEC Position");
+        assert_eq!(settings.symbol, "RNP");
+    }
+
+    #[test]
+    fn test_update_too_long() {
+        let mut settings = MetadataSettings::default();
+        assert_eq!(
+            settings.update(
+                Pubkey::new_unique(),
+                "a".repeat(MAX_NAME_LENGTH + 1),
+                "RNP".to_string(),
+                String::new(),
+            ),
+            Err(ErrorCode::InvalidPositionMetadata)
+        );
+        assert_eq!(
+            settings.update(
+                Pubkey::new_unique(),
+                String::new(),
+                "a".repeat(MAX_SYMBOL_LENGTH + 1),
+                String::new(),
+            ),
+            Err(ErrorCode::InvalidPositionMetadata)
+        );
+        assert_eq!(
+            settings.update(
+                Pubkey::new_unique(),
+                String::new(),
+                String::new(),
+                "a".repeat(MAX_URI_LENGTH + 1),
+            ),
+            Err(ErrorCode::InvalidPositionMetadata)
+        );
+    }
+
+    #[test]
+    fn test_update_uri_template_too_long_once_expanded() {
+        let mut settings = MetadataSettings::default();
+        let prefix = "https://nft.renec.foundation/";
+
+        // 4 public keys of up to 44 characters do not fit in the URI after the prefix
+        assert_eq!(
+            settings.update(
+                Pubkey::new_unique(),
+                String::new(),
+                String::new(),
+                format!(
+                    "{}{{position_mint}}{{whirlpool}}{{token_mint_a}}{{token_mint_b}}",
+                    prefix
+                ),
+            ),
+            Err(ErrorCode::InvalidPositionMetadata)
+        );
+
+        // Tick indexes expand to at most 11 characters
+        let uri_template = format!(
+            "{}{}{{tick_lower_index}}",
+            prefix,
+            "a".repeat(MAX_URI_LENGTH - prefix.len() - 11)
+        );
+        assert!(settings
+            .update(
+                Pubkey::new_unique(),
+                String::new(),
+                String::new(),
+                uri_template.clone()
+            )
+            .is_ok());
+        assert_eq!(
+            settings.update(
+                Pubkey::new_unique(),
+                String::new(),
+                String::new(),
+                format!("{}a", uri_template)
+            ),
+            Err(ErrorCode::InvalidPositionMetadata)
+        );
+    }
+
+    #[test]
+    fn test_collection() {
+        let mut settings = MetadataSettings::default();
+        assert_eq!(settings.collection(), None);
+
+        let collection_mint = Pubkey::new_unique();
+        settings.set_collection_mint(collection_mint);
+        assert_eq!(settings.collection(), Some(collection_mint));
+    }
+
+    #[test]
+    fn test_uri() {
+        let whirlpool_key = Pubkey::new_unique();
+        let whirlpool = Whirlpool {
+            token_mint_a: Pubkey::new_unique(),
+            token_mint_b: Pubkey::new_unique(),
+            ..Default::default()
+        };
+        let position = PositionBuilder::new(-128, 256).build();
+
+        let settings = MetadataSettings {
+            uri_template: "https://example.com/{position_mint}.json".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            settings.uri(&whirlpool_key, &whirlpool, &position).unwrap(),
+            format!("https://example.com/{}.json", position.position_mint)
+        );
+
+        let settings = MetadataSettings {
+            uri_template:
+                "{whirlpool}?a={token_mint_a}&b={token_mint_b}&l={tick_lower_index}&u={tick_upper_index}"
+                    .to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            settings.uri(&whirlpool_key, &whirlpool, &position).unwrap(),
+            format!(
+                "{}?a={}&b={}&l=-128&u=256",
+                whirlpool_key, whirlpool.token_mint_a, whirlpool.token_mint_b
+            )
+        );
+
+        let settings = MetadataSettings {
+            uri_template: "https://example.com/position.json".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            settings.uri(&whirlpool_key, &whirlpool, &position).unwrap(),
+            "https://example.com/position.json"
+        );
+    }
+
+    #[test]
+    fn test_uri_too_long() {
+        let settings = MetadataSettings {
+            uri_template: format!("{}{{position_mint}}", "a".repeat(MAX_URI_LENGTH - 20)),
+            ..Default::default()
+        };
+        assert_eq!(
+            settings.uri(
+                &Pubkey::new_unique(),
+                &Whirlpool::default(),
+                &PositionBuilder::new(0, 64).build()
+            ),
+            Err(ErrorCode::InvalidPositionMetadata)
+        );
+    }
+}
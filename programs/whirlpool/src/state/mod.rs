@@ -1,11 +1,29 @@
 pub mod config;
+pub mod dynamic_fee;
+pub mod fee_rate_schedule;
 pub mod fee_tier;
+pub mod fee_tier_policy;
+pub mod metadata_settings;
+pub mod oracle;
+pub mod pause_controls;
+pub mod pending_authorities;
+pub mod pool_creation_policy;
 pub mod position;
+pub mod range_order_book;
 pub mod tick;
 pub mod whirlpool;
 
 pub use self::whirlpool::*;
 pub use config::*;
+pub use dynamic_fee::*;
+pub use fee_rate_schedule::*;
 pub use fee_tier::*;
+pub use fee_tier_policy::*;
+pub use metadata_settings::*;
+pub use oracle::*;
+pub use pause_controls::*;
+pub use pending_authorities::*;
+pub use pool_creation_policy::*;
 pub use position::*;
+pub use range_order_book::*;
 pub use tick::*;
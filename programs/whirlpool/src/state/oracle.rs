@@ -0,0 +1,620 @@
+use anchor_lang::prelude::*;
+use std::mem;
+
+use crate::{
+    errors::ErrorCode,
+    math::{checked_mul_div, sqrt_price_from_tick_index, Q64_RESOLUTION},
+};
+
+use super::Whirlpool;
+
+// Upper bound of the ring buffer, which keeps the growth of the oracle within the size that a
+// single instruction can reallocate.
+pub const MAX_OBSERVATION_CARDINALITY: u16 = 300;
+
+/// Stores a ring buffer of price & liquidity accumulators for a Whirlpool.
+/// Observations are written at most once per timestamp by the swap instructions and are
+/// used to derive time-weighted average prices that originate from the pool itself.
+///
+/// The account is zero-copy and the observations are stored after the oracle in the account data,
+/// so that a swap only writes the current observation in place. The account starts off with room
+/// for a single observation and is reallocated as the cardinality is increased.
+#[account(zero_copy)]
+#[repr(packed)]
+#[derive(Default)]
+pub struct Oracle {
+    pub whirlpool: Pubkey, // 32
+
+    // Index of the most recently written observation
+    pub observation_index: u16, // 2
+    // Number of populated observations in the ring buffer
+    pub observation_cardinality: u16, // 2
+    // Number of observations the ring buffer will grow to once the current index wraps
+    pub observation_cardinality_next: u16, // 2
+}
+
+impl Oracle {
+    pub const LEN: usize = Oracle::space(1);
+
+    /// The size of an oracle account with room for `observation_cardinality` observations.
+    pub const fn space(observation_cardinality: u16) -> usize {
+        8 + 32 + 2 + 2 + 2 + Observation::LEN * observation_cardinality as usize
+    }
+
+    /// Split the data of an oracle account into the oracle and the observations stored after it.
+    /// The discriminator of the account has to be checked by the caller.
+    pub fn split_data(data: &[u8]) -> (&Oracle, &[Observation]) {
+        let (oracle, observations) = data[8..].split_at(mem::size_of::<Oracle>());
+        let observations_len = observations.len() / Observation::LEN * Observation::LEN;
+        (
+            bytemuck::from_bytes(oracle),
+            bytemuck::cast_slice(&observations[..observations_len]),
+        )
+    }
+
+    /// Mutable variant of `split_data`.
+    pub fn split_data_mut(data: &mut [u8]) -> (&mut Oracle, &mut [Observation]) {
+        let (oracle, observations) = data[8..].split_at_mut(mem::size_of::<Oracle>());
+        let observations_len = observations.len() / Observation::LEN * Observation::LEN;
+        (
+            bytemuck::from_bytes_mut(oracle),
+            bytemuck::cast_slice_mut(&mut observations[..observations_len]),
+        )
+    }
+
+    pub fn initialize(
+        &mut self,
+        observations: &mut [Observation],
+        whirlpool: &Account<Whirlpool>,
+        timestamp: u64,
+    ) -> Result<(), ErrorCode> {
+        self.whirlpool = whirlpool.key();
+        self.observation_index = 0;
+        self.observation_cardinality = 1;
+        self.observation_cardinality_next = 1;
+        observations[0] = Observation {
+            timestamp,
+            tick_cumulative: 0,
+            seconds_per_liquidity_cumulative_x64: 0,
+            initialized: true,
+        };
+
+        Ok(())
+    }
+
+    /// Extend the ring buffer to hold `observation_cardinality_next` observations.
+    /// The new slots are only used once the current ring buffer wraps around. The account has to
+    /// be reallocated to `Oracle::space(observation_cardinality_next)` by the caller.
+    ///
+    /// # Parameters
+    /// - `observation_cardinality_next` - The new number of observations to store
+    ///
+    /// # Errors
+    /// - `InvalidObservationCardinality` - The new cardinality does not exceed the current one
+    ///                                     or exceeds `MAX_OBSERVATION_CARDINALITY`
+    pub fn grow(&mut self, observation_cardinality_next: u16) -> Result<(), ErrorCode> {
+        if observation_cardinality_next <= self.observation_cardinality_next
+            || observation_cardinality_next > MAX_OBSERVATION_CARDINALITY
+        {
+            return Err(ErrorCode::InvalidObservationCardinality);
+        }
+
+        self.observation_cardinality_next = observation_cardinality_next;
+
+        Ok(())
+    }
+
+    /// Record a new observation using the Whirlpool values that were valid up until `timestamp`.
+    /// Only one observation is written per timestamp; subsequent calls within the same
+    /// timestamp are no-ops.
+    ///
+    /// # Parameters
+    /// - `observations` - The observations stored after the oracle
+    /// - `timestamp` - The current timestamp
+    /// - `tick_index` - The tick index of the Whirlpool before this update
+    /// - `liquidity` - The in-range liquidity of the Whirlpool before this update
+    ///
+    /// # Errors
+    /// - `InvalidTimestamp` - The timestamp is older than the last written observation
+    pub fn write(
+        &mut self,
+        observations: &mut [Observation],
+        timestamp: u64,
+        tick_index: i32,
+        liquidity: u128,
+    ) -> Result<(), ErrorCode> {
+        let last = observations[self.observation_index as usize];
+        if timestamp < last.timestamp {
+            return Err(ErrorCode::InvalidTimestamp);
+        }
+
+        if timestamp == last.timestamp {
+            return Ok(());
+        }
+
+        // Grow into the newly allocated slots only once the ring buffer wraps around,
+        // so that the ordering of the existing observations is preserved.
+        if self.observation_cardinality_next > self.observation_cardinality
+            && self.observation_index == self.observation_cardinality - 1
+        {
+            self.observation_cardinality = self.observation_cardinality_next;
+        }
+
+        self.observation_index = (self.observation_index + 1) % self.observation_cardinality;
+        observations[self.observation_index as usize] =
+            last.transform(timestamp, tick_index, liquidity);
+
+        Ok(())
+    }
+
+    /// Returns the accumulator values as of each `seconds_ago` from `timestamp`, along with the
+    /// time-weighted average tick & sqrt-price between each consecutive pair of `seconds_agos`.
+    /// Values between two stored observations are interpolated, values after the latest
+    /// observation are extrapolated with the current tick index & liquidity.
+    ///
+    /// # Parameters
+    /// - `observations` - The observations stored after the oracle
+    /// - `timestamp` - The current timestamp
+    /// - `seconds_agos` - Strictly decreasing list of look-back periods in seconds
+    /// - `tick_index` - The current tick index of the Whirlpool
+    /// - `liquidity` - The current in-range liquidity of the Whirlpool
+    ///
+    /// # Errors
+    /// - `InvalidObservationWindow` - `seconds_agos` is not strictly decreasing or reaches before the epoch
+    /// - `ObservationTooOld` - A requested timestamp is older than the oldest stored observation
+    pub fn observe(
+        &self,
+        observations: &[Observation],
+        timestamp: u64,
+        seconds_agos: &[u32],
+        tick_index: i32,
+        liquidity: u128,
+    ) -> Result<ObserveResult, ErrorCode> {
+        let mut result = ObserveResult::default();
+
+        for (i, seconds_ago) in seconds_agos.iter().enumerate() {
+            if i > 0 && seconds_agos[i - 1] <= *seconds_ago {
+                return Err(ErrorCode::InvalidObservationWindow);
+            }
+
+            let target = timestamp
+                .checked_sub(*seconds_ago as u64)
+                .ok_or(ErrorCode::InvalidObservationWindow)?;
+            let observation = self.observe_single(observations, target, tick_index, liquidity)?;
+
+            if let Some(tick_cumulative_start) = result.tick_cumulatives.last() {
+                let average_tick = time_weighted_average_tick(
+                    *tick_cumulative_start,
+                    observation.tick_cumulative,
+                    seconds_agos[i - 1] - seconds_ago,
+                );
+                result.average_ticks.push(average_tick);
+                result
+                    .average_sqrt_prices
+                    .push(sqrt_price_from_tick_index(average_tick));
+            }
+
+            result.tick_cumulatives.push(observation.tick_cumulative);
+            result
+                .seconds_per_liquidity_cumulatives_x64
+                .push(observation.seconds_per_liquidity_cumulative_x64);
+        }
+
+        Ok(result)
+    }
+
+    /// Returns the observation at `target`, interpolating between the surrounding stored
+    /// observations if necessary.
+    fn observe_single(
+        &self,
+        observations: &[Observation],
+        target: u64,
+        tick_index: i32,
+        liquidity: u128,
+    ) -> Result<Observation, ErrorCode> {
+        let last = observations[self.observation_index as usize];
+        if last.timestamp <= target {
+            return Ok(if last.timestamp == target {
+                last
+            } else {
+                last.transform(target, tick_index, liquidity)
+            });
+        }
+
+        let (before_or_at, at_or_after) =
+            self.get_surrounding_observations(observations, target)?;
+        if target == before_or_at.timestamp {
+            return Ok(before_or_at);
+        }
+        if target == at_or_after.timestamp {
+            return Ok(at_or_after);
+        }
+
+        let observation_time_delta = at_or_after.timestamp - before_or_at.timestamp;
+        let target_delta = target - before_or_at.timestamp;
+
+        // The tick index was constant between both observations, so the tick accumulator
+        // divides evenly by the elapsed time.
+        let tick_cumulative = before_or_at.tick_cumulative.wrapping_add(
+            (at_or_after
+                .tick_cumulative
+                .wrapping_sub(before_or_at.tick_cumulative)
+                / observation_time_delta as i64)
+                .wrapping_mul(target_delta as i64),
+        );
+        let seconds_per_liquidity_cumulative_x64 = before_or_at
+            .seconds_per_liquidity_cumulative_x64
+            .wrapping_add(checked_mul_div(
+                at_or_after
+                    .seconds_per_liquidity_cumulative_x64
+                    .wrapping_sub(before_or_at.seconds_per_liquidity_cumulative_x64),
+                target_delta as u128,
+                observation_time_delta as u128,
+            )?);
+
+        Ok(Observation {
+            timestamp: target,
+            tick_cumulative,
+            seconds_per_liquidity_cumulative_x64,
+            initialized: true,
+        })
+    }
+
+    /// Binary search the ring buffer for the observations surrounding `target`.
+    /// The caller has to ensure that `target` is older than the latest observation.
+    fn get_surrounding_observations(
+        &self,
+        observations: &[Observation],
+        target: u64,
+    ) -> Result<(Observation, Observation), ErrorCode> {
+        let cardinality = self.observation_cardinality as usize;
+        let oldest_index = (self.observation_index as usize + 1) % cardinality;
+
+        // Slots after the latest observation are not populated until the buffer wraps once
+        let oldest = if observations[oldest_index].initialized {
+            observations[oldest_index]
+        } else {
+            observations[0]
+        };
+        if target < oldest.timestamp {
+            return Err(ErrorCode::ObservationTooOld);
+        }
+
+        let mut left = oldest_index;
+        let mut right = oldest_index + cardinality - 1;
+        loop {
+            let i = (left + right) / 2;
+            let before_or_at = observations[i % cardinality];
+            if !before_or_at.initialized {
+                left = i + 1;
+                continue;
+            }
+
+            let at_or_after = observations[(i + 1) % cardinality];
+            let target_at_or_after = before_or_at.timestamp <= target;
+            if target_at_or_after && target <= at_or_after.timestamp {
+                return Ok((before_or_at, at_or_after));
+            }
+
+            if !target_at_or_after {
+                right = i - 1;
+            } else {
+                left = i + 1;
+            }
+        }
+    }
+}
+
+/// A snapshot of the accumulated tick & seconds-per-liquidity values of a Whirlpool.
+#[zero_copy]
+#[repr(packed)]
+#[derive(Default, Debug, PartialEq)]
+pub struct Observation {
+    pub timestamp: u64,       // 8
+    pub tick_cumulative: i64, // 8
+    // Q64.64
+    pub seconds_per_liquidity_cumulative_x64: u128, // 16
+    pub initialized: bool,                          // 1
+}
+
+impl Observation {
+    pub const LEN: usize = 33;
+
+    /// Derive the observation at `timestamp` from this observation, assuming that the
+    /// tick index & liquidity stayed constant in between.
+    ///
+    /// # Parameters
+    /// - `timestamp` - The timestamp of the new observation. Must not be older than this observation.
+    /// - `tick_index` - The tick index that was active since this observation
+    /// - `liquidity` - The in-range liquidity that was active since this observation
+    pub fn transform(&self, timestamp: u64, tick_index: i32, liquidity: u128) -> Observation {
+        let time_delta = timestamp - self.timestamp;
+
+        // Accumulators are allowed to overflow. Consumers only rely on the difference
+        // between two observations.
+        let tick_cumulative = self
+            .tick_cumulative
+            .wrapping_add((tick_index as i64).wrapping_mul(time_delta as i64));
+        let seconds_per_liquidity_cumulative_x64 = self
+            .seconds_per_liquidity_cumulative_x64
+            .wrapping_add(((time_delta as u128) << Q64_RESOLUTION) / liquidity.max(1));
+
+        Observation {
+            timestamp,
+            tick_cumulative,
+            seconds_per_liquidity_cumulative_x64,
+            initialized: true,
+        }
+    }
+}
+
+/// The accumulator values and time-weighted averages returned by `Oracle::observe`.
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Default, Debug, PartialEq)]
+pub struct ObserveResult {
+    pub tick_cumulatives: Vec<i64>,
+    // Q64.64
+    pub seconds_per_liquidity_cumulatives_x64: Vec<u128>,
+    // Averages between each consecutive pair of the requested look-back periods
+    pub average_ticks: Vec<i32>,
+    // Q64.64, geometric mean sqrt-price derived from the average tick
+    pub average_sqrt_prices: Vec<u128>,
+}
+
+/// Derive the time-weighted average tick from two tick accumulator values.
+/// The result is rounded towards negative infinity.
+///
+/// # Parameters
+/// - `tick_cumulative_start` - The tick accumulator at the start of the period
+/// - `tick_cumulative_end` - The tick accumulator at the end of the period
+/// - `seconds` - The length of the period in seconds. Must be non-zero.
+pub fn time_weighted_average_tick(
+    tick_cumulative_start: i64,
+    tick_cumulative_end: i64,
+    seconds: u32,
+) -> i32 {
+    tick_cumulative_end
+        .wrapping_sub(tick_cumulative_start)
+        .div_euclid(seconds as i64) as i32
+}
+
+#[cfg(test)]
+mod oracle_write_tests {
+    use super::*;
+
+    fn build_oracle(timestamp: u64, observation_cardinality: u16) -> (Oracle, Vec<Observation>) {
+        let oracle = Oracle {
+            whirlpool: Pubkey::new_unique(),
+            observation_index: 0,
+            observation_cardinality: 1,
+            observation_cardinality_next: observation_cardinality,
+        };
+        let mut observations = vec![Observation::default(); observation_cardinality as usize];
+        observations[0] = Observation {
+            timestamp,
+            initialized: true,
+            ..Default::default()
+        };
+        (oracle, observations)
+    }
+
+    #[test]
+    fn test_split_data() {
+        let mut data = vec![0u8; Oracle::space(3)];
+        let (oracle, observations) = Oracle::split_data_mut(&mut data);
+        assert_eq!(observations.len(), 3);
+        oracle.observation_cardinality = 3;
+        observations[2].timestamp = 42;
+
+        let (oracle, observations) = Oracle::split_data(&data);
+        assert_eq!({ oracle.observation_cardinality }, 3);
+        assert_eq!({ observations[2].timestamp }, 42);
+        assert_eq!(Oracle::LEN, 8 + 38 + Observation::LEN);
+    }
+
+    #[test]
+    fn test_transform_accumulates_tick_and_seconds_per_liquidity() {
+        let observation = Observation {
+            timestamp: 100,
+            initialized: true,
+            ..Default::default()
+        };
+        let next = observation.transform(110, -20, 5);
+        assert_eq!({ next.timestamp }, 110);
+        assert_eq!({ next.tick_cumulative }, -200);
+        assert_eq!(
+            { next.seconds_per_liquidity_cumulative_x64 },
+            (10u128 << 64) / 5
+        );
+        assert_eq!(next.initialized, true);
+    }
+
+    #[test]
+    fn test_transform_zero_liquidity() {
+        let observation = Observation::default();
+        let next = observation.transform(3, 1, 0);
+        assert_eq!({ next.seconds_per_liquidity_cumulative_x64 }, 3u128 << 64);
+    }
+
+    #[test]
+    fn test_write_same_timestamp_is_noop() {
+        let (mut oracle, mut observations) = build_oracle(100, 1);
+        oracle.write(&mut observations, 100, 50, 1000).unwrap();
+        assert_eq!({ oracle.observation_index }, 0);
+        assert_eq!({ observations[0].tick_cumulative }, 0);
+    }
+
+    #[test]
+    fn test_write_older_timestamp_fails() {
+        let (mut oracle, mut observations) = build_oracle(100, 1);
+        assert_eq!(
+            oracle.write(&mut observations, 99, 50, 1000).unwrap_err(),
+            ErrorCode::InvalidTimestamp
+        );
+    }
+
+    #[test]
+    fn test_write_overwrites_single_slot() {
+        let (mut oracle, mut observations) = build_oracle(100, 1);
+        oracle.write(&mut observations, 110, 50, 1000).unwrap();
+        assert_eq!({ oracle.observation_index }, 0);
+        assert_eq!({ observations[0].timestamp }, 110);
+        assert_eq!({ observations[0].tick_cumulative }, 500);
+    }
+
+    #[test]
+    fn test_grow() {
+        let (mut oracle, _) = build_oracle(100, 1);
+        oracle.grow(4).unwrap();
+        assert_eq!({ oracle.observation_cardinality }, 1);
+        assert_eq!({ oracle.observation_cardinality_next }, 4);
+    }
+
+    #[test]
+    fn test_grow_not_larger_fails() {
+        let (mut oracle, _) = build_oracle(100, 2);
+        assert_eq!(
+            oracle.grow(2).unwrap_err(),
+            ErrorCode::InvalidObservationCardinality
+        );
+    }
+
+    #[test]
+    fn test_grow_above_max_fails() {
+        let (mut oracle, _) = build_oracle(100, 1);
+        assert_eq!(
+            oracle.grow(MAX_OBSERVATION_CARDINALITY + 1).unwrap_err(),
+            ErrorCode::InvalidObservationCardinality
+        );
+        oracle.grow(MAX_OBSERVATION_CARDINALITY).unwrap();
+        assert_eq!(
+            { oracle.observation_cardinality_next },
+            MAX_OBSERVATION_CARDINALITY
+        );
+    }
+
+    #[test]
+    fn test_write_grows_into_next_cardinality_and_wraps() {
+        let (mut oracle, mut observations) = build_oracle(100, 3);
+        oracle.write(&mut observations, 110, 1, 1).unwrap();
+        assert_eq!({ oracle.observation_cardinality }, 3);
+        assert_eq!({ oracle.observation_index }, 1);
+        oracle.write(&mut observations, 120, 2, 1).unwrap();
+        assert_eq!({ oracle.observation_index }, 2);
+        oracle.write(&mut observations, 130, 3, 1).unwrap();
+        assert_eq!({ oracle.observation_index }, 0);
+        assert_eq!({ observations[0].timestamp }, 130);
+        assert_eq!({ observations[0].tick_cumulative }, 10 + 20 + 30);
+    }
+}
+
+#[cfg(test)]
+mod oracle_observe_tests {
+    use super::*;
+    use crate::math::tick_index_from_sqrt_price;
+
+    // Builds an oracle with observations at 100, 110, 120 written with ticks 10 & 20.
+    fn build_oracle(observation_cardinality: u16) -> (Oracle, Vec<Observation>) {
+        let mut oracle = Oracle {
+            whirlpool: Pubkey::new_unique(),
+            observation_index: 0,
+            observation_cardinality: 1,
+            observation_cardinality_next: observation_cardinality,
+        };
+        let mut observations = vec![Observation::default(); observation_cardinality as usize];
+        observations[0] = Observation {
+            timestamp: 100,
+            initialized: true,
+            ..Default::default()
+        };
+        oracle.write(&mut observations, 110, 10, 1000).unwrap();
+        oracle.write(&mut observations, 120, 20, 1000).unwrap();
+        (oracle, observations)
+    }
+
+    #[test]
+    fn test_observe_latest_and_extrapolated() {
+        let (oracle, observations) = build_oracle(3);
+        let result = oracle
+            .observe(&observations, 130, &[10, 0], -30, 1000)
+            .unwrap();
+        assert_eq!(result.tick_cumulatives, vec![300, 0]);
+        assert_eq!(result.average_ticks, vec![-30]);
+    }
+
+    #[test]
+    fn test_observe_exact_observation() {
+        let (oracle, observations) = build_oracle(3);
+        let result = oracle.observe(&observations, 120, &[10], 0, 1000).unwrap();
+        assert_eq!(result.tick_cumulatives, vec![100]);
+        assert_eq!(result.average_ticks.len(), 0);
+    }
+
+    #[test]
+    fn test_observe_interpolates() {
+        let (oracle, observations) = build_oracle(3);
+        let result = oracle
+            .observe(&observations, 120, &[15, 5], 0, 1000)
+            .unwrap();
+        assert_eq!(result.tick_cumulatives, vec![50, 200]);
+        assert_eq!(result.average_ticks, vec![15]);
+        assert_eq!(
+            result.seconds_per_liquidity_cumulatives_x64[1]
+                - result.seconds_per_liquidity_cumulatives_x64[0],
+            (10u128 << 64) / 1000
+        );
+    }
+
+    #[test]
+    fn test_observe_after_wrap() {
+        let (mut oracle, mut observations) = build_oracle(3);
+        oracle.write(&mut observations, 130, 30, 1000).unwrap();
+        assert_eq!({ oracle.observation_index }, 0);
+        let result = oracle
+            .observe(&observations, 130, &[20, 5, 0], 0, 1000)
+            .unwrap();
+        assert_eq!(result.tick_cumulatives, vec![100, 450, 600]);
+        assert_eq!(result.average_ticks, vec![23, 30]);
+    }
+
+    #[test]
+    fn test_observe_too_old() {
+        let (oracle, observations) = build_oracle(3);
+        assert_eq!(
+            oracle
+                .observe(&observations, 120, &[21], 0, 1000)
+                .unwrap_err(),
+            ErrorCode::ObservationTooOld
+        );
+    }
+
+    #[test]
+    fn test_observe_unsorted_window() {
+        let (oracle, observations) = build_oracle(3);
+        assert_eq!(
+            oracle
+                .observe(&observations, 120, &[5, 10], 0, 1000)
+                .unwrap_err(),
+            ErrorCode::InvalidObservationWindow
+        );
+    }
+
+    #[test]
+    fn test_average_sqrt_price_matches_average_tick() {
+        let (oracle, observations) = build_oracle(3);
+        let result = oracle
+            .observe(&observations, 120, &[20, 0], 0, 1000)
+            .unwrap();
+        assert_eq!(result.average_ticks, vec![15]);
+        assert_eq!(
+            tick_index_from_sqrt_price(&result.average_sqrt_prices[0]),
+            15
+        );
+    }
+
+    #[test]
+    fn test_time_weighted_average_tick_rounds_down() {
+        assert_eq!(time_weighted_average_tick(0, 7, 2), 3);
+        assert_eq!(time_weighted_average_tick(0, -7, 2), -4);
+        assert_eq!(time_weighted_average_tick(10, 10, 5), 0);
+    }
+}
@@ -0,0 +1,210 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+use super::WhirlpoolsConfig;
+
+pub const PAUSE_SWAP: u8 = 1 << 0;
+pub const PAUSE_INCREASE_LIQUIDITY: u8 = 1 << 1;
+pub const PAUSE_DECREASE_LIQUIDITY: u8 = 1 << 2;
+/// Pauses collecting both fees and rewards.
+pub const PAUSE_COLLECT: u8 = 1 << 3;
+pub const PAUSE_OPEN_POSITION: u8 = 1 << 4;
+pub const PAUSE_ALL: u8 = PAUSE_SWAP
+    | PAUSE_INCREASE_LIQUIDITY
+    | PAUSE_DECREASE_LIQUIDITY
+    | PAUSE_COLLECT
+    | PAUSE_OPEN_POSITION;
+
+pub const MAX_PAUSED_POOLS: usize = 32;
+
+#[derive(Copy, Clone, AnchorSerialize, AnchorDeserialize, Default, Debug, PartialEq)]
+pub struct PausedPool {
+    pub whirlpool: Pubkey,     // 32
+    pub paused_operations: u8, // 1
+}
+
+/// Pauses individual operations of the Whirlpools in a WhirlpoolsConfig.
+/// The operations paused on the config apply to every pool of the config, while the operations
+/// paused on a pool only apply to that pool. Configs without this account pause nothing.
+///
+/// The per-pool pauses live in this account rather than in a per-pool account, so that every
+/// pool instruction only needs a single extra account to check both.
+#[account]
+#[derive(Default)]
+pub struct PauseControls {
+    pub whirlpools_config: Pubkey, // 32
+
+    // Operations paused on every pool of the config
+    pub paused_operations: u8, // 1
+
+    pub paused_pools: Vec<PausedPool>, // 4 + 33 * MAX_PAUSED_POOLS
+}
+
+impl PauseControls {
+    pub const LEN: usize = 8 + 32 + 1 + 4 + 33 * MAX_PAUSED_POOLS;
+
+    pub fn initialize(&mut self, whirlpools_config: &Account<WhirlpoolsConfig>) {
+        self.whirlpools_config = whirlpools_config.key();
+        self.paused_operations = 0;
+        self.paused_pools = Vec::new();
+    }
+
+    /// Set the operations that are paused on every pool of the config.
+    ///
+    /// # Errors
+    /// - `InvalidPausedOperations` - `paused_operations` contains unknown operations
+    pub fn update_paused_operations(&mut self, paused_operations: u8) -> Result<(), ErrorCode> {
+        if paused_operations & !PAUSE_ALL != 0 {
+            return Err(ErrorCode::InvalidPausedOperations);
+        }
+        self.paused_operations = paused_operations;
+        Ok(())
+    }
+
+    /// Set the operations that are paused on a single pool. Pools without paused operations
+    /// are removed from the list.
+    ///
+    /// # Errors
+    /// - `InvalidPausedOperations` - `paused_operations` contains unknown operations
+    /// - `PausedPoolsFull` - MAX_PAUSED_POOLS pools already have paused operations
+    pub fn update_pool_paused_operations(
+        &mut self,
+        whirlpool: Pubkey,
+        paused_operations: u8,
+    ) -> Result<(), ErrorCode> {
+        if paused_operations & !PAUSE_ALL != 0 {
+            return Err(ErrorCode::InvalidPausedOperations);
+        }
+
+        if paused_operations == 0 {
+            self.paused_pools
+                .retain(|paused_pool| paused_pool.whirlpool != whirlpool);
+            return Ok(());
+        }
+
+        match self
+            .paused_pools
+            .iter_mut()
+            .find(|paused_pool| paused_pool.whirlpool == whirlpool)
+        {
+            Some(paused_pool) => paused_pool.paused_operations = paused_operations,
+            None => {
+                if self.paused_pools.len() >= MAX_PAUSED_POOLS {
+                    return Err(ErrorCode::PausedPoolsFull);
+                }
+                self.paused_pools.push(PausedPool {
+                    whirlpool,
+                    paused_operations,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the operations that are paused on the pool, including the ones paused on the config.
+    pub fn paused_operations_of(&self, whirlpool: &Pubkey) -> u8 {
+        self.paused_pools
+            .iter()
+            .find(|paused_pool| &paused_pool.whirlpool == whirlpool)
+            .map_or(0, |paused_pool| paused_pool.paused_operations)
+            | self.paused_operations
+    }
+
+    /// # Errors
+    /// - `OperationPaused` - The operation is paused on the pool or on the config
+    pub fn require_not_paused(&self, whirlpool: &Pubkey, operation: u8) -> Result<(), ErrorCode> {
+        if self.paused_operations_of(whirlpool) & operation != 0 {
+            return Err(ErrorCode::OperationPaused);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod pause_controls_tests {
+    use super::*;
+
+    #[test]
+    fn test_pause_pool() {
+        let mut pause_controls = PauseControls::default();
+        let whirlpool = Pubkey::new_unique();
+        let other_whirlpool = Pubkey::new_unique();
+
+        pause_controls
+            .update_pool_paused_operations(whirlpool, PAUSE_SWAP)
+            .unwrap();
+        assert_eq!(
+            pause_controls.require_not_paused(&whirlpool, PAUSE_SWAP),
+            Err(ErrorCode::OperationPaused)
+        );
+        assert!(pause_controls
+            .require_not_paused(&whirlpool, PAUSE_DECREASE_LIQUIDITY)
+            .is_ok());
+        assert!(pause_controls
+            .require_not_paused(&other_whirlpool, PAUSE_SWAP)
+            .is_ok());
+
+        pause_controls
+            .update_pool_paused_operations(whirlpool, PAUSE_SWAP | PAUSE_COLLECT)
+            .unwrap();
+        assert_eq!(pause_controls.paused_pools.len(), 1);
+        assert_eq!(
+            pause_controls.paused_operations_of(&whirlpool),
+            PAUSE_SWAP | PAUSE_COLLECT
+        );
+
+        pause_controls
+            .update_pool_paused_operations(whirlpool, 0)
+            .unwrap();
+        assert!(pause_controls.paused_pools.is_empty());
+        assert!(pause_controls
+            .require_not_paused(&whirlpool, PAUSE_SWAP)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_emergency_pause() {
+        let mut pause_controls = PauseControls::default();
+        let whirlpool = Pubkey::new_unique();
+        pause_controls
+            .update_pool_paused_operations(whirlpool, PAUSE_SWAP)
+            .unwrap();
+
+        pause_controls.update_paused_operations(PAUSE_ALL).unwrap();
+        assert_eq!(
+            pause_controls.require_not_paused(&Pubkey::new_unique(), PAUSE_OPEN_POSITION),
+            Err(ErrorCode::OperationPaused)
+        );
+
+        pause_controls.update_paused_operations(0).unwrap();
+        assert_eq!(pause_controls.paused_operations_of(&whirlpool), PAUSE_SWAP);
+    }
+
+    #[test]
+    fn test_invalid_paused_operations() {
+        let mut pause_controls = PauseControls::default();
+        assert_eq!(
+            pause_controls.update_paused_operations(PAUSE_ALL + 1),
+            Err(ErrorCode::InvalidPausedOperations)
+        );
+        assert_eq!(
+            pause_controls.update_pool_paused_operations(Pubkey::new_unique(), 1 << 7),
+            Err(ErrorCode::InvalidPausedOperations)
+        );
+    }
+
+    #[test]
+    fn test_paused_pools_full() {
+        let mut pause_controls = PauseControls::default();
+        for _ in 0..MAX_PAUSED_POOLS {
+            pause_controls
+                .update_pool_paused_operations(Pubkey::new_unique(), PAUSE_SWAP)
+                .unwrap();
+        }
+        assert_eq!(
+            pause_controls.update_pool_paused_operations(Pubkey::new_unique(), PAUSE_SWAP),
+            Err(ErrorCode::PausedPoolsFull)
+        );
+    }
+}
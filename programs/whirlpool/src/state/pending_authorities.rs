@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use super::WhirlpoolsConfig;
+
+/// Holds the authority transfers of a WhirlpoolsConfig that are waiting to be accepted by
+/// the new authority. A transfer only takes effect once the new authority signs for it, so a
+/// mistyped key can not take over the config.
+/// `Pubkey::default()` marks an authority without a pending transfer.
+#[account]
+#[derive(Default)]
+pub struct PendingAuthorities {
+    pub whirlpools_config: Pubkey, // 32
+
+    pub fee_authority: Pubkey,                    // 32
+    pub collect_protocol_fees_authority: Pubkey,  // 32
+    pub reward_emissions_super_authority: Pubkey, // 32
+    pub pool_creator_authority: Pubkey,           // 32
+}
+
+impl PendingAuthorities {
+    pub const LEN: usize = 8 + 32 + 128;
+
+    pub fn initialize(&mut self, whirlpools_config: &Account<WhirlpoolsConfig>) {
+        self.whirlpools_config = whirlpools_config.key();
+        self.fee_authority = Pubkey::default();
+        self.collect_protocol_fees_authority = Pubkey::default();
+        self.reward_emissions_super_authority = Pubkey::default();
+        self.pool_creator_authority = Pubkey::default();
+    }
+}
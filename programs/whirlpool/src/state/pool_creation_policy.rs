@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+use super::WhirlpoolsConfig;
+
+/// Anyone can create pools in the config.
+pub const POOL_CREATION_PERMISSIONLESS: u8 = 0;
+/// Only the pool creator authority can create pools in the config.
+pub const POOL_CREATION_CREATOR_ONLY: u8 = 1;
+/// Anyone can create pools whose token mints are both allowlisted.
+pub const POOL_CREATION_ALLOWLISTED_MINTS: u8 = 2;
+
+pub const MAX_ALLOWED_MINTS: usize = 32;
+
+/// Restricts who can create pools in a WhirlpoolsConfig.
+/// Configs without this account allow anyone to create pools.
+/// The pool creator authority can always create pools, regardless of the mode.
+#[account]
+#[derive(Default)]
+pub struct PoolCreationPolicy {
+    pub whirlpools_config: Pubkey,  // 32
+    pub mode: u8,                   // 1
+    pub allowed_mints: Vec<Pubkey>, // 4 + 32 * MAX_ALLOWED_MINTS
+}
+
+impl PoolCreationPolicy {
+    pub const LEN: usize = 8 + 32 + 1 + 4 + 32 * MAX_ALLOWED_MINTS;
+
+    pub fn initialize(
+        &mut self,
+        whirlpools_config: &Account<WhirlpoolsConfig>,
+        mode: u8,
+    ) -> Result<(), ErrorCode> {
+        self.whirlpools_config = whirlpools_config.key();
+        self.allowed_mints = Vec::new();
+        self.update_mode(mode)
+    }
+
+    pub fn update_mode(&mut self, mode: u8) -> Result<(), ErrorCode> {
+        if mode > POOL_CREATION_ALLOWLISTED_MINTS {
+            return Err(ErrorCode::InvalidPoolCreationMode);
+        }
+        self.mode = mode;
+        Ok(())
+    }
+
+    /// Add a mint to the allowlist. Mints that are already allowed are ignored.
+    pub fn add_allowed_mint(&mut self, mint: Pubkey) -> Result<(), ErrorCode> {
+        if self.is_allowed_mint(&mint) {
+            return Ok(());
+        }
+        if self.allowed_mints.len() >= MAX_ALLOWED_MINTS {
+            return Err(ErrorCode::AllowedMintsFull);
+        }
+        self.allowed_mints.push(mint);
+        Ok(())
+    }
+
+    pub fn remove_allowed_mint(&mut self, mint: &Pubkey) {
+        self.allowed_mints
+            .retain(|allowed_mint| allowed_mint != mint);
+    }
+
+    pub fn is_allowed_mint(&self, mint: &Pubkey) -> bool {
+        self.allowed_mints.contains(mint)
+    }
+
+    /// Check whether `creator` may create a pool of the given token mints.
+    ///
+    /// # Errors
+    /// - `PoolCreationNotAllowed` - The mode of the policy does not allow the pool
+    pub fn validate_pool_creation(
+        &self,
+        creator: &Pubkey,
+        pool_creator_authority: &Pubkey,
+        token_mint_a: &Pubkey,
+        token_mint_b: &Pubkey,
+    ) -> Result<(), ErrorCode> {
+        if creator == pool_creator_authority {
+            return Ok(());
+        }
+
+        let allowed = match self.mode {
+            POOL_CREATION_PERMISSIONLESS => true,
+            POOL_CREATION_ALLOWLISTED_MINTS => {
+                self.is_allowed_mint(token_mint_a) && self.is_allowed_mint(token_mint_b)
+            }
+            _ => false,
+        };
+        if !allowed {
+            return Err(ErrorCode::PoolCreationNotAllowed);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod pool_creation_policy_tests {
+    use super::*;
+
+    fn test_policy(mode: u8) -> PoolCreationPolicy {
+        PoolCreationPolicy {
+            mode,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_creator_only() {
+        let creator = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let mint_a = Pubkey::new_unique();
+        let mint_b = Pubkey::new_unique();
+        let policy = test_policy(POOL_CREATION_CREATOR_ONLY);
+
+        assert_eq!(
+            policy.validate_pool_creation(&creator, &authority, &mint_a, &mint_b),
+            Err(ErrorCode::PoolCreationNotAllowed)
+        );
+        assert!(policy
+            .validate_pool_creation(&authority, &authority, &mint_a, &mint_b)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_allowlisted_mints() {
+        let creator = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let mint_a = Pubkey::new_unique();
+        let mint_b = Pubkey::new_unique();
+        let mut policy = test_policy(POOL_CREATION_ALLOWLISTED_MINTS);
+
+        policy.add_allowed_mint(mint_a).unwrap();
+        assert_eq!(
+            policy.validate_pool_creation(&creator, &authority, &mint_a, &mint_b),
+            Err(ErrorCode::PoolCreationNotAllowed)
+        );
+
+        policy.add_allowed_mint(mint_b).unwrap();
+        assert!(policy
+            .validate_pool_creation(&creator, &authority, &mint_a, &mint_b)
+            .is_ok());
+
+        policy.remove_allowed_mint(&mint_a);
+        assert_eq!(
+            policy.validate_pool_creation(&creator, &authority, &mint_a, &mint_b),
+            Err(ErrorCode::PoolCreationNotAllowed)
+        );
+    }
+
+    #[test]
+    fn test_permissionless() {
+        let policy = test_policy(POOL_CREATION_PERMISSIONLESS);
+        assert!(policy
+            .validate_pool_creation(
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique()
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn test_add_allowed_mint() {
+        let mut policy = test_policy(POOL_CREATION_ALLOWLISTED_MINTS);
+        let mint = Pubkey::new_unique();
+        policy.add_allowed_mint(mint).unwrap();
+        policy.add_allowed_mint(mint).unwrap();
+        assert_eq!(policy.allowed_mints.len(), 1);
+
+        for _ in 1..MAX_ALLOWED_MINTS {
+            policy.add_allowed_mint(Pubkey::new_unique()).unwrap();
+        }
+        assert_eq!(
+            policy.add_allowed_mint(Pubkey::new_unique()),
+            Err(ErrorCode::AllowedMintsFull)
+        );
+    }
+
+    #[test]
+    fn test_invalid_mode() {
+        let mut policy = test_policy(POOL_CREATION_PERMISSIONLESS);
+        assert_eq!(
+            policy.update_mode(POOL_CREATION_ALLOWLISTED_MINTS + 1),
+            Err(ErrorCode::InvalidPoolCreationMode)
+        );
+    }
+}
@@ -62,12 +62,7 @@ impl Position {
         tick_lower_index: i32,
         tick_upper_index: i32,
     ) -> Result<(), ErrorCode> {
-        if !Tick::check_is_usable_tick(tick_lower_index, whirlpool.tick_spacing)
-            || !Tick::check_is_usable_tick(tick_upper_index, whirlpool.tick_spacing)
-            || tick_lower_index >= tick_upper_index
-        {
-            return Err(ErrorCode::InvalidTickIndex.into());
-        }
+        check_tick_range(tick_lower_index, tick_upper_index, whirlpool.tick_spacing)?;
 
         self.whirlpool = whirlpool.key();
         self.position_mint = position_mint;
@@ -77,6 +72,24 @@ impl Position {
         Ok(())
     }
 
+    /// Move the position to a new tick range. The position has to hold no liquidity, so that
+    /// the fee and reward checkpoints are reset to the new range once liquidity is added.
+    pub fn reset_position_range(
+        &mut self,
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+        tick_spacing: u16,
+    ) -> Result<(), ErrorCode> {
+        if self.liquidity != 0 {
+            return Err(ErrorCode::PositionLiquidityNonZero);
+        }
+        check_tick_range(tick_lower_index, tick_upper_index, tick_spacing)?;
+
+        self.tick_lower_index = tick_lower_index;
+        self.tick_upper_index = tick_upper_index;
+        Ok(())
+    }
+
     pub fn reset_fees_owed(&mut self) {
         self.fee_owed_a = 0;
         self.fee_owed_b = 0;
@@ -87,6 +100,20 @@ impl Position {
     }
 }
 
+fn check_tick_range(
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+    tick_spacing: u16,
+) -> Result<(), ErrorCode> {
+    if !Tick::check_is_usable_tick(tick_lower_index, tick_spacing)
+        || !Tick::check_is_usable_tick(tick_upper_index, tick_spacing)
+        || tick_lower_index >= tick_upper_index
+    {
+        return Err(ErrorCode::InvalidTickIndex.into());
+    }
+    Ok(())
+}
+
 #[derive(Copy, Clone, AnchorSerialize, AnchorDeserialize, Default, Debug, PartialEq)]
 pub struct PositionRewardInfo {
     // Q64.64
@@ -187,6 +214,44 @@ mod is_position_empty_tests {
     }
 }
 
+#[cfg(test)]
+mod reset_position_range_tests {
+    use super::position_builder::PositionBuilder;
+    use crate::errors::ErrorCode;
+
+    #[test]
+    fn test_reset_position_range() {
+        let mut position = PositionBuilder::new(-64, 64).fee_owed_a(100).build();
+        position.reset_position_range(128, 256, 64).unwrap();
+        assert_eq!(position.tick_lower_index, 128);
+        assert_eq!(position.tick_upper_index, 256);
+        assert_eq!(position.fee_owed_a, 100);
+    }
+
+    #[test]
+    fn test_reset_position_range_with_liquidity() {
+        let mut position = PositionBuilder::new(-64, 64).liquidity(100).build();
+        assert_eq!(
+            position.reset_position_range(128, 256, 64),
+            Err(ErrorCode::PositionLiquidityNonZero)
+        );
+        assert_eq!(position.tick_lower_index, -64);
+    }
+
+    #[test]
+    fn test_reset_position_range_invalid_ticks() {
+        let mut position = PositionBuilder::new(-64, 64).build();
+        assert_eq!(
+            position.reset_position_range(128, 200, 64),
+            Err(ErrorCode::InvalidTickIndex)
+        );
+        assert_eq!(
+            position.reset_position_range(256, 128, 64),
+            Err(ErrorCode::InvalidTickIndex)
+        );
+    }
+}
+
 #[cfg(test)]
 pub mod position_builder {
     use anchor_lang::prelude::Pubkey;
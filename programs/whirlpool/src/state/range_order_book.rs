@@ -0,0 +1,319 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+use super::{Position, Whirlpool, NUM_REWARDS};
+
+pub const MAX_RANGE_ORDERS: usize = 32;
+
+// Orders hold on to a slot of the book until they are withdrawn, so dust orders are rejected
+// to keep the book from being filled by them.
+pub const MIN_RANGE_ORDER_LIQUIDITY: u128 = 1_000_000;
+
+/// A position spanning a single tick-spacing that is taken out of the pool once the price
+/// fully crosses it, so that it holds only the token it was converted to.
+#[derive(Copy, Clone, AnchorSerialize, AnchorDeserialize, Default, Debug, PartialEq)]
+pub struct RangeOrder {
+    pub position: Pubkey,      // 32
+    pub owner: Pubkey,         // 32
+    pub tick_lower_index: i32, // 4
+    pub tick_upper_index: i32, // 4
+    pub liquidity: u128,       // 16
+
+    // The order was placed above the price and converts token A into token B.
+    // Otherwise it was placed below the price and converts token B into token A.
+    pub sells_token_a: bool, // 1
+    pub filled: bool,        // 1
+
+    // Growths inside the range when the order was filled, the position stops earning after them
+    // Q64.64
+    pub fee_growth_inside_a: u128, // 16
+    // Q64.64
+    pub fee_growth_inside_b: u128, // 16
+    // Q64.64
+    pub reward_growths_inside: [u128; NUM_REWARDS], // 48
+}
+
+impl RangeOrder {
+    pub const LEN: usize = 32 + 32 + 4 + 4 + 16 + 1 + 1 + 16 + 16 + 48;
+
+    /// Returns true if the order is pending and the price has moved past its range.
+    pub fn is_crossed(&self, tick_current_index: i32) -> bool {
+        if self.filled {
+            return false;
+        }
+
+        if self.sells_token_a {
+            tick_current_index >= self.tick_upper_index
+        } else {
+            tick_current_index < self.tick_lower_index
+        }
+    }
+}
+
+/// Holds the range orders of a Whirlpool, which are filled by the swaps that cross them.
+/// The position tokens of the orders are held in escrow until they are withdrawn.
+#[account]
+#[derive(Default)]
+pub struct RangeOrderBook {
+    pub whirlpool: Pubkey, // 32
+
+    pub range_orders: Vec<RangeOrder>, // 4 + RangeOrder::LEN * MAX_RANGE_ORDERS
+}
+
+impl RangeOrderBook {
+    pub const LEN: usize = 8 + 32 + 4 + RangeOrder::LEN * MAX_RANGE_ORDERS;
+
+    pub fn initialize(&mut self, whirlpool: &Account<Whirlpool>) {
+        self.whirlpool = whirlpool.key();
+        self.range_orders = Vec::new();
+    }
+
+    /// Place a range order for all of the liquidity of the position.
+    ///
+    /// # Parameters
+    /// - `position_key` - The key of the position
+    /// - `position` - The position, which has to span exactly one tick-spacing
+    /// - `owner` - The account that is allowed to withdraw the order
+    /// - `tick_spacing` - The tick spacing of the Whirlpool
+    /// - `tick_current_index` - The current tick index of the Whirlpool
+    ///
+    /// # Errors
+    /// - `InvalidRangeOrderTickRange` - The position does not span exactly one tick-spacing
+    /// - `LiquidityZero` - The position has no liquidity
+    /// - `RangeOrderLiquidityTooLow` - The position has less than MIN_RANGE_ORDER_LIQUIDITY
+    /// - `RangeOrderInRange` - The current price is inside the range of the position
+    /// - `RangeOrderBookFull` - MAX_RANGE_ORDERS orders are already placed
+    pub fn place(
+        &mut self,
+        position_key: Pubkey,
+        position: &Position,
+        owner: Pubkey,
+        tick_spacing: u16,
+        tick_current_index: i32,
+    ) -> Result<(), ErrorCode> {
+        if position.tick_upper_index - position.tick_lower_index != tick_spacing as i32 {
+            return Err(ErrorCode::InvalidRangeOrderTickRange);
+        }
+        if position.liquidity == 0 {
+            return Err(ErrorCode::LiquidityZero);
+        }
+        if position.liquidity < MIN_RANGE_ORDER_LIQUIDITY {
+            return Err(ErrorCode::RangeOrderLiquidityTooLow);
+        }
+
+        let sells_token_a = if tick_current_index < position.tick_lower_index {
+            true
+        } else if tick_current_index >= position.tick_upper_index {
+            false
+        } else {
+            return Err(ErrorCode::RangeOrderInRange);
+        };
+
+        if self.range_orders.len() >= MAX_RANGE_ORDERS {
+            return Err(ErrorCode::RangeOrderBookFull);
+        }
+
+        self.range_orders.push(RangeOrder {
+            position: position_key,
+            owner,
+            tick_lower_index: position.tick_lower_index,
+            tick_upper_index: position.tick_upper_index,
+            liquidity: position.liquidity,
+            sells_token_a,
+            ..Default::default()
+        });
+
+        Ok(())
+    }
+
+    pub fn contains(&self, position: &Pubkey) -> bool {
+        self.range_orders
+            .iter()
+            .any(|range_order| &range_order.position == position)
+    }
+
+    /// Remove the range order of the position.
+    ///
+    /// # Errors
+    /// - `RangeOrderNotFound` - The position has no range order placed by `owner`
+    pub fn remove(&mut self, position: &Pubkey, owner: &Pubkey) -> Result<RangeOrder, ErrorCode> {
+        let index = self
+            .range_orders
+            .iter()
+            .position(|range_order| {
+                &range_order.position == position && &range_order.owner == owner
+            })
+            .ok_or(ErrorCode::RangeOrderNotFound)?;
+
+        Ok(self.range_orders.remove(index))
+    }
+}
+
+#[cfg(test)]
+mod range_order_book_tests {
+    use super::*;
+    use crate::state::position_builder::PositionBuilder;
+
+    #[test]
+    fn test_place_above_and_below_price() {
+        let mut book = RangeOrderBook::default();
+        let owner = Pubkey::new_unique();
+        let above = Pubkey::new_unique();
+        let below = Pubkey::new_unique();
+
+        book.place(
+            above,
+            &PositionBuilder::new(128, 192)
+                .liquidity(MIN_RANGE_ORDER_LIQUIDITY)
+                .build(),
+            owner,
+            64,
+            127,
+        )
+        .unwrap();
+        book.place(
+            below,
+            &PositionBuilder::new(-64, 0)
+                .liquidity(MIN_RANGE_ORDER_LIQUIDITY)
+                .build(),
+            owner,
+            64,
+            0,
+        )
+        .unwrap();
+
+        assert!(book.range_orders[0].sells_token_a);
+        assert!(!book.range_orders[1].sells_token_a);
+        assert_eq!(book.range_orders[1].liquidity, MIN_RANGE_ORDER_LIQUIDITY);
+        assert!(book.contains(&above));
+        assert!(book.contains(&below));
+    }
+
+    #[test]
+    fn test_place_invalid_orders() {
+        let mut book = RangeOrderBook::default();
+        let owner = Pubkey::new_unique();
+
+        assert_eq!(
+            book.place(
+                Pubkey::new_unique(),
+                &PositionBuilder::new(128, 256)
+                    .liquidity(MIN_RANGE_ORDER_LIQUIDITY)
+                    .build(),
+                owner,
+                64,
+                0,
+            ),
+            Err(ErrorCode::InvalidRangeOrderTickRange)
+        );
+        assert_eq!(
+            book.place(
+                Pubkey::new_unique(),
+                &PositionBuilder::new(128, 192).build(),
+                owner,
+                64,
+                0,
+            ),
+            Err(ErrorCode::LiquidityZero)
+        );
+        assert_eq!(
+            book.place(
+                Pubkey::new_unique(),
+                &PositionBuilder::new(128, 192)
+                    .liquidity(MIN_RANGE_ORDER_LIQUIDITY - 1)
+                    .build(),
+                owner,
+                64,
+                0,
+            ),
+            Err(ErrorCode::RangeOrderLiquidityTooLow)
+        );
+        assert_eq!(
+            book.place(
+                Pubkey::new_unique(),
+                &PositionBuilder::new(128, 192)
+                    .liquidity(MIN_RANGE_ORDER_LIQUIDITY)
+                    .build(),
+                owner,
+                64,
+                128,
+            ),
+            Err(ErrorCode::RangeOrderInRange)
+        );
+        assert!(book.range_orders.is_empty());
+    }
+
+    #[test]
+    fn test_place_book_full() {
+        let mut book = RangeOrderBook::default();
+        let owner = Pubkey::new_unique();
+        let position = PositionBuilder::new(128, 192)
+            .liquidity(MIN_RANGE_ORDER_LIQUIDITY)
+            .build();
+
+        for _ in 0..MAX_RANGE_ORDERS {
+            book.place(Pubkey::new_unique(), &position, owner, 64, 0)
+                .unwrap();
+        }
+        assert_eq!(
+            book.place(Pubkey::new_unique(), &position, owner, 64, 0),
+            Err(ErrorCode::RangeOrderBookFull)
+        );
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut book = RangeOrderBook::default();
+        let owner = Pubkey::new_unique();
+        let position = Pubkey::new_unique();
+        book.place(
+            position,
+            &PositionBuilder::new(128, 192)
+                .liquidity(MIN_RANGE_ORDER_LIQUIDITY)
+                .build(),
+            owner,
+            64,
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(
+            book.remove(&position, &Pubkey::new_unique()),
+            Err(ErrorCode::RangeOrderNotFound)
+        );
+
+        let range_order = book.remove(&position, &owner).unwrap();
+        assert_eq!(range_order.position, position);
+        assert!(!book.contains(&position));
+        assert_eq!(
+            book.remove(&position, &owner),
+            Err(ErrorCode::RangeOrderNotFound)
+        );
+    }
+
+    #[test]
+    fn test_is_crossed() {
+        let range_order = RangeOrder {
+            tick_lower_index: 128,
+            tick_upper_index: 192,
+            sells_token_a: true,
+            ..Default::default()
+        };
+        assert!(!range_order.is_crossed(191));
+        assert!(range_order.is_crossed(192));
+
+        let range_order = RangeOrder {
+            sells_token_a: false,
+            ..range_order
+        };
+        assert!(!range_order.is_crossed(128));
+        assert!(range_order.is_crossed(127));
+
+        let range_order = RangeOrder {
+            filled: true,
+            ..range_order
+        };
+        assert!(!range_order.is_crossed(0));
+    }
+}
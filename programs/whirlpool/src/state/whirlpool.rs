@@ -60,8 +60,18 @@ pub struct Whirlpool {
 // Number of rewards supported by Whirlpools
 pub const NUM_REWARDS: usize = 3;
 
+// Extensions of a Whirlpool. The side account of an enabled extension has to be passed to the
+// pool instructions that use it, in remaining_accounts and in the order of these flags.
+pub const EXTENSION_ORACLE: u8 = 1 << 0;
+pub const EXTENSION_DYNAMIC_FEE: u8 = 1 << 1;
+pub const EXTENSION_FEE_RATE_SCHEDULE: u8 = 1 << 2;
+pub const EXTENSION_RANGE_ORDER_BOOK: u8 = 1 << 3;
+
 impl Whirlpool {
     pub const LEN: usize = 1 + 8 + 261 + 384;
+    // The enabled extensions are stored in a byte after the serialized Whirlpool, so that pools
+    // created before extensions existed keep their layout. Pools without it have no extensions.
+    pub const LEN_WITH_EXTENSIONS: usize = Whirlpool::LEN + 1;
     pub fn seeds(&self) -> [&[u8]; 6] {
         [
             &b"whirlpool"[..],
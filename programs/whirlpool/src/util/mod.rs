@@ -1,12 +1,18 @@
 pub mod swap_tick_sequence;
 pub mod swap_utils;
+pub mod tick_array_provider;
 pub mod token;
+pub mod token_2022;
 pub mod util;
+pub mod whirlpool_extensions;
 
 pub use swap_tick_sequence::*;
 pub use swap_utils::*;
+pub use tick_array_provider::*;
 pub use token::*;
+pub use token_2022::*;
 pub use util::*;
+pub use whirlpool_extensions::*;
 
 #[cfg(test)]
 pub mod test_utils;
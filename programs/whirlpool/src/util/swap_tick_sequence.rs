@@ -1,12 +1,13 @@
 use crate::errors::ErrorCode;
 use crate::state::*;
-use std::cell::RefMut;
+use crate::util::TickArrayProvider;
+use std::cell::{Ref, RefMut};
 
-pub struct SwapTickSequence<'info> {
-    arrays: Vec<RefMut<'info, TickArray>>,
+pub struct SwapTickSequence<P: TickArrayProvider> {
+    arrays: P,
 }
 
-impl<'info> SwapTickSequence<'info> {
+impl<'info> SwapTickSequence<Vec<RefMut<'info, TickArray>>> {
     pub fn new(
         ta0: RefMut<'info, TickArray>,
         ta1: Option<RefMut<'info, TickArray>>,
@@ -20,7 +21,35 @@ impl<'info> SwapTickSequence<'info> {
         if ta2.is_some() {
             vec.push(ta2.unwrap());
         }
-        Self { arrays: vec }
+        Self::from_provider(vec)
+    }
+}
+
+impl<'info> SwapTickSequence<Vec<Ref<'info, TickArray>>> {
+    /// Create a sequence over read-only tick arrays. The updates of the crossed ticks are
+    /// discarded, so the sequence can only be used to quote a swap.
+    pub fn new_read_only(
+        ta0: Ref<'info, TickArray>,
+        ta1: Option<Ref<'info, TickArray>>,
+        ta2: Option<Ref<'info, TickArray>>,
+    ) -> Self {
+        let mut vec = Vec::with_capacity(3);
+        vec.push(ta0);
+        vec.extend(ta1);
+        vec.extend(ta2);
+        Self::from_provider(vec)
+    }
+}
+
+impl<P: TickArrayProvider> SwapTickSequence<P> {
+    /// Create a sequence over any source of tick arrays, such as owned arrays in a simulation.
+    pub fn from_provider(arrays: P) -> Self {
+        Self { arrays }
+    }
+
+    /// Consume the sequence and return its tick arrays, including the ticks updated by a swap.
+    pub fn into_provider(self) -> P {
+        self.arrays
     }
 
     /// Get the Tick object at the given tick-index & tick-spacing
@@ -40,7 +69,7 @@ impl<'info> SwapTickSequence<'info> {
         tick_index: i32,
         tick_spacing: u16,
     ) -> Result<&Tick, ErrorCode> {
-        let array = self.arrays.get(array_index);
+        let array = self.arrays.tick_array(array_index);
         match array {
             Some(array) => array.get_tick(tick_index, tick_spacing),
             _ => Err(ErrorCode::TickArrayIndexOutofBounds),
@@ -65,14 +94,21 @@ impl<'info> SwapTickSequence<'info> {
         tick_spacing: u16,
         update: &TickUpdate,
     ) -> Result<(), ErrorCode> {
-        let array = self.arrays.get_mut(array_index);
-        match array {
-            Some(array) => {
-                array.update_tick(tick_index, tick_spacing, update)?;
-                Ok(())
-            }
-            _ => Err(ErrorCode::TickArrayIndexOutofBounds),
-        }
+        self.arrays
+            .update_tick(array_index, tick_index, tick_spacing, update)
+    }
+
+    /// Find the array index of the tick array in this sequence that contains the given tick-index
+    ///
+    /// # Returns
+    /// - `Some(usize)`: The array index of the tick array containing the tick-index
+    /// - `None`: None of the tick arrays in this sequence contain the tick-index
+    pub fn find_array_index(&self, tick_index: i32, tick_spacing: u16) -> Option<usize> {
+        (0..self.arrays.tick_array_count()).find(|array_index| {
+            self.arrays.tick_array(*array_index).map_or(false, |array| {
+                array.check_in_array_bounds(tick_index, tick_spacing)
+            })
+        })
     }
 
     pub fn get_tick_offset(
@@ -81,7 +117,7 @@ impl<'info> SwapTickSequence<'info> {
         tick_index: i32,
         tick_spacing: u16,
     ) -> Result<isize, ErrorCode> {
-        let array = self.arrays.get(array_index);
+        let array = self.arrays.tick_array(array_index);
         match array {
             Some(array) => array.tick_offset(tick_index, tick_spacing),
             _ => Err(ErrorCode::TickArrayIndexOutofBounds),
@@ -116,7 +152,7 @@ impl<'info> SwapTickSequence<'info> {
         // Keep looping the arrays until an initialized tick index in the subsequent tick-arrays found.
         loop {
             // If we get to the end of the array sequence and next_index is still not found, throw error
-            let next_array = match self.arrays.get(array_index) {
+            let next_array = match self.arrays.tick_array(array_index) {
                 Some(array) => array,
                 None => return Err(ErrorCode::TickArraySequenceInvalidIndex),
             };
@@ -137,7 +173,7 @@ impl<'info> SwapTickSequence<'info> {
                     }
 
                     // If we are at the last tick array in the sequencer, return the last tick
-                    if array_index + 1 == self.arrays.len() {
+                    if array_index + 1 == self.arrays.tick_array_count() {
                         if a_to_b {
                             return Ok((array_index, next_array.start_tick_index));
                         } else {
@@ -732,4 +768,74 @@ mod swap_tick_sequence_tests {
             curr_array_index = array_index;
         }
     }
+
+    mod owned_provider {
+        use super::*;
+
+        #[test]
+        fn search_and_update_owned_tick_arrays() {
+            let ta0 = build_tick_array(0, vec![10]).into_inner();
+            let ta1 = build_tick_array(-704, vec![80]).into_inner();
+            let mut swap_tick_sequence = SwapTickSequence::from_provider(vec![ta0, ta1]);
+
+            let (array_index, index) = swap_tick_sequence
+                .get_next_initialized_tick_index(100, TS_8, true, 0)
+                .unwrap();
+            assert_eq!((array_index, index), (0, 80));
+
+            let (array_index, index) = swap_tick_sequence
+                .get_next_initialized_tick_index(79, TS_8, true, 0)
+                .unwrap();
+            assert_eq!((array_index, index), (1, -64));
+
+            let update = TickUpdate {
+                initialized: true,
+                liquidity_net: 10,
+                ..Default::default()
+            };
+            swap_tick_sequence
+                .update_tick(1, -64, TS_8, &update)
+                .unwrap();
+
+            let arrays = swap_tick_sequence.into_provider();
+            assert_eq!({ arrays[1].ticks[80].liquidity_net }, 10);
+        }
+    }
+
+    mod read_only_provider {
+        use super::*;
+
+        #[test]
+        fn update_read_only_tick_arrays_is_discarded() {
+            let ta0 = build_tick_array(0, vec![10]);
+            let ta1 = build_tick_array(-704, vec![80]);
+            let mut swap_tick_sequence =
+                SwapTickSequence::new_read_only(ta0.borrow(), Some(ta1.borrow()), None);
+
+            let update = TickUpdate {
+                initialized: true,
+                liquidity_net: 10,
+                ..Default::default()
+            };
+            swap_tick_sequence
+                .update_tick(1, -64, TS_8, &update)
+                .unwrap();
+            let tick = swap_tick_sequence.get_tick(1, -64, TS_8).unwrap();
+            assert_eq!({ tick.liquidity_net }, 0);
+            drop(swap_tick_sequence);
+            assert_eq!({ ta1.borrow().ticks[80].liquidity_net }, 0);
+        }
+
+        #[test]
+        fn update_read_only_uninitializable_tick() {
+            let ta0 = build_tick_array(0, vec![10]);
+            let mut swap_tick_sequence = SwapTickSequence::new_read_only(ta0.borrow(), None, None);
+
+            let result = swap_tick_sequence.update_tick(0, 3, TS_8, &TickUpdate::default());
+            assert_eq!(result.unwrap_err(), ErrorCode::TickNotFound);
+
+            let result = swap_tick_sequence.update_tick(1, 0, TS_8, &TickUpdate::default());
+            assert_eq!(result.unwrap_err(), ErrorCode::TickArrayIndexOutofBounds);
+        }
+    }
 }
@@ -1,11 +1,23 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{TokenAccount, Token};
+use std::cell::Ref;
 
 use crate::{
-    manager::swap_manager::PostSwapUpdate, state::Whirlpool
+    errors::ErrorCode,
+    events::{RangeOrderFilled, Swapped},
+    manager::{
+        range_order_manager::fill_range_orders,
+        swap_manager::{swap, PostSwapUpdate},
+    },
+    state::{DynamicFee, Oracle, RangeOrderBook, TickArray, Whirlpool},
 };
 
-use super::{transfer_from_owner_to_vault, transfer_from_vault_to_owner};
+use super::{
+    calculate_transfer_fee_excluded_amount, calculate_transfer_fee_included_amount,
+    initialized_side_account, transfer_from_owner_to_vault, transfer_from_owner_to_vault_v2,
+    transfer_from_vault_to_owner, transfer_from_vault_to_owner_v2, SwapTickSequence,
+    TickArrayProvider, TransferFee,
+};
 
 pub fn update_and_swap_whirlpool<'info>(
     whirlpool: &mut Account<'info, Whirlpool>,
@@ -15,20 +27,20 @@ pub fn update_and_swap_whirlpool<'info>(
     token_vault_a: &Account<'info, TokenAccount>,
     token_vault_b: &Account<'info, TokenAccount>,
     token_program: &Program<'info, Token>,
+    oracle: Option<&AccountInfo<'info>>,
+    dynamic_fee: Option<&AccountInfo<'info>>,
     swap_update: PostSwapUpdate,
     is_token_fee_in_a: bool,
     reward_last_updated_timestamp: u64,
 ) -> ProgramResult {
-    whirlpool.update_after_swap(
-        swap_update.next_liquidity,
-        swap_update.next_tick_index,
-        swap_update.next_sqrt_price,
-        swap_update.next_fee_growth_global,
-        swap_update.next_reward_infos,
-        swap_update.next_protocol_fee,
+    update_whirlpool_after_swap(
+        whirlpool,
+        oracle,
+        dynamic_fee,
+        &swap_update,
         is_token_fee_in_a,
         reward_last_updated_timestamp,
-    );
+    )?;
 
     perform_swap(
         whirlpool,
@@ -41,7 +53,242 @@ pub fn update_and_swap_whirlpool<'info>(
         swap_update.amount_a,
         swap_update.amount_b,
         is_token_fee_in_a,
-    )
+    )?;
+
+    emit_swapped(whirlpool, &swap_update, is_token_fee_in_a);
+    Ok(())
+}
+
+/// Same as `update_and_swap_whirlpool` for Whirlpools whose mints may belong to the Token-2022
+/// program. The amounts of the swap update are the amounts transferred, including the transfer
+/// fees (see `swap_with_transfer_fee`).
+pub fn update_and_swap_whirlpool_v2<'info>(
+    whirlpool: &mut Account<'info, Whirlpool>,
+    token_authority: &Signer<'info>,
+    token_mint_a: &AccountInfo<'info>,
+    token_mint_b: &AccountInfo<'info>,
+    token_owner_account_a: &AccountInfo<'info>,
+    token_owner_account_b: &AccountInfo<'info>,
+    token_vault_a: &AccountInfo<'info>,
+    token_vault_b: &AccountInfo<'info>,
+    token_program_a: &AccountInfo<'info>,
+    token_program_b: &AccountInfo<'info>,
+    oracle: Option<&AccountInfo<'info>>,
+    dynamic_fee: Option<&AccountInfo<'info>>,
+    swap_update: PostSwapUpdate,
+    is_token_fee_in_a: bool,
+    reward_last_updated_timestamp: u64,
+) -> ProgramResult {
+    update_whirlpool_after_swap(
+        whirlpool,
+        oracle,
+        dynamic_fee,
+        &swap_update,
+        is_token_fee_in_a,
+        reward_last_updated_timestamp,
+    )?;
+
+    if is_token_fee_in_a {
+        transfer_from_owner_to_vault_v2(
+            token_authority,
+            token_mint_a,
+            token_owner_account_a,
+            token_vault_a,
+            token_program_a,
+            swap_update.amount_a,
+        )?;
+        transfer_from_vault_to_owner_v2(
+            whirlpool,
+            token_mint_b,
+            token_vault_b,
+            token_owner_account_b,
+            token_program_b,
+            swap_update.amount_b,
+        )?;
+    } else {
+        transfer_from_owner_to_vault_v2(
+            token_authority,
+            token_mint_b,
+            token_owner_account_b,
+            token_vault_b,
+            token_program_b,
+            swap_update.amount_b,
+        )?;
+        transfer_from_vault_to_owner_v2(
+            whirlpool,
+            token_mint_a,
+            token_vault_a,
+            token_owner_account_a,
+            token_program_a,
+            swap_update.amount_a,
+        )?;
+    }
+
+    emit_swapped(whirlpool, &swap_update, is_token_fee_in_a);
+    Ok(())
+}
+
+fn update_whirlpool_after_swap<'info>(
+    whirlpool: &mut Account<'info, Whirlpool>,
+    oracle: Option<&AccountInfo<'info>>,
+    dynamic_fee: Option<&AccountInfo<'info>>,
+    swap_update: &PostSwapUpdate,
+    is_token_fee_in_a: bool,
+    reward_last_updated_timestamp: u64,
+) -> ProgramResult {
+    // The observation has to be taken from the pool state that was valid before this swap.
+    update_oracle(whirlpool, oracle, reward_last_updated_timestamp)?;
+    update_dynamic_fee(
+        whirlpool,
+        dynamic_fee,
+        swap_update.next_tick_index,
+        reward_last_updated_timestamp,
+    )?;
+
+    whirlpool.update_after_swap(
+        swap_update.next_liquidity,
+        swap_update.next_tick_index,
+        swap_update.next_sqrt_price,
+        swap_update.next_fee_growth_global,
+        swap_update.next_reward_infos,
+        swap_update.next_protocol_fee,
+        is_token_fee_in_a,
+        reward_last_updated_timestamp,
+    );
+    Ok(())
+}
+
+fn emit_swapped(whirlpool: &Account<Whirlpool>, swap_update: &PostSwapUpdate, a_to_b: bool) {
+    emit!(Swapped {
+        whirlpool: whirlpool.key(),
+        a_to_b,
+        amount_a: swap_update.amount_a,
+        amount_b: swap_update.amount_b,
+        fee_amount: swap_update.fee_amount,
+        protocol_fee_amount: swap_update.next_protocol_fee,
+        sqrt_price: whirlpool.sqrt_price,
+        tick_current_index: whirlpool.tick_current_index,
+    });
+}
+
+/// Compute a swap of a Whirlpool whose mints may charge a transfer fee.
+///
+/// An exact input `amount` is what the user sends: the pool swaps what is left of it once the
+/// transfer fee has been withheld. An exact output `amount` is what the user receives: the pool
+/// swaps it with the transfer fee added. The amounts of the returned update are the amounts to
+/// transfer, the input including its transfer fee and the output before its transfer fee, which
+/// is withheld from what the user receives.
+pub fn swap_with_transfer_fee<P: TickArrayProvider>(
+    whirlpool: &Whirlpool,
+    dynamic_fee: Option<&DynamicFee>,
+    swap_tick_sequence: &mut SwapTickSequence<P>,
+    transfer_fee_a: Option<&TransferFee>,
+    transfer_fee_b: Option<&TransferFee>,
+    amount: u64,
+    sqrt_price_limit: u128,
+    amount_specified_is_input: bool,
+    a_to_b: bool,
+    timestamp: u64,
+) -> Result<PostSwapUpdate, ErrorCode> {
+    let (transfer_fee_in, transfer_fee_out) = if a_to_b {
+        (transfer_fee_a, transfer_fee_b)
+    } else {
+        (transfer_fee_b, transfer_fee_a)
+    };
+
+    let swap_amount = if amount_specified_is_input {
+        calculate_transfer_fee_excluded_amount(transfer_fee_in, amount)?
+    } else {
+        calculate_transfer_fee_included_amount(transfer_fee_out, amount)?
+    };
+
+    let mut swap_update = swap(
+        whirlpool,
+        dynamic_fee,
+        swap_tick_sequence,
+        swap_amount,
+        sqrt_price_limit,
+        amount_specified_is_input,
+        a_to_b,
+        timestamp,
+    )?;
+
+    let amount_in = if a_to_b {
+        swap_update.amount_a
+    } else {
+        swap_update.amount_b
+    };
+    // The whole input is sent when it is consumed, the fee of the smallest amount leaving
+    // `swap_amount` may be lower than the fee that was deducted from `amount`.
+    let transfer_amount_in = if amount_specified_is_input && amount_in == swap_amount {
+        amount
+    } else {
+        calculate_transfer_fee_included_amount(transfer_fee_in, amount_in)?
+    };
+
+    if a_to_b {
+        swap_update.amount_a = transfer_amount_in;
+    } else {
+        swap_update.amount_b = transfer_amount_in;
+    }
+    Ok(swap_update)
+}
+
+/// Record an observation in the Whirlpool's oracle from the pool state valid up until now.
+/// Pools that have not initialized their oracle yet are skipped.
+pub fn update_oracle<'info>(
+    whirlpool: &Account<'info, Whirlpool>,
+    oracle: Option<&AccountInfo<'info>>,
+    timestamp: u64,
+) -> ProgramResult {
+    let oracle = match initialized_side_account(oracle) {
+        Some(oracle) => oracle,
+        None => return Ok(()),
+    };
+
+    // Only the current observation is written in place, the ring buffer is never deserialized
+    AccountLoader::<Oracle>::try_from(oracle)?;
+    let mut data = oracle.try_borrow_mut_data()?;
+    let (oracle, observations) = Oracle::split_data_mut(&mut data);
+    oracle.write(
+        observations,
+        timestamp,
+        whirlpool.tick_current_index,
+        whirlpool.liquidity,
+    )?;
+    Ok(())
+}
+
+/// Load the dynamic fee account of a Whirlpool.
+/// Returns `None` for pools that have not initialized a dynamic fee, which only charge their fee rate.
+pub fn load_dynamic_fee<'info>(
+    dynamic_fee: Option<&AccountInfo<'info>>,
+) -> Result<Option<Account<'info, DynamicFee>>, ProgramError> {
+    match initialized_side_account(dynamic_fee) {
+        Some(dynamic_fee) => Ok(Some(Account::try_from(dynamic_fee)?)),
+        None => Ok(None),
+    }
+}
+
+/// Accumulate the tick movement of a swap into the Whirlpool's dynamic fee.
+fn update_dynamic_fee<'info>(
+    whirlpool: &Account<'info, Whirlpool>,
+    dynamic_fee: Option<&AccountInfo<'info>>,
+    next_tick_index: i32,
+    timestamp: u64,
+) -> ProgramResult {
+    let mut dynamic_fee = match load_dynamic_fee(dynamic_fee)? {
+        Some(dynamic_fee) => dynamic_fee,
+        None => return Ok(()),
+    };
+
+    dynamic_fee.update_volatility(
+        timestamp,
+        whirlpool.tick_current_index,
+        next_tick_index,
+        whirlpool.tick_spacing,
+    );
+    dynamic_fee.exit(&crate::ID)
 }
 
 fn perform_swap<'info>(
@@ -102,3 +349,183 @@ fn perform_swap<'info>(
 
   Ok(())
 }
+
+/// Load the range order book of a Whirlpool.
+/// Returns `None` for pools that have not initialized a range order book.
+pub fn load_range_order_book<'info>(
+    range_order_book: Option<&AccountInfo<'info>>,
+) -> Result<Option<Account<'info, RangeOrderBook>>, ProgramError> {
+    match initialized_side_account(range_order_book) {
+        Some(range_order_book) => Ok(Some(Account::try_from(range_order_book)?)),
+        None => Ok(None),
+    }
+}
+
+/// Fill the range orders of a Whirlpool that were crossed by the swap that was just applied.
+/// Pools that have not initialized a range order book are skipped.
+pub fn update_range_order_book<'info, P: TickArrayProvider>(
+    whirlpool: &Account<'info, Whirlpool>,
+    range_order_book: Option<&AccountInfo<'info>>,
+    swap_tick_sequence: &mut SwapTickSequence<P>,
+) -> ProgramResult {
+    let mut range_order_book = match load_range_order_book(range_order_book)? {
+        Some(range_order_book) => range_order_book,
+        None => return Ok(()),
+    };
+
+    let filled_positions = fill_range_orders(whirlpool, &mut range_order_book, swap_tick_sequence)?;
+    if filled_positions.is_empty() {
+        return Ok(());
+    }
+
+    for position in filled_positions {
+        emit!(RangeOrderFilled {
+            whirlpool: whirlpool.key(),
+            position,
+        });
+    }
+    range_order_book.exit(&crate::ID)
+}
+
+/// Load the tick arrays of a swap as a read-only sequence to quote the swap.
+/// A tick array passed more than once is only used once, like in a swap where loading it
+/// mutably a second time fails.
+pub fn load_read_only_tick_sequence<'a, 'info>(
+    tick_array_0: &'a AccountLoader<'info, TickArray>,
+    tick_array_1: &'a AccountLoader<'info, TickArray>,
+    tick_array_2: &'a AccountLoader<'info, TickArray>,
+) -> Result<SwapTickSequence<Vec<Ref<'a, TickArray>>>, ProgramError> {
+    let use_tick_array_1 = tick_array_1.key() != tick_array_0.key();
+    let use_tick_array_2 =
+        tick_array_2.key() != tick_array_0.key() && tick_array_2.key() != tick_array_1.key();
+
+    Ok(SwapTickSequence::new_read_only(
+        tick_array_0.load()?,
+        tick_array_1.load().ok().filter(|_| use_tick_array_1),
+        tick_array_2.load().ok().filter(|_| use_tick_array_2),
+    ))
+}
+
+#[cfg(test)]
+mod swap_with_transfer_fee_tests {
+    use super::*;
+    use crate::math::tick_math::sqrt_price_from_tick_index;
+    use crate::util::test_utils::swap_test_fixture::*;
+    use crate::util::MAX_TRANSFER_FEE_BASIS_POINTS;
+
+    const TRANSFER_FEE: TransferFee = TransferFee {
+        maximum_fee: u64::MAX,
+        transfer_fee_basis_points: 100,
+    };
+
+    fn fixture(trade_amount: u64, sqrt_price_limit: u128, is_input: bool) -> SwapTestFixture {
+        SwapTestFixture::new(SwapTestFixtureInfo {
+            tick_spacing: TS_128,
+            liquidity: 100_000_000_000,
+            curr_tick_index: 1000,
+            start_tick_index: 0,
+            trade_amount,
+            sqrt_price_limit,
+            amount_specified_is_input: is_input,
+            a_to_b: true,
+            fee_rate: 3000,
+            ..Default::default()
+        })
+    }
+
+    fn swap_fixture(
+        swap_test_info: &SwapTestFixture,
+        transfer_fee_a: Option<&TransferFee>,
+        transfer_fee_b: Option<&TransferFee>,
+    ) -> Result<PostSwapUpdate, ErrorCode> {
+        let mut tick_sequence = SwapTickSequence::new(
+            swap_test_info.tick_arrays[0].borrow_mut(),
+            Some(swap_test_info.tick_arrays[1].borrow_mut()),
+            Some(swap_test_info.tick_arrays[2].borrow_mut()),
+        );
+        swap_with_transfer_fee(
+            &swap_test_info.whirlpool,
+            None,
+            &mut tick_sequence,
+            transfer_fee_a,
+            transfer_fee_b,
+            swap_test_info.trade_amount,
+            swap_test_info.sqrt_price_limit,
+            swap_test_info.amount_specified_is_input,
+            swap_test_info.a_to_b,
+            100,
+        )
+    }
+
+    #[test]
+    fn test_without_transfer_fee_matches_swap() {
+        let swap_test_info = fixture(1_000_000, sqrt_price_from_tick_index(0), true);
+        let expected = swap_test_info.eval(
+            &mut SwapTickSequence::new(
+                swap_test_info.tick_arrays[0].borrow_mut(),
+                Some(swap_test_info.tick_arrays[1].borrow_mut()),
+                Some(swap_test_info.tick_arrays[2].borrow_mut()),
+            ),
+            100,
+        );
+        let post_swap = swap_fixture(&swap_test_info, None, None);
+        let (expected, post_swap) = (expected.unwrap(), post_swap.unwrap());
+        assert_eq!(post_swap.amount_a, expected.amount_a);
+        assert_eq!(post_swap.amount_b, expected.amount_b);
+        assert_eq!(post_swap.next_sqrt_price, expected.next_sqrt_price);
+    }
+
+    #[test]
+    fn test_exact_input_swaps_amount_net_of_transfer_fee() {
+        let swap_test_info = fixture(1_000_000, sqrt_price_from_tick_index(0), true);
+        let post_swap = swap_fixture(&swap_test_info, Some(&TRANSFER_FEE), None).unwrap();
+        let net_swap_info = fixture(990_000, sqrt_price_from_tick_index(0), true);
+        let net_swap = swap_fixture(&net_swap_info, None, None).unwrap();
+
+        assert_eq!(post_swap.amount_a, 1_000_000);
+        assert_eq!(post_swap.amount_b, net_swap.amount_b);
+        assert_eq!(post_swap.next_sqrt_price, net_swap.next_sqrt_price);
+    }
+
+    #[test]
+    fn test_exact_input_partial_fill_includes_transfer_fee_of_consumed_amount() {
+        let swap_test_info = fixture(u32::MAX.into(), sqrt_price_from_tick_index(900), true);
+        let post_swap = swap_fixture(&swap_test_info, Some(&TRANSFER_FEE), None).unwrap();
+        let net_swap = swap_fixture(&swap_test_info, None, None).unwrap();
+
+        assert!(post_swap.amount_a < u32::MAX.into());
+        assert_eq!(
+            calculate_transfer_fee_excluded_amount(Some(&TRANSFER_FEE), post_swap.amount_a),
+            Ok(net_swap.amount_a)
+        );
+        assert_eq!(post_swap.amount_b, net_swap.amount_b);
+    }
+
+    #[test]
+    fn test_exact_output_swaps_amount_including_transfer_fee() {
+        let swap_test_info = fixture(1_000_000, sqrt_price_from_tick_index(0), false);
+        let post_swap =
+            swap_fixture(&swap_test_info, Some(&TRANSFER_FEE), Some(&TRANSFER_FEE)).unwrap();
+        let gross_swap_info = fixture(post_swap.amount_b, sqrt_price_from_tick_index(0), false);
+        let gross_swap = swap_fixture(&gross_swap_info, None, None).unwrap();
+
+        assert_eq!(
+            calculate_transfer_fee_excluded_amount(Some(&TRANSFER_FEE), post_swap.amount_b),
+            Ok(1_000_000)
+        );
+        assert_eq!(
+            calculate_transfer_fee_excluded_amount(Some(&TRANSFER_FEE), post_swap.amount_a),
+            Ok(gross_swap.amount_a)
+        );
+    }
+
+    #[test]
+    fn test_transfer_fee_of_the_whole_input_fails() {
+        let transfer_fee = TransferFee {
+            maximum_fee: u64::MAX,
+            transfer_fee_basis_points: MAX_TRANSFER_FEE_BASIS_POINTS,
+        };
+        let swap_test_info = fixture(1_000_000, sqrt_price_from_tick_index(0), true);
+        assert!(swap_fixture(&swap_test_info, Some(&transfer_fee), None).is_err());
+    }
+}
@@ -5,7 +5,7 @@ use crate::state::{
     tick::*, tick_builder::TickBuilder, whirlpool_builder::WhirlpoolBuilder, TickArray, Whirlpool,
 };
 use crate::state::{WhirlpoolRewardInfo, NUM_REWARDS};
-use crate::util::SwapTickSequence;
+use crate::util::{SwapTickSequence, TickArrayProvider};
 use anchor_lang::prelude::*;
 use std::cell::RefCell;
 
@@ -205,9 +205,14 @@ impl SwapTestFixture {
         }
     }
 
-    pub fn run(&self, tick_sequence: &mut SwapTickSequence, next_timestamp: u64) -> PostSwapUpdate {
+    pub fn run<P: TickArrayProvider>(
+        &self,
+        tick_sequence: &mut SwapTickSequence<P>,
+        next_timestamp: u64,
+    ) -> PostSwapUpdate {
         swap(
             &self.whirlpool,
+            None,
             tick_sequence,
             self.trade_amount,
             self.sqrt_price_limit,
@@ -218,13 +223,14 @@ impl SwapTestFixture {
         .unwrap()
     }
 
-    pub fn eval(
+    pub fn eval<P: TickArrayProvider>(
         &self,
-        tick_sequence: &mut SwapTickSequence,
+        tick_sequence: &mut SwapTickSequence<P>,
         next_timestamp: u64,
     ) -> Result<PostSwapUpdate, ErrorCode> {
         swap(
             &self.whirlpool,
+            None,
             tick_sequence,
             self.trade_amount,
             self.sqrt_price_limit,
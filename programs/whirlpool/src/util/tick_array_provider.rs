@@ -0,0 +1,252 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use std::cell::{Ref, RefMut};
+use std::collections::BTreeMap;
+
+/// A source of the tick arrays traversed by a swap, indexed in the order of traversal.
+///
+/// On-chain the arrays come from `AccountLoader::load_mut`, while off-chain users (quotes,
+/// simulators, fuzzers) can provide owned arrays without wrapping them in `RefCell`s.
+pub trait TickArrayProvider {
+    /// Number of tick arrays in the sequence
+    fn tick_array_count(&self) -> usize;
+
+    /// Get the tick array at the given position of the sequence
+    fn tick_array(&self, array_index: usize) -> Option<&TickArray>;
+
+    /// Get the tick array at the given position of the sequence for modification
+    fn tick_array_mut(&mut self, array_index: usize) -> Option<&mut TickArray>;
+
+    /// Apply the update of a tick crossed by a swap
+    fn update_tick(
+        &mut self,
+        array_index: usize,
+        tick_index: i32,
+        tick_spacing: u16,
+        update: &TickUpdate,
+    ) -> Result<(), ErrorCode> {
+        match self.tick_array_mut(array_index) {
+            Some(array) => array.update_tick(tick_index, tick_spacing, update),
+            None => Err(ErrorCode::TickArrayIndexOutofBounds),
+        }
+    }
+
+    /// Whether the tick array at the given position of the sequence stands in for an account
+    /// that was not provided, so a swap reaching it would fail on-chain
+    fn is_synthesized(&self, _array_index: usize) -> bool {
+        false
+    }
+}
+
+impl<'info> TickArrayProvider for Vec<RefMut<'info, TickArray>> {
+    fn tick_array_count(&self) -> usize {
+        self.len()
+    }
+
+    fn tick_array(&self, array_index: usize) -> Option<&TickArray> {
+        self.get(array_index).map(|array| &**array)
+    }
+
+    fn tick_array_mut(&mut self, array_index: usize) -> Option<&mut TickArray> {
+        self.get_mut(array_index).map(|array| &mut **array)
+    }
+}
+
+/// Tick arrays loaded from read-only accounts, used to quote a swap without persisting it.
+/// The arrays cannot be modified, so the updates of the crossed ticks are discarded.
+impl<'info> TickArrayProvider for Vec<Ref<'info, TickArray>> {
+    fn tick_array_count(&self) -> usize {
+        self.len()
+    }
+
+    fn tick_array(&self, array_index: usize) -> Option<&TickArray> {
+        self.get(array_index).map(|array| &**array)
+    }
+
+    fn tick_array_mut(&mut self, _array_index: usize) -> Option<&mut TickArray> {
+        None
+    }
+
+    fn update_tick(
+        &mut self,
+        array_index: usize,
+        tick_index: i32,
+        tick_spacing: u16,
+        _update: &TickUpdate,
+    ) -> Result<(), ErrorCode> {
+        match self.tick_array(array_index) {
+            Some(array) => array.get_tick(tick_index, tick_spacing).map(|_| ()),
+            None => Err(ErrorCode::TickArrayIndexOutofBounds),
+        }
+    }
+}
+
+impl TickArrayProvider for Vec<TickArray> {
+    fn tick_array_count(&self) -> usize {
+        self.len()
+    }
+
+    fn tick_array(&self, array_index: usize) -> Option<&TickArray> {
+        self.get(array_index)
+    }
+
+    fn tick_array_mut(&mut self, array_index: usize) -> Option<&mut TickArray> {
+        self.get_mut(array_index)
+    }
+}
+
+/// Tick arrays keyed by their start tick index.
+///
+/// The sequence starts at the tick array containing the current tick of the Whirlpool and
+/// moves in the direction of the swap. Arrays of the sequence missing from the map are
+/// synthesized without initialized ticks. They are reported by `is_synthesized`, as the
+/// swap instruction cannot be given an account for them.
+pub struct SparseTickArrays {
+    tick_arrays: BTreeMap<i32, TickArray>,
+    start_tick_indexes: Vec<i32>,
+    synthesized: Vec<bool>,
+}
+
+impl SparseTickArrays {
+    /// # Parameters
+    /// - `tick_arrays` - The known tick arrays keyed by their start tick index
+    /// - `tick_current_index` - The current tick index of the Whirlpool
+    /// - `tick_spacing` - The tick spacing of the Whirlpool
+    /// - `a_to_b` - The direction of the swap
+    /// - `max_tick_array_count` - The maximum number of tick arrays in the sequence
+    pub fn new(
+        mut tick_arrays: BTreeMap<i32, TickArray>,
+        tick_current_index: i32,
+        tick_spacing: u16,
+        a_to_b: bool,
+        max_tick_array_count: usize,
+    ) -> Self {
+        let ticks_in_array = TICK_ARRAY_SIZE * tick_spacing as i32;
+        // b_to_a swaps search from the next usable tick, which can be in the next array
+        let search_tick_index = if a_to_b {
+            tick_current_index
+        } else {
+            tick_current_index + tick_spacing as i32
+        };
+        let mut start_tick_index = search_tick_index.div_euclid(ticks_in_array) * ticks_in_array;
+
+        let mut start_tick_indexes = Vec::with_capacity(max_tick_array_count);
+        let mut synthesized = Vec::with_capacity(max_tick_array_count);
+        while start_tick_indexes.len() < max_tick_array_count {
+            synthesized.push(!tick_arrays.contains_key(&start_tick_index));
+            let tick_array = tick_arrays
+                .entry(start_tick_index)
+                .or_insert_with(|| TickArray {
+                    start_tick_index,
+                    ..Default::default()
+                });
+            start_tick_indexes.push(start_tick_index);
+
+            if (a_to_b && tick_array.is_min_tick_array())
+                || (!a_to_b && tick_array.is_max_tick_array(tick_spacing))
+            {
+                break;
+            }
+            start_tick_index = if a_to_b {
+                start_tick_index - ticks_in_array
+            } else {
+                start_tick_index + ticks_in_array
+            };
+        }
+
+        Self {
+            tick_arrays,
+            start_tick_indexes,
+            synthesized,
+        }
+    }
+
+    /// Consume the sequence and return the tick arrays, including the updates of a swap.
+    pub fn into_tick_arrays(self) -> BTreeMap<i32, TickArray> {
+        self.tick_arrays
+    }
+}
+
+impl TickArrayProvider for SparseTickArrays {
+    fn tick_array_count(&self) -> usize {
+        self.start_tick_indexes.len()
+    }
+
+    fn tick_array(&self, array_index: usize) -> Option<&TickArray> {
+        let start_tick_index = self.start_tick_indexes.get(array_index)?;
+        self.tick_arrays.get(start_tick_index)
+    }
+
+    fn tick_array_mut(&mut self, array_index: usize) -> Option<&mut TickArray> {
+        let start_tick_index = self.start_tick_indexes.get(array_index)?;
+        self.tick_arrays.get_mut(start_tick_index)
+    }
+
+    fn is_synthesized(&self, array_index: usize) -> bool {
+        self.synthesized.get(array_index).copied().unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod sparse_tick_arrays_tests {
+    use super::*;
+
+    const TS_64: u16 = 64;
+    const TICKS_IN_ARRAY: i32 = TICK_ARRAY_SIZE * TS_64 as i32;
+
+    fn start_tick_indexes(sparse: &SparseTickArrays) -> Vec<i32> {
+        (0..sparse.tick_array_count())
+            .map(|i| sparse.tick_array(i).unwrap().start_tick_index)
+            .collect()
+    }
+
+    #[test]
+    fn test_sequence_a_to_b() {
+        let sparse = SparseTickArrays::new(BTreeMap::new(), 100, TS_64, true, 3);
+        assert_eq!(
+            start_tick_indexes(&sparse),
+            vec![0, -TICKS_IN_ARRAY, -2 * TICKS_IN_ARRAY]
+        );
+    }
+
+    #[test]
+    fn test_sequence_b_to_a_shifted() {
+        // The next usable tick of a b_to_a swap is in the next tick array
+        let sparse = SparseTickArrays::new(BTreeMap::new(), -1, TS_64, false, 2);
+        assert_eq!(start_tick_indexes(&sparse), vec![0, TICKS_IN_ARRAY]);
+    }
+
+    #[test]
+    fn test_sequence_stops_at_min_tick_array() {
+        let sparse = SparseTickArrays::new(BTreeMap::new(), MIN_TICK_INDEX, TS_64, true, 3);
+        assert_eq!(sparse.tick_array_count(), 1);
+        assert!(sparse.tick_array(0).unwrap().is_min_tick_array());
+    }
+
+    #[test]
+    fn test_provided_arrays_are_used() {
+        let mut tick_array = TickArray {
+            start_tick_index: -TICKS_IN_ARRAY,
+            ..Default::default()
+        };
+        tick_array.ticks[5].initialized = true;
+        let mut tick_arrays = BTreeMap::new();
+        tick_arrays.insert(-TICKS_IN_ARRAY, tick_array);
+
+        let mut sparse = SparseTickArrays::new(tick_arrays, 0, TS_64, true, 3);
+        assert!(sparse.tick_array(1).unwrap().ticks[5].initialized);
+        assert!(!sparse.tick_array(0).unwrap().ticks[5].initialized);
+        assert!(!sparse.is_synthesized(1));
+        assert!(sparse.is_synthesized(0) && sparse.is_synthesized(2));
+
+        sparse.tick_array_mut(0).unwrap().ticks[0].initialized = true;
+        assert!(sparse.into_tick_arrays()[&0].ticks[0].initialized);
+    }
+
+    #[test]
+    fn test_out_of_sequence_index() {
+        let mut sparse = SparseTickArrays::new(BTreeMap::new(), 0, TS_64, true, 1);
+        assert!(sparse.tick_array(1).is_none());
+        assert!(sparse.tick_array_mut(1).is_none());
+    }
+}
@@ -1,9 +1,21 @@
-use crate::state::Whirlpool;
+use crate::errors::ErrorCode;
+use crate::state::{MetadataSettings, Position, Whirlpool};
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
-use mpl_token_metadata::instruction::create_metadata_accounts_v2;
-use solana_program::program::invoke_signed;
-use spl_token::instruction::{burn_checked, close_account, mint_to, set_authority, AuthorityType};
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+use mpl_token_metadata::instruction::{
+    create_master_edition_v3, create_metadata_accounts_v2, update_metadata_accounts_v2,
+    verify_collection,
+};
+use mpl_token_metadata::state::{Collection, DataV2, Metadata};
+use solana_program::instruction::Instruction;
+use solana_program::program::{get_return_data, invoke, invoke_signed, set_return_data};
+use solana_program::system_instruction;
+use spl_token::instruction::{
+    burn_checked, close_account, initialize_account2, mint_to, set_authority, sync_native,
+    transfer_checked, AuthorityType,
+};
+
+use super::{load_mint, token_vault_len};
 
 pub fn transfer_from_owner_to_vault<'info>(
     position_authority: &Signer<'info>,
@@ -46,6 +58,216 @@ pub fn transfer_from_vault_to_owner<'info>(
     )
 }
 
+/// Transfer tokens of the token program or the Token-2022 program from the owner to a vault with
+/// `transfer_checked`. The transfer fee of a Token-2022 mint is withheld from the amount that
+/// arrives in the vault.
+pub fn transfer_from_owner_to_vault_v2<'info>(
+    authority: &Signer<'info>,
+    token_mint: &AccountInfo<'info>,
+    token_owner_account: &AccountInfo<'info>,
+    token_vault: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+) -> ProgramResult {
+    invoke(
+        &transfer_checked_ix(
+            token_program.key,
+            token_owner_account.key,
+            token_mint,
+            token_vault.key,
+            authority.key,
+            amount,
+        )?,
+        &[
+            token_owner_account.clone(),
+            token_mint.clone(),
+            token_vault.clone(),
+            authority.to_account_info(),
+            token_program.clone(),
+        ],
+    )
+}
+
+/// Transfer tokens of the token program or the Token-2022 program from a vault to the owner with
+/// `transfer_checked`. The transfer fee of a Token-2022 mint is withheld from the amount that
+/// arrives in the token account of the owner.
+pub fn transfer_from_vault_to_owner_v2<'info>(
+    whirlpool: &Account<'info, Whirlpool>,
+    token_mint: &AccountInfo<'info>,
+    token_vault: &AccountInfo<'info>,
+    token_owner_account: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+) -> ProgramResult {
+    invoke_signed(
+        &transfer_checked_ix(
+            token_program.key,
+            token_vault.key,
+            token_mint,
+            token_owner_account.key,
+            &whirlpool.key(),
+            amount,
+        )?,
+        &[
+            token_vault.clone(),
+            token_mint.clone(),
+            token_owner_account.clone(),
+            whirlpool.to_account_info(),
+            token_program.clone(),
+        ],
+        &[&whirlpool.seeds()],
+    )
+}
+
+// The instructions of the Token-2022 program share the layout of the ones of the token program,
+// whose builders only accept the token program.
+fn transfer_checked_ix(
+    token_program: &Pubkey,
+    source: &Pubkey,
+    token_mint: &AccountInfo,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let mut instruction = transfer_checked(
+        &spl_token::ID,
+        source,
+        token_mint.key,
+        destination,
+        authority,
+        &[],
+        amount,
+        load_mint(token_mint)?.decimals,
+    )?;
+    instruction.program_id = *token_program;
+    Ok(instruction)
+}
+
+/// Create a token vault of a Whirlpool for a mint of the token program or the Token-2022 program,
+/// with the rent paid by the funder.
+pub fn initialize_token_vault<'info>(
+    whirlpool: &Account<'info, Whirlpool>,
+    token_vault: &Signer<'info>,
+    token_mint: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    funder: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    rent: &Sysvar<'info, Rent>,
+) -> ProgramResult {
+    let space = token_vault_len(token_mint)?;
+    invoke(
+        &system_instruction::create_account(
+            funder.key,
+            token_vault.key,
+            rent.minimum_balance(space),
+            space as u64,
+            token_program.key,
+        ),
+        &[
+            funder.to_account_info(),
+            token_vault.to_account_info(),
+            system_program.to_account_info(),
+        ],
+    )?;
+
+    let mut instruction = initialize_account2(
+        &spl_token::ID,
+        token_vault.key,
+        token_mint.key,
+        &whirlpool.key(),
+    )?;
+    instruction.program_id = *token_program.key;
+    invoke(
+        &instruction,
+        &[
+            token_vault.to_account_info(),
+            token_mint.clone(),
+            rent.to_account_info(),
+            token_program.clone(),
+        ],
+    )
+}
+
+pub fn close_range_order_escrow<'info>(
+    whirlpool: &Account<'info, Whirlpool>,
+    range_order_escrow: &Account<'info, TokenAccount>,
+    receiver: &Signer<'info>,
+    token_program: &Program<'info, Token>,
+) -> Result<(), ProgramError> {
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        CloseAccount {
+            account: range_order_escrow.to_account_info(),
+            destination: receiver.to_account_info(),
+            authority: whirlpool.to_account_info(),
+        },
+        &[&whirlpool.seeds()],
+    ))
+}
+
+/// Fund the temporary wrapped RENEC account of a token authority with `amount` lamports.
+pub fn wrap_native<'info>(
+    token_authority: &Signer<'info>,
+    native_wrap_account: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> ProgramResult {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    invoke(
+        &system_instruction::transfer(token_authority.key, &native_wrap_account.key(), amount),
+        &[
+            token_authority.to_account_info(),
+            native_wrap_account.to_account_info(),
+            system_program.to_account_info(),
+        ],
+    )?;
+
+    invoke(
+        &sync_native(token_program.key, &native_wrap_account.key())?,
+        &[
+            native_wrap_account.to_account_info(),
+            token_program.to_account_info(),
+        ],
+    )
+}
+
+/// Close the temporary wrapped RENEC account of a token authority, returning its balance and rent
+/// to the token authority as lamports.
+pub fn unwrap_native<'info>(
+    token_authority: &Signer<'info>,
+    native_wrap_account: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+) -> ProgramResult {
+    // Every CPI clears the return data, so the return data of the swap is set again afterwards
+    let return_data = get_return_data();
+
+    invoke(
+        &close_account(
+            token_program.key,
+            &native_wrap_account.key(),
+            token_authority.key,
+            token_authority.key,
+            &[],
+        )?,
+        &[
+            native_wrap_account.to_account_info(),
+            token_authority.to_account_info(),
+            token_program.to_account_info(),
+        ],
+    )?;
+
+    if let Some((program_id, data)) = return_data {
+        if program_id == crate::ID {
+            set_return_data(&data);
+        }
+    }
+    Ok(())
+}
+
 pub fn burn_and_close_user_position_token<'info>(
     token_authority: &Signer<'info>,
     receiver: &UncheckedAccount<'info>,
@@ -107,16 +329,91 @@ pub fn mint_position_token_and_remove_authority<'info>(
     remove_position_token_mint_authority(whirlpool, position_mint, token_program)
 }
 
+/// Rewrite the metadata of a position from the metadata settings, so that its URI reflects the
+/// current tick range of the position. The creators and collection of the position are kept.
+///
+/// The update has to be signed by the update authority stored in the metadata. Metadata whose
+/// update authority differs from the one of the metadata settings, e.g. of positions opened
+/// before the update authority was rotated, is handed over to the one of the metadata settings.
+pub fn update_position_metadata<'info>(
+    whirlpool: &Account<'info, Whirlpool>,
+    position: &Position,
+    position_metadata_account: &UncheckedAccount<'info>,
+    metadata_update_auth: &Signer<'info>,
+    metadata_settings: &MetadataSettings,
+    metadata_program: &UncheckedAccount<'info>,
+) -> ProgramResult {
+    let (name, symbol, uri) = position_metadata(whirlpool, position, Some(metadata_settings))?;
+    let metadata = Metadata::from_account_info(&position_metadata_account.to_account_info())?;
+    if metadata_update_auth.key() != metadata.update_authority {
+        return Err(ErrorCode::InvalidMetadataUpdateAuthority.into());
+    }
+
+    let new_update_authority = if metadata.update_authority != metadata_settings.update_authority {
+        Some(metadata_settings.update_authority)
+    } else {
+        None
+    };
+
+    invoke(
+        &update_metadata_accounts_v2(
+            metadata_program.key(),
+            position_metadata_account.key(),
+            metadata_update_auth.key(),
+            new_update_authority,
+            Some(DataV2 {
+                name,
+                symbol,
+                uri,
+                seller_fee_basis_points: metadata.data.seller_fee_basis_points,
+                creators: metadata.data.creators,
+                collection: metadata.collection,
+                uses: metadata.uses,
+            }),
+            None,
+            None,
+        ),
+        &[
+            position_metadata_account.to_account_info(),
+            metadata_update_auth.to_account_info(),
+            metadata_program.to_account_info(),
+        ],
+    )
+}
+
+// Returns the name, symbol and URI of a position.
+// Configs without metadata settings use the default Whirlpool position metadata.
+fn position_metadata(
+    whirlpool: &Account<Whirlpool>,
+    position: &Position,
+    metadata_settings: Option<&MetadataSettings>,
+) -> Result<(String, String, String), ErrorCode> {
+    Ok(match metadata_settings {
+        Some(metadata_settings) => (
+            metadata_settings.name.clone(),
+            metadata_settings.symbol.clone(),
+            metadata_settings.uri(&whirlpool.key(), whirlpool, position)?,
+        ),
+        None => (
+            WP_METADATA_NAME.to_string(),
+            WP_METADATA_SYMBOL.to_string(),
+            WP_METADATA_URI.to_string(),
+        ),
+    })
+}
+
 const WP_METADATA_NAME: &str = "Orca Whirlpool Position";
 const WP_METADATA_SYMBOL: &str = "OWP";
 const WP_METADATA_URI: &str = "https://arweave.net/E19ZNY2sqMqddm1Wx7mrXPUZ0ZZ5ISizhebb0UsVEws";
 
 pub fn mint_position_token_with_metadata_and_remove_authority<'info>(
     whirlpool: &Account<'info, Whirlpool>,
+    position: &Position,
     position_mint: &Account<'info, Mint>,
     position_token_account: &Account<'info, TokenAccount>,
     position_metadata_account: &UncheckedAccount<'info>,
     metadata_update_auth: &UncheckedAccount<'info>,
+    metadata_settings: Option<&MetadataSettings>,
     funder: &Signer<'info>,
     metadata_program: &UncheckedAccount<'info>,
     token_program: &Program<'info, Token>,
@@ -130,6 +427,15 @@ pub fn mint_position_token_with_metadata_and_remove_authority<'info>(
         token_program,
     )?;
 
+    let (name, symbol, uri) = position_metadata(whirlpool, position, metadata_settings)?;
+    // The collection is verified once the metadata account has been created
+    let collection = metadata_settings
+        .and_then(|metadata_settings| metadata_settings.collection())
+        .map(|collection_mint| Collection {
+            verified: false,
+            key: collection_mint,
+        });
+
     let metadata_mint_auth_account = whirlpool;
     invoke_signed(
         &create_metadata_accounts_v2(
@@ -139,14 +445,14 @@ pub fn mint_position_token_with_metadata_and_remove_authority<'info>(
             metadata_mint_auth_account.key(),
             funder.key(),
             metadata_update_auth.key(),
-            WP_METADATA_NAME.to_string(),
-            WP_METADATA_SYMBOL.to_string(),
-            WP_METADATA_URI.to_string(),
+            name,
+            symbol,
+            uri,
             None,
             0,
             false,
             true,
-            None,
+            collection,
             None,
         ),
         &[
@@ -165,6 +471,133 @@ pub fn mint_position_token_with_metadata_and_remove_authority<'info>(
     remove_position_token_mint_authority(whirlpool, position_mint, token_program)
 }
 
+/// Verify a position NFT as a member of the collection NFT of its WhirlpoolsConfig.
+pub fn verify_position_collection<'info>(
+    position_metadata_account: &UncheckedAccount<'info>,
+    metadata_settings: &Account<'info, MetadataSettings>,
+    funder: &Signer<'info>,
+    collection_mint: &UncheckedAccount<'info>,
+    collection_metadata_account: &UncheckedAccount<'info>,
+    collection_master_edition_account: &UncheckedAccount<'info>,
+    metadata_program: &UncheckedAccount<'info>,
+) -> ProgramResult {
+    invoke_signed(
+        &verify_collection(
+            metadata_program.key(),
+            position_metadata_account.key(),
+            metadata_settings.key(),
+            funder.key(),
+            collection_mint.key(),
+            collection_metadata_account.key(),
+            collection_master_edition_account.key(),
+        ),
+        &[
+            position_metadata_account.to_account_info(),
+            metadata_settings.to_account_info(),
+            funder.to_account_info(),
+            collection_mint.to_account_info(),
+            collection_metadata_account.to_account_info(),
+            collection_master_edition_account.to_account_info(),
+            metadata_program.to_account_info(),
+        ],
+        &[&metadata_settings.seeds()],
+    )
+}
+
+/// Mint the collection NFT of the positions of a WhirlpoolsConfig. The metadata settings are the
+/// mint and update authority of the collection, and hold its single token.
+pub fn mint_position_collection<'info>(
+    metadata_settings: &Account<'info, MetadataSettings>,
+    collection_mint: &Account<'info, Mint>,
+    collection_token_account: &Account<'info, TokenAccount>,
+    collection_metadata_account: &UncheckedAccount<'info>,
+    collection_master_edition_account: &UncheckedAccount<'info>,
+    funder: &Signer<'info>,
+    metadata_program: &UncheckedAccount<'info>,
+    token_program: &Program<'info, Token>,
+    system_program: &Program<'info, System>,
+    rent: &Sysvar<'info, Rent>,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> ProgramResult {
+    let metadata_settings_seeds = metadata_settings.seeds();
+
+    invoke_signed(
+        &mint_to(
+            token_program.key,
+            collection_mint.to_account_info().key,
+            collection_token_account.to_account_info().key,
+            metadata_settings.to_account_info().key,
+            &[metadata_settings.to_account_info().key],
+            1,
+        )?,
+        &[
+            collection_mint.to_account_info(),
+            collection_token_account.to_account_info(),
+            metadata_settings.to_account_info(),
+            token_program.to_account_info(),
+        ],
+        &[&metadata_settings_seeds],
+    )?;
+
+    invoke_signed(
+        &create_metadata_accounts_v2(
+            metadata_program.key(),
+            collection_metadata_account.key(),
+            collection_mint.key(),
+            metadata_settings.key(),
+            funder.key(),
+            metadata_settings.key(),
+            name,
+            symbol,
+            uri,
+            None,
+            0,
+            true,
+            true,
+            None,
+            None,
+        ),
+        &[
+            collection_metadata_account.to_account_info(),
+            collection_mint.to_account_info(),
+            metadata_settings.to_account_info(),
+            funder.to_account_info(),
+            metadata_program.to_account_info(),
+            system_program.to_account_info(),
+            rent.to_account_info(),
+        ],
+        &[&metadata_settings_seeds],
+    )?;
+
+    // The master edition takes over the mint authority, so that no other token can be minted
+    invoke_signed(
+        &create_master_edition_v3(
+            metadata_program.key(),
+            collection_master_edition_account.key(),
+            collection_mint.key(),
+            metadata_settings.key(),
+            metadata_settings.key(),
+            collection_metadata_account.key(),
+            funder.key(),
+            Some(0),
+        ),
+        &[
+            collection_master_edition_account.to_account_info(),
+            collection_mint.to_account_info(),
+            metadata_settings.to_account_info(),
+            funder.to_account_info(),
+            collection_metadata_account.to_account_info(),
+            token_program.to_account_info(),
+            system_program.to_account_info(),
+            rent.to_account_info(),
+            metadata_program.to_account_info(),
+        ],
+        &[&metadata_settings_seeds],
+    )
+}
+
 fn mint_position_token<'info>(
     whirlpool: &Account<'info, Whirlpool>,
     position_mint: &Account<'info, Mint>,
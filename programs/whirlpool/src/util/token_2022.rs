@@ -0,0 +1,445 @@
+//! Mints of the Token-2022 program share the layout of the token program. Mints and token
+//! accounts with extensions store their account type right after the layout of a token account,
+//! mints being padded to it, followed by the extensions as type-length-value entries.
+use anchor_lang::prelude::*;
+use solana_program::program_pack::Pack;
+use spl_token::state::{Account as TokenAccountState, Mint as MintState};
+use std::convert::{TryFrom, TryInto};
+
+use crate::errors::ErrorCode;
+
+pub mod token_2022_program {
+    use super::*;
+    declare_id!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+}
+
+const ACCOUNT_TYPE_INDEX: usize = TokenAccountState::LEN;
+const ACCOUNT_TYPE_MINT: u8 = 1;
+const EXTENSION_HEADER_LEN: usize = 4;
+
+const EXTENSION_UNINITIALIZED: u16 = 0;
+const EXTENSION_TRANSFER_FEE_CONFIG: u16 = 1;
+const EXTENSION_METADATA_POINTER: u16 = 18;
+const EXTENSION_TOKEN_METADATA: u16 = 19;
+
+/// Extensions of the Token-2022 mints that pools can be initialized with. The other extensions
+/// change how tokens are transferred, e.g. transfer hooks and confidential transfers, or let a
+/// third party freeze, move or close the tokens held by the vaults.
+pub const SUPPORTED_MINT_EXTENSIONS: [u16; 3] = [
+    EXTENSION_TRANSFER_FEE_CONFIG,
+    EXTENSION_METADATA_POINTER,
+    EXTENSION_TOKEN_METADATA,
+];
+
+// TransferFeeConfig holds two authorities and the withheld amount, followed by the older and the
+// newer transfer fee, each an epoch, a maximum fee and a fee rate in basis points
+const TRANSFER_FEE_CONFIG_LEN: usize = 108;
+const OLDER_TRANSFER_FEE_OFFSET: usize = 72;
+const NEWER_TRANSFER_FEE_OFFSET: usize = 90;
+// Token accounts of mints with a transfer fee hold their withheld amount in a TransferFeeAmount
+const TRANSFER_FEE_AMOUNT_LEN: usize = 8;
+
+pub const MAX_TRANSFER_FEE_BASIS_POINTS: u16 = 10_000;
+
+/// Check whether a program is the token program or the Token-2022 program.
+pub fn is_token_program(program_id: &Pubkey) -> bool {
+    program_id == &spl_token::ID || program_id == &token_2022_program::ID
+}
+
+/// The transfer fee of a Token-2022 mint in the current epoch.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TransferFee {
+    pub maximum_fee: u64,
+    pub transfer_fee_basis_points: u16,
+}
+
+impl TransferFee {
+    /// The fee that Token-2022 withholds from a transfer of `amount`.
+    pub fn calculate_fee(&self, amount: u64) -> Option<u64> {
+        if self.transfer_fee_basis_points == 0 || amount == 0 {
+            return Some(0);
+        }
+
+        let numerator = u128::from(amount).checked_mul(self.transfer_fee_basis_points.into())?;
+        let fee = ceil_div(numerator, MAX_TRANSFER_FEE_BASIS_POINTS.into())?;
+        Some(u64::try_from(fee).ok()?.min(self.maximum_fee))
+    }
+
+    /// The smallest amount that leaves `post_fee_amount` once the fee has been withheld.
+    pub fn calculate_pre_fee_amount(&self, post_fee_amount: u64) -> Option<u64> {
+        if self.transfer_fee_basis_points == 0 || post_fee_amount == 0 {
+            return Some(post_fee_amount);
+        }
+        if self.transfer_fee_basis_points >= MAX_TRANSFER_FEE_BASIS_POINTS {
+            return post_fee_amount.checked_add(self.maximum_fee);
+        }
+
+        let numerator =
+            u128::from(post_fee_amount).checked_mul(MAX_TRANSFER_FEE_BASIS_POINTS.into())?;
+        let denominator = MAX_TRANSFER_FEE_BASIS_POINTS - self.transfer_fee_basis_points;
+        let pre_fee_amount = ceil_div(numerator, denominator.into())?;
+        if pre_fee_amount - u128::from(post_fee_amount) >= u128::from(self.maximum_fee) {
+            post_fee_amount.checked_add(self.maximum_fee)
+        } else {
+            u64::try_from(pre_fee_amount).ok()
+        }
+    }
+}
+
+fn ceil_div(numerator: u128, denominator: u128) -> Option<u128> {
+    numerator
+        .checked_add(denominator.checked_sub(1)?)?
+        .checked_div(denominator)
+}
+
+/// The amount that arrives from a transfer of `amount`, net of the transfer fee.
+pub fn calculate_transfer_fee_excluded_amount(
+    transfer_fee: Option<&TransferFee>,
+    amount: u64,
+) -> Result<u64, ErrorCode> {
+    let fee = match transfer_fee {
+        Some(transfer_fee) => transfer_fee
+            .calculate_fee(amount)
+            .ok_or(ErrorCode::TransferFeeCalculationError)?,
+        None => 0,
+    };
+    amount
+        .checked_sub(fee)
+        .ok_or(ErrorCode::TransferFeeCalculationError)
+}
+
+/// The amount to transfer for `amount` to arrive, including the transfer fee.
+pub fn calculate_transfer_fee_included_amount(
+    transfer_fee: Option<&TransferFee>,
+    amount: u64,
+) -> Result<u64, ErrorCode> {
+    let transfer_fee = match transfer_fee {
+        Some(transfer_fee) => transfer_fee,
+        None => return Ok(amount),
+    };
+
+    let included_amount = transfer_fee
+        .calculate_pre_fee_amount(amount)
+        .ok_or(ErrorCode::TransferFeeCalculationError)?;
+    if calculate_transfer_fee_excluded_amount(Some(transfer_fee), included_amount)? != amount {
+        return Err(ErrorCode::TransferFeeCalculationError);
+    }
+    Ok(included_amount)
+}
+
+/// Load the transfer fee of a mint in the current epoch.
+/// Returns `None` for the mints of the token program and Token-2022 mints without a transfer fee.
+pub fn get_transfer_fee(token_mint: &AccountInfo) -> Result<Option<TransferFee>, ProgramError> {
+    if token_mint.owner != &token_2022_program::ID {
+        return Ok(None);
+    }
+
+    let epoch = Clock::get()?.epoch;
+    Ok(parse_transfer_fee(&token_mint.try_borrow_data()?, epoch)?)
+}
+
+/// Load the state of a mint of the token program or the Token-2022 program.
+pub fn load_mint(token_mint: &AccountInfo) -> Result<MintState, ProgramError> {
+    let data = token_mint.try_borrow_data()?;
+    let mint = data
+        .get(..MintState::LEN)
+        .ok_or(ProgramError::InvalidAccountData)?;
+    MintState::unpack(mint)
+}
+
+/// Check that a mint belongs to the token program or the Token-2022 program, and only has the
+/// extensions in SUPPORTED_MINT_EXTENSIONS.
+pub fn validate_mint_extensions(token_mint: &AccountInfo) -> ProgramResult {
+    if !is_token_program(token_mint.owner) {
+        return Err(ErrorCode::UnsupportedTokenMint.into());
+    }
+    if token_mint.owner != &token_2022_program::ID {
+        return Ok(());
+    }
+
+    let data = token_mint.try_borrow_data()?;
+    for (extension_type, _) in parse_mint_extensions(&data)? {
+        if !SUPPORTED_MINT_EXTENSIONS.contains(&extension_type) {
+            return Err(ErrorCode::UnsupportedTokenMint.into());
+        }
+    }
+    Ok(())
+}
+
+/// Size of the token vaults of a mint, including the account extensions that Token-2022 adds to
+/// the token accounts of the mint.
+pub fn token_vault_len(token_mint: &AccountInfo) -> Result<usize, ProgramError> {
+    if token_mint.owner != &token_2022_program::ID {
+        return Ok(TokenAccountState::LEN);
+    }
+
+    Ok(vault_len(&token_mint.try_borrow_data()?)?)
+}
+
+fn vault_len(mint_data: &[u8]) -> Result<usize, ErrorCode> {
+    let has_transfer_fee = parse_mint_extensions(mint_data)?
+        .iter()
+        .any(|(extension_type, _)| *extension_type == EXTENSION_TRANSFER_FEE_CONFIG);
+    if has_transfer_fee {
+        Ok(ACCOUNT_TYPE_INDEX + 1 + EXTENSION_HEADER_LEN + TRANSFER_FEE_AMOUNT_LEN)
+    } else {
+        Ok(TokenAccountState::LEN)
+    }
+}
+
+/// The extensions of a Token-2022 mint, as their type and data.
+fn parse_mint_extensions(data: &[u8]) -> Result<Vec<(u16, &[u8])>, ErrorCode> {
+    if data.len() == MintState::LEN {
+        return Ok(Vec::new());
+    }
+    if data.get(ACCOUNT_TYPE_INDEX) != Some(&ACCOUNT_TYPE_MINT) {
+        return Err(ErrorCode::UnsupportedTokenMint);
+    }
+
+    let mut extensions = Vec::new();
+    let mut offset = ACCOUNT_TYPE_INDEX + 1;
+    while offset + EXTENSION_HEADER_LEN <= data.len() {
+        let extension_type = read_u16(data, offset);
+        if extension_type == EXTENSION_UNINITIALIZED {
+            break;
+        }
+
+        let value_offset = offset + EXTENSION_HEADER_LEN;
+        let value_len = usize::from(read_u16(data, offset + 2));
+        let value = data
+            .get(value_offset..value_offset + value_len)
+            .ok_or(ErrorCode::UnsupportedTokenMint)?;
+        extensions.push((extension_type, value));
+        offset = value_offset + value_len;
+    }
+    Ok(extensions)
+}
+
+/// The transfer fee of a mint in `epoch`. The newer transfer fee of a mint applies from its epoch
+/// onwards, the older one before.
+fn parse_transfer_fee(data: &[u8], epoch: u64) -> Result<Option<TransferFee>, ErrorCode> {
+    let transfer_fee_config = match parse_mint_extensions(data)?
+        .into_iter()
+        .find(|(extension_type, _)| *extension_type == EXTENSION_TRANSFER_FEE_CONFIG)
+    {
+        Some((_, transfer_fee_config)) => transfer_fee_config,
+        None => return Ok(None),
+    };
+    if transfer_fee_config.len() != TRANSFER_FEE_CONFIG_LEN {
+        return Err(ErrorCode::UnsupportedTokenMint);
+    }
+
+    let offset = if epoch >= read_u64(transfer_fee_config, NEWER_TRANSFER_FEE_OFFSET) {
+        NEWER_TRANSFER_FEE_OFFSET
+    } else {
+        OLDER_TRANSFER_FEE_OFFSET
+    };
+    Ok(Some(TransferFee {
+        maximum_fee: read_u64(transfer_fee_config, offset + 8),
+        transfer_fee_basis_points: read_u16(transfer_fee_config, offset + 16),
+    }))
+}
+
+fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes(data[offset..offset + 2].try_into().unwrap())
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod token_2022_tests {
+    use super::*;
+
+    fn transfer_fee(transfer_fee_basis_points: u16, maximum_fee: u64) -> TransferFee {
+        TransferFee {
+            maximum_fee,
+            transfer_fee_basis_points,
+        }
+    }
+
+    fn push_extension(data: &mut Vec<u8>, extension_type: u16, value: &[u8]) {
+        data.extend_from_slice(&extension_type.to_le_bytes());
+        data.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        data.extend_from_slice(value);
+    }
+
+    fn mint_data(extensions: &[(u16, Vec<u8>)]) -> Vec<u8> {
+        let mut data = vec![0; ACCOUNT_TYPE_INDEX];
+        data.push(ACCOUNT_TYPE_MINT);
+        for (extension_type, value) in extensions {
+            push_extension(&mut data, *extension_type, value);
+        }
+        data
+    }
+
+    fn transfer_fee_config(older: (u64, u64, u16), newer: (u64, u64, u16)) -> Vec<u8> {
+        let mut data = vec![0; OLDER_TRANSFER_FEE_OFFSET];
+        for (epoch, maximum_fee, transfer_fee_basis_points) in [older, newer] {
+            data.extend_from_slice(&epoch.to_le_bytes());
+            data.extend_from_slice(&maximum_fee.to_le_bytes());
+            data.extend_from_slice(&transfer_fee_basis_points.to_le_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn test_calculate_fee() {
+        assert_eq!(transfer_fee(0, u64::MAX).calculate_fee(1_000), Some(0));
+        assert_eq!(transfer_fee(100, u64::MAX).calculate_fee(0), Some(0));
+        assert_eq!(transfer_fee(100, u64::MAX).calculate_fee(1_000), Some(10));
+        // The fee is rounded up
+        assert_eq!(transfer_fee(100, u64::MAX).calculate_fee(1_001), Some(11));
+        assert_eq!(transfer_fee(100, u64::MAX).calculate_fee(1), Some(1));
+        assert_eq!(transfer_fee(100, 5).calculate_fee(1_000), Some(5));
+        assert_eq!(
+            transfer_fee(10_000, u64::MAX).calculate_fee(1_000),
+            Some(1_000)
+        );
+        assert_eq!(
+            transfer_fee(10_000, u64::MAX).calculate_fee(u64::MAX),
+            Some(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_calculate_pre_fee_amount() {
+        assert_eq!(
+            transfer_fee(0, 10).calculate_pre_fee_amount(1_000),
+            Some(1_000)
+        );
+        assert_eq!(transfer_fee(100, 10).calculate_pre_fee_amount(0), Some(0));
+        assert_eq!(
+            transfer_fee(100, u64::MAX).calculate_pre_fee_amount(99),
+            Some(100)
+        );
+        assert_eq!(
+            transfer_fee(100, u64::MAX).calculate_pre_fee_amount(100),
+            Some(102)
+        );
+        assert_eq!(
+            transfer_fee(100, 5).calculate_pre_fee_amount(1_000),
+            Some(1_005)
+        );
+        assert_eq!(
+            transfer_fee(10_000, 5).calculate_pre_fee_amount(1_000),
+            Some(1_005)
+        );
+        assert_eq!(
+            transfer_fee(10_000, 5).calculate_pre_fee_amount(u64::MAX),
+            None
+        );
+    }
+
+    #[test]
+    fn test_transfer_fee_included_amount_round_trip() {
+        for fee in [
+            transfer_fee(1, u64::MAX),
+            transfer_fee(100, u64::MAX),
+            transfer_fee(3_333, u64::MAX),
+            transfer_fee(9_999, u64::MAX),
+            transfer_fee(250, 1_000),
+        ] {
+            for amount in [1, 7, 99, 100, 101, 12_345, 1_000_000, 1 << 40] {
+                let included_amount =
+                    calculate_transfer_fee_included_amount(Some(&fee), amount).unwrap();
+                assert_eq!(
+                    calculate_transfer_fee_excluded_amount(Some(&fee), included_amount),
+                    Ok(amount)
+                );
+                // No smaller amount leaves `amount` after the fee
+                assert!(
+                    calculate_transfer_fee_excluded_amount(Some(&fee), included_amount - 1)
+                        .unwrap()
+                        < amount
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_transfer_fee_amounts_without_transfer_fee() {
+        assert_eq!(
+            calculate_transfer_fee_excluded_amount(None, 1_000),
+            Ok(1_000)
+        );
+        assert_eq!(
+            calculate_transfer_fee_included_amount(None, 1_000),
+            Ok(1_000)
+        );
+    }
+
+    #[test]
+    fn test_transfer_fee_included_amount_overflow() {
+        assert_eq!(
+            calculate_transfer_fee_included_amount(Some(&transfer_fee(100, u64::MAX)), u64::MAX),
+            Err(ErrorCode::TransferFeeCalculationError)
+        );
+    }
+
+    #[test]
+    fn test_parse_mint_without_extensions() {
+        let data = vec![0; MintState::LEN];
+        assert_eq!(parse_mint_extensions(&data), Ok(Vec::new()));
+        assert_eq!(parse_transfer_fee(&data, 0), Ok(None));
+        assert_eq!(vault_len(&data), Ok(TokenAccountState::LEN));
+    }
+
+    #[test]
+    fn test_parse_transfer_fee() {
+        let config = transfer_fee_config((0, 1_000, 100), (10, 2_000, 250));
+        let data = mint_data(&[
+            (EXTENSION_METADATA_POINTER, vec![0; 64]),
+            (EXTENSION_TRANSFER_FEE_CONFIG, config),
+        ]);
+
+        assert_eq!(
+            parse_transfer_fee(&data, 9),
+            Ok(Some(transfer_fee(100, 1_000)))
+        );
+        assert_eq!(
+            parse_transfer_fee(&data, 10),
+            Ok(Some(transfer_fee(250, 2_000)))
+        );
+        assert_eq!(vault_len(&data), Ok(178));
+    }
+
+    #[test]
+    fn test_parse_mint_extensions_stops_at_uninitialized_extension() {
+        let mut data = mint_data(&[(EXTENSION_METADATA_POINTER, vec![0; 64])]);
+        data.extend_from_slice(&[0; 16]);
+
+        let extensions = parse_mint_extensions(&data).unwrap();
+        assert_eq!(extensions.len(), 1);
+        assert_eq!(extensions[0].0, EXTENSION_METADATA_POINTER);
+        assert_eq!(parse_transfer_fee(&data, 0), Ok(None));
+        assert_eq!(vault_len(&data), Ok(TokenAccountState::LEN));
+    }
+
+    #[test]
+    fn test_parse_invalid_mint_extensions() {
+        // The account type is not a mint
+        let mut data = mint_data(&[]);
+        data[ACCOUNT_TYPE_INDEX] = 2;
+        assert_eq!(
+            parse_mint_extensions(&data),
+            Err(ErrorCode::UnsupportedTokenMint)
+        );
+
+        // The length of the extension exceeds the account
+        let mut data = mint_data(&[(EXTENSION_METADATA_POINTER, vec![0; 64])]);
+        data.truncate(data.len() - 1);
+        assert_eq!(
+            parse_mint_extensions(&data),
+            Err(ErrorCode::UnsupportedTokenMint)
+        );
+
+        // The transfer fee config is truncated
+        let data = mint_data(&[(EXTENSION_TRANSFER_FEE_CONFIG, vec![0; 90])]);
+        assert_eq!(
+            parse_transfer_fee(&data, 0),
+            Err(ErrorCode::UnsupportedTokenMint)
+        );
+    }
+}
@@ -1,12 +1,21 @@
 use anchor_lang::{
-    prelude::{AccountInfo, ProgramError, Pubkey, Signer},
-    ToAccountInfo,
+    prelude::{
+        emit, Account, AccountInfo, Program, ProgramError, ProgramResult, Pubkey, Rent, Signer,
+        System,
+    },
+    AccountsExit, Discriminator, Key, ToAccountInfo,
 };
 use anchor_spl::token::TokenAccount;
-use solana_program::program_option::COption;
+use solana_program::{
+    program::invoke, program_option::COption, system_instruction, sysvar::Sysvar,
+};
 use std::convert::TryFrom;
 
 use crate::errors::ErrorCode;
+use crate::events::{FeeRateUpdated, FeeTierMigrated, ProtocolFeeRateUpdated};
+use crate::state::{
+    FeeRateSchedule, FeeTier, FeeTierPolicy, MetadataSettings, PauseControls, Whirlpool,
+};
 
 pub fn verify_position_authority<'info>(
     position_token_account: &TokenAccount,
@@ -42,3 +51,162 @@ fn validate_owner(
 pub fn to_timestamp_u64(t: i64) -> Result<u64, ErrorCode> {
     u64::try_from(t).or(Err(ErrorCode::InvalidTimestampConversion))
 }
+
+/// Apply a scheduled fee rate change to the Whirlpool once it is due.
+/// Pools that have not initialized a fee rate schedule are skipped.
+pub fn apply_fee_rate_schedule<'info>(
+    whirlpool: &mut Account<'info, Whirlpool>,
+    fee_rate_schedule: Option<&AccountInfo<'info>>,
+    timestamp: u64,
+) -> ProgramResult {
+    let fee_rate_schedule = match initialized_side_account(fee_rate_schedule) {
+        Some(fee_rate_schedule) => fee_rate_schedule,
+        None => return Ok(()),
+    };
+
+    let mut fee_rate_schedule: Account<FeeRateSchedule> = Account::try_from(fee_rate_schedule)?;
+    if fee_rate_schedule.apply(whirlpool, timestamp)? {
+        fee_rate_schedule.exit(&crate::ID)?;
+        emit!(FeeRateUpdated {
+            whirlpool: whirlpool.key(),
+            fee_rate: whirlpool.fee_rate,
+        });
+        emit!(ProtocolFeeRateUpdated {
+            whirlpool: whirlpool.key(),
+            protocol_fee_rate: whirlpool.protocol_fee_rate,
+        });
+    }
+    Ok(())
+}
+
+/// Get a copy of the Whirlpool with a due fee rate change applied, without persisting the
+/// change. Used to quote swaps with the fee rates the swap would use.
+pub fn preview_fee_rate_schedule<'info>(
+    whirlpool: &Whirlpool,
+    fee_rate_schedule: Option<&AccountInfo<'info>>,
+    timestamp: u64,
+) -> Result<Whirlpool, ProgramError> {
+    let mut whirlpool = whirlpool.clone();
+    let fee_rate_schedule = match initialized_side_account(fee_rate_schedule) {
+        Some(fee_rate_schedule) => fee_rate_schedule,
+        None => return Ok(whirlpool),
+    };
+
+    let mut fee_rate_schedule: Account<FeeRateSchedule> = Account::try_from(fee_rate_schedule)?;
+    fee_rate_schedule.apply(&mut whirlpool, timestamp)?;
+    Ok(whirlpool)
+}
+
+/// Fail if `operation` is paused on the Whirlpool or on its WhirlpoolsConfig.
+/// Nothing is paused in configs that have not initialized their pause controls.
+pub fn require_not_paused<'info>(
+    pause_controls: &AccountInfo<'info>,
+    whirlpool: &Account<'info, Whirlpool>,
+    operation: u8,
+) -> ProgramResult {
+    let pause_controls = match initialized_side_account(Some(pause_controls)) {
+        Some(pause_controls) => pause_controls,
+        None => return Ok(()),
+    };
+
+    let pause_controls: Account<PauseControls> = Account::try_from(pause_controls)?;
+    Ok(pause_controls.require_not_paused(&whirlpool.key(), operation)?)
+}
+
+/// Check that the fee tier policy of the config allows positions on the tick spacing of the
+/// Whirlpool. Every tick spacing is allowed until the fee tier policy has been initialized.
+pub fn require_tick_spacing_allowed<'info>(
+    fee_tier_policy: &AccountInfo<'info>,
+    whirlpool: &Account<'info, Whirlpool>,
+) -> ProgramResult {
+    let fee_tier_policy = match initialized_side_account(Some(fee_tier_policy)) {
+        Some(fee_tier_policy) => fee_tier_policy,
+        None => return Ok(()),
+    };
+
+    let fee_tier_policy: Account<FeeTierPolicy> = Account::try_from(fee_tier_policy)?;
+    if !fee_tier_policy.is_tick_spacing_allowed(whirlpool.tick_spacing) {
+        return Err(ErrorCode::InvalidTickSpacing.into());
+    }
+    Ok(())
+}
+
+/// Side accounts can be passed before they have been initialized, in which case the feature they
+/// hold is skipped.
+pub fn initialized_side_account<'a, 'info>(
+    account: Option<&'a AccountInfo<'info>>,
+) -> Option<&'a AccountInfo<'info>> {
+    account.filter(|account| account.owner == &crate::ID && !account.data_is_empty())
+}
+
+/// Load the metadata settings of a WhirlpoolsConfig.
+/// Returns None for configs that have not initialized their metadata settings.
+pub fn load_metadata_settings<'info>(
+    metadata_settings: &AccountInfo<'info>,
+) -> Result<Option<Account<'info, MetadataSettings>>, ProgramError> {
+    if metadata_settings.owner != &crate::ID || metadata_settings.data_is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(Account::try_from(metadata_settings)?))
+}
+
+/// Migrate a FeeTier of the config created before the pools of fee tiers were counted, with the
+/// additional rent paid by the funder. The Whirlpools initialized with the fee tier before can not
+/// be counted, so its pool count is untracked and the fee tier can never be closed.
+pub fn migrate_legacy_fee_tier<'info>(
+    fee_tier: &AccountInfo<'info>,
+    whirlpools_config: &Pubkey,
+    funder: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> ProgramResult {
+    {
+        let data = fee_tier.try_borrow_data()?;
+        if fee_tier.owner != &crate::ID
+            || data.len() != FeeTier::LEGACY_LEN
+            || data[..8] != FeeTier::discriminator()
+            || data[8..40] != whirlpools_config.as_ref()[..]
+        {
+            return Err(ErrorCode::InvalidFeeTierMigration.into());
+        }
+    }
+
+    grow_account(fee_tier, FeeTier::LEN, funder, system_program)?;
+
+    let mut fee_tier: Account<FeeTier> = Account::try_from(fee_tier)?;
+    fee_tier.pool_count = FeeTier::UNTRACKED_POOL_COUNT;
+    fee_tier.exit(&crate::ID)?;
+
+    emit!(FeeTierMigrated {
+        whirlpools_config: *whirlpools_config,
+        tick_spacing: fee_tier.tick_spacing,
+    });
+    Ok(())
+}
+
+/// Grow an account to `len` bytes, e.g. one created before its type gained fields, with the
+/// additional rent paid by the funder. The new bytes are zeroed.
+pub fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    len: usize,
+    funder: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> ProgramResult {
+    if account.data_len() >= len {
+        return Ok(());
+    }
+
+    let minimum_balance = Rent::get()?.minimum_balance(len);
+    let lamports = minimum_balance.saturating_sub(account.lamports());
+    if lamports > 0 {
+        invoke(
+            &system_instruction::transfer(funder.key, account.key, lamports),
+            &[
+                funder.to_account_info(),
+                account.clone(),
+                system_program.to_account_info(),
+            ],
+        )?;
+    }
+    account.realloc(len, true)
+}
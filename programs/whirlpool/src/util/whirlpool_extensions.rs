@@ -0,0 +1,157 @@
+use anchor_lang::{prelude::*, Discriminator};
+use std::slice::Iter;
+
+use super::grow_account;
+use crate::{
+    errors::ErrorCode,
+    state::{
+        DynamicFee, FeeRateSchedule, Oracle, RangeOrderBook, Whirlpool, EXTENSION_DYNAMIC_FEE,
+        EXTENSION_FEE_RATE_SCHEDULE, EXTENSION_ORACLE, EXTENSION_RANGE_ORDER_BOOK,
+    },
+};
+
+/// Get the extensions enabled on a Whirlpool.
+pub fn get_whirlpool_extensions(whirlpool: &AccountInfo) -> Result<u8, ProgramError> {
+    Ok(whirlpool
+        .try_borrow_data()?
+        .get(Whirlpool::LEN)
+        .copied()
+        .unwrap_or(0))
+}
+
+/// Enable an extension on a Whirlpool once its side account has been initialized.
+/// Pools created before extensions existed are grown by a byte to store them, with the additional
+/// rent paid by the funder.
+pub fn enable_whirlpool_extension<'info>(
+    whirlpool: &AccountInfo<'info>,
+    funder: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    extension: u8,
+) -> ProgramResult {
+    grow_account(
+        whirlpool,
+        Whirlpool::LEN_WITH_EXTENSIONS,
+        funder,
+        system_program,
+    )?;
+    whirlpool.try_borrow_mut_data()?[Whirlpool::LEN] |= extension;
+    Ok(())
+}
+
+/// The side accounts of the extensions enabled on a Whirlpool, parsed from `remaining_accounts`.
+/// The accounts of extensions that are not enabled, or not used by the instruction, are `None`.
+#[derive(Default)]
+pub struct WhirlpoolExtensionAccounts<'info> {
+    pub oracle: Option<AccountInfo<'info>>,
+    pub dynamic_fee: Option<AccountInfo<'info>>,
+    pub fee_rate_schedule: Option<AccountInfo<'info>>,
+    pub range_order_book: Option<AccountInfo<'info>>,
+}
+
+impl<'info> WhirlpoolExtensionAccounts<'info> {
+    /// Parse the side accounts of the extensions in `used` that are enabled on the Whirlpool from
+    /// the front of `accounts`, one account per extension in the order of the extension flags.
+    /// Returns the side accounts along with the number of accounts they took.
+    pub fn try_from(
+        whirlpool: &Account<'info, Whirlpool>,
+        accounts: &[AccountInfo<'info>],
+        used: u8,
+    ) -> Result<(Self, usize), ProgramError> {
+        let extensions = get_whirlpool_extensions(whirlpool.as_ref())? & used;
+        let whirlpool_key = whirlpool.key();
+        let mut accounts = accounts.iter();
+
+        let extension_accounts = WhirlpoolExtensionAccounts {
+            oracle: next_extension_account::<Oracle>(
+                &mut accounts,
+                extensions,
+                EXTENSION_ORACLE,
+                &whirlpool_key,
+            )?,
+            dynamic_fee: next_extension_account::<DynamicFee>(
+                &mut accounts,
+                extensions,
+                EXTENSION_DYNAMIC_FEE,
+                &whirlpool_key,
+            )?,
+            fee_rate_schedule: next_extension_account::<FeeRateSchedule>(
+                &mut accounts,
+                extensions,
+                EXTENSION_FEE_RATE_SCHEDULE,
+                &whirlpool_key,
+            )?,
+            range_order_book: next_extension_account::<RangeOrderBook>(
+                &mut accounts,
+                extensions,
+                EXTENSION_RANGE_ORDER_BOOK,
+                &whirlpool_key,
+            )?,
+        };
+
+        Ok((extension_accounts, extensions.count_ones() as usize))
+    }
+}
+
+/// Find the pause controls of each Whirlpool among `accounts`, for instructions that pass the
+/// pause controls once per config after the accounts of their Whirlpools. The pause controls are
+/// matched by the address derived from the config, so they are required whether or not they have
+/// been initialized.
+///
+/// Fails with `InvalidExtensionAccount` if the pause controls of a Whirlpool are missing, or if an
+/// account is not the pause controls of any of the Whirlpools.
+pub fn find_pause_controls<'info>(
+    whirlpools: &[&Account<'info, Whirlpool>],
+    accounts: &[AccountInfo<'info>],
+) -> Result<Vec<AccountInfo<'info>>, ProgramError> {
+    let mut used_accounts = vec![false; accounts.len()];
+    let mut pause_controls = Vec::with_capacity(whirlpools.len());
+    for whirlpool in whirlpools {
+        let (address, _) = Pubkey::find_program_address(
+            &[b"pause_controls", whirlpool.whirlpools_config.as_ref()],
+            &crate::ID,
+        );
+        let i = accounts
+            .iter()
+            .position(|account| account.key == &address)
+            .ok_or(ErrorCode::InvalidExtensionAccount)?;
+        used_accounts[i] = true;
+        pause_controls.push(accounts[i].clone());
+    }
+
+    if used_accounts.contains(&false) {
+        return Err(ErrorCode::InvalidExtensionAccount.into());
+    }
+    Ok(pause_controls)
+}
+
+/// Take the side account of `extension` from `accounts` if the extension is enabled.
+fn next_extension_account<'info, T: Discriminator>(
+    accounts: &mut Iter<AccountInfo<'info>>,
+    extensions: u8,
+    extension: u8,
+    parent: &Pubkey,
+) -> Result<Option<AccountInfo<'info>>, ProgramError> {
+    if extensions & extension == 0 {
+        return Ok(None);
+    }
+
+    let account = accounts.next().ok_or(ErrorCode::InvalidExtensionAccount)?;
+    if !is_extension_account::<T>(account, parent)? {
+        return Err(ErrorCode::InvalidExtensionAccount.into());
+    }
+    Ok(Some(account.clone()))
+}
+
+/// Side accounts are only initialized at the address derived from their parent, which they store
+/// as their first field, so an initialized account of the extension's type with the expected
+/// parent is the side account of the extension.
+fn is_extension_account<T: Discriminator>(
+    account: &AccountInfo,
+    parent: &Pubkey,
+) -> Result<bool, ProgramError> {
+    let data = account.try_borrow_data()?;
+    Ok(account.owner == &crate::ID
+        && data.len() >= 8 + 32
+        && data[..8] == T::discriminator()
+        && data[8..40] == parent.as_ref()[..])
+}
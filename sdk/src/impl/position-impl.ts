@@ -140,6 +140,8 @@ export class PositionImpl implements Position {
     const increaseIx = increaseLiquidityIx(this.ctx.program, {
       ...liquidityInput,
       whirlpool: this.data.whirlpool,
+      whirlpoolsConfig: whirlpool.whirlpoolsConfig,
+      extensions: whirlpool.extensions,
       position: this.address,
       positionTokenAccount,
       tokenOwnerAccountA,
@@ -211,6 +213,8 @@ export class PositionImpl implements Position {
     const decreaseIx = decreaseLiquidityIx(this.ctx.program, {
       ...liquidityInput,
       whirlpool: this.data.whirlpool,
+      whirlpoolsConfig: whirlpool.whirlpoolsConfig,
+      extensions: whirlpool.extensions,
       position: this.address,
       positionTokenAccount: await deriveATA(positionWalletKey, this.data.positionMint),
       tokenOwnerAccountA,
@@ -309,6 +313,7 @@ export class PositionImpl implements Position {
 
     const ix = collectFeesIx(this.ctx.program, {
       whirlpool: this.data.whirlpool,
+      whirlpoolsConfig: whirlpool.whirlpoolsConfig,
       position: this.address,
       positionTokenAccount,
       tokenOwnerAccountA: AddressUtil.toPubKey(tokenOwnerAccountA),
@@ -408,6 +413,7 @@ export class PositionImpl implements Position {
 
       const ix = collectRewardIx(this.ctx.program, {
         whirlpool: this.data.whirlpool,
+        whirlpoolsConfig: whirlpool.whirlpoolsConfig,
         position: this.address,
         positionTokenAccount,
         rewardIndex: index,
@@ -512,6 +518,7 @@ export class PositionImpl implements Position {
     const updateIx = updateFeesAndRewardsIx(this.ctx.program, {
       whirlpool: this.data.whirlpool,
       position: this.address,
+      extensions: whirlpool.extensions,
       tickArrayLower: tickArrayLowerPda.publicKey,
       tickArrayUpper: tickArrayUpperPda.publicKey,
     });
@@ -400,6 +400,8 @@ export class WhirlpoolClientImpl implements WhirlpoolClient {
     const poolParams = {
       whirlpoolOne: whirlpool1.getAddress(),
       whirlpoolTwo: whirlpool2.getAddress(),
+      whirlpoolsConfigOne: whirlpoolData1.whirlpoolsConfig,
+      whirlpoolsConfigTwo: whirlpoolData2.whirlpoolsConfig,
       tokenOwnerAccountOneA: resolveAllAtas[0].address,
       tokenVaultOneA: whirlpoolData1.tokenVaultA,
       tokenOwnerAccountOneB: resolveAllAtas[1].address,
@@ -410,6 +412,8 @@ export class WhirlpoolClientImpl implements WhirlpoolClient {
       tokenVaultTwoB: whirlpoolData2.tokenVaultB,
       oracleOne,
       oracleTwo,
+      extensionsOne: whirlpoolData1.extensions,
+      extensionsTwo: whirlpoolData2.extensions,
     };
 
     const ix = WhirlpoolIx.twoHopSwapIx(this.ctx.program, {
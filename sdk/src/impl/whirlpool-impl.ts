@@ -281,6 +281,9 @@ export class WhirlpoolImpl implements Whirlpool {
     );
     const metadataPda = PDAUtil.getPositionMetadata(positionMintKeypair.publicKey);
     const positionTokenAccountAddress = await deriveATA(wallet, positionMintKeypair.publicKey);
+    const metadataUpdateAuth = withMetadata
+      ? await this.getMetadataUpdateAuth(whirlpool.whirlpoolsConfig)
+      : undefined;
 
     const txBuilder = new TransactionBuilder(
       this.ctx.provider.connection,
@@ -294,9 +297,11 @@ export class WhirlpoolImpl implements Whirlpool {
         owner: wallet,
         positionPda,
         metadataPda,
+        metadataUpdateAuth,
         positionMintAddress: positionMintKeypair.publicKey,
         positionTokenAccount: positionTokenAccountAddress,
         whirlpool: this.address,
+        whirlpoolsConfig: whirlpool.whirlpoolsConfig,
         tickLowerIndex: tickLower,
         tickUpperIndex: tickUpper,
       }
@@ -337,6 +342,8 @@ export class WhirlpoolImpl implements Whirlpool {
       tokenMaxA,
       tokenMaxB,
       whirlpool: this.address,
+      whirlpoolsConfig: whirlpool.whirlpoolsConfig,
+      extensions: whirlpool.extensions,
       positionAuthority: wallet,
       position: positionPda.publicKey,
       positionTokenAccount: positionTokenAccountAddress,
@@ -507,6 +514,8 @@ export class WhirlpoolImpl implements Whirlpool {
       const liquidityIx = decreaseLiquidityIx(this.ctx.program, {
         ...decreaseLiqQuote,
         whirlpool: positionData.whirlpool,
+        whirlpoolsConfig: whirlpool.whirlpoolsConfig,
+        extensions: whirlpool.extensions,
         positionAuthority: positionWallet,
         position: positionAddress,
         positionTokenAccount,
@@ -570,6 +579,18 @@ export class WhirlpoolImpl implements Whirlpool {
     return txBuilders;
   }
 
+  // Configs without metadata settings use the default update authority of the instruction builder
+  private async getMetadataUpdateAuth(whirlpoolsConfig: PublicKey) {
+    const metadataSettingsPda = PDAUtil.getMetadataSettings(
+      this.ctx.program.programId,
+      whirlpoolsConfig
+    );
+    const metadataSettings = await this.ctx.program.account.metadataSettings.fetchNullable(
+      metadataSettingsPda.publicKey
+    );
+    return metadataSettings?.updateAuthority;
+  }
+
   private async refresh() {
     const account = await this.ctx.fetcher.getPool(this.address, true);
     if (!!account) {
@@ -0,0 +1,49 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to accept the pending transfer of the collect protocol fees authority in a WhirlpoolsConfig
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig
+ * @param newCollectProtocolFeesAuthority - The collectProtocolFeesAuthority proposed for the WhirlpoolsConfig
+ */
+export type AcceptCollectProtocolFeesAuthorityParams = {
+  whirlpoolsConfig: PublicKey;
+  newCollectProtocolFeesAuthority: PublicKey;
+};
+
+/**
+ * Accepts the pending transfer of the collect protocol fees authority for a WhirlpoolsConfig.
+ * Only the proposed collect protocol fees authority has permission to invoke this instruction.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - AcceptCollectProtocolFeesAuthorityParams object
+ * @returns - Instruction to perform the action.
+ */
+export function acceptCollectProtocolFeesAuthorityIx(
+  program: Program<Whirlpool>,
+  params: AcceptCollectProtocolFeesAuthorityParams
+): Instruction {
+  const { whirlpoolsConfig, newCollectProtocolFeesAuthority } = params;
+
+  const pendingAuthoritiesPda = PDAUtil.getPendingAuthorities(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.acceptCollectProtocolFeesAuthority({
+    accounts: {
+      whirlpoolsConfig,
+      pendingAuthorities: pendingAuthoritiesPda.publicKey,
+      newCollectProtocolFeesAuthority,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
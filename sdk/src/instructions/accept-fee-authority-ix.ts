@@ -0,0 +1,49 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to accept the pending transfer of the fee authority in a WhirlpoolsConfig
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig
+ * @param newFeeAuthority - The feeAuthority proposed for the WhirlpoolsConfig
+ */
+export type AcceptFeeAuthorityParams = {
+  whirlpoolsConfig: PublicKey;
+  newFeeAuthority: PublicKey;
+};
+
+/**
+ * Accepts the pending transfer of the fee authority for a WhirlpoolsConfig.
+ * Only the proposed fee authority has permission to invoke this instruction.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - AcceptFeeAuthorityParams object
+ * @returns - Instruction to perform the action.
+ */
+export function acceptFeeAuthorityIx(
+  program: Program<Whirlpool>,
+  params: AcceptFeeAuthorityParams
+): Instruction {
+  const { whirlpoolsConfig, newFeeAuthority } = params;
+
+  const pendingAuthoritiesPda = PDAUtil.getPendingAuthorities(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.acceptFeeAuthority({
+    accounts: {
+      whirlpoolsConfig,
+      pendingAuthorities: pendingAuthoritiesPda.publicKey,
+      newFeeAuthority,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
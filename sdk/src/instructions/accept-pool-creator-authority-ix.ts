@@ -0,0 +1,49 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to accept the pending transfer of the pool creator authority in a WhirlpoolsConfig
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig
+ * @param newPoolCreatorAuthority - The poolCreatorAuthority proposed for the WhirlpoolsConfig
+ */
+export type AcceptPoolCreatorAuthorityParams = {
+  whirlpoolsConfig: PublicKey;
+  newPoolCreatorAuthority: PublicKey;
+};
+
+/**
+ * Accepts the pending transfer of the pool creator authority for a WhirlpoolsConfig.
+ * Only the proposed pool creator authority has permission to invoke this instruction.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - AcceptPoolCreatorAuthorityParams object
+ * @returns - Instruction to perform the action.
+ */
+export function acceptPoolCreatorAuthorityIx(
+  program: Program<Whirlpool>,
+  params: AcceptPoolCreatorAuthorityParams
+): Instruction {
+  const { whirlpoolsConfig, newPoolCreatorAuthority } = params;
+
+  const pendingAuthoritiesPda = PDAUtil.getPendingAuthorities(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.acceptPoolCreatorAuthority({
+    accounts: {
+      whirlpoolsConfig,
+      pendingAuthorities: pendingAuthoritiesPda.publicKey,
+      newPoolCreatorAuthority,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
@@ -0,0 +1,49 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to accept the pending transfer of the reward emissions super authority in a WhirlpoolsConfig
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig
+ * @param newRewardEmissionsSuperAuthority - The rewardEmissionsSuperAuthority proposed for the WhirlpoolsConfig
+ */
+export type AcceptRewardEmissionsSuperAuthorityParams = {
+  whirlpoolsConfig: PublicKey;
+  newRewardEmissionsSuperAuthority: PublicKey;
+};
+
+/**
+ * Accepts the pending transfer of the reward emissions super authority for a WhirlpoolsConfig.
+ * Only the proposed reward emissions super authority has permission to invoke this instruction.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - AcceptRewardEmissionsSuperAuthorityParams object
+ * @returns - Instruction to perform the action.
+ */
+export function acceptRewardEmissionsSuperAuthorityIx(
+  program: Program<Whirlpool>,
+  params: AcceptRewardEmissionsSuperAuthorityParams
+): Instruction {
+  const { whirlpoolsConfig, newRewardEmissionsSuperAuthority } = params;
+
+  const pendingAuthoritiesPda = PDAUtil.getPendingAuthorities(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.acceptRewardEmissionsSuperAuthority({
+    accounts: {
+      whirlpoolsConfig,
+      pendingAuthorities: pendingAuthoritiesPda.publicKey,
+      newRewardEmissionsSuperAuthority,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
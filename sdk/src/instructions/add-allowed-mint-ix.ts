@@ -0,0 +1,54 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to add a token mint to the allowlist of the pool creation policy of a WhirlpoolsConfig
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig
+ * @param poolCreatorAuthority - The current poolCreatorAuthority in the WhirlpoolsConfig
+ * @param mint - The token mint to add
+ */
+export type AddAllowedMintParams = {
+  whirlpoolsConfig: PublicKey;
+  poolCreatorAuthority: PublicKey;
+  mint: PublicKey;
+};
+
+/**
+ * Adds a token mint to the allowlist of the pool creation policy of a WhirlpoolsConfig.
+ * Only the current pool creator authority has permission to invoke this instruction.
+ *
+ * #### Special Errors
+ * - `AllowedMintsFull` - If the allowlist already holds MAX_ALLOWED_MINTS mints.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - AddAllowedMintParams object
+ * @returns - Instruction to perform the action.
+ */
+export function addAllowedMintIx(
+  program: Program<Whirlpool>,
+  params: AddAllowedMintParams
+): Instruction {
+  const { whirlpoolsConfig, poolCreatorAuthority, mint } = params;
+
+  const poolCreationPolicyPda = PDAUtil.getPoolCreationPolicy(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.addAllowedMint(mint, {
+    accounts: {
+      whirlpoolsConfig,
+      poolCreatorAuthority,
+      poolCreationPolicy: poolCreationPolicyPda.publicKey,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
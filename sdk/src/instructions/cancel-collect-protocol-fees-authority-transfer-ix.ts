@@ -0,0 +1,49 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to cancel the pending transfer of the collect protocol fees authority in a WhirlpoolsConfig
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig
+ * @param collectProtocolFeesAuthority - The current collectProtocolFeesAuthority in the WhirlpoolsConfig
+ */
+export type CancelCollectProtocolFeesAuthorityTransferParams = {
+  whirlpoolsConfig: PublicKey;
+  collectProtocolFeesAuthority: PublicKey;
+};
+
+/**
+ * Cancels the pending transfer of the collect protocol fees authority for a WhirlpoolsConfig.
+ * Only the current collect protocol fees authority has permission to invoke this instruction.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - CancelCollectProtocolFeesAuthorityTransferParams object
+ * @returns - Instruction to perform the action.
+ */
+export function cancelCollectProtocolFeesAuthorityTransferIx(
+  program: Program<Whirlpool>,
+  params: CancelCollectProtocolFeesAuthorityTransferParams
+): Instruction {
+  const { whirlpoolsConfig, collectProtocolFeesAuthority } = params;
+
+  const pendingAuthoritiesPda = PDAUtil.getPendingAuthorities(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.cancelCollectProtocolFeesAuthorityTransfer({
+    accounts: {
+      whirlpoolsConfig,
+      collectProtocolFeesAuthority,
+      pendingAuthorities: pendingAuthoritiesPda.publicKey,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
@@ -0,0 +1,49 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to cancel the pending transfer of the fee authority in a WhirlpoolsConfig
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig
+ * @param feeAuthority - The current feeAuthority in the WhirlpoolsConfig
+ */
+export type CancelFeeAuthorityTransferParams = {
+  whirlpoolsConfig: PublicKey;
+  feeAuthority: PublicKey;
+};
+
+/**
+ * Cancels the pending transfer of the fee authority for a WhirlpoolsConfig.
+ * Only the current fee authority has permission to invoke this instruction.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - CancelFeeAuthorityTransferParams object
+ * @returns - Instruction to perform the action.
+ */
+export function cancelFeeAuthorityTransferIx(
+  program: Program<Whirlpool>,
+  params: CancelFeeAuthorityTransferParams
+): Instruction {
+  const { whirlpoolsConfig, feeAuthority } = params;
+
+  const pendingAuthoritiesPda = PDAUtil.getPendingAuthorities(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.cancelFeeAuthorityTransfer({
+    accounts: {
+      whirlpoolsConfig,
+      feeAuthority,
+      pendingAuthorities: pendingAuthoritiesPda.publicKey,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
@@ -0,0 +1,52 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to cancel the pending fee rate change of a Whirlpool
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig this pool is initialized in
+ * @param whirlpool - PublicKey for the whirlpool whose pending fee rate change will be cancelled.
+ * @param feeAuthority - Authority authorized in the WhirlpoolsConfig to set fee rates.
+ */
+export type CancelFeeRateChangeParams = {
+  whirlpoolsConfig: PublicKey;
+  whirlpool: PublicKey;
+  feeAuthority: PublicKey;
+};
+
+/**
+ * Cancels the pending fee rate change of a Whirlpool.
+ * Only the current fee authority has permission to invoke this instruction.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - CancelFeeRateChangeParams object
+ * @returns - Instruction to perform the action.
+ */
+export function cancelFeeRateChangeIx(
+  program: Program<Whirlpool>,
+  params: CancelFeeRateChangeParams
+): Instruction {
+  const { whirlpoolsConfig, whirlpool, feeAuthority } = params;
+
+  const feeRateSchedulePda = PDAUtil.getFeeRateSchedule(program.programId, whirlpool);
+
+  const ix = program.instruction.cancelFeeRateChange({
+    accounts: {
+      whirlpoolsConfig,
+      whirlpool,
+      feeAuthority,
+      feeRateSchedule: feeRateSchedulePda.publicKey,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
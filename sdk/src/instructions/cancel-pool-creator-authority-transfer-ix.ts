@@ -0,0 +1,49 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to cancel the pending transfer of the pool creator authority in a WhirlpoolsConfig
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig
+ * @param poolCreatorAuthority - The current poolCreatorAuthority in the WhirlpoolsConfig
+ */
+export type CancelPoolCreatorAuthorityTransferParams = {
+  whirlpoolsConfig: PublicKey;
+  poolCreatorAuthority: PublicKey;
+};
+
+/**
+ * Cancels the pending transfer of the pool creator authority for a WhirlpoolsConfig.
+ * Only the current pool creator authority has permission to invoke this instruction.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - CancelPoolCreatorAuthorityTransferParams object
+ * @returns - Instruction to perform the action.
+ */
+export function cancelPoolCreatorAuthorityTransferIx(
+  program: Program<Whirlpool>,
+  params: CancelPoolCreatorAuthorityTransferParams
+): Instruction {
+  const { whirlpoolsConfig, poolCreatorAuthority } = params;
+
+  const pendingAuthoritiesPda = PDAUtil.getPendingAuthorities(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.cancelPoolCreatorAuthorityTransfer({
+    accounts: {
+      whirlpoolsConfig,
+      poolCreatorAuthority,
+      pendingAuthorities: pendingAuthoritiesPda.publicKey,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
@@ -0,0 +1,49 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to cancel the pending transfer of the reward emissions super authority in a WhirlpoolsConfig
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig
+ * @param rewardEmissionsSuperAuthority - The current rewardEmissionsSuperAuthority in the WhirlpoolsConfig
+ */
+export type CancelRewardEmissionsSuperAuthorityTransferParams = {
+  whirlpoolsConfig: PublicKey;
+  rewardEmissionsSuperAuthority: PublicKey;
+};
+
+/**
+ * Cancels the pending transfer of the reward emissions super authority for a WhirlpoolsConfig.
+ * Only the current reward emissions super authority has permission to invoke this instruction.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - CancelRewardEmissionsSuperAuthorityTransferParams object
+ * @returns - Instruction to perform the action.
+ */
+export function cancelRewardEmissionsSuperAuthorityTransferIx(
+  program: Program<Whirlpool>,
+  params: CancelRewardEmissionsSuperAuthorityTransferParams
+): Instruction {
+  const { whirlpoolsConfig, rewardEmissionsSuperAuthority } = params;
+
+  const pendingAuthoritiesPda = PDAUtil.getPendingAuthorities(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.cancelRewardEmissionsSuperAuthorityTransfer({
+    accounts: {
+      whirlpoolsConfig,
+      rewardEmissionsSuperAuthority,
+      pendingAuthorities: pendingAuthoritiesPda.publicKey,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
@@ -0,0 +1,57 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to close a FeeTier.
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig this fee-tier is initialized in
+ * @param feeAuthority - The current feeAuthority in the WhirlpoolsConfig
+ * @param tickSpacing - The tick spacing of the fee-tier that we would like to close.
+ * @param receiver - PublicKey for the wallet that will receive the rented lamports.
+ */
+export type CloseFeeTierParams = {
+  whirlpoolsConfig: PublicKey;
+  feeAuthority: PublicKey;
+  tickSpacing: number;
+  receiver: PublicKey;
+};
+
+/**
+ * Closes a FeeTier and returns its rent to the receiver. Only fee-tiers that no pool has been initialized
+ * with can be closed. No new pools can be created with its tick spacing until the fee-tier is initialized again.
+ *
+ * #### Special Errors
+ * - `FeeTierHasPools` - If pools have been initialized with the fee-tier.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - CloseFeeTierParams object
+ * @returns - Instruction to perform the action.
+ */
+export function closeFeeTierIx(
+  program: Program<Whirlpool>,
+  params: CloseFeeTierParams
+): Instruction {
+  const { whirlpoolsConfig, feeAuthority, tickSpacing, receiver } = params;
+
+  const feeTierPda = PDAUtil.getFeeTier(program.programId, whirlpoolsConfig, tickSpacing);
+
+  const ix = program.instruction.closeFeeTier({
+    accounts: {
+      whirlpoolsConfig,
+      feeTier: feeTierPda.publicKey,
+      feeAuthority,
+      receiver,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
@@ -2,6 +2,9 @@ import { Program } from "@project-serum/anchor";
 import { Whirlpool } from "../artifacts/whirlpool";
 import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { PublicKey } from "@solana/web3.js";
+import { tokenProgramAccounts } from "../utils/instructions-util";
+import { PDAUtil } from "../utils/public";
+import { TokenProgramParams } from "./initialize-pool-ix";
 
 import { Instruction } from "@orca-so/common-sdk";
 
@@ -10,6 +13,7 @@ import { Instruction } from "@orca-so/common-sdk";
  *
  * @category Instruction Types
  * @param whirlpool - PublicKey for the whirlpool that the position will be opened for.
+ * @param whirlpoolsConfig - PublicKey for the WhirlpoolsConfig of the whirlpool.
  * @param position - PublicKey for the  position will be opened for.
  * @param positionTokenAccount - PublicKey for the position token's associated token address.
  * @param tokenOwnerAccountA - PublicKey for the token A account that will be withdrawed from.
@@ -20,6 +24,7 @@ import { Instruction } from "@orca-so/common-sdk";
  */
 export type CollectFeesParams = {
   whirlpool: PublicKey;
+  whirlpoolsConfig: PublicKey;
   position: PublicKey;
   positionTokenAccount: PublicKey;
   tokenOwnerAccountA: PublicKey;
@@ -29,6 +34,14 @@ export type CollectFeesParams = {
   positionAuthority: PublicKey;
 };
 
+/**
+ * Parameters to collect fees from a position of a Whirlpool whose mints may belong to the
+ * Token-2022 program.
+ *
+ * @category Instruction Types
+ */
+export type CollectFeesV2Params = CollectFeesParams & TokenProgramParams;
+
 /**
  * Collect fees accrued for this position.
  * Call updateFeesAndRewards before this to update the position to the newest accrued values.
@@ -41,6 +54,7 @@ export type CollectFeesParams = {
 export function collectFeesIx(program: Program<Whirlpool>, params: CollectFeesParams): Instruction {
   const {
     whirlpool,
+    whirlpoolsConfig,
     positionAuthority,
     position,
     positionTokenAccount,
@@ -50,6 +64,8 @@ export function collectFeesIx(program: Program<Whirlpool>, params: CollectFeesPa
     tokenVaultB,
   } = params;
 
+  const pauseControlsPda = PDAUtil.getPauseControls(program.programId, whirlpoolsConfig);
+
   const ix = program.instruction.collectFees({
     accounts: {
       whirlpool,
@@ -61,6 +77,57 @@ export function collectFeesIx(program: Program<Whirlpool>, params: CollectFeesPa
       tokenVaultA,
       tokenVaultB,
       tokenProgram: TOKEN_PROGRAM_ID,
+      pauseControls: pauseControlsPda.publicKey,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
+
+/**
+ * Collect fees accrued for a position of a Whirlpool whose mints may belong to the Token-2022
+ * program. The transfer fees are withheld from the fees collected.
+ * Call updateFeesAndRewards before this to update the position to the newest accrued values.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - CollectFeesV2Params object
+ * @returns - Instruction to perform the action.
+ */
+export function collectFeesV2Ix(
+  program: Program<Whirlpool>,
+  params: CollectFeesV2Params
+): Instruction {
+  const {
+    whirlpool,
+    whirlpoolsConfig,
+    positionAuthority,
+    position,
+    positionTokenAccount,
+    tokenOwnerAccountA,
+    tokenOwnerAccountB,
+    tokenVaultA,
+    tokenVaultB,
+  } = params;
+
+  const pauseControlsPda = PDAUtil.getPauseControls(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.collectFeesV2({
+    accounts: {
+      whirlpool,
+      positionAuthority,
+      position,
+      positionTokenAccount,
+      tokenOwnerAccountA,
+      tokenOwnerAccountB,
+      tokenVaultA,
+      tokenVaultB,
+      ...tokenProgramAccounts(params),
+      pauseControls: pauseControlsPda.publicKey,
     },
   });
 
@@ -3,6 +3,8 @@ import { Whirlpool } from "../artifacts/whirlpool";
 import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { Instruction } from "@orca-so/common-sdk";
 import { PublicKey } from "@solana/web3.js";
+import { tokenProgramAccounts } from "../utils/instructions-util";
+import { TokenProgramParams } from "./initialize-pool-ix";
 
 /**
  * Parameters to collect protocol fees for a Whirlpool
@@ -26,6 +28,14 @@ export type CollectProtocolFeesParams = {
   collectProtocolFeesAuthority: PublicKey;
 };
 
+/**
+ * Parameters to collect protocol fees for a Whirlpool whose mints may belong to the Token-2022
+ * program.
+ *
+ * @category Instruction Types
+ */
+export type CollectProtocolFeesV2Params = CollectProtocolFeesParams & TokenProgramParams;
+
 /**
  * Collect protocol fees accrued in this Whirlpool.
  *
@@ -67,3 +77,46 @@ export function collectProtocolFeesIx(
     signers: [],
   };
 }
+
+/**
+ * Collect protocol fees accrued in a Whirlpool whose mints may belong to the Token-2022 program.
+ * The transfer fees are withheld from the fees collected.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - CollectProtocolFeesV2Params object
+ * @returns - Instruction to perform the action.
+ */
+export function collectProtocolFeesV2Ix(
+  program: Program<Whirlpool>,
+  params: CollectProtocolFeesV2Params
+): Instruction {
+  const {
+    whirlpoolsConfig,
+    whirlpool,
+    collectProtocolFeesAuthority,
+    tokenVaultA,
+    tokenVaultB,
+    tokenOwnerAccountA: tokenDestinationA,
+    tokenOwnerAccountB: tokenDestinationB,
+  } = params;
+
+  const ix = program.instruction.collectProtocolFeesV2({
+    accounts: {
+      whirlpoolsConfig,
+      whirlpool,
+      collectProtocolFeesAuthority,
+      ...tokenProgramAccounts(params),
+      tokenVaultA,
+      tokenVaultB,
+      tokenDestinationA,
+      tokenDestinationB,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
@@ -3,12 +3,14 @@ import { Whirlpool } from "../artifacts/whirlpool";
 import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { Instruction } from "@orca-so/common-sdk";
 import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
 
 /**
  * Parameters to collect rewards from a reward index in a position.
  *
  * @category Instruction Types
  * @param whirlpool - PublicKey for the whirlpool that the position will be opened for.
+ * @param whirlpoolsConfig - PublicKey for the WhirlpoolsConfig of the whirlpool.
  * @param position - PublicKey for the  position will be opened for.
  * @param positionTokenAccount - PublicKey for the position token's associated token address.
  * @param rewardIndex - The reward index that we'd like to initialize. (0 <= index <= NUM_REWARDS).
@@ -18,6 +20,7 @@ import { PublicKey } from "@solana/web3.js";
  */
 export type CollectRewardParams = {
   whirlpool: PublicKey;
+  whirlpoolsConfig: PublicKey;
   position: PublicKey;
   positionTokenAccount: PublicKey;
   rewardIndex: number;
@@ -41,6 +44,7 @@ export function collectRewardIx(
 ): Instruction {
   const {
     whirlpool,
+    whirlpoolsConfig,
     positionAuthority,
     position,
     positionTokenAccount,
@@ -49,6 +53,8 @@ export function collectRewardIx(
     rewardIndex,
   } = params;
 
+  const pauseControlsPda = PDAUtil.getPauseControls(program.programId, whirlpoolsConfig);
+
   const ix = program.instruction.collectReward(rewardIndex, {
     accounts: {
       whirlpool,
@@ -58,6 +64,7 @@ export function collectRewardIx(
       rewardOwnerAccount,
       rewardVault,
       tokenProgram: TOKEN_PROGRAM_ID,
+      pauseControls: pauseControlsPda.publicKey,
     },
   });
 
@@ -239,6 +239,7 @@ const constructCollectIxForPosition = (
       updateFeesAndRewardsIx(ctx.program, {
         position: positionKey,
         whirlpool: whirlpoolKey,
+        extensions: whirlpool.extensions,
         tickArrayLower: PDAUtil.getTickArray(
           ctx.program.programId,
           whirlpoolKey,
@@ -265,6 +266,7 @@ const constructCollectIxForPosition = (
   ixForPosition.push(
     WhirlpoolIx.collectFeesIx(ctx.program, {
       whirlpool: whirlpoolKey,
+      whirlpoolsConfig: whirlpool.whirlpoolsConfig,
       position: positionKey,
       positionAuthority,
       positionTokenAccount,
@@ -288,6 +290,7 @@ const constructCollectIxForPosition = (
       ixForPosition.push(
         WhirlpoolIx.collectRewardIx(ctx.program, {
           whirlpool: whirlpoolKey,
+          whirlpoolsConfig: whirlpool.whirlpoolsConfig,
           position: positionKey,
           positionAuthority,
           positionTokenAccount,
@@ -1,8 +1,11 @@
 import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { Program } from "@project-serum/anchor";
 import { Whirlpool } from "../artifacts/whirlpool";
+import { nativeWrapAccounts, tokenProgramAccounts } from "../utils/instructions-util";
+import { ExtensionUtil, MODIFY_LIQUIDITY_EXTENSIONS, PDAUtil } from "../utils/public";
 import { Instruction } from "@orca-so/common-sdk";
 import { PublicKey } from "@solana/web3.js";
+import { TokenProgramParams } from "./initialize-pool-ix";
 import { BN } from "@project-serum/anchor";
 
 /**
@@ -13,6 +16,8 @@ import { BN } from "@project-serum/anchor";
  * @param tokenMinA - The minimum amount of token A to remove from the position.
  * @param tokenMinB - The minimum amount of token B to remove from the position.
  * @param whirlpool - PublicKey for the whirlpool that the position will be opened for.
+ * @param whirlpoolsConfig - PublicKey for the WhirlpoolsConfig of the whirlpool.
+ * @param extensions - The extensions enabled on the whirlpool, see {@link WhirlpoolData}. Defaults to none.
  * @param position - PublicKey for the  position will be opened for.
  * @param positionTokenAccount - PublicKey for the position token's associated token address.
  * @param tokenOwnerAccountA - PublicKey for the token A account that will be withdrawed from.
@@ -25,6 +30,8 @@ import { BN } from "@project-serum/anchor";
  */
 export type DecreaseLiquidityParams = {
   whirlpool: PublicKey;
+  whirlpoolsConfig: PublicKey;
+  extensions?: number;
   position: PublicKey;
   positionTokenAccount: PublicKey;
   tokenOwnerAccountA: PublicKey;
@@ -36,6 +43,14 @@ export type DecreaseLiquidityParams = {
   positionAuthority: PublicKey;
 } & DecreaseLiquidityInput;
 
+/**
+ * Parameters to remove liquidity from a position of a Whirlpool whose mints may belong to the
+ * Token-2022 program.
+ *
+ * @category Instruction Types
+ */
+export type DecreaseLiquidityV2Params = DecreaseLiquidityParams & TokenProgramParams;
+
 /**
  * @category Instruction Types
  */
@@ -62,11 +77,97 @@ export function decreaseLiquidityIx(
   program: Program<Whirlpool>,
   params: DecreaseLiquidityParams
 ): Instruction {
-  const {
+  const { liquidityAmount, tokenMinA, tokenMinB } = params;
+
+  const ix = program.instruction.decreaseLiquidity(liquidityAmount, tokenMinA, tokenMinB, {
+    accounts: modifyLiquidityAccounts(program, params),
+    remainingAccounts: modifyLiquidityRemainingAccounts(program, params),
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
+
+/**
+ * Remove liquidity from a position in the Whirlpool into the lamport balance of the position
+ * authority. RENEC is withdrawn into a temporary wrapped account, which is closed back to the
+ * position authority. Pass the address from `PDAUtil.getNativeWrap` as the token owner account of
+ * the native mint.
+ *
+ * #### Special Errors
+ * Same as {@link decreaseLiquidityIx}.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - DecreaseLiquidityParams object
+ * @returns - Instruction to perform the action.
+ */
+export function decreaseLiquidityWithNativeIx(
+  program: Program<Whirlpool>,
+  params: DecreaseLiquidityParams
+): Instruction {
+  const { liquidityAmount, tokenMinA, tokenMinB, positionAuthority } = params;
+
+  const ix = program.instruction.decreaseLiquidityWithNative(
     liquidityAmount,
     tokenMinA,
     tokenMinB,
+    {
+      accounts: {
+        positionAuthority,
+        ...nativeWrapAccounts(program.programId, positionAuthority),
+        modifyLiquidity: modifyLiquidityAccounts(program, params),
+      },
+      remainingAccounts: modifyLiquidityRemainingAccounts(program, params),
+    }
+  );
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
+
+/**
+ * Remove liquidity from a position in a Whirlpool whose mints may belong to the Token-2022
+ * program. The token mins exclude the transfer fees of the withdrawals.
+ *
+ * #### Special Errors
+ * Same as {@link decreaseLiquidityIx}.
+ * - `TransferFeeCalculationError` - The transfer fee of a withdrawal could not be calculated.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - DecreaseLiquidityV2Params object
+ * @returns - Instruction to perform the action.
+ */
+export function decreaseLiquidityV2Ix(
+  program: Program<Whirlpool>,
+  params: DecreaseLiquidityV2Params
+): Instruction {
+  const { liquidityAmount, tokenMinA, tokenMinB } = params;
+  const { tokenProgram, ...accounts } = modifyLiquidityAccounts(program, params);
+
+  const ix = program.instruction.decreaseLiquidityV2(liquidityAmount, tokenMinA, tokenMinB, {
+    accounts: { ...accounts, ...tokenProgramAccounts(params) },
+    remainingAccounts: modifyLiquidityRemainingAccounts(program, params),
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
+
+function modifyLiquidityAccounts(program: Program<Whirlpool>, params: DecreaseLiquidityParams) {
+  const {
     whirlpool,
+    whirlpoolsConfig,
     positionAuthority,
     position,
     positionTokenAccount,
@@ -78,25 +179,30 @@ export function decreaseLiquidityIx(
     tickArrayUpper,
   } = params;
 
-  const ix = program.instruction.decreaseLiquidity(liquidityAmount, tokenMinA, tokenMinB, {
-    accounts: {
-      whirlpool,
-      tokenProgram: TOKEN_PROGRAM_ID,
-      positionAuthority,
-      position,
-      positionTokenAccount,
-      tokenOwnerAccountA,
-      tokenOwnerAccountB,
-      tokenVaultA,
-      tokenVaultB,
-      tickArrayLower,
-      tickArrayUpper,
-    },
-  });
-
   return {
-    instructions: [ix],
-    cleanupInstructions: [],
-    signers: [],
+    whirlpool,
+    tokenProgram: TOKEN_PROGRAM_ID,
+    positionAuthority,
+    position,
+    positionTokenAccount,
+    tokenOwnerAccountA,
+    tokenOwnerAccountB,
+    tokenVaultA,
+    tokenVaultB,
+    tickArrayLower,
+    tickArrayUpper,
+    pauseControls: PDAUtil.getPauseControls(program.programId, whirlpoolsConfig).publicKey,
   };
 }
+
+function modifyLiquidityRemainingAccounts(
+  program: Program<Whirlpool>,
+  params: DecreaseLiquidityParams
+) {
+  return ExtensionUtil.getRemainingAccounts(
+    program.programId,
+    params.whirlpool,
+    params.extensions ?? 0,
+    MODIFY_LIQUIDITY_EXTENSIONS
+  );
+}
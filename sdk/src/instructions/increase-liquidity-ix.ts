@@ -1,7 +1,10 @@
 import { Program, BN } from "@project-serum/anchor";
 import { Whirlpool } from "../artifacts/whirlpool";
+import { nativeWrapAccounts, tokenProgramAccounts } from "../utils/instructions-util";
+import { ExtensionUtil, MODIFY_LIQUIDITY_EXTENSIONS, PDAUtil } from "../utils/public";
 import { TOKEN_PROGRAM_ID, u64 } from "@solana/spl-token";
 import { PublicKey } from "@solana/web3.js";
+import { TokenProgramParams } from "./initialize-pool-ix";
 
 import { Instruction } from "@orca-so/common-sdk";
 
@@ -13,6 +16,8 @@ import { Instruction } from "@orca-so/common-sdk";
  * @param tokenMaxA - The maximum amount of token A to add to the position.
  * @param tokenMaxB - The maximum amount of token B to add to the position.
  * @param whirlpool - PublicKey for the whirlpool that the position will be opened for.
+ * @param whirlpoolsConfig - PublicKey for the WhirlpoolsConfig of the whirlpool.
+ * @param extensions - The extensions enabled on the whirlpool, see {@link WhirlpoolData}. Defaults to none.
  * @param position - PublicKey for the  position will be opened for.
  * @param positionTokenAccount - PublicKey for the position token's associated token address.
  * @param tokenOwnerAccountA - PublicKey for the token A account that will be withdrawed from.
@@ -25,6 +30,8 @@ import { Instruction } from "@orca-so/common-sdk";
  */
 export type IncreaseLiquidityParams = {
   whirlpool: PublicKey;
+  whirlpoolsConfig: PublicKey;
+  extensions?: number;
   position: PublicKey;
   positionTokenAccount: PublicKey;
   tokenOwnerAccountA: PublicKey;
@@ -36,6 +43,14 @@ export type IncreaseLiquidityParams = {
   positionAuthority: PublicKey;
 } & IncreaseLiquidityInput;
 
+/**
+ * Parameters to increase liquidity for a position of a Whirlpool whose mints may belong to the
+ * Token-2022 program.
+ *
+ * @category Instruction Types
+ */
+export type IncreaseLiquidityV2Params = IncreaseLiquidityParams & TokenProgramParams;
+
 /**
  * Input parameters to deposit liquidity into a position.
  *
@@ -70,11 +85,101 @@ export function increaseLiquidityIx(
   program: Program<Whirlpool>,
   params: IncreaseLiquidityParams
 ): Instruction {
-  const {
+  const { liquidityAmount, tokenMaxA, tokenMaxB } = params;
+
+  const ix = program.instruction.increaseLiquidity(liquidityAmount, tokenMaxA, tokenMaxB, {
+    accounts: modifyLiquidityAccounts(program, params),
+    remainingAccounts: modifyLiquidityRemainingAccounts(program, params),
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
+
+/**
+ * Add liquidity to a position in the Whirlpool from the lamport balance of the position authority.
+ * Up to the token max of RENEC is wrapped into a temporary account, which is closed back to the
+ * position authority once the liquidity has been deposited. Pass the address from
+ * `PDAUtil.getNativeWrap` as the token owner account of the native mint.
+ *
+ * #### Special Errors
+ * Same as {@link increaseLiquidityIx}.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - IncreaseLiquidityParams object
+ * @returns - Instruction to perform the action.
+ */
+export function increaseLiquidityWithNativeIx(
+  program: Program<Whirlpool>,
+  params: IncreaseLiquidityParams
+): Instruction {
+  const { liquidityAmount, tokenMaxA, tokenMaxB, positionAuthority } = params;
+
+  const ix = program.instruction.increaseLiquidityWithNative(
     liquidityAmount,
     tokenMaxA,
     tokenMaxB,
+    {
+      accounts: {
+        positionAuthority,
+        ...nativeWrapAccounts(program.programId, positionAuthority),
+        modifyLiquidity: modifyLiquidityAccounts(program, params),
+      },
+      remainingAccounts: modifyLiquidityRemainingAccounts(program, params),
+    }
+  );
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
+
+/**
+ * Add liquidity to a position in a Whirlpool whose mints may belong to the Token-2022 program.
+ * The token maxes include the transfer fees of the deposits.
+ *
+ * #### Special Errors
+ * Same as {@link increaseLiquidityIx}.
+ * `TransferFeeCalculationError` - The transfer fee of a deposit could not be calculated.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - IncreaseLiquidityV2Params object
+ * @returns - Instruction to perform the action.
+ */
+export function increaseLiquidityV2Ix(
+  program: Program<Whirlpool>,
+  params: IncreaseLiquidityV2Params
+): Instruction {
+  const { liquidityAmount, tokenMaxA, tokenMaxB } = params;
+
+  const ix = program.instruction.increaseLiquidityV2(liquidityAmount, tokenMaxA, tokenMaxB, {
+    accounts: modifyLiquidityV2Accounts(program, params),
+    remainingAccounts: modifyLiquidityRemainingAccounts(program, params),
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
+
+function modifyLiquidityV2Accounts(program: Program<Whirlpool>, params: IncreaseLiquidityV2Params) {
+  const { tokenProgram, ...accounts } = modifyLiquidityAccounts(program, params);
+  return { ...accounts, ...tokenProgramAccounts(params) };
+}
+
+function modifyLiquidityAccounts(program: Program<Whirlpool>, params: IncreaseLiquidityParams) {
+  const {
     whirlpool,
+    whirlpoolsConfig,
     positionAuthority,
     position,
     positionTokenAccount,
@@ -86,25 +191,30 @@ export function increaseLiquidityIx(
     tickArrayUpper,
   } = params;
 
-  const ix = program.instruction.increaseLiquidity(liquidityAmount, tokenMaxA, tokenMaxB, {
-    accounts: {
-      whirlpool,
-      tokenProgram: TOKEN_PROGRAM_ID,
-      positionAuthority,
-      position,
-      positionTokenAccount,
-      tokenOwnerAccountA,
-      tokenOwnerAccountB,
-      tokenVaultA,
-      tokenVaultB,
-      tickArrayLower,
-      tickArrayUpper,
-    },
-  });
-
   return {
-    instructions: [ix],
-    cleanupInstructions: [],
-    signers: [],
+    whirlpool,
+    tokenProgram: TOKEN_PROGRAM_ID,
+    positionAuthority,
+    position,
+    positionTokenAccount,
+    tokenOwnerAccountA,
+    tokenOwnerAccountB,
+    tokenVaultA,
+    tokenVaultB,
+    tickArrayLower,
+    tickArrayUpper,
+    pauseControls: PDAUtil.getPauseControls(program.programId, whirlpoolsConfig).publicKey,
   };
 }
+
+function modifyLiquidityRemainingAccounts(
+  program: Program<Whirlpool>,
+  params: IncreaseLiquidityParams
+) {
+  return ExtensionUtil.getRemainingAccounts(
+    program.programId,
+    params.whirlpool,
+    params.extensions ?? 0,
+    MODIFY_LIQUIDITY_EXTENSIONS
+  );
+}
@@ -0,0 +1,57 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to grow the number of observations stored by the oracle of a Whirlpool
+ *
+ * @category Instruction Types
+ * @param whirlpool - PublicKey for the whirlpool that owns the oracle.
+ * @param observationCardinalityNext - The number of observations the oracle should store.
+ * @param funder - The account that would fund the rent of the additional observations
+ */
+export type IncreaseObservationCardinalityParams = {
+  whirlpool: PublicKey;
+  observationCardinalityNext: number;
+  funder: PublicKey;
+};
+
+/**
+ * Grows the number of observations stored by the oracle of a Whirlpool. The oracle account is
+ * reallocated for the new observation slots, which are used once the current observation ring
+ * buffer wraps around.
+ *
+ * #### Special Errors
+ * - `InvalidObservationCardinality` - The new cardinality does not exceed the current one or
+ *                                     exceeds the maximum cardinality.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - IncreaseObservationCardinalityParams object
+ * @returns - Instruction to perform the action.
+ */
+export function increaseObservationCardinalityIx(
+  program: Program<Whirlpool>,
+  params: IncreaseObservationCardinalityParams
+): Instruction {
+  const { whirlpool, observationCardinalityNext, funder } = params;
+
+  const oraclePda = PDAUtil.getOracle(program.programId, whirlpool);
+
+  const ix = program.instruction.increaseObservationCardinality(observationCardinalityNext, {
+    accounts: {
+      whirlpool,
+      funder,
+      oracle: oraclePda.publicKey,
+      systemProgram: SystemProgram.programId,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
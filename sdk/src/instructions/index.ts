@@ -1,3 +1,14 @@
+export * from "./accept-collect-protocol-fees-authority-ix";
+export * from "./accept-fee-authority-ix";
+export * from "./accept-pool-creator-authority-ix";
+export * from "./accept-reward-emissions-super-authority-ix";
+export * from "./add-allowed-mint-ix";
+export * from "./cancel-collect-protocol-fees-authority-transfer-ix";
+export * from "./cancel-fee-authority-transfer-ix";
+export * from "./cancel-fee-rate-change-ix";
+export * from "./cancel-pool-creator-authority-transfer-ix";
+export * from "./cancel-reward-emissions-super-authority-transfer-ix";
+export * from "./close-fee-tier-ix";
 export * from "./close-position-ix";
 export * from "./collect-fees-ix";
 export * from "./collect-protocol-fees-ix";
@@ -5,24 +16,50 @@ export * from "./collect-reward-ix";
 export * from "./composites";
 export * from "./decrease-liquidity-ix";
 export * from "./increase-liquidity-ix";
+export * from "./increase-observation-cardinality-ix";
 export * from "./initialize-config-ix";
+export * from "./initialize-fee-rate-schedule-ix";
 export * from "./initialize-fee-tier-ix";
+export * from "./initialize-fee-tier-policy-ix";
+export * from "./initialize-metadata-settings-ix";
+export * from "./initialize-oracle-ix";
+export * from "./initialize-pause-controls-ix";
+export * from "./initialize-pending-authorities-ix";
+export * from "./initialize-pool-creation-policy-ix";
 export * from "./initialize-pool-ix";
+export * from "./initialize-position-collection-ix";
+export * from "./initialize-range-order-book-ix";
 export * from "./initialize-reward-ix";
 export * from "./initialize-tick-array-ix";
+export * from "./migrate-fee-tier-ix";
+export * from "./observe-ix";
 export * from "./open-position-ix";
+export * from "./place-range-order-ix";
+export * from "./rebalance-position-ix";
+export * from "./remove-allowed-mint-ix";
+export * from "./route-swap-ix";
+export * from "./schedule-fee-rate-change-ix";
+export * from "./set-allowed-tick-spacings-ix";
 export * from "./set-collect-protocol-fees-authority-ix";
+export * from "./set-config-paused-operations-ix";
 export * from "./set-default-fee-rate-ix";
 export * from "./set-default-protocol-fee-rate-ix";
 export * from "./set-fee-authority-ix";
+export * from "./set-pool-creation-mode-ix";
 export * from "./set-pool-creator-authority-ix";
+export * from "./set-pool-paused-operations-ix";
 export * from "./set-fee-rate-ix";
+export * from "./set-fee-tier-enabled-ix";
+export * from "./set-metadata-settings-ix";
 export * from "./set-protocol-fee-rate-ix";
 export * from "./set-reward-authority-by-super-authority-ix";
 export * from "./set-reward-authority-ix";
 export * from "./set-reward-emissions-ix";
 export * from "./set-reward-emissions-super-authority-ix";
+export * from "./split-swap-ix";
 export * from "./swap-ix";
 export * from "./two-hop-swap-ix";
 export * from "./update-fees-and-rewards-ix";
+export * from "./update-position-metadata-ix";
+export * from "./withdraw-range-order-ix";
 export * from "./set-enable-flag-ix";
@@ -0,0 +1,57 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to initialize the fee rate schedule of a Whirlpool
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig this pool is initialized in
+ * @param whirlpool - PublicKey for the whirlpool that the fee rate schedule will be initialized for.
+ * @param feeAuthority - Authority authorized in the WhirlpoolsConfig to set fee rates.
+ * @param funder - The account that would fund the creation of this account
+ */
+export type InitFeeRateScheduleParams = {
+  whirlpoolsConfig: PublicKey;
+  whirlpool: PublicKey;
+  feeAuthority: PublicKey;
+  funder: PublicKey;
+};
+
+/**
+ * Initializes the fee rate schedule of a Whirlpool, which is required to change its fee rates.
+ * Fee rate changes are scheduled at least MIN_FEE_RATE_CHANGE_DELAY seconds ahead.
+ * Only the current fee authority has permission to invoke this instruction.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - InitFeeRateScheduleParams object
+ * @returns - Instruction to perform the action.
+ */
+export function initializeFeeRateScheduleIx(
+  program: Program<Whirlpool>,
+  params: InitFeeRateScheduleParams
+): Instruction {
+  const { whirlpoolsConfig, whirlpool, feeAuthority, funder } = params;
+
+  const feeRateSchedulePda = PDAUtil.getFeeRateSchedule(program.programId, whirlpool);
+
+  const ix = program.instruction.initializeFeeRateSchedule({
+    accounts: {
+      whirlpoolsConfig,
+      whirlpool,
+      feeAuthority,
+      funder,
+      feeRateSchedule: feeRateSchedulePda.publicKey,
+      systemProgram: SystemProgram.programId,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
@@ -4,6 +4,7 @@ import { Whirlpool } from "../artifacts/whirlpool";
 import { PDA } from "@orca-so/common-sdk";
 
 import { Instruction } from "@orca-so/common-sdk";
+import { PDAUtil } from "../utils/public";
 
 /**
  * Parameters to initialize a FeeTier account.
@@ -30,6 +31,7 @@ export type InitFeeTierParams = {
  *
  *  Special Errors
  * `FeeRateMaxExceeded` - If the provided default_fee_rate exceeds MAX_FEE_RATE.
+ * `InvalidTickSpacing` - If the fee tier policy of the config does not allow the tick spacing.
  *
  * @category Instructions
  * @param context - Context object containing services required to generate the instruction
@@ -43,12 +45,15 @@ export function initializeFeeTierIx(
   const { feeTierPda, whirlpoolsConfig, tickSpacing, feeAuthority, defaultFeeRate, funder } =
     params;
 
+  const feeTierPolicyPda = PDAUtil.getFeeTierPolicy(program.programId, whirlpoolsConfig);
+
   const ix = program.instruction.initializeFeeTier(tickSpacing, defaultFeeRate, {
     accounts: {
       config: whirlpoolsConfig,
       feeTier: feeTierPda.publicKey,
       feeAuthority,
       funder,
+      feeTierPolicy: feeTierPolicyPda.publicKey,
       systemProgram: SystemProgram.programId,
     },
   });
@@ -0,0 +1,53 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to initialize the fee tier policy of a WhirlpoolsConfig
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig
+ * @param feeAuthority - The current feeAuthority in the WhirlpoolsConfig
+ * @param funder - The account that would fund the creation of this account
+ */
+export type InitFeeTierPolicyParams = {
+  whirlpoolsConfig: PublicKey;
+  feeAuthority: PublicKey;
+  funder: PublicKey;
+};
+
+/**
+ * Initializes the fee tier policy of a WhirlpoolsConfig, which restricts the tick spacings of fee tiers and new pools.
+ * Only the current fee authority has permission to invoke this instruction.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - InitFeeTierPolicyParams object
+ * @returns - Instruction to perform the action.
+ */
+export function initializeFeeTierPolicyIx(
+  program: Program<Whirlpool>,
+  params: InitFeeTierPolicyParams
+): Instruction {
+  const { whirlpoolsConfig, feeAuthority, funder } = params;
+
+  const feeTierPolicyPda = PDAUtil.getFeeTierPolicy(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.initializeFeeTierPolicy({
+    accounts: {
+      whirlpoolsConfig,
+      feeAuthority,
+      funder,
+      feeTierPolicy: feeTierPolicyPda.publicKey,
+      systemProgram: SystemProgram.programId,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
@@ -0,0 +1,50 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to initialize the oracle of a Whirlpool
+ *
+ * @category Instruction Types
+ * @param whirlpool - PublicKey for the whirlpool that the oracle will be initialized for.
+ * @param funder - The account that would fund the creation of this account
+ */
+export type InitOracleParams = {
+  whirlpool: PublicKey;
+  funder: PublicKey;
+};
+
+/**
+ * Initializes the oracle of a Whirlpool. Once initialized, every swap records an observation
+ * of the pool's tick & liquidity that can be used to derive a time-weighted average price.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - InitOracleParams object
+ * @returns - Instruction to perform the action.
+ */
+export function initializeOracleIx(
+  program: Program<Whirlpool>,
+  params: InitOracleParams
+): Instruction {
+  const { whirlpool, funder } = params;
+
+  const oraclePda = PDAUtil.getOracle(program.programId, whirlpool);
+
+  const ix = program.instruction.initializeOracle({
+    accounts: {
+      whirlpool,
+      funder,
+      oracle: oraclePda.publicKey,
+      systemProgram: SystemProgram.programId,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
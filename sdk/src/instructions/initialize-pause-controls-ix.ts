@@ -0,0 +1,53 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to initialize the pause controls of a WhirlpoolsConfig
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig
+ * @param poolCreatorAuthority - The current poolCreatorAuthority in the WhirlpoolsConfig
+ * @param funder - The account that would fund the creation of this account
+ */
+export type InitPauseControlsParams = {
+  whirlpoolsConfig: PublicKey;
+  poolCreatorAuthority: PublicKey;
+  funder: PublicKey;
+};
+
+/**
+ * Initializes the pause controls of a WhirlpoolsConfig, which pause individual operations of its pools.
+ * Only the current pool creator authority has permission to invoke this instruction.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - InitPauseControlsParams object
+ * @returns - Instruction to perform the action.
+ */
+export function initializePauseControlsIx(
+  program: Program<Whirlpool>,
+  params: InitPauseControlsParams
+): Instruction {
+  const { whirlpoolsConfig, poolCreatorAuthority, funder } = params;
+
+  const pauseControlsPda = PDAUtil.getPauseControls(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.initializePauseControls({
+    accounts: {
+      whirlpoolsConfig,
+      poolCreatorAuthority,
+      funder,
+      pauseControls: pauseControlsPda.publicKey,
+      systemProgram: SystemProgram.programId,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
@@ -0,0 +1,50 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to initialize the pending authorities account of a WhirlpoolsConfig
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig
+ * @param funder - The account that would fund the creation of this account
+ */
+export type InitPendingAuthoritiesParams = {
+  whirlpoolsConfig: PublicKey;
+  funder: PublicKey;
+};
+
+/**
+ * Initializes the account that holds the pending authority transfers of a WhirlpoolsConfig.
+ * Has to be invoked once before any authority of the config can be transferred.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - InitPendingAuthoritiesParams object
+ * @returns - Instruction to perform the action.
+ */
+export function initializePendingAuthoritiesIx(
+  program: Program<Whirlpool>,
+  params: InitPendingAuthoritiesParams
+): Instruction {
+  const { whirlpoolsConfig, funder } = params;
+
+  const pendingAuthoritiesPda = PDAUtil.getPendingAuthorities(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.initializePendingAuthorities({
+    accounts: {
+      whirlpoolsConfig,
+      funder,
+      pendingAuthorities: pendingAuthoritiesPda.publicKey,
+      systemProgram: SystemProgram.programId,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
@@ -0,0 +1,58 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to initialize the pool creation policy of a WhirlpoolsConfig
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig
+ * @param poolCreatorAuthority - The current poolCreatorAuthority in the WhirlpoolsConfig
+ * @param mode - The pool creation mode, one of the POOL_CREATION_* constants
+ * @param funder - The account that would fund the creation of this account
+ */
+export type InitPoolCreationPolicyParams = {
+  whirlpoolsConfig: PublicKey;
+  poolCreatorAuthority: PublicKey;
+  mode: number;
+  funder: PublicKey;
+};
+
+/**
+ * Initializes the pool creation policy of a WhirlpoolsConfig, which restricts who can create pools in the config.
+ * Only the current pool creator authority has permission to invoke this instruction.
+ *
+ * #### Special Errors
+ * - `InvalidPoolCreationMode` - If the provided mode is unknown.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - InitPoolCreationPolicyParams object
+ * @returns - Instruction to perform the action.
+ */
+export function initializePoolCreationPolicyIx(
+  program: Program<Whirlpool>,
+  params: InitPoolCreationPolicyParams
+): Instruction {
+  const { whirlpoolsConfig, poolCreatorAuthority, mode, funder } = params;
+
+  const poolCreationPolicyPda = PDAUtil.getPoolCreationPolicy(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.initializePoolCreationPolicy(mode, {
+    accounts: {
+      whirlpoolsConfig,
+      poolCreatorAuthority,
+      funder,
+      poolCreationPolicy: poolCreationPolicyPda.publicKey,
+      systemProgram: SystemProgram.programId,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
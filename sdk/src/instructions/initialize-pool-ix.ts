@@ -6,6 +6,8 @@ import { WhirlpoolBumpsData } from "../types/public/anchor-types";
 import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { BN } from "@project-serum/anchor";
 import { PDA } from "@orca-so/common-sdk";
+import { tokenProgramAccounts } from "../utils/instructions-util";
+import { PDAUtil } from "../utils/public";
 
 /**
  * Parameters to initialize a Whirlpool account.
@@ -21,6 +23,7 @@ import { PDA } from "@orca-so/common-sdk";
  * @param feeTierKey - PublicKey of the fee-tier account that this pool would use for the fee-rate
  * @param tickSpacing - The desired tick spacing for this pool.
  * @param funder - The account that would fund the creation of this account
+ * @param poolCreator - The account creating the pool, checked against the pool creation policy of the config. Defaults to the funder.
  */
 export type InitPoolParams = {
   initSqrtPrice: BN;
@@ -33,14 +36,40 @@ export type InitPoolParams = {
   feeTierKey: PublicKey;
   tickSpacing: number;
   funder: PublicKey;
+  poolCreator?: PublicKey;
 };
 
+/**
+ * The mints of a Whirlpool and the token programs that own them, which the `V2` instructions take
+ * to support the mints of the Token-2022 program.
+ *
+ * @category Instruction Types
+ * @param tokenMintA - Mint public key for token A
+ * @param tokenMintB - Mint public key for token B
+ * @param tokenProgramA - The program that owns the mint of token A, TOKEN_PROGRAM_ID or TOKEN_2022_PROGRAM_ID
+ * @param tokenProgramB - The program that owns the mint of token B, TOKEN_PROGRAM_ID or TOKEN_2022_PROGRAM_ID
+ */
+export type TokenProgramParams = {
+  tokenMintA: PublicKey;
+  tokenMintB: PublicKey;
+  tokenProgramA: PublicKey;
+  tokenProgramB: PublicKey;
+};
+
+/**
+ * Parameters to initialize a Whirlpool account whose mints may belong to the Token-2022 program.
+ *
+ * @category Instruction Types
+ */
+export type InitPoolV2Params = InitPoolParams & TokenProgramParams;
+
 /**
  * Initializes a tick_array account to represent a tick-range in a Whirlpool.
  *
  * Special Errors
  * `InvalidTokenMintOrder` - The order of mints have to be ordered by
  * `SqrtPriceOutOfBounds` - provided initial_sqrt_price is not between 2^-64 to 2^64
+ * `PoolCreationNotAllowed` - The pool creation policy of the config does not allow the poolCreator to create this pool
  *
  * @category Instructions
  * @param context - Context object containing services required to generate the instruction
@@ -59,22 +88,29 @@ export function initializePoolIx(program: Program<Whirlpool>, params: InitPoolPa
     tokenVaultBKeypair,
     tickSpacing,
     funder,
+    poolCreator,
   } = params;
 
   const whirlpoolBumps: WhirlpoolBumpsData = {
     whirlpoolBump: whirlpoolPda.bump,
   };
 
+  const poolCreationPolicyPda = PDAUtil.getPoolCreationPolicy(program.programId, whirlpoolsConfig);
+  const feeTierPolicyPda = PDAUtil.getFeeTierPolicy(program.programId, whirlpoolsConfig);
+
   const ix = program.instruction.initializePool(whirlpoolBumps, tickSpacing, initSqrtPrice, {
     accounts: {
       whirlpoolsConfig,
       tokenMintA,
       tokenMintB,
       funder,
+      poolCreator: poolCreator ?? funder,
       whirlpool: whirlpoolPda.publicKey,
       tokenVaultA: tokenVaultAKeypair.publicKey,
       tokenVaultB: tokenVaultBKeypair.publicKey,
       feeTier: feeTierKey,
+      poolCreationPolicy: poolCreationPolicyPda.publicKey,
+      feeTierPolicy: feeTierPolicyPda.publicKey,
       tokenProgram: TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
       rent: SYSVAR_RENT_PUBKEY,
@@ -87,3 +123,63 @@ export function initializePoolIx(program: Program<Whirlpool>, params: InitPoolPa
     signers: [tokenVaultAKeypair, tokenVaultBKeypair],
   };
 }
+
+/**
+ * Initializes a Whirlpool account whose mints belong to the token program or the Token-2022
+ * program. The token vaults are created with the account extensions of their mint.
+ *
+ * Special Errors
+ * Same as {@link initializePoolIx}.
+ * `UnsupportedTokenMint` - A mint has an extension other than the transfer fee, the metadata pointer and the metadata
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - InitPoolV2Params object
+ * @returns - Instruction to perform the action.
+ */
+export function initializePoolV2Ix(
+  program: Program<Whirlpool>,
+  params: InitPoolV2Params
+): Instruction {
+  const {
+    initSqrtPrice,
+    whirlpoolsConfig,
+    whirlpoolPda,
+    feeTierKey,
+    tokenVaultAKeypair,
+    tokenVaultBKeypair,
+    tickSpacing,
+    funder,
+    poolCreator,
+  } = params;
+
+  const whirlpoolBumps: WhirlpoolBumpsData = {
+    whirlpoolBump: whirlpoolPda.bump,
+  };
+
+  const poolCreationPolicyPda = PDAUtil.getPoolCreationPolicy(program.programId, whirlpoolsConfig);
+  const feeTierPolicyPda = PDAUtil.getFeeTierPolicy(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.initializePoolV2(whirlpoolBumps, tickSpacing, initSqrtPrice, {
+    accounts: {
+      whirlpoolsConfig,
+      ...tokenProgramAccounts(params),
+      funder,
+      poolCreator: poolCreator ?? funder,
+      whirlpool: whirlpoolPda.publicKey,
+      tokenVaultA: tokenVaultAKeypair.publicKey,
+      tokenVaultB: tokenVaultBKeypair.publicKey,
+      feeTier: feeTierKey,
+      poolCreationPolicy: poolCreationPolicyPda.publicKey,
+      feeTierPolicy: feeTierPolicyPda.publicKey,
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [tokenVaultAKeypair, tokenVaultBKeypair],
+  };
+}
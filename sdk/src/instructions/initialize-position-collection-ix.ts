@@ -0,0 +1,81 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY } from "@solana/web3.js";
+import { METADATA_PROGRAM_ADDRESS } from "../types/public";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to initialize the position collection of a WhirlpoolsConfig
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig
+ * @param feeAuthority - The current feeAuthority in the WhirlpoolsConfig
+ * @param collectionTokenAccount - The associated token address of the collection mint for the metadata settings
+ * @param name - The name of the collection NFT
+ * @param symbol - The symbol of the collection NFT
+ * @param uri - The URI of the collection NFT
+ * @param funder - The account that would fund the creation of the collection accounts
+ */
+export type InitPositionCollectionParams = {
+  whirlpoolsConfig: PublicKey;
+  feeAuthority: PublicKey;
+  collectionTokenAccount: PublicKey;
+  name: string;
+  symbol: string;
+  uri: string;
+  funder: PublicKey;
+};
+
+/**
+ * Mints the collection NFT of the positions of a WhirlpoolsConfig. Positions opened with
+ * `openPositionWithMetadataIx` afterwards are verified as members of the collection.
+ * The metadata settings of the WhirlpoolsConfig must be initialized and are the update authority
+ * of the collection.
+ * Only the current fee authority has permission to invoke this instruction.
+ *
+ * #### Special Errors
+ * - `InvalidPositionMetadata` - If a value exceeds the length limits of the metadata program.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - InitPositionCollectionParams object
+ * @returns - Instruction to perform the action.
+ */
+export function initializePositionCollectionIx(
+  program: Program<Whirlpool>,
+  params: InitPositionCollectionParams
+): Instruction {
+  const { whirlpoolsConfig, feeAuthority, collectionTokenAccount, name, symbol, uri, funder } =
+    params;
+
+  const metadataSettingsPda = PDAUtil.getMetadataSettings(program.programId, whirlpoolsConfig);
+  const collectionMintPda = PDAUtil.getPositionCollection(program.programId, whirlpoolsConfig);
+  const collectionMetadataPda = PDAUtil.getPositionMetadata(collectionMintPda.publicKey);
+  const collectionMasterEditionPda = PDAUtil.getMasterEdition(collectionMintPda.publicKey);
+
+  const ix = program.instruction.initializePositionCollection(name, symbol, uri, {
+    accounts: {
+      whirlpoolsConfig,
+      feeAuthority,
+      funder,
+      metadataSettings: metadataSettingsPda.publicKey,
+      collectionMint: collectionMintPda.publicKey,
+      collectionTokenAccount,
+      collectionMetadataAccount: collectionMetadataPda.publicKey,
+      collectionMasterEditionAccount: collectionMasterEditionPda.publicKey,
+      metadataProgram: METADATA_PROGRAM_ADDRESS,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
@@ -0,0 +1,50 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to initialize the range order book of a Whirlpool
+ *
+ * @category Instruction Types
+ * @param whirlpool - PublicKey for the whirlpool that the range order book will be initialized for.
+ * @param funder - The account that would fund the creation of this account
+ */
+export type InitRangeOrderBookParams = {
+  whirlpool: PublicKey;
+  funder: PublicKey;
+};
+
+/**
+ * Initializes the range order book of a Whirlpool. Once initialized, positions in the pool
+ * can be placed as range orders that are filled by the swaps crossing them.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - InitRangeOrderBookParams object
+ * @returns - Instruction to perform the action.
+ */
+export function initializeRangeOrderBookIx(
+  program: Program<Whirlpool>,
+  params: InitRangeOrderBookParams
+): Instruction {
+  const { whirlpool, funder } = params;
+
+  const rangeOrderBookPda = PDAUtil.getRangeOrderBook(program.programId, whirlpool);
+
+  const ix = program.instruction.initializeRangeOrderBook({
+    accounts: {
+      whirlpool,
+      funder,
+      rangeOrderBook: rangeOrderBookPda.publicKey,
+      systemProgram: SystemProgram.programId,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
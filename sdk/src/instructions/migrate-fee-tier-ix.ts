@@ -0,0 +1,59 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to migrate a FeeTier created before the pools of fee-tiers were counted.
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig this fee-tier is initialized in
+ * @param feeAuthority - The current feeAuthority in the WhirlpoolsConfig
+ * @param tickSpacing - The tick spacing of the fee-tier that we would like to migrate.
+ * @param funder - The account that funds the rent of the grown fee-tier
+ */
+export type MigrateFeeTierParams = {
+  whirlpoolsConfig: PublicKey;
+  feeAuthority: PublicKey;
+  tickSpacing: number;
+  funder: PublicKey;
+};
+
+/**
+ * Grows a FeeTier created before the pools of fee-tiers were counted. The pools initialized with
+ * the fee-tier can not be counted, so its pool count is left untracked and it can never be closed.
+ * Fee-tiers have to be migrated before their default fee rate can be set.
+ *
+ * #### Special Errors
+ * - `InvalidFeeTierMigration` - If the fee-tier is not part of the config or has already been migrated.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - MigrateFeeTierParams object
+ * @returns - Instruction to perform the action.
+ */
+export function migrateFeeTierIx(
+  program: Program<Whirlpool>,
+  params: MigrateFeeTierParams
+): Instruction {
+  const { whirlpoolsConfig, feeAuthority, tickSpacing, funder } = params;
+
+  const feeTierPda = PDAUtil.getFeeTier(program.programId, whirlpoolsConfig, tickSpacing);
+
+  const ix = program.instruction.migrateFeeTier({
+    accounts: {
+      whirlpoolsConfig,
+      feeTier: feeTierPda.publicKey,
+      feeAuthority,
+      funder,
+      systemProgram: SystemProgram.programId,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
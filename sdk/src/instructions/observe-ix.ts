@@ -0,0 +1,50 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to read the oracle of a Whirlpool
+ *
+ * @category Instruction Types
+ * @param whirlpool - PublicKey for the whirlpool that owns the oracle.
+ * @param secondsAgos - Strictly decreasing list of look-back periods in seconds.
+ */
+export type ObserveParams = {
+  whirlpool: PublicKey;
+  secondsAgos: number[];
+};
+
+/**
+ * Returns the oracle accumulators of a Whirlpool as of each of the provided look-back periods,
+ * along with the time-weighted average tick & sqrt-price between each consecutive pair of periods.
+ * The Borsh-encoded `ObserveResult` is written to the transaction return data.
+ *
+ * #### Special Errors
+ * - `ObservationTooOld` - A look-back period reaches past the oldest stored observation.
+ * - `InvalidObservationWindow` - The look-back periods are not strictly decreasing.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - ObserveParams object
+ * @returns - Instruction to perform the action.
+ */
+export function observeIx(program: Program<Whirlpool>, params: ObserveParams): Instruction {
+  const { whirlpool, secondsAgos } = params;
+
+  const oraclePda = PDAUtil.getOracle(program.programId, whirlpool);
+
+  const ix = program.instruction.observe(secondsAgos, {
+    accounts: {
+      whirlpool,
+      oracle: oraclePda.publicKey,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
@@ -2,18 +2,20 @@ import { Program } from "@project-serum/anchor";
 import { Whirlpool } from "../artifacts/whirlpool";
 import { PublicKey } from "@solana/web3.js";
 import { PDA, Instruction } from "@orca-so/common-sdk";
-import { METADATA_PROGRAM_ADDRESS } from "..";
+import { METADATA_PROGRAM_ADDRESS, WHIRLPOOL_NFT_UPDATE_AUTH } from "..";
 import {
   OpenPositionBumpsData,
   OpenPositionWithMetadataBumpsData,
 } from "../types/public/anchor-types";
 import { openPositionAccounts } from "../utils/instructions-util";
+import { PDAUtil } from "../utils/public";
 
 /**
  * Parameters to open a position in a Whirlpool.
  *
  * @category Instruction Types
  * @param whirlpool - PublicKey for the whirlpool that the position will be opened for.
+ * @param whirlpoolsConfig - PublicKey for the WhirlpoolsConfig of the whirlpool.
  * @param ownerKey - PublicKey for the wallet that will host the minted position token.
  * @param positionPda - PDA for the derived position address.
  * @param positionMintAddress - PublicKey for the mint token for the Position token.
@@ -24,6 +26,7 @@ import { openPositionAccounts } from "../utils/instructions-util";
  */
 export type OpenPositionParams = {
   whirlpool: PublicKey;
+  whirlpoolsConfig: PublicKey;
   owner: PublicKey;
   positionPda: PDA;
   positionMintAddress: PublicKey;
@@ -39,6 +42,7 @@ export type OpenPositionParams = {
  *
  * #### Special Errors
  * `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of the tick-spacing in this pool.
+ * `InvalidTickSpacing` - If the fee tier policy of the config does not allow the tick-spacing of this pool.
  *
  * @category Instructions
  * @param context - Context object containing services required to generate the instruction
@@ -56,7 +60,7 @@ export function openPositionIx(
   };
 
   const ix = program.instruction.openPosition(bumps, tickLowerIndex, tickUpperIndex, {
-    accounts: openPositionAccounts(params),
+    accounts: openPositionAccounts(program.programId, params),
   });
 
   // TODO: Require Keypair and auto sign this ix
@@ -70,33 +74,57 @@ export function openPositionIx(
 /**
  * Open a position in a Whirlpool. A unique token will be minted to represent the position
  * in the users wallet. Additional Metaplex metadata is appended to identify the token.
+ * The metadata is taken from the metadata settings of the WhirlpoolsConfig, if initialized.
+ * Once the position collection of the WhirlpoolsConfig has been initialized, the token is verified
+ * as a member of the collection.
  * The position will start off with 0 liquidity.
  *
  * #### Special Errors
  * `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of the tick-spacing in this pool.
+ * `InvalidTickSpacing` - If the fee tier policy of the config does not allow the tick-spacing of this pool.
+ * `InvalidMetadataUpdateAuthority` - If the metadata update authority does not match the metadata settings.
+ * `InvalidPositionMetadata` - If the expanded metadata URI is too long.
  *
  * @category Instructions
  * @param context - Context object containing services required to generate the instruction
- * @param params - OpenPositionParams object and a derived PDA that hosts the position's metadata.
+ * @param params - OpenPositionParams object, a derived PDA that hosts the position's metadata and
+ *                 the update authority of the metadata settings. The update authority defaults to
+ *                 WHIRLPOOL_NFT_UPDATE_AUTH for configs without metadata settings.
  * @returns - Instruction to perform the action.
  */
 export function openPositionWithMetadataIx(
   program: Program<Whirlpool>,
-  params: OpenPositionParams & { metadataPda: PDA }
+  params: OpenPositionParams & { metadataPda: PDA; metadataUpdateAuth?: PublicKey }
 ): Instruction {
-  const { positionPda, metadataPda, tickLowerIndex, tickUpperIndex } = params;
+  const {
+    whirlpoolsConfig,
+    positionPda,
+    metadataPda,
+    metadataUpdateAuth = WHIRLPOOL_NFT_UPDATE_AUTH,
+    tickLowerIndex,
+    tickUpperIndex,
+  } = params;
 
   const bumps: OpenPositionWithMetadataBumpsData = {
     positionBump: positionPda.bump,
     metadataBump: metadataPda.bump,
   };
 
+  const metadataSettingsPda = PDAUtil.getMetadataSettings(program.programId, whirlpoolsConfig);
+  const collectionMintPda = PDAUtil.getPositionCollection(program.programId, whirlpoolsConfig);
+  const collectionMetadataPda = PDAUtil.getPositionMetadata(collectionMintPda.publicKey);
+  const collectionMasterEditionPda = PDAUtil.getMasterEdition(collectionMintPda.publicKey);
+
   const ix = program.instruction.openPositionWithMetadata(bumps, tickLowerIndex, tickUpperIndex, {
     accounts: {
-      ...openPositionAccounts(params),
+      ...openPositionAccounts(program.programId, params),
       positionMetadataAccount: metadataPda.publicKey,
       metadataProgram: METADATA_PROGRAM_ADDRESS,
-      metadataUpdateAuth: new PublicKey("3axbTs2z5GBy6usVbNVoqEgZMng3vZvMnAoX29BFfwhr"),
+      metadataUpdateAuth,
+      metadataSettings: metadataSettingsPda.publicKey,
+      collectionMint: collectionMintPda.publicKey,
+      collectionMetadataAccount: collectionMetadataPda.publicKey,
+      collectionMasterEditionAccount: collectionMasterEditionPda.publicKey,
     },
   });
 
@@ -0,0 +1,74 @@
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { PDAUtil } from "../utils/public";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY } from "@solana/web3.js";
+
+/**
+ * Parameters to place a position as a range order.
+ *
+ * @category Instruction Types
+ * @param whirlpool - PublicKey for the whirlpool that the position belongs to.
+ * @param position - PublicKey for the position, which has to span exactly one tick-spacing.
+ * @param positionMint - PublicKey for the mint of the position token.
+ * @param positionTokenAccount - PublicKey for the position token's associated token address.
+ * @param positionAuthority - authority that owns the token corresponding to this desired position.
+ *                            It also funds the escrow account of the position token.
+ */
+export type PlaceRangeOrderParams = {
+  whirlpool: PublicKey;
+  position: PublicKey;
+  positionMint: PublicKey;
+  positionTokenAccount: PublicKey;
+  positionAuthority: PublicKey;
+};
+
+/**
+ * Place a position spanning a single tick-spacing as a range order.
+ * Once a swap moves the price across the range, the liquidity of the position is taken out
+ * of the pool, so that it holds only the converted token and stops earning fees and rewards.
+ * The position token is held in escrow until the order is withdrawn.
+ *
+ * #### Special Errors
+ * - `InvalidRangeOrderTickRange` - The position does not span exactly one tick-spacing.
+ * - `LiquidityZero` - The position has no liquidity.
+ * - `RangeOrderLiquidityTooLow` - The position has less than the minimum liquidity of range orders.
+ * - `RangeOrderInRange` - The current price of the Whirlpool is inside the range of the position.
+ * - `RangeOrderBookFull` - The range order book already holds the maximum number of orders.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - PlaceRangeOrderParams object
+ * @returns - Instruction to perform the action.
+ */
+export function placeRangeOrderIx(
+  program: Program<Whirlpool>,
+  params: PlaceRangeOrderParams
+): Instruction {
+  const { whirlpool, position, positionMint, positionTokenAccount, positionAuthority } = params;
+
+  const rangeOrderEscrowPda = PDAUtil.getRangeOrderEscrow(program.programId, position);
+  const rangeOrderBookPda = PDAUtil.getRangeOrderBook(program.programId, whirlpool);
+
+  const ix = program.instruction.placeRangeOrder({
+    accounts: {
+      whirlpool,
+      positionAuthority,
+      position,
+      positionMint,
+      positionTokenAccount,
+      rangeOrderEscrow: rangeOrderEscrowPda.publicKey,
+      rangeOrderBook: rangeOrderBookPda.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
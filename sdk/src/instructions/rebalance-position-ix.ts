@@ -0,0 +1,150 @@
+import { Program, BN } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { ExtensionUtil, MODIFY_LIQUIDITY_EXTENSIONS, PDAUtil } from "../utils/public";
+import { TOKEN_PROGRAM_ID, u64 } from "@solana/spl-token";
+import { PublicKey } from "@solana/web3.js";
+
+import { Instruction } from "@orca-so/common-sdk";
+
+/**
+ * Parameters to move the liquidity of a position to a new tick range.
+ *
+ * @category Instruction Types
+ * @param tickLowerIndex - The new lower tick index of the position.
+ * @param tickUpperIndex - The new upper tick index of the position.
+ * @param liquidityAmount - The amount of Liquidity the user desires to deposit in the new range.
+ * @param tokenMinA - The minimum amount of token A to withdraw, including the fees owed.
+ * @param tokenMinB - The minimum amount of token B to withdraw, including the fees owed.
+ * @param tokenMaxA - The maximum amount of token A to deposit in the new range.
+ * @param tokenMaxB - The maximum amount of token B to deposit in the new range.
+ * @param whirlpool - PublicKey for the whirlpool that the position belongs to.
+ * @param whirlpoolsConfig - PublicKey for the WhirlpoolsConfig of the whirlpool.
+ * @param extensions - The extensions enabled on the whirlpool, see {@link WhirlpoolData}. Defaults to none.
+ * @param position - PublicKey for the position to rebalance.
+ * @param positionTokenAccount - PublicKey for the position token's associated token address.
+ * @param tokenOwnerAccountA - PublicKey for the token A account that settles the difference.
+ * @param tokenOwnerAccountB - PublicKey for the token B account that settles the difference.
+ * @param tokenVaultA - PublicKey for the tokenA vault for this whirlpool.
+ * @param tokenVaultB - PublicKey for the tokenB vault for this whirlpool.
+ * @param tickArrayLower - PublicKey for the tick-array account that hosts the current lower tick.
+ * @param tickArrayUpper - PublicKey for the tick-array account that hosts the current upper tick.
+ * @param newTickArrayLower - PublicKey for the tick-array account that hosts the new lower tick.
+ * @param newTickArrayUpper - PublicKey for the tick-array account that hosts the new upper tick.
+ * @param positionAuthority - authority that owns the token corresponding to this desired position.
+ */
+export type RebalancePositionParams = {
+  tickLowerIndex: number;
+  tickUpperIndex: number;
+  liquidityAmount: BN;
+  tokenMinA: u64;
+  tokenMinB: u64;
+  tokenMaxA: u64;
+  tokenMaxB: u64;
+  whirlpool: PublicKey;
+  whirlpoolsConfig: PublicKey;
+  extensions?: number;
+  position: PublicKey;
+  positionTokenAccount: PublicKey;
+  tokenOwnerAccountA: PublicKey;
+  tokenOwnerAccountB: PublicKey;
+  tokenVaultA: PublicKey;
+  tokenVaultB: PublicKey;
+  tickArrayLower: PublicKey;
+  tickArrayUpper: PublicKey;
+  newTickArrayLower: PublicKey;
+  newTickArrayUpper: PublicKey;
+  positionAuthority: PublicKey;
+};
+
+/**
+ * Move all of the liquidity of a position to a new tick range on the same Whirlpool.
+ * The liquidity and the fees owed are withdrawn, and the position is reopened in the new range
+ * with `liquidityAmount`. Only the difference between the withdrawn and the deposited amounts of
+ * each token is transferred. Rewards owed remain collectable from the position. Positions without
+ * liquidity are only moved to the new range.
+ *
+ * #### Special Errors
+ * `LiquidityZero` - The provided liquidity amount is zero.
+ * `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of the tick-spacing in this pool.
+ * `InvalidTickSpacing` - If the fee tier policy of the config does not allow the tick-spacing of this pool.
+ * `TokenMinSubceeded` - The withdrawn tokens subceed the user defined amount.
+ * `TokenMaxExceeded` - The deposited tokens exceed the user defined amount.
+ * `InvalidExtensionAccount` - The side account of an extension enabled on the whirlpool is missing.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - RebalancePositionParams object
+ * @returns - Instruction to perform the action.
+ */
+export function rebalancePositionIx(
+  program: Program<Whirlpool>,
+  params: RebalancePositionParams
+): Instruction {
+  const {
+    tickLowerIndex,
+    tickUpperIndex,
+    liquidityAmount,
+    tokenMinA,
+    tokenMinB,
+    tokenMaxA,
+    tokenMaxB,
+    whirlpool,
+    whirlpoolsConfig,
+    extensions,
+    positionAuthority,
+    position,
+    positionTokenAccount,
+    tokenOwnerAccountA,
+    tokenOwnerAccountB,
+    tokenVaultA,
+    tokenVaultB,
+    tickArrayLower,
+    tickArrayUpper,
+    newTickArrayLower,
+    newTickArrayUpper,
+  } = params;
+
+  const pauseControlsPda = PDAUtil.getPauseControls(program.programId, whirlpoolsConfig);
+  const feeTierPolicyPda = PDAUtil.getFeeTierPolicy(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.rebalancePosition(
+    tickLowerIndex,
+    tickUpperIndex,
+    liquidityAmount,
+    tokenMinA,
+    tokenMinB,
+    tokenMaxA,
+    tokenMaxB,
+    {
+      accounts: {
+        whirlpool,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        positionAuthority,
+        position,
+        positionTokenAccount,
+        tokenOwnerAccountA,
+        tokenOwnerAccountB,
+        tokenVaultA,
+        tokenVaultB,
+        tickArrayLower,
+        tickArrayUpper,
+        newTickArrayLower,
+        newTickArrayUpper,
+        pauseControls: pauseControlsPda.publicKey,
+        feeTierPolicy: feeTierPolicyPda.publicKey,
+      },
+      remainingAccounts: ExtensionUtil.getRemainingAccounts(
+        program.programId,
+        whirlpool,
+        extensions ?? 0,
+        MODIFY_LIQUIDITY_EXTENSIONS
+      ),
+    }
+  );
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
@@ -0,0 +1,51 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to remove a token mint from the allowlist of the pool creation policy of a WhirlpoolsConfig
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig
+ * @param poolCreatorAuthority - The current poolCreatorAuthority in the WhirlpoolsConfig
+ * @param mint - The token mint to remove
+ */
+export type RemoveAllowedMintParams = {
+  whirlpoolsConfig: PublicKey;
+  poolCreatorAuthority: PublicKey;
+  mint: PublicKey;
+};
+
+/**
+ * Removes a token mint from the allowlist of the pool creation policy of a WhirlpoolsConfig.
+ * Only the current pool creator authority has permission to invoke this instruction.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - RemoveAllowedMintParams object
+ * @returns - Instruction to perform the action.
+ */
+export function removeAllowedMintIx(
+  program: Program<Whirlpool>,
+  params: RemoveAllowedMintParams
+): Instruction {
+  const { whirlpoolsConfig, poolCreatorAuthority, mint } = params;
+
+  const poolCreationPolicyPda = PDAUtil.getPoolCreationPolicy(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.removeAllowedMint(mint, {
+    accounts: {
+      whirlpoolsConfig,
+      poolCreatorAuthority,
+      poolCreationPolicy: poolCreationPolicyPda.publicKey,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
@@ -0,0 +1,128 @@
+import { Instruction } from "@orca-so/common-sdk";
+import { BN, Program } from "@project-serum/anchor";
+import { TOKEN_PROGRAM_ID, u64 } from "@solana/spl-token";
+import { AccountMeta, PublicKey } from "@solana/web3.js";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { ExtensionUtil, ROUTE_HOP_EXTENSIONS } from "../utils/public";
+
+/**
+ * Parameters to execute a swap across an ordered route of Whirlpools.
+ *
+ * @category Instruction Types
+ * @param amount - The amount of input or output token to swap from (depending on amountSpecifiedIsInput).
+ * @param otherAmountThreshold - The maximum/minimum of input/output token to swap into (depending on amountSpecifiedIsInput).
+ * @param amountSpecifiedIsInput - Specifies the token the parameter `amount`represents. If true, the amount represents
+ *                                 the input token of the first hop. If false, the output token of the last hop.
+ * @param hops - The hops of the route in {@link RouteSwapHop}, in order.
+ * @param tokenAuthority - authority to withdraw tokens from the input token account
+ */
+export type RouteSwapParams = {
+  amount: u64;
+  otherAmountThreshold: u64;
+  amountSpecifiedIsInput: boolean;
+  hops: RouteSwapHop[];
+  tokenAuthority: PublicKey;
+};
+
+/**
+ * Parameters that define a single hop of a route swap.
+ *
+ * @category Instruction Types
+ * @param whirlpool - PublicKey for the whirlpool that the hop will occur on
+ * @param whirlpoolsConfig - PublicKey for the WhirlpoolsConfig of the whirlpool.
+ * @param tokenOwnerAccountA - PublicKey for the associated token account for tokenA in the collection wallet
+ * @param tokenOwnerAccountB - PublicKey for the associated token account for tokenB in the collection wallet
+ * @param tokenVaultA - PublicKey for the tokenA vault for this whirlpool.
+ * @param tokenVaultB - PublicKey for the tokenB vault for this whirlpool.
+ * @param tickArray0 - PublicKey of the tick-array where the Whirlpool's currentTickIndex resides in
+ * @param tickArray1 - The next tick-array in the swap direction. If the swap will not reach the next tick-aray, input the same array as tickArray0.
+ * @param tickArray2 - The next tick-array in the swap direction after tickArray2. If the swap will not reach the next tick-aray, input the same array as tickArray1.
+ * @param aToB - The direction of the hop. True if swapping from A to B. False if swapping from B to A.
+ * @param sqrtPriceLimit - The maximum/minimum price the hop will swap to.
+ * @param extensions - The extensions enabled on the whirlpool, see {@link WhirlpoolData}. Defaults to none.
+ */
+export type RouteSwapHop = {
+  whirlpool: PublicKey;
+  whirlpoolsConfig: PublicKey;
+  tokenOwnerAccountA: PublicKey;
+  tokenOwnerAccountB: PublicKey;
+  tokenVaultA: PublicKey;
+  tokenVaultB: PublicKey;
+  tickArray0: PublicKey;
+  tickArray1: PublicKey;
+  tickArray2: PublicKey;
+  aToB: boolean;
+  sqrtPriceLimit: BN;
+  extensions?: number;
+};
+
+/**
+ * Perform a swap across an ordered route of Whirlpools. The output of each hop is used as the
+ * input of the next hop.
+ *
+ * #### Special Errors
+ * - `InvalidRouteAccounts` - The hops are empty or their accounts are invalid.
+ * - `InvalidIntermediaryMint` - Error if the output mint of a hop does not equal the input mint of the next hop.
+ * - `DuplicateRoutePool` - Error if a whirlpool is visited more than once.
+ * - `InvalidExtensionAccount` - The side account of an extension enabled on a whirlpool is missing.
+ * - `AmountOutBelowMinimum` - In exact-out mode, the last hop cannot provide the full specified output amount.
+ * - `IntermediateTokenAmountMismatch` - In exact-out mode, a hop cannot provide the full input required by the next hop.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - {@link RouteSwapParams} object
+ * @returns - Instruction to perform the action.
+ */
+export function routeSwapIx(program: Program<Whirlpool>, params: RouteSwapParams): Instruction {
+  const { amount, otherAmountThreshold, amountSpecifiedIsInput, hops, tokenAuthority } = params;
+
+  const ix = program.instruction.routeSwap(
+    amount,
+    otherAmountThreshold,
+    amountSpecifiedIsInput,
+    hops.map((hop) => hop.aToB),
+    hops.map((hop) => hop.sqrtPriceLimit),
+    {
+      accounts: {
+        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenAuthority,
+      },
+      remainingAccounts: routeSwapRemainingAccounts(program, hops),
+    }
+  );
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
+
+// The accounts of each hop are followed by the side accounts of its extensions. The pause controls
+// come last, once per config.
+function routeSwapRemainingAccounts(program: Program<Whirlpool>, hops: RouteSwapHop[]) {
+  const remainingAccounts: AccountMeta[] = [];
+  for (const hop of hops) {
+    remainingAccounts.push(
+      ...[
+        hop.whirlpool,
+        hop.tokenOwnerAccountA,
+        hop.tokenVaultA,
+        hop.tokenOwnerAccountB,
+        hop.tokenVaultB,
+        hop.tickArray0,
+        hop.tickArray1,
+        hop.tickArray2,
+      ].map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })),
+      ...ExtensionUtil.getRemainingAccounts(
+        program.programId,
+        hop.whirlpool,
+        hop.extensions ?? 0,
+        ROUTE_HOP_EXTENSIONS
+      )
+    );
+  }
+  remainingAccounts.push(...ExtensionUtil.getPauseControlsPerConfig(program.programId, hops));
+
+  return remainingAccounts;
+}
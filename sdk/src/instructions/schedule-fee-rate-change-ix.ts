@@ -0,0 +1,66 @@
+import { BN, Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to schedule a fee rate change of a Whirlpool
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig this pool is initialized in
+ * @param whirlpool - PublicKey for the whirlpool whose fee rates will change.
+ * @param feeAuthority - Authority authorized in the WhirlpoolsConfig to set fee rates.
+ * @param feeRate - The new fee rate of the whirlpool. Stored as a hundredths of a basis point.
+ * @param protocolFeeRate - The new protocol fee rate of the whirlpool. Stored as a basis point.
+ * @param effectiveAt - The unix timestamp from which the new rates apply.
+ */
+export type ScheduleFeeRateChangeParams = {
+  whirlpoolsConfig: PublicKey;
+  whirlpool: PublicKey;
+  feeAuthority: PublicKey;
+  feeRate: number;
+  protocolFeeRate: number;
+  effectiveAt: BN;
+};
+
+/**
+ * Schedules a change of the fee rate & protocol fee rate of a Whirlpool.
+ * The new rates are applied by the first swap or liquidity change at or after `effectiveAt`.
+ * Scheduling a change replaces any change that is still pending.
+ * Only the current fee authority has permission to invoke this instruction.
+ *
+ * #### Special Errors
+ * - `FeeRateMaxExceeded` - If the provided feeRate exceeds MAX_FEE_RATE.
+ * - `ProtocolFeeRateMaxExceeded` - If the provided protocolFeeRate exceeds MAX_PROTOCOL_FEE_RATE.
+ * - `FeeRateChangeTooEarly` - If `effectiveAt` is less than MIN_FEE_RATE_CHANGE_DELAY seconds away.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - ScheduleFeeRateChangeParams object
+ * @returns - Instruction to perform the action.
+ */
+export function scheduleFeeRateChangeIx(
+  program: Program<Whirlpool>,
+  params: ScheduleFeeRateChangeParams
+): Instruction {
+  const { whirlpoolsConfig, whirlpool, feeAuthority, feeRate, protocolFeeRate, effectiveAt } =
+    params;
+
+  const feeRateSchedulePda = PDAUtil.getFeeRateSchedule(program.programId, whirlpool);
+
+  const ix = program.instruction.scheduleFeeRateChange(feeRate, protocolFeeRate, effectiveAt, {
+    accounts: {
+      whirlpoolsConfig,
+      whirlpool,
+      feeAuthority,
+      feeRateSchedule: feeRateSchedulePda.publicKey,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
@@ -0,0 +1,57 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to set the allowed tick spacings of the fee tier policy of a WhirlpoolsConfig
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig
+ * @param feeAuthority - The current feeAuthority in the WhirlpoolsConfig
+ * @param allowedTickSpacings - The tick spacings that fee tiers can be initialized for. Every tick spacing is allowed if empty.
+ */
+export type SetAllowedTickSpacingsParams = {
+  whirlpoolsConfig: PublicKey;
+  feeAuthority: PublicKey;
+  allowedTickSpacings: number[];
+};
+
+/**
+ * Sets the tick spacings that fee tiers can be initialized for in a WhirlpoolsConfig.
+ * No new pools can be created, and no positions can be opened or rebalanced on the pools of a
+ * tick spacing that is not allowed.
+ * Only the current fee authority has permission to invoke this instruction.
+ *
+ * #### Special Errors
+ * - `InvalidTickSpacing` - If a provided tick spacing is zero.
+ * - `TooManyTickSpacings` - If more than MAX_POLICY_TICK_SPACINGS tick spacings are provided.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - SetAllowedTickSpacingsParams object
+ * @returns - Instruction to perform the action.
+ */
+export function setAllowedTickSpacingsIx(
+  program: Program<Whirlpool>,
+  params: SetAllowedTickSpacingsParams
+): Instruction {
+  const { whirlpoolsConfig, feeAuthority, allowedTickSpacings } = params;
+
+  const feeTierPolicyPda = PDAUtil.getFeeTierPolicy(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.setAllowedTickSpacings(allowedTickSpacings, {
+    accounts: {
+      whirlpoolsConfig,
+      feeAuthority,
+      feeTierPolicy: feeTierPolicyPda.publicKey,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
@@ -2,6 +2,7 @@ import { Program } from "@project-serum/anchor";
 import { Whirlpool } from "../artifacts/whirlpool";
 import { Instruction } from "@orca-so/common-sdk";
 import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
 
 /**
  * Parameters to set the collect fee authority in a WhirlpoolsConfig
@@ -18,7 +19,8 @@ export type SetCollectProtocolFeesAuthorityParams = {
 };
 
 /**
- * Sets the fee authority to collect protocol fees for a WhirlpoolsConfig.
+ * Proposes a new authority to collect protocol fees for a WhirlpoolsConfig.
+ * The transfer takes effect once the new authority invokes {@link acceptCollectProtocolFeesAuthorityIx}.
  * Only the current collect protocol fee authority has permission to invoke this instruction.
  *
  * @category Instructions
@@ -33,11 +35,14 @@ export function setCollectProtocolFeesAuthorityIx(
   const { whirlpoolsConfig, collectProtocolFeesAuthority, newCollectProtocolFeesAuthority } =
     params;
 
+  const pendingAuthoritiesPda = PDAUtil.getPendingAuthorities(program.programId, whirlpoolsConfig);
+
   const ix = program.instruction.setCollectProtocolFeesAuthority({
     accounts: {
       whirlpoolsConfig,
       collectProtocolFeesAuthority,
       newCollectProtocolFeesAuthority,
+      pendingAuthorities: pendingAuthoritiesPda.publicKey,
     },
   });
 
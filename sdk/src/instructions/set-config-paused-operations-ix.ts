@@ -0,0 +1,54 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to set the operations that are paused on every pool of a WhirlpoolsConfig
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig
+ * @param poolCreatorAuthority - The current poolCreatorAuthority in the WhirlpoolsConfig
+ * @param pausedOperations - Bitmask of the PAUSE_* constants, PAUSE_ALL to pause every operation
+ */
+export type SetConfigPausedOperationsParams = {
+  whirlpoolsConfig: PublicKey;
+  poolCreatorAuthority: PublicKey;
+  pausedOperations: number;
+};
+
+/**
+ * Sets the operations that are paused on every pool of a WhirlpoolsConfig.
+ * Only the current pool creator authority has permission to invoke this instruction.
+ *
+ * #### Special Errors
+ * - `InvalidPausedOperations` - If the bitmask contains unknown operations.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - SetConfigPausedOperationsParams object
+ * @returns - Instruction to perform the action.
+ */
+export function setConfigPausedOperationsIx(
+  program: Program<Whirlpool>,
+  params: SetConfigPausedOperationsParams
+): Instruction {
+  const { whirlpoolsConfig, poolCreatorAuthority, pausedOperations } = params;
+
+  const pauseControlsPda = PDAUtil.getPauseControls(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.setConfigPausedOperations(pausedOperations, {
+    accounts: {
+      whirlpoolsConfig,
+      poolCreatorAuthority,
+      pauseControls: pauseControlsPda.publicKey,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
@@ -2,6 +2,7 @@ import { Program } from "@project-serum/anchor";
 import { Whirlpool } from "../artifacts/whirlpool";
 import { Instruction } from "@orca-so/common-sdk";
 import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
 
 /**
  * Parameters to set the fee authority in a WhirlpoolsConfig
@@ -18,8 +19,9 @@ export type SetFeeAuthorityParams = {
 };
 
 /**
- * Sets the fee authority for a WhirlpoolsConfig.
+ * Proposes a new fee authority for a WhirlpoolsConfig.
  * The fee authority can set the fee & protocol fee rate for individual pools or set the default fee rate for newly minted pools.
+ * The transfer takes effect once the new fee authority invokes {@link acceptFeeAuthorityIx}.
  * Only the current fee authority has permission to invoke this instruction.
  *
  * @category Instructions
@@ -33,11 +35,14 @@ export function setFeeAuthorityIx(
 ): Instruction {
   const { whirlpoolsConfig, feeAuthority, newFeeAuthority } = params;
 
+  const pendingAuthoritiesPda = PDAUtil.getPendingAuthorities(program.programId, whirlpoolsConfig);
+
   const ix = program.instruction.setFeeAuthority({
     accounts: {
       whirlpoolsConfig,
       feeAuthority,
       newFeeAuthority,
+      pendingAuthorities: pendingAuthoritiesPda.publicKey,
     },
   });
 
@@ -2,6 +2,7 @@ import { Program } from "@project-serum/anchor";
 import { Whirlpool } from "../artifacts/whirlpool";
 import { Instruction } from "@orca-so/common-sdk";
 import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
 
 /**
  * Parameters to set fee rate for a Whirlpool.
@@ -21,6 +22,9 @@ export type SetFeeRateParams = {
 
 /**
  * Sets the fee rate for a Whirlpool.
+ * The change is scheduled through the fee rate schedule of the Whirlpool, which has to be
+ * initialized first, and applies MIN_FEE_RATE_CHANGE_DELAY seconds later. The protocol fee rate of a
+ * pending change is kept.
  * Only the current fee authority has permission to invoke this instruction.
  *
  * #### Special Errors
@@ -34,11 +38,14 @@ export type SetFeeRateParams = {
 export function setFeeRateIx(program: Program<Whirlpool>, params: SetFeeRateParams): Instruction {
   const { whirlpoolsConfig, whirlpool, feeAuthority, feeRate } = params;
 
+  const feeRateSchedulePda = PDAUtil.getFeeRateSchedule(program.programId, whirlpool);
+
   const ix = program.instruction.setFeeRate(feeRate, {
     accounts: {
       whirlpoolsConfig,
       whirlpool,
       feeAuthority,
+      feeRateSchedule: feeRateSchedulePda.publicKey,
     },
   });
 
@@ -0,0 +1,58 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to enable or disable the creation of new pools on a FeeTier.
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig this fee-tier is initialized in
+ * @param feeAuthority - The current feeAuthority in the WhirlpoolsConfig
+ * @param tickSpacing - The tick spacing of the fee-tier that we would like to update.
+ * @param isEnabled - Whether new pools can be created on the fee-tier.
+ */
+export type SetFeeTierEnabledParams = {
+  whirlpoolsConfig: PublicKey;
+  feeAuthority: PublicKey;
+  tickSpacing: number;
+  isEnabled: boolean;
+};
+
+/**
+ * Enables or disables the creation of new pools on a FeeTier. Existing pools of the fee-tier are not affected.
+ * Requires the fee tier policy of the WhirlpoolsConfig to be initialized.
+ *
+ * #### Special Errors
+ * - `TooManyTickSpacings` - If MAX_POLICY_TICK_SPACINGS fee tiers are already disabled.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - SetFeeTierEnabledParams object
+ * @returns - Instruction to perform the action.
+ */
+export function setFeeTierEnabledIx(
+  program: Program<Whirlpool>,
+  params: SetFeeTierEnabledParams
+): Instruction {
+  const { whirlpoolsConfig, feeAuthority, tickSpacing, isEnabled } = params;
+
+  const feeTierPda = PDAUtil.getFeeTier(program.programId, whirlpoolsConfig, tickSpacing);
+  const feeTierPolicyPda = PDAUtil.getFeeTierPolicy(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.setFeeTierEnabled(isEnabled, {
+    accounts: {
+      whirlpoolsConfig,
+      feeTier: feeTierPda.publicKey,
+      feeAuthority,
+      feeTierPolicy: feeTierPolicyPda.publicKey,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
@@ -0,0 +1,62 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to set the metadata settings of a WhirlpoolsConfig
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig
+ * @param feeAuthority - The current feeAuthority in the WhirlpoolsConfig
+ * @param updateAuthority - The update authority of the position metadata accounts
+ * @param name - The name of the position NFTs
+ * @param symbol - The symbol of the position NFTs
+ * @param uriTemplate - The URI of the position NFTs. `{position_mint}`, `{whirlpool}`, `{token_mint_a}`,
+ *                      `{token_mint_b}`, `{tick_lower_index}` and `{tick_upper_index}` are replaced by the values of the position
+ */
+export type SetMetadataSettingsParams = {
+  whirlpoolsConfig: PublicKey;
+  feeAuthority: PublicKey;
+  updateAuthority: PublicKey;
+  name: string;
+  symbol: string;
+  uriTemplate: string;
+};
+
+/**
+ * Sets the metadata settings of a WhirlpoolsConfig. Positions that are already open keep their metadata.
+ * Only the current fee authority has permission to invoke this instruction.
+ *
+ * #### Special Errors
+ * - `InvalidPositionMetadata` - If a value exceeds the length limits of the metadata program,
+ *                               or if the URI template can expand to a URI that exceeds it.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - SetMetadataSettingsParams object
+ * @returns - Instruction to perform the action.
+ */
+export function setMetadataSettingsIx(
+  program: Program<Whirlpool>,
+  params: SetMetadataSettingsParams
+): Instruction {
+  const { whirlpoolsConfig, feeAuthority, updateAuthority, name, symbol, uriTemplate } = params;
+
+  const metadataSettingsPda = PDAUtil.getMetadataSettings(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.setMetadataSettings(updateAuthority, name, symbol, uriTemplate, {
+    accounts: {
+      whirlpoolsConfig,
+      feeAuthority,
+      metadataSettings: metadataSettingsPda.publicKey,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
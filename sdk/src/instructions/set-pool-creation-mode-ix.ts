@@ -0,0 +1,54 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to set the mode of the pool creation policy of a WhirlpoolsConfig
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig
+ * @param poolCreatorAuthority - The current poolCreatorAuthority in the WhirlpoolsConfig
+ * @param mode - The pool creation mode, one of the POOL_CREATION_* constants
+ */
+export type SetPoolCreationModeParams = {
+  whirlpoolsConfig: PublicKey;
+  poolCreatorAuthority: PublicKey;
+  mode: number;
+};
+
+/**
+ * Sets the mode of the pool creation policy of a WhirlpoolsConfig.
+ * Only the current pool creator authority has permission to invoke this instruction.
+ *
+ * #### Special Errors
+ * - `InvalidPoolCreationMode` - If the provided mode is unknown.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - SetPoolCreationModeParams object
+ * @returns - Instruction to perform the action.
+ */
+export function setPoolCreationModeIx(
+  program: Program<Whirlpool>,
+  params: SetPoolCreationModeParams
+): Instruction {
+  const { whirlpoolsConfig, poolCreatorAuthority, mode } = params;
+
+  const poolCreationPolicyPda = PDAUtil.getPoolCreationPolicy(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.setPoolCreationMode(mode, {
+    accounts: {
+      whirlpoolsConfig,
+      poolCreatorAuthority,
+      poolCreationPolicy: poolCreationPolicyPda.publicKey,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
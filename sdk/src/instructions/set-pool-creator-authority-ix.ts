@@ -2,6 +2,7 @@ import { Program } from "@project-serum/anchor";
 import { Whirlpool } from "../artifacts/whirlpool";
 import { Instruction } from "@orca-so/common-sdk";
 import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
 
 /**
  * Parameters to set the pool creator authority in a WhirlpoolsConfig
@@ -18,9 +19,9 @@ export type SetPoolCreatorAuthorityParams = {
 };
 
 /**
- * Sets the fee authority for a WhirlpoolsConfig.
- * The fee authority can set the fee & protocol fee rate for individual pools or set the default fee rate for newly minted pools.
- * Only the current fee authority has permission to invoke this instruction.
+ * Proposes a new pool creator authority for a WhirlpoolsConfig.
+ * The transfer takes effect once the new authority invokes {@link acceptPoolCreatorAuthorityIx}.
+ * Only the current pool creator authority has permission to invoke this instruction.
  *
  * @category Instructions
  * @param context - Context object containing services required to generate the instruction
@@ -33,11 +34,14 @@ export function setPoolCreatorAuthorityIx(
 ): Instruction {
   const { whirlpoolsConfig, poolCreatorAuthority, newPoolCreatorAuthority } = params;
 
+  const pendingAuthoritiesPda = PDAUtil.getPendingAuthorities(program.programId, whirlpoolsConfig);
+
   const ix = program.instruction.setPoolCreatorAuthority({
     accounts: {
       whirlpoolsConfig,
       poolCreatorAuthority,
       newPoolCreatorAuthority,
+      pendingAuthorities: pendingAuthoritiesPda.publicKey,
     },
   });
 
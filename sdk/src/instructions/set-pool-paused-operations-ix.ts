@@ -0,0 +1,58 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to set the operations that are paused on a single Whirlpool
+ *
+ * @category Instruction Types
+ * @param whirlpoolsConfig - The public key for the WhirlpoolsConfig of the whirlpool
+ * @param whirlpool - PublicKey for the whirlpool that the operations will be paused on
+ * @param poolCreatorAuthority - The current poolCreatorAuthority in the WhirlpoolsConfig
+ * @param pausedOperations - Bitmask of the PAUSE_* constants, 0 to resume every operation of the pool
+ */
+export type SetPoolPausedOperationsParams = {
+  whirlpoolsConfig: PublicKey;
+  whirlpool: PublicKey;
+  poolCreatorAuthority: PublicKey;
+  pausedOperations: number;
+};
+
+/**
+ * Sets the operations that are paused on a single Whirlpool, in addition to the ones paused on its WhirlpoolsConfig.
+ * Only the current pool creator authority has permission to invoke this instruction.
+ *
+ * #### Special Errors
+ * - `InvalidPausedOperations` - If the bitmask contains unknown operations.
+ * - `PausedPoolsFull` - If MAX_PAUSED_POOLS pools already have paused operations.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - SetPoolPausedOperationsParams object
+ * @returns - Instruction to perform the action.
+ */
+export function setPoolPausedOperationsIx(
+  program: Program<Whirlpool>,
+  params: SetPoolPausedOperationsParams
+): Instruction {
+  const { whirlpoolsConfig, whirlpool, poolCreatorAuthority, pausedOperations } = params;
+
+  const pauseControlsPda = PDAUtil.getPauseControls(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.setPoolPausedOperations(pausedOperations, {
+    accounts: {
+      whirlpoolsConfig,
+      whirlpool,
+      poolCreatorAuthority,
+      pauseControls: pauseControlsPda.publicKey,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
@@ -2,6 +2,7 @@ import { Program } from "@project-serum/anchor";
 import { Whirlpool } from "../artifacts/whirlpool";
 import { Instruction } from "@orca-so/common-sdk";
 import { PublicKey } from "@solana/web3.js";
+import { PDAUtil } from "../utils/public";
 
 /**
  * Parameters to set rewards emissions for a reward in a Whirlpool
@@ -18,7 +19,8 @@ export type SetRewardEmissionsSuperAuthorityParams = {
 };
 
 /**
- * Set the whirlpool reward super authority for a WhirlpoolsConfig
+ * Propose a new whirlpool reward super authority for a WhirlpoolsConfig
+ * The transfer takes effect once the new authority invokes {@link acceptRewardEmissionsSuperAuthorityIx}.
  * Only the current reward super authority has permission to invoke this instruction.
  * This instruction will not change the authority on any `WhirlpoolRewardInfo` whirlpool rewards.
  *
@@ -34,11 +36,14 @@ export function setRewardEmissionsSuperAuthorityIx(
   const { whirlpoolsConfig, rewardEmissionsSuperAuthority, newRewardEmissionsSuperAuthority } =
     params;
 
+  const pendingAuthoritiesPda = PDAUtil.getPendingAuthorities(program.programId, whirlpoolsConfig);
+
   const ix = program.instruction.setRewardEmissionsSuperAuthority({
     accounts: {
       whirlpoolsConfig,
       rewardEmissionsSuperAuthority: rewardEmissionsSuperAuthority,
       newRewardEmissionsSuperAuthority,
+      pendingAuthorities: pendingAuthoritiesPda.publicKey,
     },
   });
 
@@ -0,0 +1,139 @@
+import { Instruction } from "@orca-so/common-sdk";
+import { BN, Program } from "@project-serum/anchor";
+import { TOKEN_PROGRAM_ID, u64 } from "@solana/spl-token";
+import { AccountMeta, PublicKey } from "@solana/web3.js";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { ExtensionUtil, SPLIT_SWAP_LEG_EXTENSIONS } from "../utils/public";
+
+/**
+ * Parameters to execute a swap split across several Whirlpools of the same token pair.
+ *
+ * @category Instruction Types
+ * @param amount - The total amount of input or output token to swap from (depending on amountSpecifiedIsInput).
+ * @param otherAmountThreshold - The maximum/minimum of combined input/output token to swap into (depending on amountSpecifiedIsInput).
+ * @param amountSpecifiedIsInput - Specifies the token the parameter `amount`represents. If true, the amount represents
+ *                                 the input token of the swap.
+ * @param aToB - The direction of the swap. True if swapping from A to B. False if swapping from B to A.
+ * @param legs - The legs of the split in {@link SplitSwapLeg}.
+ * @param tokenOwnerAccountA - PublicKey for the associated token account for tokenA in the collection wallet
+ * @param tokenOwnerAccountB - PublicKey for the associated token account for tokenB in the collection wallet
+ * @param tokenAuthority - authority to withdraw tokens from the input token account
+ */
+export type SplitSwapParams = {
+  amount: u64;
+  otherAmountThreshold: u64;
+  amountSpecifiedIsInput: boolean;
+  aToB: boolean;
+  legs: SplitSwapLeg[];
+  tokenOwnerAccountA: PublicKey;
+  tokenOwnerAccountB: PublicKey;
+  tokenAuthority: PublicKey;
+};
+
+/**
+ * Parameters that define a single leg of a split swap.
+ *
+ * @category Instruction Types
+ * @param whirlpool - PublicKey for the whirlpool that the leg will occur on
+ * @param whirlpoolsConfig - PublicKey for the WhirlpoolsConfig of the whirlpool.
+ * @param tokenVaultA - PublicKey for the tokenA vault for this whirlpool.
+ * @param tokenVaultB - PublicKey for the tokenB vault for this whirlpool.
+ * @param tickArray0 - PublicKey of the tick-array where the Whirlpool's currentTickIndex resides in
+ * @param tickArray1 - The next tick-array in the swap direction. If the swap will not reach the next tick-aray, input the same array as tickArray0.
+ * @param tickArray2 - The next tick-array in the swap direction after tickArray2. If the swap will not reach the next tick-aray, input the same array as tickArray1.
+ * @param splitBps - The share of the amount swapped on this leg in basis points. The shares of all legs have to sum up to 10_000.
+ * @param sqrtPriceLimit - The maximum/minimum price the leg will swap to.
+ * @param extensions - The extensions enabled on the whirlpool, see {@link WhirlpoolData}. Defaults to none.
+ */
+export type SplitSwapLeg = {
+  whirlpool: PublicKey;
+  whirlpoolsConfig: PublicKey;
+  tokenVaultA: PublicKey;
+  tokenVaultB: PublicKey;
+  tickArray0: PublicKey;
+  tickArray1: PublicKey;
+  tickArray2: PublicKey;
+  splitBps: number;
+  sqrtPriceLimit: BN;
+  extensions?: number;
+};
+
+/**
+ * Perform a swap that splits a single amount across several Whirlpools of the same token pair,
+ * e.g. pools of different fee tiers. The slippage threshold applies to the combined amounts of all
+ * legs.
+ *
+ * #### Special Errors
+ * - `InvalidSplitSwap` - The legs do not trade the same token pair, their accounts are invalid or
+ *                        their `splitBps` do not sum up to 10_000.
+ * - `DuplicateRoutePool` - Error if a whirlpool is used by more than one leg.
+ * - `InvalidExtensionAccount` - The side account of an extension enabled on a whirlpool is missing.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - {@link SplitSwapParams} object
+ * @returns - Instruction to perform the action.
+ */
+export function splitSwapIx(program: Program<Whirlpool>, params: SplitSwapParams): Instruction {
+  const {
+    amount,
+    otherAmountThreshold,
+    amountSpecifiedIsInput,
+    aToB,
+    legs,
+    tokenOwnerAccountA,
+    tokenOwnerAccountB,
+    tokenAuthority,
+  } = params;
+
+  const ix = program.instruction.splitSwap(
+    amount,
+    otherAmountThreshold,
+    amountSpecifiedIsInput,
+    aToB,
+    legs.map((leg) => leg.splitBps),
+    legs.map((leg) => leg.sqrtPriceLimit),
+    {
+      accounts: {
+        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenAuthority,
+        tokenOwnerAccountA,
+        tokenOwnerAccountB,
+      },
+      remainingAccounts: splitSwapRemainingAccounts(program, legs),
+    }
+  );
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
+
+// The accounts of each leg are followed by the side accounts of its extensions. The pause controls
+// come last, once per config.
+function splitSwapRemainingAccounts(program: Program<Whirlpool>, legs: SplitSwapLeg[]) {
+  const remainingAccounts: AccountMeta[] = [];
+  for (const leg of legs) {
+    remainingAccounts.push(
+      ...[
+        leg.whirlpool,
+        leg.tokenVaultA,
+        leg.tokenVaultB,
+        leg.tickArray0,
+        leg.tickArray1,
+        leg.tickArray2,
+      ].map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })),
+      ...ExtensionUtil.getRemainingAccounts(
+        program.programId,
+        leg.whirlpool,
+        leg.extensions ?? 0,
+        SPLIT_SWAP_LEG_EXTENSIONS
+      )
+    );
+  }
+  remainingAccounts.push(...ExtensionUtil.getPauseControlsPerConfig(program.programId, legs));
+
+  return remainingAccounts;
+}
@@ -3,6 +3,9 @@ import { BN, Program } from "@project-serum/anchor";
 import { TOKEN_PROGRAM_ID, u64 } from "@solana/spl-token";
 import { PublicKey } from "@solana/web3.js";
 import { Whirlpool } from "../artifacts/whirlpool";
+import { nativeWrapAccounts, tokenProgramAccounts } from "../utils/instructions-util";
+import { ExtensionUtil, PDAUtil, SWAP_EXTENSIONS } from "../utils/public";
+import { TokenProgramParams } from "./initialize-pool-ix";
 
 /**
  * Raw parameters and accounts to swap on a Whirlpool
@@ -10,23 +13,35 @@ import { Whirlpool } from "../artifacts/whirlpool";
  * @category Instruction Types
  * @param swapInput - Parameters in {@link SwapInput}
  * @param whirlpool - PublicKey for the whirlpool that the swap will occur on
+ * @param whirlpoolsConfig - PublicKey for the WhirlpoolsConfig of the whirlpool.
  * @param tokenOwnerAccountA - PublicKey for the associated token account for tokenA in the collection wallet
  * @param tokenOwnerAccountB - PublicKey for the associated token account for tokenB in the collection wallet
  * @param tokenVaultA - PublicKey for the tokenA vault for this whirlpool.
  * @param tokenVaultB - PublicKey for the tokenB vault for this whirlpool.
  * @param oracle - PublicKey for the oracle account for this Whirlpool.
+ * @param extensions - The extensions enabled on the whirlpool, see {@link WhirlpoolData}. Defaults to none.
  * @param tokenAuthority - authority to withdraw tokens from the input token account
  */
 export type SwapParams = SwapInput & {
   whirlpool: PublicKey;
+  whirlpoolsConfig: PublicKey;
   tokenOwnerAccountA: PublicKey;
   tokenOwnerAccountB: PublicKey;
   tokenVaultA: PublicKey;
   tokenVaultB: PublicKey;
   oracle: PublicKey;
+  extensions?: number;
   tokenAuthority: PublicKey;
 };
 
+/**
+ * Raw parameters and accounts to swap on a Whirlpool whose mints may belong to the Token-2022
+ * program.
+ *
+ * @category Instruction Types
+ */
+export type SwapV2Params = SwapParams & TokenProgramParams;
+
 /**
  * Parameters that describe the nature of a swap on a Whirlpool.
  *
@@ -83,25 +98,46 @@ export type DevFeeSwapInput = SwapInput & {
  * @returns - Instruction to perform the action.
  */
 export function swapIx(program: Program<Whirlpool>, params: SwapParams): Instruction {
-  const {
+  const { amount, otherAmountThreshold, sqrtPriceLimit, amountSpecifiedIsInput, aToB } = params;
+
+  const ix = program.instruction.swap(
     amount,
     otherAmountThreshold,
     sqrtPriceLimit,
     amountSpecifiedIsInput,
     aToB,
-    whirlpool,
-    tokenAuthority,
-    tokenOwnerAccountA,
-    tokenVaultA,
-    tokenOwnerAccountB,
-    tokenVaultB,
-    tickArray0,
-    tickArray1,
-    tickArray2,
-    oracle,
-  } = params;
+    {
+      accounts: swapAccounts(program, params),
+      remainingAccounts: swapRemainingAccounts(program, params),
+    }
+  );
+  ExtensionUtil.markOracleWritable(ix, params.oracle, params.extensions ?? 0);
 
-  const ix = program.instruction.swap(
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
+
+/**
+ * Perform a swap in this Whirlpool from and/or into the lamport balance of the token authority.
+ * The native side of the swap goes through a temporary wrapped RENEC account, which is closed back to
+ * the token authority once the swap is done. Pass the address from `PDAUtil.getNativeWrap` as the
+ * token owner account of the native mint.
+ *
+ * #### Special Errors
+ * Same as {@link swapIx}.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - {@link SwapParams}
+ * @returns - Instruction to perform the action.
+ */
+export function swapWithNativeIx(program: Program<Whirlpool>, params: SwapParams): Instruction {
+  const { amount, otherAmountThreshold, sqrtPriceLimit, amountSpecifiedIsInput, aToB } = params;
+
+  const ix = program.instruction.swapWithNative(
     amount,
     otherAmountThreshold,
     sqrtPriceLimit,
@@ -109,20 +145,53 @@ export function swapIx(program: Program<Whirlpool>, params: SwapParams): Instruc
     aToB,
     {
       accounts: {
-        tokenProgram: TOKEN_PROGRAM_ID,
-        tokenAuthority: tokenAuthority,
-        whirlpool,
-        tokenOwnerAccountA,
-        tokenVaultA,
-        tokenOwnerAccountB,
-        tokenVaultB,
-        tickArray0,
-        tickArray1,
-        tickArray2,
-        oracle,
+        tokenAuthority: params.tokenAuthority,
+        ...nativeWrapAccounts(program.programId, params.tokenAuthority),
+        swap: swapAccounts(program, params),
       },
+      remainingAccounts: swapRemainingAccounts(program, params),
+    }
+  );
+  ExtensionUtil.markOracleWritable(ix, params.oracle, params.extensions ?? 0);
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
+
+/**
+ * Perform a swap in a Whirlpool whose mints may belong to the Token-2022 program.
+ * An exact input `amount` and a maximum input `otherAmountThreshold` include the transfer fee of
+ * the input token, an exact output `amount` and a minimum output `otherAmountThreshold` exclude the
+ * transfer fee of the output token.
+ *
+ * #### Special Errors
+ * Same as {@link swapIx}.
+ * - `TransferFeeCalculationError` - The transfer fee of an amount could not be calculated.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - {@link SwapV2Params}
+ * @returns - Instruction to perform the action.
+ */
+export function swapV2Ix(program: Program<Whirlpool>, params: SwapV2Params): Instruction {
+  const { amount, otherAmountThreshold, sqrtPriceLimit, amountSpecifiedIsInput, aToB } = params;
+  const { tokenProgram, ...accounts } = swapAccounts(program, params);
+
+  const ix = program.instruction.swapV2(
+    amount,
+    otherAmountThreshold,
+    sqrtPriceLimit,
+    amountSpecifiedIsInput,
+    aToB,
+    {
+      accounts: { ...accounts, ...tokenProgramAccounts(params) },
+      remainingAccounts: swapRemainingAccounts(program, params),
     }
   );
+  ExtensionUtil.markOracleWritable(ix, params.oracle, params.extensions ?? 0);
 
   return {
     instructions: [ix],
@@ -130,3 +199,43 @@ export function swapIx(program: Program<Whirlpool>, params: SwapParams): Instruc
     signers: [],
   };
 }
+
+function swapAccounts(program: Program<Whirlpool>, params: SwapParams) {
+  const {
+    whirlpool,
+    whirlpoolsConfig,
+    tokenAuthority,
+    tokenOwnerAccountA,
+    tokenVaultA,
+    tokenOwnerAccountB,
+    tokenVaultB,
+    tickArray0,
+    tickArray1,
+    tickArray2,
+    oracle,
+  } = params;
+
+  return {
+    tokenProgram: TOKEN_PROGRAM_ID,
+    tokenAuthority: tokenAuthority,
+    whirlpool,
+    tokenOwnerAccountA,
+    tokenVaultA,
+    tokenOwnerAccountB,
+    tokenVaultB,
+    tickArray0,
+    tickArray1,
+    tickArray2,
+    oracle,
+    pauseControls: PDAUtil.getPauseControls(program.programId, whirlpoolsConfig).publicKey,
+  };
+}
+
+function swapRemainingAccounts(program: Program<Whirlpool>, params: SwapParams) {
+  return ExtensionUtil.getRemainingAccounts(
+    program.programId,
+    params.whirlpool,
+    params.extensions ?? 0,
+    SWAP_EXTENSIONS
+  );
+}
@@ -1,8 +1,10 @@
 import { Instruction } from "@orca-so/common-sdk";
 import { BN, Program } from "@project-serum/anchor";
 import { TOKEN_PROGRAM_ID, u64 } from "@solana/spl-token";
-import { PublicKey } from "@solana/web3.js";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
 import { Whirlpool } from "../artifacts/whirlpool";
+import { nativeWrapAccounts } from "../utils/instructions-util";
+import { ExtensionUtil, PDAUtil, SWAP_EXTENSIONS } from "../utils/public";
 
 /**
  * Parameters to execute a two-hop swap on a Whirlpool.
@@ -10,6 +12,8 @@ import { Whirlpool } from "../artifacts/whirlpool";
  * @category Instruction Types
  * @param whirlpoolOne - PublicKey for the whirlpool that the swap-one will occur on
  * @param whirlpoolTwo - PublicKey for the whirlpool that the swap-two will occur on
+ * @param whirlpoolsConfigOne - PublicKey for the WhirlpoolsConfig of whirlpoolOne.
+ * @param whirlpoolsConfigTwo - PublicKey for the WhirlpoolsConfig of whirlpoolTwo.
  * @param tokenOwnerAccountOneA - PublicKey for the associated token account for tokenA in whirlpoolOne in the collection wallet
  * @param tokenOwnerAccountOneB - PublicKey for the associated token account for tokenB in whirlpoolOne in the collection wallet
  * @param tokenOwnerAccountTwoA - PublicKey for the associated token account for tokenA in whirlpoolTwo in the collection wallet
@@ -20,12 +24,16 @@ import { Whirlpool } from "../artifacts/whirlpool";
  * @param tokenVaultTwoB - PublicKey for the tokenB vault for whirlpoolTwo.
  * @param oracleOne - PublicKey for the oracle account for this whirlpoolOne.
  * @param oracleTwo - PublicKey for the oracle account for this whirlpoolTwo.
+ * @param extensionsOne - The extensions enabled on whirlpoolOne, see {@link WhirlpoolData}. Defaults to none.
+ * @param extensionsTwo - The extensions enabled on whirlpoolTwo, see {@link WhirlpoolData}. Defaults to none.
  * @param tokenAuthority - authority to withdraw tokens from the input token account
  * @param swapInput - Parameters in {@link TwoHopSwapInput}
  */
 export type TwoHopSwapParams = TwoHopSwapInput & {
   whirlpoolOne: PublicKey;
   whirlpoolTwo: PublicKey;
+  whirlpoolsConfigOne: PublicKey;
+  whirlpoolsConfigTwo: PublicKey;
   tokenOwnerAccountOneA: PublicKey;
   tokenOwnerAccountOneB: PublicKey;
   tokenOwnerAccountTwoA: PublicKey;
@@ -36,6 +44,8 @@ export type TwoHopSwapParams = TwoHopSwapInput & {
   tokenVaultTwoB: PublicKey;
   oracleOne: PublicKey;
   oracleTwo: PublicKey;
+  extensionsOne?: number;
+  extensionsTwo?: number;
   tokenAuthority: PublicKey;
 };
 
@@ -104,8 +114,90 @@ export function twoHopSwapIx(program: Program<Whirlpool>, params: TwoHopSwapPara
     aToBTwo,
     sqrtPriceLimitOne,
     sqrtPriceLimitTwo,
+  } = params;
+
+  const ix = program.instruction.twoHopSwap(
+    amount,
+    otherAmountThreshold,
+    amountSpecifiedIsInput,
+    aToBOne,
+    aToBTwo,
+    sqrtPriceLimitOne,
+    sqrtPriceLimitTwo,
+    {
+      accounts: twoHopSwapAccounts(program, params),
+      remainingAccounts: twoHopSwapRemainingAccounts(program, params),
+    }
+  );
+  markOraclesWritable(ix, params);
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
+
+/**
+ * Perform a two-hop swap from and/or into the lamport balance of the token authority.
+ * The native side of the swap goes through a temporary wrapped RENEC account, which is closed back to
+ * the token authority once the swaps are done. Pass the address from `PDAUtil.getNativeWrap` as the
+ * token owner accounts of the native mint.
+ *
+ * #### Special Errors
+ * Same as {@link twoHopSwapIx}.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - {@link TwoHopSwapParams}
+ * @returns - Instruction to perform the action.
+ */
+export function twoHopSwapWithNativeIx(
+  program: Program<Whirlpool>,
+  params: TwoHopSwapParams
+): Instruction {
+  const {
+    amount,
+    otherAmountThreshold,
+    amountSpecifiedIsInput,
+    aToBOne,
+    aToBTwo,
+    sqrtPriceLimitOne,
+    sqrtPriceLimitTwo,
+  } = params;
+
+  const ix = program.instruction.twoHopSwapWithNative(
+    amount,
+    otherAmountThreshold,
+    amountSpecifiedIsInput,
+    aToBOne,
+    aToBTwo,
+    sqrtPriceLimitOne,
+    sqrtPriceLimitTwo,
+    {
+      accounts: {
+        tokenAuthority: params.tokenAuthority,
+        ...nativeWrapAccounts(program.programId, params.tokenAuthority),
+        twoHopSwap: twoHopSwapAccounts(program, params),
+      },
+      remainingAccounts: twoHopSwapRemainingAccounts(program, params),
+    }
+  );
+  markOraclesWritable(ix, params);
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
+
+function twoHopSwapAccounts(program: Program<Whirlpool>, params: TwoHopSwapParams) {
+  const {
     whirlpoolOne,
     whirlpoolTwo,
+    whirlpoolsConfigOne,
+    whirlpoolsConfigTwo,
     tokenAuthority,
     tokenOwnerAccountOneA,
     tokenVaultOneA,
@@ -122,46 +214,54 @@ export function twoHopSwapIx(program: Program<Whirlpool>, params: TwoHopSwapPara
     tickArrayTwo1,
     tickArrayTwo2,
     oracleOne,
-    oracleTwo
+    oracleTwo,
   } = params;
 
-  const ix = program.instruction.twoHopSwap(
-    amount,
-    otherAmountThreshold,
-    amountSpecifiedIsInput,
-    aToBOne,
-    aToBTwo,
-    sqrtPriceLimitOne,
-    sqrtPriceLimitTwo,
-    {
-      accounts: {
-        tokenProgram: TOKEN_PROGRAM_ID,
-        tokenAuthority,
-        whirlpoolOne,
-        whirlpoolTwo,
-        tokenOwnerAccountOneA,
-        tokenVaultOneA,
-        tokenOwnerAccountOneB,
-        tokenVaultOneB,
-        tokenOwnerAccountTwoA,
-        tokenVaultTwoA,
-        tokenOwnerAccountTwoB,
-        tokenVaultTwoB,
-        tickArrayOne0,
-        tickArrayOne1,
-        tickArrayOne2,
-        tickArrayTwo0,
-        tickArrayTwo1,
-        tickArrayTwo2,
-        oracleOne,
-        oracleTwo
-      },
-    }
-  );
-
   return {
-    instructions: [ix],
-    cleanupInstructions: [],
-    signers: [],
+    tokenProgram: TOKEN_PROGRAM_ID,
+    tokenAuthority,
+    whirlpoolOne,
+    whirlpoolTwo,
+    tokenOwnerAccountOneA,
+    tokenVaultOneA,
+    tokenOwnerAccountOneB,
+    tokenVaultOneB,
+    tokenOwnerAccountTwoA,
+    tokenVaultTwoA,
+    tokenOwnerAccountTwoB,
+    tokenVaultTwoB,
+    tickArrayOne0,
+    tickArrayOne1,
+    tickArrayOne2,
+    tickArrayTwo0,
+    tickArrayTwo1,
+    tickArrayTwo2,
+    oracleOne,
+    oracleTwo,
+    pauseControlsOne: PDAUtil.getPauseControls(program.programId, whirlpoolsConfigOne).publicKey,
+    pauseControlsTwo: PDAUtil.getPauseControls(program.programId, whirlpoolsConfigTwo).publicKey,
   };
 }
+
+// The side accounts of whirlpoolOne come before the ones of whirlpoolTwo.
+function twoHopSwapRemainingAccounts(program: Program<Whirlpool>, params: TwoHopSwapParams) {
+  return [
+    ...ExtensionUtil.getRemainingAccounts(
+      program.programId,
+      params.whirlpoolOne,
+      params.extensionsOne ?? 0,
+      SWAP_EXTENSIONS
+    ),
+    ...ExtensionUtil.getRemainingAccounts(
+      program.programId,
+      params.whirlpoolTwo,
+      params.extensionsTwo ?? 0,
+      SWAP_EXTENSIONS
+    ),
+  ];
+}
+
+function markOraclesWritable(ix: TransactionInstruction, params: TwoHopSwapParams) {
+  ExtensionUtil.markOracleWritable(ix, params.oracleOne, params.extensionsOne ?? 0);
+  ExtensionUtil.markOracleWritable(ix, params.oracleTwo, params.extensionsTwo ?? 0);
+}
@@ -3,6 +3,8 @@ import { Whirlpool } from "../artifacts/whirlpool";
 import { PublicKey } from "@solana/web3.js";
 
 import { Instruction } from "@orca-so/common-sdk";
+import { EXTENSION_RANGE_ORDER_BOOK } from "../types/public";
+import { ExtensionUtil, PDAUtil } from "../utils/public";
 
 /**
  * Parameters to update fees and reward values for a position.
@@ -10,12 +12,14 @@ import { Instruction } from "@orca-so/common-sdk";
  * @category Instruction Types
  * @param whirlpool - PublicKey for the whirlpool that the position will be opened for.
  * @param position - PublicKey for the  position will be opened for.
+ * @param extensions - The extensions enabled on the whirlpool, see {@link WhirlpoolData}. Defaults to none.
  * @param tickArrayLower - PublicKey for the tick-array account that hosts the tick at the lower tick index.
  * @param tickArrayUpper - PublicKey for the tick-array account that hosts the tick at the upper tick index.
  */
 export type UpdateFeesAndRewardsParams = {
   whirlpool: PublicKey;
   position: PublicKey;
+  extensions?: number;
   tickArrayLower: PublicKey;
   tickArrayUpper: PublicKey;
 };
@@ -26,6 +30,7 @@ export type UpdateFeesAndRewardsParams = {
  * #### Special Errors
  * `TickNotFound` - Provided tick array account does not contain the tick for this position.
  * `LiquidityZero` - Position has zero liquidity and therefore already has the most updated fees and reward values.
+ * `PositionHasRangeOrder` - The position has an open range order, its fees and rewards are accrued when it is withdrawn.
  *
  * @category Instructions
  * @param context - Context object containing services required to generate the instruction
@@ -36,7 +41,21 @@ export function updateFeesAndRewardsIx(
   program: Program<Whirlpool>,
   params: UpdateFeesAndRewardsParams
 ): Instruction {
-  const { whirlpool, position, tickArrayLower, tickArrayUpper } = params;
+  const { whirlpool, position, extensions, tickArrayLower, tickArrayUpper } = params;
+
+  // The range order book is the only side account of this instruction
+  const remainingAccounts = ExtensionUtil.isExtensionEnabled(
+    extensions ?? 0,
+    EXTENSION_RANGE_ORDER_BOOK
+  )
+    ? [
+        {
+          pubkey: PDAUtil.getRangeOrderBook(program.programId, whirlpool).publicKey,
+          isSigner: false,
+          isWritable: true,
+        },
+      ]
+    : [];
 
   const ix = program.instruction.updateFeesAndRewards({
     accounts: {
@@ -45,6 +64,7 @@ export function updateFeesAndRewardsIx(
       tickArrayLower,
       tickArrayUpper,
     },
+    remainingAccounts,
   });
 
   return {
@@ -0,0 +1,70 @@
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey } from "@solana/web3.js";
+import { METADATA_PROGRAM_ADDRESS } from "../types/public";
+import { PDAUtil } from "../utils/public";
+
+/**
+ * Parameters to update the metadata of a position.
+ *
+ * @category Instruction Types
+ * @param whirlpool - PublicKey for the whirlpool that the position belongs to.
+ * @param whirlpoolsConfig - PublicKey for the WhirlpoolsConfig of the whirlpool.
+ * @param position - PublicKey for the position.
+ * @param positionMint - PublicKey for the mint of the position token.
+ * @param metadataUpdateAuth - The update authority stored in the position metadata. This is the
+ *                             update authority of the metadata settings, or the previous one for
+ *                             positions opened before it was rotated.
+ */
+export type UpdatePositionMetadataParams = {
+  whirlpool: PublicKey;
+  whirlpoolsConfig: PublicKey;
+  position: PublicKey;
+  positionMint: PublicKey;
+  metadataUpdateAuth: PublicKey;
+};
+
+/**
+ * Rewrite the metadata of a position from the metadata settings of its WhirlpoolsConfig,
+ * so that its URI reflects the current tick range of the position and URI template. The metadata of
+ * positions opened before the update authority of the metadata settings was rotated is handed over
+ * to the current update authority.
+ *
+ * #### Special Errors
+ * - `InvalidMetadataUpdateAuthority` - If the signer is not the update authority stored in the
+ *                                      position metadata.
+ * - `InvalidPositionMetadataAccount` - If the metadata account does not belong to the position mint.
+ * - `InvalidPositionMetadata` - If the expanded metadata URI is too long.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - UpdatePositionMetadataParams object
+ * @returns - Instruction to perform the action.
+ */
+export function updatePositionMetadataIx(
+  program: Program<Whirlpool>,
+  params: UpdatePositionMetadataParams
+): Instruction {
+  const { whirlpool, whirlpoolsConfig, position, positionMint, metadataUpdateAuth } = params;
+
+  const metadataPda = PDAUtil.getPositionMetadata(positionMint);
+  const metadataSettingsPda = PDAUtil.getMetadataSettings(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.updatePositionMetadata({
+    accounts: {
+      whirlpool,
+      position,
+      positionMetadataAccount: metadataPda.publicKey,
+      metadataUpdateAuth,
+      metadataSettings: metadataSettingsPda.publicKey,
+      metadataProgram: METADATA_PROGRAM_ADDRESS,
+    },
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
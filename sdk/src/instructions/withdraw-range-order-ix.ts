@@ -0,0 +1,116 @@
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Program } from "@project-serum/anchor";
+import { Whirlpool } from "../artifacts/whirlpool";
+import { ExtensionUtil, MODIFY_LIQUIDITY_EXTENSIONS, PDAUtil } from "../utils/public";
+import { Instruction } from "@orca-so/common-sdk";
+import { PublicKey } from "@solana/web3.js";
+import { BN } from "@project-serum/anchor";
+
+/**
+ * Parameters to withdraw a range order.
+ *
+ * @category Instruction Types
+ * @param tokenMinA - The minimum amount of token A to withdraw.
+ * @param tokenMinB - The minimum amount of token B to withdraw.
+ * @param whirlpool - PublicKey for the whirlpool that the position belongs to.
+ * @param whirlpoolsConfig - PublicKey for the WhirlpoolsConfig of the whirlpool.
+ * @param extensions - The extensions enabled on the whirlpool, see {@link WhirlpoolData}. Defaults to none.
+ * @param owner - The owner of the position token when the order was placed.
+ * @param position - PublicKey for the position of the range order.
+ * @param positionTokenAccount - PublicKey for the token account that receives the position token.
+ * @param tokenOwnerAccountA - PublicKey for the token A account that will receive the withdrawn tokens.
+ * @param tokenOwnerAccountB - PublicKey for the token B account that will receive the withdrawn tokens.
+ * @param tokenVaultA - PublicKey for the tokenA vault for this whirlpool.
+ * @param tokenVaultB - PublicKey for the tokenB vault for this whirlpool.
+ * @param tickArrayLower - PublicKey for the tick-array account that hosts the tick at the lower tick index.
+ * @param tickArrayUpper - PublicKey for the tick-array account that hosts the tick at the upper tick index.
+ */
+export type WithdrawRangeOrderParams = {
+  tokenMinA: BN;
+  tokenMinB: BN;
+  whirlpool: PublicKey;
+  whirlpoolsConfig: PublicKey;
+  extensions?: number;
+  owner: PublicKey;
+  position: PublicKey;
+  positionTokenAccount: PublicKey;
+  tokenOwnerAccountA: PublicKey;
+  tokenOwnerAccountB: PublicKey;
+  tokenVaultA: PublicKey;
+  tokenVaultB: PublicKey;
+  tickArrayLower: PublicKey;
+  tickArrayUpper: PublicKey;
+};
+
+/**
+ * Withdraw a range order and return the position token to its owner.
+ * A filled order withdraws the token it was converted to. An order that has not been
+ * filled yet withdraws all of its liquidity like `decreaseLiquidityIx`.
+ * The fees and rewards earned by the order can be collected from the position afterwards.
+ *
+ * #### Special Errors
+ * - `RangeOrderNotFound` - The position has no range order placed by the owner.
+ * - `TokenMinSubceeded` - The required token to perform this operation subceeds the user defined amount.
+ * - `InvalidExtensionAccount` - The side account of an extension enabled on the whirlpool is missing.
+ *
+ * @category Instructions
+ * @param context - Context object containing services required to generate the instruction
+ * @param params - WithdrawRangeOrderParams object
+ * @returns - Instruction to perform the action.
+ */
+export function withdrawRangeOrderIx(
+  program: Program<Whirlpool>,
+  params: WithdrawRangeOrderParams
+): Instruction {
+  const {
+    tokenMinA,
+    tokenMinB,
+    whirlpool,
+    whirlpoolsConfig,
+    extensions,
+    owner,
+    position,
+    positionTokenAccount,
+    tokenOwnerAccountA,
+    tokenOwnerAccountB,
+    tokenVaultA,
+    tokenVaultB,
+    tickArrayLower,
+    tickArrayUpper,
+  } = params;
+
+  const rangeOrderEscrowPda = PDAUtil.getRangeOrderEscrow(program.programId, position);
+  const rangeOrderBookPda = PDAUtil.getRangeOrderBook(program.programId, whirlpool);
+  const pauseControlsPda = PDAUtil.getPauseControls(program.programId, whirlpoolsConfig);
+
+  const ix = program.instruction.withdrawRangeOrder(tokenMinA, tokenMinB, {
+    accounts: {
+      whirlpool,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      owner,
+      position,
+      positionTokenAccount,
+      rangeOrderEscrow: rangeOrderEscrowPda.publicKey,
+      rangeOrderBook: rangeOrderBookPda.publicKey,
+      tokenOwnerAccountA,
+      tokenOwnerAccountB,
+      tokenVaultA,
+      tokenVaultB,
+      tickArrayLower,
+      tickArrayUpper,
+      pauseControls: pauseControlsPda.publicKey,
+    },
+    remainingAccounts: ExtensionUtil.getRemainingAccounts(
+      program.programId,
+      whirlpool,
+      extensions ?? 0,
+      MODIFY_LIQUIDITY_EXTENSIONS
+    ),
+  });
+
+  return {
+    instructions: [ix],
+    cleanupInstructions: [],
+    signers: [],
+  };
+}
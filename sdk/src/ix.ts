@@ -1,5 +1,6 @@
 import { PDA } from "@orca-so/common-sdk";
 import { Program } from "@project-serum/anchor";
+import { PublicKey } from "@solana/web3.js";
 import { WhirlpoolContext } from ".";
 import { Whirlpool } from "./artifacts/whirlpool";
 import * as ix from "./instructions";
@@ -51,6 +52,22 @@ export class WhirlpoolIx {
     return ix.initializePoolIx(program, params);
   }
 
+  /**
+   * Initializes a Whirlpool account whose mints belong to the token program or the Token-2022
+   * program. The token vaults are created with the account extensions of their mint.
+   *
+   * Special Errors
+   * Same as {@link initializePoolIx}.
+   * `UnsupportedTokenMint` - A mint has an extension other than the transfer fee, the metadata pointer and the metadata
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - InitPoolV2Params object
+   * @returns - Instruction to perform the action.
+   */
+  public static initializePoolV2Ix(program: Program<Whirlpool>, params: ix.InitPoolV2Params) {
+    return ix.initializePoolV2Ix(program, params);
+  }
+
   /**
    * Initialize reward for a Whirlpool. A pool can only support up to a set number of rewards.
    * The initial emissionsPerSecond is set to 0.
@@ -87,6 +104,7 @@ export class WhirlpoolIx {
    *
    * #### Special Errors
    * `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of the tick-spacing in this pool.
+   * `InvalidTickSpacing` - If the fee tier policy of the config does not allow the tick-spacing of this pool.
    *
 
    * @param program - program object containing services required to generate the instruction
@@ -100,19 +118,23 @@ export class WhirlpoolIx {
   /**
    * Open a position in a Whirlpool. A unique token will be minted to represent the position
    * in the users wallet. Additional Metaplex metadata is appended to identify the token.
+   * The metadata is taken from the metadata settings of the WhirlpoolsConfig, if initialized.
    * The position will start off with 0 liquidity.
    *
    * #### Special Errors
    * `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of the tick-spacing in this pool.
+   * `InvalidTickSpacing` - If the fee tier policy of the config does not allow the tick-spacing of this pool.
+   * `InvalidMetadataUpdateAuthority` - If the metadata update authority does not match the metadata settings.
    *
 
    * @param program - program object containing services required to generate the instruction
-   * @param params - OpenPositionParams object and a derived PDA that hosts the position's metadata.
+   * @param params - OpenPositionParams object, a derived PDA that hosts the position's metadata and
+   *                 the update authority of the metadata settings, if initialized.
    * @returns - Instruction to perform the action.
    */
   public static openPositionWithMetadataIx(
     program: Program<Whirlpool>,
-    params: ix.OpenPositionParams & { metadataPda: PDA }
+    params: ix.OpenPositionParams & { metadataPda: PDA; metadataUpdateAuth?: PublicKey }
   ) {
     return ix.openPositionWithMetadataIx(program, params);
   }
@@ -136,6 +158,21 @@ export class WhirlpoolIx {
     return ix.increaseLiquidityIx(program, params);
   }
 
+  /**
+   * Add liquidity to a position in a Whirlpool whose mints may belong to the Token-2022 program.
+   * The token maxes include the transfer fees of the deposits.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - IncreaseLiquidityV2Params object
+   * @returns - Instruction to perform the action.
+   */
+  public static increaseLiquidityV2Ix(
+    program: Program<Whirlpool>,
+    params: ix.IncreaseLiquidityV2Params
+  ) {
+    return ix.increaseLiquidityV2Ix(program, params);
+  }
+
   /**
    * Remove liquidity to a position in the Whirlpool. This call also updates the position's accrued fees and rewards.
    *
@@ -155,6 +192,21 @@ export class WhirlpoolIx {
     return ix.decreaseLiquidityIx(program, params);
   }
 
+  /**
+   * Remove liquidity from a position in a Whirlpool whose mints may belong to the Token-2022
+   * program. The token mins exclude the transfer fees of the withdrawals.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - DecreaseLiquidityV2Params object
+   * @returns - Instruction to perform the action.
+   */
+  public static decreaseLiquidityV2Ix(
+    program: Program<Whirlpool>,
+    params: ix.DecreaseLiquidityV2Params
+  ) {
+    return ix.decreaseLiquidityV2Ix(program, params);
+  }
+
   /**
    * Close a position in a Whirlpool. Burns the position token in the owner's wallet.
    *
@@ -189,6 +241,20 @@ export class WhirlpoolIx {
     return ix.swapIx(program, params);
   }
 
+  /**
+   * Perform a swap in a Whirlpool whose mints may belong to the Token-2022 program.
+   * An exact input `amount` and a maximum input `otherAmountThreshold` include the transfer fee of
+   * the input token, an exact output `amount` and a minimum output `otherAmountThreshold` exclude
+   * the transfer fee of the output token.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - {@link SwapV2Params}
+   * @returns - Instruction to perform the action.
+   */
+  public static swapV2Ix(program: Program<Whirlpool>, params: ix.SwapV2Params) {
+    return ix.swapV2Ix(program, params);
+  }
+
   /**
    * Perform a two-hop-swap in this Whirlpool
    *
@@ -213,6 +279,42 @@ export class WhirlpoolIx {
       return ix.twoHopSwapIx(program, params);
     }
 
+  /**
+   * Perform a swap across an ordered route of Whirlpools. The output of each hop is used as the
+   * input of the next hop, and the slippage threshold applies to the whole route.
+   *
+   * #### Special Errors
+   * - `InvalidRouteAccounts` - The hops are empty or their accounts are invalid.
+   * - `InvalidIntermediaryMint` - Error if the output mint of a hop does not equal the input mint of the next hop.
+   * - `DuplicateRoutePool` - Error if a whirlpool is visited more than once.
+   * - `AmountOutBelowMinimum` - In exact-out mode, the last hop cannot provide the full specified output amount.
+   * - `IntermediateTokenAmountMismatch` - In exact-out mode, a hop cannot provide the full input required by the next hop.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - {@link RouteSwapParams}
+   * @returns - Instruction to perform the action.
+   */
+  public static routeSwapIx(program: Program<Whirlpool>, params: ix.RouteSwapParams) {
+    return ix.routeSwapIx(program, params);
+  }
+
+  /**
+   * Perform a swap that splits a single amount across several Whirlpools of the same token pair,
+   * e.g. pools of different fee tiers. The slippage threshold applies to the combined amounts of
+   * all legs.
+   *
+   * #### Special Errors
+   * - `InvalidSplitSwap` - The legs do not trade the same token pair, their accounts are invalid or their `splitBps` do not sum up to 10_000.
+   * - `DuplicateRoutePool` - Error if a whirlpool is used by more than one leg.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - {@link SplitSwapParams}
+   * @returns - Instruction to perform the action.
+   */
+  public static splitSwapIx(program: Program<Whirlpool>, params: ix.SplitSwapParams) {
+    return ix.splitSwapIx(program, params);
+  }
+
   /**
    * Update the accrued fees and rewards for a position.
    *
@@ -243,6 +345,18 @@ export class WhirlpoolIx {
     return ix.collectFeesIx(program, params);
   }
 
+  /**
+   * Collect fees accrued for a position of a Whirlpool whose mints may belong to the Token-2022
+   * program. The transfer fees are withheld from the fees collected.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - CollectFeesV2Params object
+   * @returns - Instruction to perform the action.
+   */
+  public static collectFeesV2Ix(program: Program<Whirlpool>, params: ix.CollectFeesV2Params) {
+    return ix.collectFeesV2Ix(program, params);
+  }
+
   /**
    * Collect protocol fees accrued in this Whirlpool.
    *
@@ -257,6 +371,21 @@ export class WhirlpoolIx {
     return ix.collectProtocolFeesIx(program, params);
   }
 
+  /**
+   * Collect protocol fees accrued in a Whirlpool whose mints may belong to the Token-2022 program.
+   * The transfer fees are withheld from the fees collected.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - CollectProtocolFeesV2Params object
+   * @returns - Instruction to perform the action.
+   */
+  public static collectProtocolFeesV2Ix(
+    program: Program<Whirlpool>,
+    params: ix.CollectProtocolFeesV2Params
+  ) {
+    return ix.collectProtocolFeesV2Ix(program, params);
+  }
+
   /**
    * Collect rewards accrued for this reward index in a position.
    * Call updateFeesAndRewards before this to update the position to the newest accrued values.
@@ -271,7 +400,8 @@ export class WhirlpoolIx {
   }
 
   /**
-   * Sets the fee authority to collect protocol fees for a WhirlpoolsConfig.
+   * Proposes a new authority to collect protocol fees for a WhirlpoolsConfig.
+   * The transfer takes effect once the new authority accepts it.
    * Only the current collect protocol fee authority has permission to invoke this instruction.
    *
    * @param program - program object containing services required to generate the instruction
@@ -322,8 +452,9 @@ export class WhirlpoolIx {
   }
 
   /**
-   * Sets the fee authority for a WhirlpoolsConfig.
+   * Proposes a new fee authority for a WhirlpoolsConfig.
    * The fee authority can set the fee & protocol fee rate for individual pools or set the default fee rate for newly minted pools.
+   * The transfer takes effect once the new fee authority accepts it.
    * Only the current fee authority has permission to invoke this instruction.
    *
    * @param program - program object containing services required to generate the instruction
@@ -335,7 +466,8 @@ export class WhirlpoolIx {
   }
 
   /**
-   * Sets the pool creator authority for a WhirlpoolsConfig.
+   * Proposes a new pool creator authority for a WhirlpoolsConfig.
+   * The transfer takes effect once the new authority accepts it.
    * Only the current pool creator authority has permission to invoke this instruction.
    *
    * @param program - program object containing services required to generate the instruction
@@ -349,8 +481,266 @@ export class WhirlpoolIx {
     return ix.setPoolCreatorAuthorityIx(program, params);
   }
 
+  /**
+   * Initializes the pool creation policy of a WhirlpoolsConfig, which restricts who can create pools in the config.
+   * Only the current pool creator authority has permission to invoke this instruction.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - InitPoolCreationPolicyParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static initializePoolCreationPolicyIx(
+    program: Program<Whirlpool>,
+    params: ix.InitPoolCreationPolicyParams
+  ) {
+    return ix.initializePoolCreationPolicyIx(program, params);
+  }
+
+  /**
+   * Sets the mode of the pool creation policy of a WhirlpoolsConfig.
+   * Only the current pool creator authority has permission to invoke this instruction.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - SetPoolCreationModeParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static setPoolCreationModeIx(
+    program: Program<Whirlpool>,
+    params: ix.SetPoolCreationModeParams
+  ) {
+    return ix.setPoolCreationModeIx(program, params);
+  }
+
+  /**
+   * Adds a token mint to the allowlist of the pool creation policy of a WhirlpoolsConfig.
+   * Only the current pool creator authority has permission to invoke this instruction.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - AddAllowedMintParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static addAllowedMintIx(program: Program<Whirlpool>, params: ix.AddAllowedMintParams) {
+    return ix.addAllowedMintIx(program, params);
+  }
+
+  /**
+   * Removes a token mint from the allowlist of the pool creation policy of a WhirlpoolsConfig.
+   * Only the current pool creator authority has permission to invoke this instruction.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - RemoveAllowedMintParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static removeAllowedMintIx(program: Program<Whirlpool>, params: ix.RemoveAllowedMintParams) {
+    return ix.removeAllowedMintIx(program, params);
+  }
+
+  /**
+   * Initializes the fee tier policy of a WhirlpoolsConfig, which restricts the tick spacings of fee tiers and new pools.
+   * Only the current fee authority has permission to invoke this instruction.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - InitFeeTierPolicyParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static initializeFeeTierPolicyIx(
+    program: Program<Whirlpool>,
+    params: ix.InitFeeTierPolicyParams
+  ) {
+    return ix.initializeFeeTierPolicyIx(program, params);
+  }
+
+  /**
+   * Sets the tick spacings that fee tiers can be initialized for in a WhirlpoolsConfig.
+   * Only the current fee authority has permission to invoke this instruction.
+   *
+   * #### Special Errors
+   * - `InvalidTickSpacing` - If a provided tick spacing is zero.
+   * - `TooManyTickSpacings` - If more than MAX_POLICY_TICK_SPACINGS tick spacings are provided.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - SetAllowedTickSpacingsParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static setAllowedTickSpacingsIx(
+    program: Program<Whirlpool>,
+    params: ix.SetAllowedTickSpacingsParams
+  ) {
+    return ix.setAllowedTickSpacingsIx(program, params);
+  }
+
+  /**
+   * Enables or disables the creation of new pools on a FeeTier. Existing pools of the fee-tier are not affected.
+   * Only the current fee authority has permission to invoke this instruction.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - SetFeeTierEnabledParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static setFeeTierEnabledIx(program: Program<Whirlpool>, params: ix.SetFeeTierEnabledParams) {
+    return ix.setFeeTierEnabledIx(program, params);
+  }
+
+  /**
+   * Closes a FeeTier and returns its rent to the receiver. Only fee-tiers that no pool has been initialized with can be closed.
+   * Only the current fee authority has permission to invoke this instruction.
+   *
+   * #### Special Errors
+   * - `FeeTierHasPools` - If pools have been initialized with the fee-tier.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - CloseFeeTierParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static closeFeeTierIx(program: Program<Whirlpool>, params: ix.CloseFeeTierParams) {
+    return ix.closeFeeTierIx(program, params);
+  }
+
+  /**
+   * Grows a FeeTier created before the pools of fee-tiers were counted. The pools initialized with
+   * the fee-tier can not be counted, so its pool count is left untracked and it can never be
+   * closed. Fee-tiers have to be migrated before their default fee rate can be set.
+   * Only the current fee authority has permission to invoke this instruction.
+   *
+   * #### Special Errors
+   * - `InvalidFeeTierMigration` - If the fee-tier is not part of the config or has already been migrated.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - MigrateFeeTierParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static migrateFeeTierIx(program: Program<Whirlpool>, params: ix.MigrateFeeTierParams) {
+    return ix.migrateFeeTierIx(program, params);
+  }
+
+  /**
+   * Initializes the account that holds the pending authority transfers of a WhirlpoolsConfig.
+   * Has to be invoked once before any authority of the config can be transferred.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - InitPendingAuthoritiesParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static initializePendingAuthoritiesIx(
+    program: Program<Whirlpool>,
+    params: ix.InitPendingAuthoritiesParams
+  ) {
+    return ix.initializePendingAuthoritiesIx(program, params);
+  }
+
+  /**
+   * Accepts the pending transfer of the fee authority for a WhirlpoolsConfig.
+   * Only the proposed fee authority has permission to invoke this instruction.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - AcceptFeeAuthorityParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static acceptFeeAuthorityIx(program: Program<Whirlpool>, params: ix.AcceptFeeAuthorityParams) {
+    return ix.acceptFeeAuthorityIx(program, params);
+  }
+
+  /**
+   * Accepts the pending transfer of the collect protocol fees authority for a WhirlpoolsConfig.
+   * Only the proposed collect protocol fees authority has permission to invoke this instruction.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - AcceptCollectProtocolFeesAuthorityParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static acceptCollectProtocolFeesAuthorityIx(program: Program<Whirlpool>, params: ix.AcceptCollectProtocolFeesAuthorityParams) {
+    return ix.acceptCollectProtocolFeesAuthorityIx(program, params);
+  }
+
+  /**
+   * Accepts the pending transfer of the pool creator authority for a WhirlpoolsConfig.
+   * Only the proposed pool creator authority has permission to invoke this instruction.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - AcceptPoolCreatorAuthorityParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static acceptPoolCreatorAuthorityIx(program: Program<Whirlpool>, params: ix.AcceptPoolCreatorAuthorityParams) {
+    return ix.acceptPoolCreatorAuthorityIx(program, params);
+  }
+
+  /**
+   * Accepts the pending transfer of the reward emissions super authority for a WhirlpoolsConfig.
+   * Only the proposed reward emissions super authority has permission to invoke this instruction.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - AcceptRewardEmissionsSuperAuthorityParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static acceptRewardEmissionsSuperAuthorityIx(program: Program<Whirlpool>, params: ix.AcceptRewardEmissionsSuperAuthorityParams) {
+    return ix.acceptRewardEmissionsSuperAuthorityIx(program, params);
+  }
+
+  /**
+   * Cancels the pending transfer of the fee authority for a WhirlpoolsConfig.
+   * Only the current fee authority has permission to invoke this instruction.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - CancelFeeAuthorityTransferParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static cancelFeeAuthorityTransferIx(
+    program: Program<Whirlpool>,
+    params: ix.CancelFeeAuthorityTransferParams
+  ) {
+    return ix.cancelFeeAuthorityTransferIx(program, params);
+  }
+
+  /**
+   * Cancels the pending transfer of the collect protocol fees authority for a WhirlpoolsConfig.
+   * Only the current collect protocol fees authority has permission to invoke this instruction.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - CancelCollectProtocolFeesAuthorityTransferParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static cancelCollectProtocolFeesAuthorityTransferIx(
+    program: Program<Whirlpool>,
+    params: ix.CancelCollectProtocolFeesAuthorityTransferParams
+  ) {
+    return ix.cancelCollectProtocolFeesAuthorityTransferIx(program, params);
+  }
+
+  /**
+   * Cancels the pending transfer of the pool creator authority for a WhirlpoolsConfig.
+   * Only the current pool creator authority has permission to invoke this instruction.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - CancelPoolCreatorAuthorityTransferParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static cancelPoolCreatorAuthorityTransferIx(
+    program: Program<Whirlpool>,
+    params: ix.CancelPoolCreatorAuthorityTransferParams
+  ) {
+    return ix.cancelPoolCreatorAuthorityTransferIx(program, params);
+  }
+
+  /**
+   * Cancels the pending transfer of the reward emissions super authority for a WhirlpoolsConfig.
+   * Only the current reward emissions super authority has permission to invoke this instruction.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - CancelRewardEmissionsSuperAuthorityTransferParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static cancelRewardEmissionsSuperAuthorityTransferIx(
+    program: Program<Whirlpool>,
+    params: ix.CancelRewardEmissionsSuperAuthorityTransferParams
+  ) {
+    return ix.cancelRewardEmissionsSuperAuthorityTransferIx(program, params);
+  }
+
   /**
    * Sets the fee rate for a Whirlpool.
+   * The change is scheduled through the fee rate schedule of the Whirlpool, which has to be
+   * initialized first, and applies MIN_FEE_RATE_CHANGE_DELAY seconds later. The protocol fee rate of a
+   * pending change is kept.
    * Only the current fee authority has permission to invoke this instruction.
    *
    * #### Special Errors
@@ -366,6 +756,9 @@ export class WhirlpoolIx {
 
   /**
    * Sets the protocol fee rate for a Whirlpool.
+   * The change is scheduled through the fee rate schedule of the Whirlpool, which has to be
+   * initialized first, and applies MIN_FEE_RATE_CHANGE_DELAY seconds later. The fee rate of a
+   * pending change is kept.
    * Only the current fee authority has permission to invoke this instruction.
    *
    * #### Special Errors
@@ -382,6 +775,58 @@ export class WhirlpoolIx {
     return ix.setProtocolFeeRateIx(program, params);
   }
 
+  /**
+   * Initializes the fee rate schedule of a Whirlpool, which is required to change its fee rates.
+   * Fee rate changes are scheduled at least MIN_FEE_RATE_CHANGE_DELAY seconds ahead.
+   * Only the current fee authority has permission to invoke this instruction.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - InitFeeRateScheduleParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static initializeFeeRateScheduleIx(
+    program: Program<Whirlpool>,
+    params: ix.InitFeeRateScheduleParams
+  ) {
+    return ix.initializeFeeRateScheduleIx(program, params);
+  }
+
+  /**
+   * Schedules a change of the fee rate & protocol fee rate of a Whirlpool.
+   * The new rates are applied by the first swap or liquidity change at or after `effectiveAt`.
+   * Only the current fee authority has permission to invoke this instruction.
+   *
+   * #### Special Errors
+   * - `FeeRateMaxExceeded` - If the provided feeRate exceeds MAX_FEE_RATE.
+   * - `ProtocolFeeRateMaxExceeded` - If the provided protocolFeeRate exceeds MAX_PROTOCOL_FEE_RATE.
+   * - `FeeRateChangeTooEarly` - If `effectiveAt` is less than MIN_FEE_RATE_CHANGE_DELAY seconds away.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - ScheduleFeeRateChangeParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static scheduleFeeRateChangeIx(
+    program: Program<Whirlpool>,
+    params: ix.ScheduleFeeRateChangeParams
+  ) {
+    return ix.scheduleFeeRateChangeIx(program, params);
+  }
+
+  /**
+   * Cancels the pending fee rate change of a Whirlpool.
+   * Only the current fee authority has permission to invoke this instruction.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - CancelFeeRateChangeParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static cancelFeeRateChangeIx(
+    program: Program<Whirlpool>,
+    params: ix.CancelFeeRateChangeParams
+  ) {
+    return ix.cancelFeeRateChangeIx(program, params);
+  }
+
   /**
    * Set the whirlpool reward authority at the provided `reward_index`.
    * Only the current reward super authority has permission to invoke this instruction.
@@ -441,7 +886,8 @@ export class WhirlpoolIx {
   }
 
   /**
-   * Set the whirlpool reward super authority for a WhirlpoolsConfig
+   * Propose a new whirlpool reward super authority for a WhirlpoolsConfig
+   * The transfer takes effect once the new authority accepts it.
    * Only the current reward super authority has permission to invoke this instruction.
    * This instruction will not change the authority on any `WhirlpoolRewardInfo` whirlpool rewards.
    *
@@ -477,4 +923,267 @@ export class WhirlpoolIx {
   public static setEnableFlagIx(program: Program<Whirlpool>, params: ix.SetEnableFlagParams) {
     return ix.setEnableFlagIx(program, params);
   }
+
+  /**
+   * Initializes the pause controls of a WhirlpoolsConfig, which pause individual operations of its pools.
+   * Only the current pool creator authority has permission to invoke this instruction.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - InitPauseControlsParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static initializePauseControlsIx(
+    program: Program<Whirlpool>,
+    params: ix.InitPauseControlsParams
+  ) {
+    return ix.initializePauseControlsIx(program, params);
+  }
+
+  /**
+   * Sets the operations that are paused on every pool of a WhirlpoolsConfig.
+   * Setting PAUSE_ALL acts as an emergency pause of the whole config.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - SetConfigPausedOperationsParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static setConfigPausedOperationsIx(
+    program: Program<Whirlpool>,
+    params: ix.SetConfigPausedOperationsParams
+  ) {
+    return ix.setConfigPausedOperationsIx(program, params);
+  }
+
+  /**
+   * Sets the operations that are paused on a single Whirlpool, in addition to the ones paused on its WhirlpoolsConfig.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - SetPoolPausedOperationsParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static setPoolPausedOperationsIx(
+    program: Program<Whirlpool>,
+    params: ix.SetPoolPausedOperationsParams
+  ) {
+    return ix.setPoolPausedOperationsIx(program, params);
+  }
+
+  /**
+   * Initializes the range order book of a Whirlpool. Once initialized, positions in the pool
+   * can be placed as range orders that are filled by the swaps crossing them.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - InitRangeOrderBookParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static initializeRangeOrderBookIx(
+    program: Program<Whirlpool>,
+    params: ix.InitRangeOrderBookParams
+  ) {
+    return ix.initializeRangeOrderBookIx(program, params);
+  }
+
+  /**
+   * Place a position spanning a single tick-spacing as a range order.
+   * The position is taken out of the pool once a swap moves the price across its range.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - PlaceRangeOrderParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static placeRangeOrderIx(program: Program<Whirlpool>, params: ix.PlaceRangeOrderParams) {
+    return ix.placeRangeOrderIx(program, params);
+  }
+
+  /**
+   * Withdraw a range order and return the position token to its owner.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - WithdrawRangeOrderParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static withdrawRangeOrderIx(
+    program: Program<Whirlpool>,
+    params: ix.WithdrawRangeOrderParams
+  ) {
+    return ix.withdrawRangeOrderIx(program, params);
+  }
+
+  /**
+   * Move all of the liquidity of a position to a new tick range on the same Whirlpool.
+   * The fees owed are collected, and only the difference between the withdrawn and the deposited
+   * amounts of each token is transferred.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - RebalancePositionParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static rebalancePositionIx(
+    program: Program<Whirlpool>,
+    params: ix.RebalancePositionParams
+  ) {
+    return ix.rebalancePositionIx(program, params);
+  }
+
+  /**
+   * Initializes the metadata settings of a WhirlpoolsConfig, which set the metadata of the
+   * position NFTs opened with `openPositionWithMetadataIx`.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - InitMetadataSettingsParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static initializeMetadataSettingsIx(
+    program: Program<Whirlpool>,
+    params: ix.InitMetadataSettingsParams
+  ) {
+    return ix.initializeMetadataSettingsIx(program, params);
+  }
+
+  /**
+   * Sets the metadata settings of a WhirlpoolsConfig. Positions that are already open keep
+   * their metadata.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - SetMetadataSettingsParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static setMetadataSettingsIx(
+    program: Program<Whirlpool>,
+    params: ix.SetMetadataSettingsParams
+  ) {
+    return ix.setMetadataSettingsIx(program, params);
+  }
+
+  /**
+   * Rewrite the metadata of a position from the metadata settings of its WhirlpoolsConfig,
+   * so that its URI reflects the current tick range of the position and URI template. The metadata
+   * of positions opened before the update authority was rotated is handed over to the current one.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - UpdatePositionMetadataParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static updatePositionMetadataIx(
+    program: Program<Whirlpool>,
+    params: ix.UpdatePositionMetadataParams
+  ) {
+    return ix.updatePositionMetadataIx(program, params);
+  }
+
+  /**
+   * Mint the collection NFT of the positions of a WhirlpoolsConfig. Positions opened with
+   * metadata afterwards are verified as members of the collection.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - InitPositionCollectionParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static initializePositionCollectionIx(
+    program: Program<Whirlpool>,
+    params: ix.InitPositionCollectionParams
+  ) {
+    return ix.initializePositionCollectionIx(program, params);
+  }
+
+  /**
+   * Perform a swap in this Whirlpool from and/or into the lamport balance of the token authority.
+   * The native side of the swap goes through a temporary wrapped RENEC account, which is closed
+   * back to the token authority once the swap is done.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - {@link SwapParams}
+   * @returns - Instruction to perform the action.
+   */
+  public static swapWithNativeIx(program: Program<Whirlpool>, params: ix.SwapParams) {
+    return ix.swapWithNativeIx(program, params);
+  }
+
+  /**
+   * Perform a two-hop swap from and/or into the lamport balance of the token authority.
+   * The native side of the swap goes through a temporary wrapped RENEC account, which is closed
+   * back to the token authority once the swaps are done.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - {@link TwoHopSwapParams}
+   * @returns - Instruction to perform the action.
+   */
+  public static twoHopSwapWithNativeIx(program: Program<Whirlpool>, params: ix.TwoHopSwapParams) {
+    return ix.twoHopSwapWithNativeIx(program, params);
+  }
+
+  /**
+   * Add liquidity to a position in the Whirlpool from the lamport balance of the position
+   * authority, through a temporary wrapped RENEC account.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - IncreaseLiquidityParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static increaseLiquidityWithNativeIx(
+    program: Program<Whirlpool>,
+    params: ix.IncreaseLiquidityParams
+  ) {
+    return ix.increaseLiquidityWithNativeIx(program, params);
+  }
+
+  /**
+   * Remove liquidity from a position in the Whirlpool into the lamport balance of the position
+   * authority, through a temporary wrapped RENEC account.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - DecreaseLiquidityParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static decreaseLiquidityWithNativeIx(
+    program: Program<Whirlpool>,
+    params: ix.DecreaseLiquidityParams
+  ) {
+    return ix.decreaseLiquidityWithNativeIx(program, params);
+  }
+
+  /**
+   * Initializes the oracle of a Whirlpool. Once initialized, every swap records an observation
+   * of the pool's tick & liquidity that can be used to derive a time-weighted average price.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - InitOracleParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static initializeOracleIx(program: Program<Whirlpool>, params: ix.InitOracleParams) {
+    return ix.initializeOracleIx(program, params);
+  }
+
+  /**
+   * Grows the number of observations stored by the oracle of a Whirlpool.
+   *
+   * #### Special Errors
+   * - `InvalidObservationCardinality` - The new cardinality does not exceed the current one or
+   *                                     exceeds the maximum cardinality.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - IncreaseObservationCardinalityParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static increaseObservationCardinalityIx(
+    program: Program<Whirlpool>,
+    params: ix.IncreaseObservationCardinalityParams
+  ) {
+    return ix.increaseObservationCardinalityIx(program, params);
+  }
+
+  /**
+   * Returns the oracle accumulators & time-weighted averages of a Whirlpool as return data.
+   *
+   * #### Special Errors
+   * - `ObservationTooOld` - A look-back period reaches past the oldest stored observation.
+   * - `InvalidObservationWindow` - The look-back periods are not strictly decreasing.
+   *
+   * @param program - program object containing services required to generate the instruction
+   * @param params - ObserveParams object
+   * @returns - Instruction to perform the action.
+   */
+  public static observeIx(program: Program<Whirlpool>, params: ix.ObserveParams) {
+    return ix.observeIx(program, params);
+  }
 }
@@ -10,6 +10,7 @@ import {
   WhirlpoolData,
   WhirlpoolsConfigData,
   WHIRLPOOL_ACCOUNT_SIZE,
+  WHIRLPOOL_ACCOUNT_SIZE_WITH_EXTENSIONS,
   WHIRLPOOL_CODER,
 } from "../..";
 import { FeeTierData } from "../../types/public";
@@ -206,19 +207,23 @@ export class AccountFetcher {
     programId,
     configId,
   }: ListWhirlpoolParams): Promise<WhirlpoolAccount[]> {
-    const filters = [
-      { dataSize: WHIRLPOOL_ACCOUNT_SIZE },
-      {
-        memcmp: WHIRLPOOL_CODER.memcmp(
-          AccountName.Whirlpool,
-          AddressUtil.toPubKey(configId).toBuffer()
-        ),
-      },
-    ];
-
-    const accounts = await this.connection.getProgramAccounts(AddressUtil.toPubKey(programId), {
-      filters,
-    });
+    const memcmpFilter = {
+      memcmp: WHIRLPOOL_CODER.memcmp(
+        AccountName.Whirlpool,
+        AddressUtil.toPubKey(configId).toBuffer()
+      ),
+    };
+
+    // Whirlpools that store their enabled extensions are a byte larger than the ones created
+    // before extensions existed.
+    const [accountsWithoutExtensions, accountsWithExtensions] = await Promise.all(
+      [WHIRLPOOL_ACCOUNT_SIZE, WHIRLPOOL_ACCOUNT_SIZE_WITH_EXTENSIONS].map((dataSize) =>
+        this.connection.getProgramAccounts(AddressUtil.toPubKey(programId), {
+          filters: [{ dataSize }, memcmpFilter],
+        })
+      )
+    );
+    const accounts = [...accountsWithoutExtensions, ...accountsWithExtensions];
 
     const parsedAccounts: WhirlpoolAccount[] = [];
     accounts.forEach(({ pubkey, account }) => {
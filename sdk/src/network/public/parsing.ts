@@ -7,6 +7,7 @@ import {
   TickArrayData,
   AccountName,
   FeeTierData,
+  WHIRLPOOL_ACCOUNT_SIZE,
 } from "../../types/public";
 import { BorshAccountsCoder, Idl } from "@project-serum/anchor";
 import * as WhirlpoolIDL from "../../artifacts/whirlpool.json";
@@ -60,7 +61,15 @@ export class ParsableWhirlpool {
     }
 
     try {
-      return parseAnchorAccount(AccountName.Whirlpool, data);
+      const whirlpool = parseAnchorAccount(AccountName.Whirlpool, data);
+      if (!whirlpool) {
+        return null;
+      }
+      // The extensions of a Whirlpool are stored in the byte following its data, which pools
+      // created before extensions existed do not have.
+      whirlpool.extensions =
+        data.length > WHIRLPOOL_ACCOUNT_SIZE ? data[WHIRLPOOL_ACCOUNT_SIZE] : 0;
+      return whirlpool;
     } catch (e) {
       console.error(`error while parsing Whirlpool: ${e}`);
       return null;
@@ -36,6 +36,13 @@ export const WHIRLPOOL_CODER = new BorshAccountsCoder(IDL);
  */
 export const WHIRLPOOL_ACCOUNT_SIZE = WHIRLPOOL_CODER.size(IDL.accounts![4]);
 
+/**
+ * Size of a Whirlpool account in bytes once it stores its enabled extensions, in the byte following
+ * the Whirlpool data.
+ * @category Solana Accounts
+ */
+export const WHIRLPOOL_ACCOUNT_SIZE_WITH_EXTENSIONS = WHIRLPOOL_ACCOUNT_SIZE + 1;
+
 /**
  * @category Solana Accounts
  */
@@ -89,6 +96,7 @@ export type WhirlpoolData = {
   rewardLastUpdatedTimestamp: BN;
   rewardInfos: WhirlpoolRewardInfoData[];
   tickSpacing: number;
+  extensions: number;
 };
 
 /**
@@ -158,4 +166,5 @@ export type FeeTierData = {
   whirlpoolsConfig: PublicKey;
   tickSpacing: number;
   defaultFeeRate: number;
+  poolCount: BN;
 };
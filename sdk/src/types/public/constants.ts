@@ -58,6 +58,20 @@ export const METADATA_PROGRAM_ADDRESS = new PublicKey(
   "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
 );
 
+/**
+ * Program ID of the Token-2022 program, whose mints are supported by the `V2` instructions.
+ * @category Constants
+ */
+export const TOKEN_2022_PROGRAM_ID = new PublicKey("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/**
+ * The update authority of the position metadata in configs without metadata settings.
+ * @category Constants
+ */
+export const WHIRLPOOL_NFT_UPDATE_AUTH = new PublicKey(
+  "3axbTs2z5GBy6usVbNVoqEgZMng3vZvMnAoX29BFfwhr"
+);
+
 /**
  * The maximum number of tick-arrays that can traversed across in a swap.
  * @category Constants
@@ -75,3 +89,88 @@ export const PROTOCOL_FEE_RATE_MUL_VALUE = new BN(10_000);
  * @category Constants
  */
 export const FEE_RATE_MUL_VALUE = new BN(1_000_000);
+
+/**
+ * Pool creation mode that allows anyone to create pools in a WhirlpoolsConfig.
+ * @category Constants
+ */
+export const POOL_CREATION_PERMISSIONLESS = 0;
+
+/**
+ * Pool creation mode that only allows the pool creator authority to create pools in a WhirlpoolsConfig.
+ * @category Constants
+ */
+export const POOL_CREATION_CREATOR_ONLY = 1;
+
+/**
+ * Pool creation mode that allows anyone to create pools whose token mints are both allowlisted.
+ * @category Constants
+ */
+export const POOL_CREATION_ALLOWLISTED_MINTS = 2;
+
+/**
+ * Pauses swaps, including the hops of two-hop, route and split swaps.
+ * @category Constants
+ */
+export const PAUSE_SWAP = 1 << 0;
+
+/**
+ * Pauses increasing the liquidity of positions.
+ * @category Constants
+ */
+export const PAUSE_INCREASE_LIQUIDITY = 1 << 1;
+
+/**
+ * Pauses decreasing the liquidity of positions.
+ * @category Constants
+ */
+export const PAUSE_DECREASE_LIQUIDITY = 1 << 2;
+
+/**
+ * Pauses collecting both fees and rewards of positions.
+ * @category Constants
+ */
+export const PAUSE_COLLECT = 1 << 3;
+
+/**
+ * Pauses opening new positions.
+ * @category Constants
+ */
+export const PAUSE_OPEN_POSITION = 1 << 4;
+
+/**
+ * Pauses every operation. Set on a WhirlpoolsConfig, this acts as an emergency pause of all of its pools.
+ * @category Constants
+ */
+export const PAUSE_ALL =
+  PAUSE_SWAP |
+  PAUSE_INCREASE_LIQUIDITY |
+  PAUSE_DECREASE_LIQUIDITY |
+  PAUSE_COLLECT |
+  PAUSE_OPEN_POSITION;
+
+/**
+ * Extension of a Whirlpool whose oracle records an observation on every swap.
+ * The side account of every extension enabled on a Whirlpool has to be passed to the instructions
+ * that use it, see {@link ExtensionUtil}.
+ * @category Constants
+ */
+export const EXTENSION_ORACLE = 1 << 0;
+
+/**
+ * Extension of a Whirlpool that charges a volatility surcharge on swaps.
+ * @category Constants
+ */
+export const EXTENSION_DYNAMIC_FEE = 1 << 1;
+
+/**
+ * Extension of a Whirlpool with fee rate changes scheduled ahead of time.
+ * @category Constants
+ */
+export const EXTENSION_FEE_RATE_SCHEDULE = 1 << 2;
+
+/**
+ * Extension of a Whirlpool with range orders that are filled by swaps.
+ * @category Constants
+ */
+export const EXTENSION_RANGE_ORDER_BOOK = 1 << 3;
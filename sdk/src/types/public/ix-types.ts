@@ -4,17 +4,22 @@ export {
   CollectAllPositionAddressParams,
   CollectAllPositionParams,
   CollectFeesParams,
+  CollectFeesV2Params,
   CollectProtocolFeesParams,
+  CollectProtocolFeesV2Params,
   CollectRewardParams,
   DecreaseLiquidityInput,
   DecreaseLiquidityParams,
+  DecreaseLiquidityV2Params,
   DevFeeSwapInput,
   IncreaseLiquidityInput,
   IncreaseLiquidityParams,
+  IncreaseLiquidityV2Params,
   InitConfigParams,
   InitFeeTierParams,
   InitializeRewardParams,
   InitPoolParams,
+  InitPoolV2Params,
   InitTickArrayParams,
   OpenPositionParams,
   SetCollectProtocolFeesAuthorityParams,
@@ -30,6 +35,8 @@ export {
   SetRewardEmissionsSuperAuthorityParams,
   SwapInput,
   SwapParams,
+  SwapV2Params,
+  TokenProgramParams,
   UpdateFeesAndRewardsParams,
   SetEnableFlagParams,
 } from "../../instructions/";
@@ -1,9 +1,10 @@
-import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
-import { OpenPositionParams } from "../instructions";
+import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID, NATIVE_MINT } from "@solana/spl-token";
+import { OpenPositionParams, TokenProgramParams } from "../instructions";
 import * as anchor from "@project-serum/anchor";
-import { SystemProgram } from "@solana/web3.js";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { PDAUtil } from "./public";
 
-export function openPositionAccounts(params: OpenPositionParams) {
+export function openPositionAccounts(programId: PublicKey, params: OpenPositionParams) {
   const {
     funder,
     owner,
@@ -11,6 +12,7 @@ export function openPositionAccounts(params: OpenPositionParams) {
     positionMintAddress,
     positionTokenAccount: positionTokenAccountAddress,
     whirlpool: whirlpoolKey,
+    whirlpoolsConfig,
   } = params;
   return {
     funder: funder,
@@ -23,5 +25,24 @@ export function openPositionAccounts(params: OpenPositionParams) {
     systemProgram: SystemProgram.programId,
     rent: anchor.web3.SYSVAR_RENT_PUBKEY,
     associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    pauseControls: PDAUtil.getPauseControls(programId, whirlpoolsConfig).publicKey,
+    feeTierPolicy: PDAUtil.getFeeTierPolicy(programId, whirlpoolsConfig).publicKey,
   };
 }
+
+// The accounts that the `*_with_native` instructions add around the accounts of the base instruction
+export function nativeWrapAccounts(programId: PublicKey, authority: PublicKey) {
+  return {
+    nativeMint: NATIVE_MINT,
+    nativeWrapAccount: PDAUtil.getNativeWrap(programId, authority).publicKey,
+    tokenProgram: TOKEN_PROGRAM_ID,
+    systemProgram: SystemProgram.programId,
+    rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+  };
+}
+
+// The accounts that the `*_v2` instructions add for the mints of the whirlpool and their programs
+export function tokenProgramAccounts(params: TokenProgramParams) {
+  const { tokenMintA, tokenMintB, tokenProgramA, tokenProgramB } = params;
+  return { tokenMintA, tokenMintB, tokenProgramA, tokenProgramB };
+}
@@ -0,0 +1,135 @@
+import { AccountMeta, PublicKey, TransactionInstruction } from "@solana/web3.js";
+import {
+  EXTENSION_DYNAMIC_FEE,
+  EXTENSION_FEE_RATE_SCHEDULE,
+  EXTENSION_ORACLE,
+  EXTENSION_RANGE_ORDER_BOOK,
+} from "../../types/public";
+import { PDAUtil } from "./pda-utils";
+
+/**
+ * The extensions whose side accounts are passed to swap and two-hop swap instructions.
+ * The oracle is passed as a named account of these instructions.
+ * @category Whirlpool Utils
+ */
+export const SWAP_EXTENSIONS =
+  EXTENSION_DYNAMIC_FEE | EXTENSION_FEE_RATE_SCHEDULE | EXTENSION_RANGE_ORDER_BOOK;
+
+/**
+ * The extensions whose side accounts are passed to increase and decrease liquidity instructions.
+ * @category Whirlpool Utils
+ */
+export const MODIFY_LIQUIDITY_EXTENSIONS = EXTENSION_ORACLE | EXTENSION_FEE_RATE_SCHEDULE;
+
+/**
+ * The extensions whose side accounts follow the accounts of each hop of a route swap instruction.
+ * The pause controls are passed once per config after the last hop instead.
+ * @category Whirlpool Utils
+ */
+export const ROUTE_HOP_EXTENSIONS =
+  EXTENSION_ORACLE |
+  EXTENSION_DYNAMIC_FEE |
+  EXTENSION_FEE_RATE_SCHEDULE |
+  EXTENSION_RANGE_ORDER_BOOK;
+
+/**
+ * The extensions whose side accounts follow the accounts of each leg of a split swap instruction.
+ * The pause controls are passed once per config after the last leg instead.
+ * @category Whirlpool Utils
+ */
+export const SPLIT_SWAP_LEG_EXTENSIONS =
+  EXTENSION_ORACLE |
+  EXTENSION_DYNAMIC_FEE |
+  EXTENSION_FEE_RATE_SCHEDULE |
+  EXTENSION_RANGE_ORDER_BOOK;
+
+/**
+ * @category Whirlpool Utils
+ */
+export class ExtensionUtil {
+  private constructor() {}
+
+  public static isExtensionEnabled(extensions: number, extension: number): boolean {
+    return (extensions & extension) !== 0;
+  }
+
+  /**
+   * Get the side accounts of the extensions enabled on a Whirlpool that an instruction uses, in the
+   * order the Whirlpool program expects them in the remaining accounts of the instruction.
+   *
+   * @param programId - PublicKey of the Whirlpool program
+   * @param whirlpool - PublicKey of the Whirlpool
+   * @param extensions - The extensions enabled on the Whirlpool, see {@link WhirlpoolData}
+   * @param used - The extensions used by the instruction
+   * @returns The remaining accounts to pass to the instruction
+   */
+  public static getRemainingAccounts(
+    programId: PublicKey,
+    whirlpool: PublicKey,
+    extensions: number,
+    used: number
+  ): AccountMeta[] {
+    const sideAccounts: [number, () => PublicKey, boolean][] = [
+      [EXTENSION_ORACLE, () => PDAUtil.getOracle(programId, whirlpool).publicKey, true],
+      [EXTENSION_DYNAMIC_FEE, () => PDAUtil.getDynamicFee(programId, whirlpool).publicKey, true],
+      [
+        EXTENSION_FEE_RATE_SCHEDULE,
+        () => PDAUtil.getFeeRateSchedule(programId, whirlpool).publicKey,
+        true,
+      ],
+      [
+        EXTENSION_RANGE_ORDER_BOOK,
+        () => PDAUtil.getRangeOrderBook(programId, whirlpool).publicKey,
+        true,
+      ],
+    ];
+
+    return sideAccounts
+      .filter(([extension]) => ExtensionUtil.isExtensionEnabled(extensions & used, extension))
+      .map(([_, getPubkey, isWritable]) => ({ pubkey: getPubkey(), isSigner: false, isWritable }));
+  }
+
+  /**
+   * Get the pause controls of the configs of the Whirlpools, once per config, for instructions that
+   * pass them after the accounts of all of their Whirlpools. They are required whether or not they
+   * have been initialized.
+   *
+   * @param programId - PublicKey of the Whirlpool program
+   * @param whirlpools - The WhirlpoolsConfig of each Whirlpool
+   * @returns The remaining accounts to pass after the accounts of the Whirlpools
+   */
+  public static getPauseControlsPerConfig(
+    programId: PublicKey,
+    whirlpools: { whirlpoolsConfig: PublicKey }[]
+  ): AccountMeta[] {
+    const configs = whirlpools.map(({ whirlpoolsConfig }) => whirlpoolsConfig);
+
+    return configs
+      .filter((config, i) => configs.findIndex((other) => other.equals(config)) === i)
+      .map((config) => ({
+        pubkey: PDAUtil.getPauseControls(programId, config).publicKey,
+        isSigner: false,
+        isWritable: false,
+      }));
+  }
+
+  /**
+   * Mark the oracle of a swap instruction writable if the oracle extension is enabled on its
+   * Whirlpool. The oracle is a read-only account of swap instructions, and the observations of an
+   * oracle-enabled Whirlpool can only be recorded when it is passed writable.
+   *
+   * @param ix - The swap instruction
+   * @param oracle - PublicKey of the oracle of the Whirlpool
+   * @param extensions - The extensions enabled on the Whirlpool, see {@link WhirlpoolData}
+   */
+  public static markOracleWritable(
+    ix: TransactionInstruction,
+    oracle: PublicKey,
+    extensions: number
+  ) {
+    if (!ExtensionUtil.isExtensionEnabled(extensions, EXTENSION_ORACLE)) {
+      return;
+    }
+    ix.keys.filter((key) => key.pubkey.equals(oracle)).forEach((key) => (key.isWritable = true));
+  }
+}
@@ -6,3 +6,4 @@ export * from "./swap-utils";
 export * from "./tick-utils";
 export * from "./types";
 export * from "./apr-calculation";
+export * from "./extension-utils";
@@ -11,6 +11,18 @@ const PDA_METADATA_SEED = "metadata";
 const PDA_TICK_ARRAY_SEED = "tick_array";
 const PDA_FEE_TIER_SEED = "fee_tier";
 const PDA_ORACLE_SEED = "oracle";
+const PDA_DYNAMIC_FEE_SEED = "dynamic_fee";
+const PDA_FEE_RATE_SCHEDULE_SEED = "fee_rate_schedule";
+const PDA_PENDING_AUTHORITIES_SEED = "pending_authorities";
+const PDA_POOL_CREATION_POLICY_SEED = "pool_creation_policy";
+const PDA_FEE_TIER_POLICY_SEED = "fee_tier_policy";
+const PDA_PAUSE_CONTROLS_SEED = "pause_controls";
+const PDA_RANGE_ORDER_BOOK_SEED = "range_order_book";
+const PDA_RANGE_ORDER_ESCROW_SEED = "range_order_escrow";
+const PDA_METADATA_SETTINGS_SEED = "metadata_settings";
+const PDA_POSITION_COLLECTION_SEED = "position_collection";
+const PDA_EDITION_SEED = "edition";
+const PDA_NATIVE_WRAP_SEED = "native_wrap";
 
 /**
  * @category Whirlpool Utils
@@ -168,4 +180,164 @@ export class PDAUtil {
       programId
     );
   }
+
+  /**
+   * @category Program Derived Addresses
+   * @param programId
+   * @param whirlpoolAddress
+   * @returns
+   */
+  public static getDynamicFee(programId: PublicKey, whirlpoolAddress: PublicKey) {
+    return AddressUtil.findProgramAddress(
+      [Buffer.from(PDA_DYNAMIC_FEE_SEED), whirlpoolAddress.toBuffer()],
+      programId
+    );
+  }
+
+  /**
+   * @category Program Derived Addresses
+   * @param programId
+   * @param whirlpoolAddress
+   * @returns
+   */
+  public static getFeeRateSchedule(programId: PublicKey, whirlpoolAddress: PublicKey) {
+    return AddressUtil.findProgramAddress(
+      [Buffer.from(PDA_FEE_RATE_SCHEDULE_SEED), whirlpoolAddress.toBuffer()],
+      programId
+    );
+  }
+
+  /**
+   * @category Program Derived Addresses
+   * @param programId
+   * @param whirlpoolsConfigAddress
+   * @returns
+   */
+  public static getPendingAuthorities(programId: PublicKey, whirlpoolsConfigAddress: PublicKey) {
+    return AddressUtil.findProgramAddress(
+      [Buffer.from(PDA_PENDING_AUTHORITIES_SEED), whirlpoolsConfigAddress.toBuffer()],
+      programId
+    );
+  }
+
+  /**
+   * @category Program Derived Addresses
+   * @param programId
+   * @param whirlpoolsConfigAddress
+   * @returns
+   */
+  public static getPoolCreationPolicy(programId: PublicKey, whirlpoolsConfigAddress: PublicKey) {
+    return AddressUtil.findProgramAddress(
+      [Buffer.from(PDA_POOL_CREATION_POLICY_SEED), whirlpoolsConfigAddress.toBuffer()],
+      programId
+    );
+  }
+
+  /**
+   * @category Program Derived Addresses
+   * @param programId
+   * @param whirlpoolsConfigAddress
+   * @returns
+   */
+  public static getFeeTierPolicy(programId: PublicKey, whirlpoolsConfigAddress: PublicKey) {
+    return AddressUtil.findProgramAddress(
+      [Buffer.from(PDA_FEE_TIER_POLICY_SEED), whirlpoolsConfigAddress.toBuffer()],
+      programId
+    );
+  }
+
+  /**
+   * @category Program Derived Addresses
+   * @param programId
+   * @param whirlpoolsConfigAddress
+   * @returns
+   */
+  public static getPauseControls(programId: PublicKey, whirlpoolsConfigAddress: PublicKey) {
+    return AddressUtil.findProgramAddress(
+      [Buffer.from(PDA_PAUSE_CONTROLS_SEED), whirlpoolsConfigAddress.toBuffer()],
+      programId
+    );
+  }
+
+  /**
+   * @category Program Derived Addresses
+   * @param programId
+   * @param whirlpoolAddress
+   * @returns
+   */
+  public static getRangeOrderBook(programId: PublicKey, whirlpoolAddress: PublicKey) {
+    return AddressUtil.findProgramAddress(
+      [Buffer.from(PDA_RANGE_ORDER_BOOK_SEED), whirlpoolAddress.toBuffer()],
+      programId
+    );
+  }
+
+  /**
+   * @category Program Derived Addresses
+   * @param programId
+   * @param positionAddress
+   * @returns
+   */
+  public static getRangeOrderEscrow(programId: PublicKey, positionAddress: PublicKey) {
+    return AddressUtil.findProgramAddress(
+      [Buffer.from(PDA_RANGE_ORDER_ESCROW_SEED), positionAddress.toBuffer()],
+      programId
+    );
+  }
+
+  /**
+   * @category Program Derived Addresses
+   * @param programId
+   * @param whirlpoolsConfigAddress
+   * @returns
+   */
+  public static getMetadataSettings(programId: PublicKey, whirlpoolsConfigAddress: PublicKey) {
+    return AddressUtil.findProgramAddress(
+      [Buffer.from(PDA_METADATA_SETTINGS_SEED), whirlpoolsConfigAddress.toBuffer()],
+      programId
+    );
+  }
+
+  /**
+   * @category Program Derived Addresses
+   * @param programId
+   * @param whirlpoolsConfigAddress
+   * @returns
+   */
+  public static getPositionCollection(programId: PublicKey, whirlpoolsConfigAddress: PublicKey) {
+    return AddressUtil.findProgramAddress(
+      [Buffer.from(PDA_POSITION_COLLECTION_SEED), whirlpoolsConfigAddress.toBuffer()],
+      programId
+    );
+  }
+
+  /**
+   * @category Program Derived Addresses
+   * @param mintKey
+   * @returns
+   */
+  public static getMasterEdition(mintKey: PublicKey) {
+    return AddressUtil.findProgramAddress(
+      [
+        Buffer.from(PDA_METADATA_SEED),
+        METADATA_PROGRAM_ADDRESS.toBuffer(),
+        mintKey.toBuffer(),
+        Buffer.from(PDA_EDITION_SEED),
+      ],
+      METADATA_PROGRAM_ADDRESS
+    );
+  }
+
+  /**
+   * @category Program Derived Addresses
+   * @param programId
+   * @param authority
+   * @returns
+   */
+  public static getNativeWrap(programId: PublicKey, authority: PublicKey) {
+    return AddressUtil.findProgramAddress(
+      [Buffer.from(PDA_NATIVE_WRAP_SEED), authority.toBuffer()],
+      programId
+    );
+  }
 }
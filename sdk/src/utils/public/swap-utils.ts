@@ -201,11 +201,13 @@ export class SwapUtils {
     const oraclePda = PDAUtil.getOracle(ctx.program.programId, addr);
     const params: SwapParams = {
       whirlpool: whirlpool.getAddress(),
+      whirlpoolsConfig: data.whirlpoolsConfig,
       tokenOwnerAccountA: aToB ? inputTokenATA : outputTokenATA,
       tokenOwnerAccountB: aToB ? outputTokenATA : inputTokenATA,
       tokenVaultA: data.tokenVaultA,
       tokenVaultB: data.tokenVaultB,
       oracle: oraclePda.publicKey,
+      extensions: data.extensions,
       tokenAuthority: wallet,
       ...quote,
     };
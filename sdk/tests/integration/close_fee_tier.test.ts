@@ -0,0 +1,119 @@
+import * as anchor from "@project-serum/anchor";
+import * as assert from "assert";
+import { toTx, WhirlpoolContext, WhirlpoolIx } from "../../src";
+import { TickSpacing } from "../utils";
+import { initFeeTier, initTestPool } from "../utils/init-utils";
+import { generateDefaultConfigParams } from "../utils/test-builders";
+
+describe("close_fee_tier", () => {
+  const provider = anchor.AnchorProvider.local();
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.Whirlpool;
+  const ctx = WhirlpoolContext.fromWorkspace(provider, program);
+  const fetcher = ctx.fetcher;
+
+  it("successfully closes a fee tier without pools", async () => {
+    const { configInitInfo, configKeypairs } = generateDefaultConfigParams(ctx);
+    await toTx(ctx, WhirlpoolIx.initializeConfigIx(ctx.program, configInitInfo)).buildAndExecute();
+    const feeAuthorityKeypair = configKeypairs.feeAuthorityKeypair;
+    const { params } = await initFeeTier(
+      ctx,
+      configInitInfo,
+      feeAuthorityKeypair,
+      TickSpacing.Standard,
+      3000
+    );
+    const receiverKeypair = anchor.web3.Keypair.generate();
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.closeFeeTierIx(ctx.program, {
+        whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+        feeAuthority: feeAuthorityKeypair.publicKey,
+        tickSpacing: TickSpacing.Standard,
+        receiver: receiverKeypair.publicKey,
+      })
+    )
+      .addSigner(feeAuthorityKeypair)
+      .buildAndExecute();
+
+    assert.equal(await provider.connection.getAccountInfo(params.feeTierPda.publicKey), undefined);
+
+    const receiverAccount = await provider.connection.getAccountInfo(receiverKeypair.publicKey);
+    const lamports = receiverAccount?.lamports;
+    assert.ok(lamports != undefined && lamports > 0);
+  });
+
+  it("counts the pools initialized with a fee tier", async () => {
+    const { feeTierParams } = await initTestPool(ctx, TickSpacing.Standard);
+
+    const feeTier = await ctx.program.account.feeTier.fetch(feeTierParams.feeTierPda.publicKey);
+    assert.ok(feeTier.poolCount.eqn(1));
+  });
+
+  it("fails to close a fee tier with pools", async () => {
+    const { configInitInfo, configKeypairs, feeTierParams } = await initTestPool(
+      ctx,
+      TickSpacing.Standard
+    );
+    const feeAuthorityKeypair = configKeypairs.feeAuthorityKeypair;
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.closeFeeTierIx(ctx.program, {
+          whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+          feeAuthority: feeAuthorityKeypair.publicKey,
+          tickSpacing: TickSpacing.Standard,
+          receiver: provider.wallet.publicKey,
+        })
+      )
+        .addSigner(feeAuthorityKeypair)
+        .buildAndExecute(),
+      /0x17b9/ // FeeTierHasPools
+    );
+
+    const feeTier = await fetcher.getFeeTier(feeTierParams.feeTierPda.publicKey, true);
+    assert.ok(feeTier);
+  });
+
+  it("fails to migrate a fee tier that already counts its pools", async () => {
+    const { configInitInfo, configKeypairs } = await initTestPool(ctx, TickSpacing.Standard);
+    const feeAuthorityKeypair = configKeypairs.feeAuthorityKeypair;
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.migrateFeeTierIx(ctx.program, {
+          whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+          feeAuthority: feeAuthorityKeypair.publicKey,
+          tickSpacing: TickSpacing.Standard,
+          funder: provider.wallet.publicKey,
+        })
+      )
+        .addSigner(feeAuthorityKeypair)
+        .buildAndExecute(),
+      /0x17ba/ // InvalidFeeTierMigration
+    );
+  });
+
+  it("fails when invalid fee authority provided", async () => {
+    const { configInitInfo } = await initTestPool(ctx, TickSpacing.Standard);
+    const fakeFeeAuthorityKeypair = anchor.web3.Keypair.generate();
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.closeFeeTierIx(ctx.program, {
+          whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+          feeAuthority: fakeFeeAuthorityKeypair.publicKey,
+          tickSpacing: TickSpacing.Standard,
+          receiver: provider.wallet.publicKey,
+        })
+      )
+        .addSigner(fakeFeeAuthorityKeypair)
+        .buildAndExecute(),
+      /0x7dc/ // ConstraintAddress
+    );
+  });
+});
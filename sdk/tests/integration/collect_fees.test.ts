@@ -48,6 +48,7 @@ describe("collect_fees", () => {
     const {
       poolInitInfo: {
         whirlpoolPda,
+        whirlpoolsConfig,
         tokenVaultAKeypair,
         tokenVaultBKeypair,
         tokenMintA,
@@ -75,6 +76,7 @@ describe("collect_fees", () => {
         amountSpecifiedIsInput: true,
         aToB: true,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: tokenVaultAKeypair.publicKey,
@@ -97,6 +99,7 @@ describe("collect_fees", () => {
         amountSpecifiedIsInput: true,
         aToB: false,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: tokenVaultAKeypair.publicKey,
@@ -146,6 +149,7 @@ describe("collect_fees", () => {
       ctx,
       WhirlpoolIx.collectFeesIx(ctx.program, {
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig,
         positionAuthority: provider.wallet.publicKey,
         position: positions[0].publicKey,
         positionTokenAccount: positions[0].tokenAccount,
@@ -188,7 +192,7 @@ describe("collect_fees", () => {
       ],
     });
     const {
-      poolInitInfo: { whirlpoolPda, tokenVaultAKeypair, tokenVaultBKeypair },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig, tokenVaultAKeypair, tokenVaultBKeypair },
       positions,
       tokenAccountA,
       tokenAccountB,
@@ -202,6 +206,7 @@ describe("collect_fees", () => {
       ctx,
       WhirlpoolIx.collectFeesIx(ctx.program, {
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig,
         positionAuthority: delegate.publicKey,
         position: position.publicKey,
         positionTokenAccount: position.tokenAccount,
@@ -224,7 +229,7 @@ describe("collect_fees", () => {
       ],
     });
     const {
-      poolInitInfo: { whirlpoolPda, tokenVaultAKeypair, tokenVaultBKeypair },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig, tokenVaultAKeypair, tokenVaultBKeypair },
       positions,
       tokenAccountA,
       tokenAccountB,
@@ -238,6 +243,7 @@ describe("collect_fees", () => {
       ctx,
       WhirlpoolIx.collectFeesIx(ctx.program, {
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig,
         positionAuthority: provider.wallet.publicKey,
         position: position.publicKey,
         positionTokenAccount: position.tokenAccount,
@@ -258,7 +264,7 @@ describe("collect_fees", () => {
       ],
     });
     const {
-      poolInitInfo: { whirlpoolPda, tokenVaultAKeypair, tokenVaultBKeypair },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig, tokenVaultAKeypair, tokenVaultBKeypair },
       positions,
       tokenAccountA,
       tokenAccountB,
@@ -278,6 +284,7 @@ describe("collect_fees", () => {
       ctx,
       WhirlpoolIx.collectFeesIx(ctx.program, {
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig,
         positionAuthority: newOwner.publicKey,
         position: position.publicKey,
         positionTokenAccount: newOwnerPositionTokenAccount,
@@ -308,7 +315,7 @@ describe("collect_fees", () => {
     } = fixture.getInfos();
 
     const {
-      poolInitInfo: { whirlpoolPda: whirlpoolPda2 },
+      poolInitInfo: { whirlpoolPda: whirlpoolPda2, whirlpoolsConfig: whirlpoolsConfig2 },
     } = await initTestPool(ctx, tickSpacing);
 
     await assert.rejects(
@@ -316,6 +323,7 @@ describe("collect_fees", () => {
         ctx,
         WhirlpoolIx.collectFeesIx(ctx.program, {
           whirlpool: whirlpoolPda2.publicKey,
+          whirlpoolsConfig: whirlpoolsConfig2,
           positionAuthority: provider.wallet.publicKey,
           position: positions[0].publicKey,
           positionTokenAccount: positions[0].tokenAccount,
@@ -339,7 +347,7 @@ describe("collect_fees", () => {
       positions: [{ tickLowerIndex, tickUpperIndex, liquidityAmount: new anchor.BN(10_000_000) }],
     });
     const {
-      poolInitInfo: { whirlpoolPda, tokenVaultAKeypair, tokenVaultBKeypair },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig, tokenVaultAKeypair, tokenVaultBKeypair },
       tokenAccountA,
       tokenAccountB,
       positions,
@@ -356,6 +364,7 @@ describe("collect_fees", () => {
         ctx,
         WhirlpoolIx.collectFeesIx(ctx.program, {
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positions[0].publicKey,
           positionTokenAccount: positionTokenAccount2,
@@ -375,6 +384,7 @@ describe("collect_fees", () => {
         ctx,
         WhirlpoolIx.collectFeesIx(ctx.program, {
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positions[0].publicKey,
           positionTokenAccount: positions[0].tokenAccount,
@@ -398,7 +408,7 @@ describe("collect_fees", () => {
       positions: [{ tickLowerIndex, tickUpperIndex, liquidityAmount: new anchor.BN(10_000_000) }],
     });
     const {
-      poolInitInfo: { whirlpoolPda, tokenVaultAKeypair, tokenVaultBKeypair },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig, tokenVaultAKeypair, tokenVaultBKeypair },
       tokenAccountA,
       tokenAccountB,
       positions,
@@ -411,6 +421,7 @@ describe("collect_fees", () => {
         ctx,
         WhirlpoolIx.collectFeesIx(ctx.program, {
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig,
           positionAuthority: delegate.publicKey,
           position: positions[0].publicKey,
           positionTokenAccount: positions[0].tokenAccount,
@@ -436,7 +447,7 @@ describe("collect_fees", () => {
       positions: [{ tickLowerIndex, tickUpperIndex, liquidityAmount: new anchor.BN(10_000_000) }],
     });
     const {
-      poolInitInfo: { whirlpoolPda, tokenVaultAKeypair, tokenVaultBKeypair },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig, tokenVaultAKeypair, tokenVaultBKeypair },
       tokenAccountA,
       tokenAccountB,
       positions,
@@ -450,6 +461,7 @@ describe("collect_fees", () => {
         ctx,
         WhirlpoolIx.collectFeesIx(ctx.program, {
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig,
           positionAuthority: delegate.publicKey,
           position: positions[0].publicKey,
           positionTokenAccount: positions[0].tokenAccount,
@@ -475,7 +487,7 @@ describe("collect_fees", () => {
       positions: [{ tickLowerIndex, tickUpperIndex, liquidityAmount: new anchor.BN(10_000_000) }],
     });
     const {
-      poolInitInfo: { whirlpoolPda, tokenVaultAKeypair, tokenVaultBKeypair },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig, tokenVaultAKeypair, tokenVaultBKeypair },
       tokenAccountA,
       tokenAccountB,
       positions,
@@ -489,6 +501,7 @@ describe("collect_fees", () => {
         ctx,
         WhirlpoolIx.collectFeesIx(ctx.program, {
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig,
           positionAuthority: delegate.publicKey,
           position: positions[0].publicKey,
           positionTokenAccount: positions[0].tokenAccount,
@@ -512,7 +525,13 @@ describe("collect_fees", () => {
       positions: [{ tickLowerIndex, tickUpperIndex, liquidityAmount: new anchor.BN(10_000_000) }],
     });
     const {
-      poolInitInfo: { whirlpoolPda, tokenVaultAKeypair, tokenVaultBKeypair, tokenMintA },
+      poolInitInfo: {
+        whirlpoolPda,
+        whirlpoolsConfig,
+        tokenVaultAKeypair,
+        tokenVaultBKeypair,
+        tokenMintA,
+      },
       tokenAccountA,
       tokenAccountB,
       positions,
@@ -529,6 +548,7 @@ describe("collect_fees", () => {
         ctx,
         WhirlpoolIx.collectFeesIx(ctx.program, {
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positions[0].publicKey,
           positionTokenAccount: fakePositionTokenAccount,
@@ -554,6 +574,7 @@ describe("collect_fees", () => {
     const {
       poolInitInfo: {
         whirlpoolPda,
+        whirlpoolsConfig,
         tokenVaultAKeypair,
         tokenVaultBKeypair,
         tokenMintA,
@@ -572,6 +593,7 @@ describe("collect_fees", () => {
         ctx,
         WhirlpoolIx.collectFeesIx(ctx.program, {
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positions[0].publicKey,
           positionTokenAccount: positions[0].tokenAccount,
@@ -589,6 +611,7 @@ describe("collect_fees", () => {
         ctx,
         WhirlpoolIx.collectFeesIx(ctx.program, {
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positions[0].publicKey,
           positionTokenAccount: positions[0].tokenAccount,
@@ -614,6 +637,7 @@ describe("collect_fees", () => {
     const {
       poolInitInfo: {
         whirlpoolPda,
+        whirlpoolsConfig,
         tokenVaultAKeypair,
         tokenVaultBKeypair,
         tokenMintA,
@@ -640,6 +664,7 @@ describe("collect_fees", () => {
         ctx,
         WhirlpoolIx.collectFeesIx(ctx.program, {
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positions[0].publicKey,
           positionTokenAccount: positions[0].tokenAccount,
@@ -657,6 +682,7 @@ describe("collect_fees", () => {
         ctx,
         WhirlpoolIx.collectFeesIx(ctx.program, {
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positions[0].publicKey,
           positionTokenAccount: positions[0].tokenAccount,
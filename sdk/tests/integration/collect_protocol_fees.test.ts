@@ -3,10 +3,23 @@ import * as anchor from "@project-serum/anchor";
 import { u64 } from "@solana/spl-token";
 import * as assert from "assert";
 import Decimal from "decimal.js";
-import { PDAUtil, toTx, WhirlpoolContext, WhirlpoolData, WhirlpoolIx } from "../../src";
-import { createTokenAccount, getTokenBalance, TickSpacing, ZERO_BN } from "../utils";
+import {
+  EXTENSION_FEE_RATE_SCHEDULE,
+  PDAUtil,
+  toTx,
+  WhirlpoolContext,
+  WhirlpoolData,
+  WhirlpoolIx,
+} from "../../src";
+import {
+  createTokenAccount,
+  getTokenBalance,
+  TickSpacing,
+  waitUntilTimestamp,
+  ZERO_BN,
+} from "../utils";
 import { WhirlpoolTestFixture } from "../utils/fixture";
-import { initTestPool } from "../utils/init-utils";
+import { initFeeRateSchedule, initTestPool } from "../utils/init-utils";
 
 describe("collect_protocol_fees", () => {
   const provider = anchor.AnchorProvider.local();
@@ -28,6 +41,7 @@ describe("collect_protocol_fees", () => {
     const {
       poolInitInfo: {
         whirlpoolPda,
+        whirlpoolsConfig,
         tokenVaultAKeypair,
         tokenVaultBKeypair,
         tokenMintA,
@@ -40,6 +54,12 @@ describe("collect_protocol_fees", () => {
       positions,
     } = fixture.getInfos();
 
+    await initFeeRateSchedule(
+      ctx,
+      whirlpoolsConfigKeypair.publicKey,
+      whirlpoolPda.publicKey,
+      feeAuthorityKeypair
+    );
     await toTx(
       ctx,
       WhirlpoolIx.setProtocolFeeRateIx(ctx.program, {
@@ -52,6 +72,16 @@ describe("collect_protocol_fees", () => {
       .addSigner(feeAuthorityKeypair)
       .buildAndExecute();
 
+    // The protocol fee rate is applied by the first swap once the change is due
+    const feeRateSchedulePda = PDAUtil.getFeeRateSchedule(
+      ctx.program.programId,
+      whirlpoolPda.publicKey
+    );
+    const feeRateSchedule = await ctx.program.account.feeRateSchedule.fetch(
+      feeRateSchedulePda.publicKey
+    );
+    await waitUntilTimestamp(provider, feeRateSchedule.effectiveAt);
+
     const poolBefore = (await fetcher.getPool(whirlpoolPda.publicKey, true)) as WhirlpoolData;
     assert.ok(poolBefore?.protocolFeeOwedA.eq(ZERO_BN));
     assert.ok(poolBefore?.protocolFeeOwedB.eq(ZERO_BN));
@@ -70,6 +100,7 @@ describe("collect_protocol_fees", () => {
         amountSpecifiedIsInput: true,
         aToB: true,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: tokenVaultAKeypair.publicKey,
@@ -79,6 +110,7 @@ describe("collect_protocol_fees", () => {
         tickArray1: tickArrayPda,
         tickArray2: tickArrayPda,
         oracle: oraclePda.publicKey,
+        extensions: EXTENSION_FEE_RATE_SCHEDULE,
       })
     ).buildAndExecute();
 
@@ -92,6 +124,7 @@ describe("collect_protocol_fees", () => {
         amountSpecifiedIsInput: true,
         aToB: false,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: tokenVaultAKeypair.publicKey,
@@ -101,6 +134,7 @@ describe("collect_protocol_fees", () => {
         tickArray1: tickArrayPda,
         tickArray2: tickArrayPda,
         oracle: oraclePda.publicKey,
+        extensions: EXTENSION_FEE_RATE_SCHEDULE,
       })
     ).buildAndExecute();
 
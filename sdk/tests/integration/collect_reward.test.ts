@@ -62,7 +62,7 @@ describe("collect_reward", () => {
       ],
     });
     const {
-      poolInitInfo: { whirlpoolPda },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig },
       positions,
       rewards,
     } = fixture.getInfos();
@@ -103,6 +103,7 @@ describe("collect_reward", () => {
         ctx,
         WhirlpoolIx.collectRewardIx(ctx.program, {
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positions[0].publicKey,
           positionTokenAccount: positions[0].tokenAccount,
@@ -140,7 +141,7 @@ describe("collect_reward", () => {
       ],
     });
     const {
-      poolInitInfo: { whirlpoolPda },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig },
       positions,
       rewards,
     } = fixture.getInfos();
@@ -167,6 +168,7 @@ describe("collect_reward", () => {
       ctx,
       WhirlpoolIx.collectRewardIx(ctx.program, {
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig,
         positionAuthority: delegate.publicKey,
         position: positions[0].publicKey,
         positionTokenAccount: positions[0].tokenAccount,
@@ -195,7 +197,7 @@ describe("collect_reward", () => {
       ],
     });
     const {
-      poolInitInfo: { whirlpoolPda },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig },
       positions,
       rewards,
     } = fixture.getInfos();
@@ -227,6 +229,7 @@ describe("collect_reward", () => {
       ctx,
       WhirlpoolIx.collectRewardIx(ctx.program, {
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig,
         positionAuthority: delegate.publicKey,
         position: positions[0].publicKey,
         positionTokenAccount: delegatePositionAccount,
@@ -255,7 +258,7 @@ describe("collect_reward", () => {
       ],
     });
     const {
-      poolInitInfo: { whirlpoolPda },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig },
       positions,
       rewards,
     } = fixture.getInfos();
@@ -282,6 +285,7 @@ describe("collect_reward", () => {
       ctx,
       WhirlpoolIx.collectRewardIx(ctx.program, {
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig,
         positionAuthority: provider.wallet.publicKey,
         position: positions[0].publicKey,
         positionTokenAccount: positions[0].tokenAccount,
@@ -301,7 +305,7 @@ describe("collect_reward", () => {
       ],
     });
     const {
-      poolInitInfo: { whirlpoolPda },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig },
       positions,
     } = fixture.getInfos();
     const fakeRewardMint = await createMint(provider);
@@ -316,6 +320,7 @@ describe("collect_reward", () => {
         ctx,
         WhirlpoolIx.collectRewardIx(ctx.program, {
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positions[0].publicKey,
           positionTokenAccount: positions[0].tokenAccount,
@@ -342,7 +347,7 @@ describe("collect_reward", () => {
     const { positions, rewards } = fixture.getInfos();
 
     const {
-      poolInitInfo: { whirlpoolPda },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig },
     } = await initTestPool(ctx, TickSpacing.Standard);
     const rewardOwnerAccount = await createTokenAccount(
       provider,
@@ -354,6 +359,7 @@ describe("collect_reward", () => {
         ctx,
         WhirlpoolIx.collectRewardIx(ctx.program, {
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positions[0].publicKey,
           positionTokenAccount: positions[0].tokenAccount,
@@ -378,7 +384,7 @@ describe("collect_reward", () => {
       ],
     });
     const {
-      poolInitInfo: { whirlpoolPda },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig },
       positions,
       rewards,
     } = fixture.getInfos();
@@ -399,6 +405,7 @@ describe("collect_reward", () => {
         ctx,
         WhirlpoolIx.collectRewardIx(ctx.program, {
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positions[0].publicKey,
           positionTokenAccount: positions[0].tokenAccount,
@@ -423,7 +430,7 @@ describe("collect_reward", () => {
       ],
     });
     const {
-      poolInitInfo: { whirlpoolPda, tokenMintA },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig, tokenMintA },
       positions,
       rewards,
     } = fixture.getInfos();
@@ -440,6 +447,7 @@ describe("collect_reward", () => {
         ctx,
         WhirlpoolIx.collectRewardIx(ctx.program, {
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positions[0].publicKey,
           positionTokenAccount: fakePositionTokenAccount,
@@ -464,7 +472,7 @@ describe("collect_reward", () => {
       ],
     });
     const {
-      poolInitInfo: { whirlpoolPda },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig },
       positions,
       rewards,
     } = fixture.getInfos();
@@ -479,6 +487,7 @@ describe("collect_reward", () => {
         ctx,
         WhirlpoolIx.collectRewardIx(ctx.program, {
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig,
           positionAuthority: delegate.publicKey,
           position: positions[0].publicKey,
           positionTokenAccount: positions[0].tokenAccount,
@@ -505,7 +514,7 @@ describe("collect_reward", () => {
       ],
     });
     const {
-      poolInitInfo: { whirlpoolPda },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig },
       positions,
       rewards,
     } = fixture.getInfos();
@@ -521,6 +530,7 @@ describe("collect_reward", () => {
         ctx,
         WhirlpoolIx.collectRewardIx(ctx.program, {
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig,
           positionAuthority: delegate.publicKey,
           position: positions[0].publicKey,
           positionTokenAccount: positions[0].tokenAccount,
@@ -547,7 +557,7 @@ describe("collect_reward", () => {
       ],
     });
     const {
-      poolInitInfo: { whirlpoolPda },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig },
       positions,
       rewards,
     } = fixture.getInfos();
@@ -563,6 +573,7 @@ describe("collect_reward", () => {
         ctx,
         WhirlpoolIx.collectRewardIx(ctx.program, {
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig,
           positionAuthority: delegate.publicKey,
           position: positions[0].publicKey,
           positionTokenAccount: positions[0].tokenAccount,
@@ -587,7 +598,7 @@ describe("collect_reward", () => {
       ],
     });
     const {
-      poolInitInfo: { whirlpoolPda },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig },
       positions,
       rewards,
     } = fixture.getInfos();
@@ -601,6 +612,7 @@ describe("collect_reward", () => {
         ctx,
         WhirlpoolIx.collectRewardIx(ctx.program, {
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positions[0].publicKey,
           positionTokenAccount: positions[0].tokenAccount,
@@ -625,7 +637,7 @@ describe("collect_reward", () => {
       ],
     });
     const {
-      poolInitInfo: { whirlpoolPda, tokenMintA },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig, tokenMintA },
       positions,
       rewards,
     } = fixture.getInfos();
@@ -639,6 +651,7 @@ describe("collect_reward", () => {
         ctx,
         WhirlpoolIx.collectRewardIx(ctx.program, {
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positions[0].publicKey,
           positionTokenAccount: positions[0].tokenAccount,
@@ -663,7 +676,7 @@ describe("collect_reward", () => {
       ],
     });
     const {
-      poolInitInfo: { whirlpoolPda, tokenMintA },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig, tokenMintA },
       positions,
       rewards,
     } = fixture.getInfos();
@@ -677,6 +690,7 @@ describe("collect_reward", () => {
         ctx,
         WhirlpoolIx.collectRewardIx(ctx.program, {
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positions[0].publicKey,
           positionTokenAccount: positions[0].tokenAccount,
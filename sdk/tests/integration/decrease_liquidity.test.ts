@@ -59,6 +59,7 @@ describe("decrease_liquidity", () => {
       WhirlpoolIx.decreaseLiquidityIx(ctx.program, {
         ...removalQuote,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         positionAuthority: provider.wallet.publicKey,
         position: positions[0].publicKey,
         positionTokenAccount: positions[0].tokenAccount,
@@ -115,6 +116,7 @@ describe("decrease_liquidity", () => {
       WhirlpoolIx.decreaseLiquidityIx(ctx.program, {
         ...removalQuote,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         positionAuthority: provider.wallet.publicKey,
         position: position.publicKey,
         positionTokenAccount: position.tokenAccount,
@@ -174,6 +176,7 @@ describe("decrease_liquidity", () => {
         tokenMinA: new u64(0),
         tokenMinB: new u64(0),
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         positionAuthority: delegate.publicKey,
         position: position.publicKey,
         positionTokenAccount: position.tokenAccount,
@@ -215,6 +218,7 @@ describe("decrease_liquidity", () => {
         tokenMinA: new u64(0),
         tokenMinB: new u64(0),
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         positionAuthority: provider.wallet.publicKey,
         position: position.publicKey,
         positionTokenAccount: position.tokenAccount,
@@ -255,6 +259,7 @@ describe("decrease_liquidity", () => {
         tokenMinA: new u64(0),
         tokenMinB: new u64(0),
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         positionAuthority: newOwner.publicKey,
         position: position.publicKey,
         positionTokenAccount: newOwnerPositionTokenAccount,
@@ -289,6 +294,7 @@ describe("decrease_liquidity", () => {
           tokenMinA: new u64(0),
           tokenMinB: new u64(0),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: position.publicKey,
           positionTokenAccount: position.tokenAccount,
@@ -322,6 +328,7 @@ describe("decrease_liquidity", () => {
           tokenMinA: new u64(0),
           tokenMinB: new u64(0),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: position.publicKey,
           positionTokenAccount: position.tokenAccount,
@@ -356,6 +363,7 @@ describe("decrease_liquidity", () => {
           tokenMinA: new u64(1_000_000),
           tokenMinB: new u64(0),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: position.publicKey,
           positionTokenAccount: position.tokenAccount,
@@ -389,6 +397,7 @@ describe("decrease_liquidity", () => {
           tokenMinA: new u64(0),
           tokenMinB: new u64(1_000_000),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: position.publicKey,
           positionTokenAccount: position.tokenAccount,
@@ -430,6 +439,7 @@ describe("decrease_liquidity", () => {
           tokenMinA: new u64(0),
           tokenMinB: new u64(0),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: position.publicKey,
           positionTokenAccount: newPositionTokenAccount,
@@ -455,6 +465,7 @@ describe("decrease_liquidity", () => {
           tokenMinA: new u64(0),
           tokenMinB: new u64(0),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: position.publicKey,
           positionTokenAccount: position.tokenAccount,
@@ -491,6 +502,7 @@ describe("decrease_liquidity", () => {
           tokenMinA: new u64(0),
           tokenMinB: new u64(0),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: position.publicKey,
           positionTokenAccount: invalidPositionTokenAccount,
@@ -530,6 +542,7 @@ describe("decrease_liquidity", () => {
           tokenMinA: new u64(0),
           tokenMinB: new u64(0),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positionPda.publicKey,
           positionTokenAccount: positionTokenAccountAddress,
@@ -567,6 +580,7 @@ describe("decrease_liquidity", () => {
           tokenMinA: new u64(0),
           tokenMinB: new u64(0),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: position.publicKey,
           positionTokenAccount: position.tokenAccount,
@@ -589,6 +603,7 @@ describe("decrease_liquidity", () => {
           tokenMinA: new u64(0),
           tokenMinB: new u64(0),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: position.publicKey,
           positionTokenAccount: position.tokenAccount,
@@ -625,6 +640,7 @@ describe("decrease_liquidity", () => {
           tokenMinA: new u64(0),
           tokenMinB: new u64(0),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: position.publicKey,
           positionTokenAccount: position.tokenAccount,
@@ -647,6 +663,7 @@ describe("decrease_liquidity", () => {
           tokenMinA: new u64(0),
           tokenMinB: new u64(0),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: position.publicKey,
           positionTokenAccount: position.tokenAccount,
@@ -685,6 +702,7 @@ describe("decrease_liquidity", () => {
           tokenMinA: new u64(0),
           tokenMinB: new u64(0),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: delegate.publicKey,
           position: position.publicKey,
           positionTokenAccount: position.tokenAccount,
@@ -726,6 +744,7 @@ describe("decrease_liquidity", () => {
           tokenMinA: new u64(0),
           tokenMinB: new u64(0),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: delegate.publicKey,
           position: position.publicKey,
           positionTokenAccount: position.tokenAccount,
@@ -767,6 +786,7 @@ describe("decrease_liquidity", () => {
           tokenMinA: new u64(0),
           tokenMinB: new u64(167_000),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: delegate.publicKey,
           position: position.publicKey,
           positionTokenAccount: position.tokenAccount,
@@ -809,6 +829,7 @@ describe("decrease_liquidity", () => {
           tokenMinA: new u64(0),
           tokenMinB: new u64(0),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: position.publicKey,
           positionTokenAccount: position.tokenAccount,
@@ -853,6 +874,7 @@ describe("decrease_liquidity", () => {
           tokenMinA: new u64(0),
           tokenMinB: new u64(0),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: position.publicKey,
           positionTokenAccount: position.tokenAccount,
@@ -0,0 +1,242 @@
+import { MathUtil } from "@orca-so/common-sdk";
+import * as anchor from "@project-serum/anchor";
+import { u64 } from "@solana/spl-token";
+import * as assert from "assert";
+import Decimal from "decimal.js";
+import {
+  EXTENSION_FEE_RATE_SCHEDULE,
+  PDAUtil,
+  toTx,
+  WhirlpoolContext,
+  WhirlpoolData,
+  WhirlpoolIx,
+} from "../../src";
+import { getChainTimestamp, TickSpacing, waitUntilTimestamp, ZERO_BN } from "../utils";
+import { WhirlpoolTestFixture } from "../utils/fixture";
+import { initFeeRateSchedule } from "../utils/init-utils";
+
+describe("fee_rate_schedule", () => {
+  const provider = anchor.AnchorProvider.local();
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.Whirlpool;
+  const ctx = WhirlpoolContext.fromWorkspace(provider, program);
+  const fetcher = ctx.fetcher;
+
+  const tickLowerIndex = 29440;
+  const tickUpperIndex = 33536;
+  const newFeeRate = 500;
+  const newProtocolFeeRate = 100;
+
+  async function initFixtureWithFeeRateSchedule() {
+    const fixture = await new WhirlpoolTestFixture(ctx).init({
+      tickSpacing: TickSpacing.Standard,
+      positions: [{ tickLowerIndex, tickUpperIndex, liquidityAmount: new anchor.BN(10_000_000) }],
+    });
+    const { poolInitInfo, configKeypairs } = fixture.getInfos();
+
+    await initFeeRateSchedule(
+      ctx,
+      poolInitInfo.whirlpoolsConfig,
+      poolInitInfo.whirlpoolPda.publicKey,
+      configKeypairs.feeAuthorityKeypair
+    );
+
+    return fixture;
+  }
+
+  async function scheduleFeeRateChange(fixture: WhirlpoolTestFixture, effectiveAt: anchor.BN) {
+    const { poolInitInfo, configKeypairs } = fixture.getInfos();
+    await toTx(
+      ctx,
+      WhirlpoolIx.scheduleFeeRateChangeIx(ctx.program, {
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+        whirlpool: poolInitInfo.whirlpoolPda.publicKey,
+        feeAuthority: configKeypairs.feeAuthorityKeypair.publicKey,
+        feeRate: newFeeRate,
+        protocolFeeRate: newProtocolFeeRate,
+        effectiveAt,
+      })
+    )
+      .addSigner(configKeypairs.feeAuthorityKeypair)
+      .buildAndExecute();
+  }
+
+  // The shortened delay of the test build lets a change become due within a few seconds
+  async function scheduleDueFeeRateChange(fixture: WhirlpoolTestFixture) {
+    const effectiveAt = new anchor.BN((await getChainTimestamp(provider)) + 5);
+    await scheduleFeeRateChange(fixture, effectiveAt);
+    await waitUntilTimestamp(provider, effectiveAt);
+  }
+
+  async function fetchFeeRateSchedule(fixture: WhirlpoolTestFixture) {
+    const whirlpool = fixture.getInfos().poolInitInfo.whirlpoolPda.publicKey;
+    const feeRateSchedulePda = PDAUtil.getFeeRateSchedule(ctx.program.programId, whirlpool);
+    return ctx.program.account.feeRateSchedule.fetch(feeRateSchedulePda.publicKey);
+  }
+
+  async function assertFeeRatesApplied(fixture: WhirlpoolTestFixture) {
+    const whirlpool = fixture.getInfos().poolInitInfo.whirlpoolPda.publicKey;
+    const pool = (await fetcher.getPool(whirlpool, true)) as WhirlpoolData;
+    assert.equal(pool.feeRate, newFeeRate);
+    assert.equal(pool.protocolFeeRate, newProtocolFeeRate);
+
+    const feeRateSchedule = await fetchFeeRateSchedule(fixture);
+    assert.ok(feeRateSchedule.effectiveAt.isZero());
+  }
+
+  it("successfully schedules a fee rate change", async () => {
+    const fixture = await initFixtureWithFeeRateSchedule();
+    const whirlpool = fixture.getInfos().poolInitInfo.whirlpoolPda.publicKey;
+    const poolBefore = (await fetcher.getPool(whirlpool, true)) as WhirlpoolData;
+    assert.equal(poolBefore.extensions, EXTENSION_FEE_RATE_SCHEDULE);
+
+    const effectiveAt = new anchor.BN((await getChainTimestamp(provider)) + 3600);
+    await scheduleFeeRateChange(fixture, effectiveAt);
+
+    const feeRateSchedule = await fetchFeeRateSchedule(fixture);
+    assert.ok(feeRateSchedule.whirlpool.equals(whirlpool));
+    assert.equal(feeRateSchedule.pendingFeeRate, newFeeRate);
+    assert.equal(feeRateSchedule.pendingProtocolFeeRate, newProtocolFeeRate);
+    assert.ok(feeRateSchedule.effectiveAt.eq(effectiveAt));
+
+    // The rates only change once the change is due
+    const poolAfter = (await fetcher.getPool(whirlpool, true)) as WhirlpoolData;
+    assert.equal(poolAfter.feeRate, poolBefore.feeRate);
+    assert.equal(poolAfter.protocolFeeRate, poolBefore.protocolFeeRate);
+  });
+
+  it("successfully cancels a pending fee rate change", async () => {
+    const fixture = await initFixtureWithFeeRateSchedule();
+    const { poolInitInfo, configKeypairs } = fixture.getInfos();
+
+    const effectiveAt = new anchor.BN((await getChainTimestamp(provider)) + 3600);
+    await scheduleFeeRateChange(fixture, effectiveAt);
+    await toTx(
+      ctx,
+      WhirlpoolIx.cancelFeeRateChangeIx(ctx.program, {
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+        whirlpool: poolInitInfo.whirlpoolPda.publicKey,
+        feeAuthority: configKeypairs.feeAuthorityKeypair.publicKey,
+      })
+    )
+      .addSigner(configKeypairs.feeAuthorityKeypair)
+      .buildAndExecute();
+
+    const feeRateSchedule = await fetchFeeRateSchedule(fixture);
+    assert.equal(feeRateSchedule.pendingFeeRate, 0);
+    assert.equal(feeRateSchedule.pendingProtocolFeeRate, 0);
+    assert.ok(feeRateSchedule.effectiveAt.isZero());
+  });
+
+  it("successfully applies a due fee rate change on swap", async () => {
+    const fixture = await initFixtureWithFeeRateSchedule();
+    const { poolInitInfo, tokenAccountA, tokenAccountB } = fixture.getInfos();
+    const whirlpool = poolInitInfo.whirlpoolPda.publicKey;
+    const tickArrayPda = PDAUtil.getTickArray(ctx.program.programId, whirlpool, 22528);
+
+    await scheduleDueFeeRateChange(fixture);
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.swapIx(ctx.program, {
+        amount: new u64(200_000),
+        otherAmountThreshold: ZERO_BN,
+        sqrtPriceLimit: MathUtil.toX64(new Decimal(4)),
+        amountSpecifiedIsInput: true,
+        aToB: true,
+        whirlpool,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+        tokenAuthority: ctx.wallet.publicKey,
+        tokenOwnerAccountA: tokenAccountA,
+        tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
+        tokenOwnerAccountB: tokenAccountB,
+        tokenVaultB: poolInitInfo.tokenVaultBKeypair.publicKey,
+        tickArray0: tickArrayPda.publicKey,
+        tickArray1: tickArrayPda.publicKey,
+        tickArray2: tickArrayPda.publicKey,
+        oracle: PDAUtil.getOracle(ctx.program.programId, whirlpool).publicKey,
+        extensions: EXTENSION_FEE_RATE_SCHEDULE,
+      })
+    ).buildAndExecute();
+
+    await assertFeeRatesApplied(fixture);
+  });
+
+  it("successfully applies a due fee rate change on increase liquidity", async () => {
+    const fixture = await initFixtureWithFeeRateSchedule();
+    const { poolInitInfo, positions, tokenAccountA, tokenAccountB } = fixture.getInfos();
+
+    await scheduleDueFeeRateChange(fixture);
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.increaseLiquidityIx(ctx.program, {
+        liquidityAmount: new anchor.BN(1_000_000),
+        tokenMaxA: new u64(1_000_000_000),
+        tokenMaxB: new u64(1_000_000_000),
+        whirlpool: poolInitInfo.whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+        extensions: EXTENSION_FEE_RATE_SCHEDULE,
+        positionAuthority: provider.wallet.publicKey,
+        position: positions[0].publicKey,
+        positionTokenAccount: positions[0].tokenAccount,
+        tokenOwnerAccountA: tokenAccountA,
+        tokenOwnerAccountB: tokenAccountB,
+        tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
+        tokenVaultB: poolInitInfo.tokenVaultBKeypair.publicKey,
+        tickArrayLower: positions[0].tickArrayLower,
+        tickArrayUpper: positions[0].tickArrayUpper,
+      })
+    ).buildAndExecute();
+
+    await assertFeeRatesApplied(fixture);
+  });
+
+  it("fails to schedule a fee rate change before the minimum delay", async () => {
+    const fixture = await initFixtureWithFeeRateSchedule();
+
+    await assert.rejects(
+      scheduleFeeRateChange(fixture, new anchor.BN(await getChainTimestamp(provider))),
+      /0x17a4/ // FeeRateChangeTooEarly
+    );
+  });
+
+  it("successfully schedules the fee rates set directly at the minimum delay", async () => {
+    const fixture = await initFixtureWithFeeRateSchedule();
+    const { poolInitInfo, configKeypairs } = fixture.getInfos();
+    const whirlpool = poolInitInfo.whirlpoolPda.publicKey;
+    const params = {
+      whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+      whirlpool,
+      feeAuthority: configKeypairs.feeAuthorityKeypair.publicKey,
+    };
+    const poolBefore = (await fetcher.getPool(whirlpool, true)) as WhirlpoolData;
+
+    const scheduledEffectiveAt = new anchor.BN((await getChainTimestamp(provider)) + 3600);
+    await scheduleFeeRateChange(fixture, scheduledEffectiveAt);
+
+    // The protocol fee rate of the pending change is kept
+    await toTx(ctx, WhirlpoolIx.setFeeRateIx(ctx.program, { ...params, feeRate: 700 }))
+      .addSigner(configKeypairs.feeAuthorityKeypair)
+      .buildAndExecute();
+    let feeRateSchedule = await fetchFeeRateSchedule(fixture);
+    assert.equal(feeRateSchedule.pendingFeeRate, 700);
+    assert.equal(feeRateSchedule.pendingProtocolFeeRate, newProtocolFeeRate);
+    assert.ok(feeRateSchedule.effectiveAt.lt(scheduledEffectiveAt));
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.setProtocolFeeRateIx(ctx.program, { ...params, protocolFeeRate: 200 })
+    )
+      .addSigner(configKeypairs.feeAuthorityKeypair)
+      .buildAndExecute();
+    feeRateSchedule = await fetchFeeRateSchedule(fixture);
+    assert.equal(feeRateSchedule.pendingFeeRate, 700);
+    assert.equal(feeRateSchedule.pendingProtocolFeeRate, 200);
+
+    // The rates only change once the change is due
+    const poolAfter = (await fetcher.getPool(whirlpool, true)) as WhirlpoolData;
+    assert.equal(poolAfter.feeRate, poolBefore.feeRate);
+    assert.equal(poolAfter.protocolFeeRate, poolBefore.protocolFeeRate);
+  });
+});
@@ -70,6 +70,7 @@ describe("increase_liquidity", () => {
         tokenMaxA: tokenAmount.tokenA,
         tokenMaxB: tokenAmount.tokenB,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         positionAuthority: provider.wallet.publicKey,
         position: positionInitInfo.publicKey,
         positionTokenAccount: positionInitInfo.tokenAccount,
@@ -138,6 +139,7 @@ describe("increase_liquidity", () => {
         tokenMaxA: tokenAmount.tokenA,
         tokenMaxB: tokenAmount.tokenB,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         positionAuthority: provider.wallet.publicKey,
         position: positionInitInfo.publicKey,
         positionTokenAccount: positionInitInfo.tokenAccount,
@@ -247,6 +249,7 @@ describe("increase_liquidity", () => {
           tokenMaxA: tokenAmount.tokenA,
           tokenMaxB: tokenAmount.tokenB,
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: params.positionPda.publicKey,
           positionTokenAccount: params.positionTokenAccount,
@@ -321,6 +324,7 @@ describe("increase_liquidity", () => {
         tokenMaxA: tokenAmount.tokenA,
         tokenMaxB: tokenAmount.tokenB,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         positionAuthority: delegate.publicKey,
         position: positionInitInfo.publicKey,
         positionTokenAccount: positionInitInfo.tokenAccount,
@@ -406,6 +410,7 @@ describe("increase_liquidity", () => {
         tokenMaxA: tokenAmount.tokenA,
         tokenMaxB: tokenAmount.tokenB,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         positionAuthority: provider.wallet.publicKey,
         position: positionPda.publicKey,
         positionTokenAccount: positionTokenAccountAddress,
@@ -466,6 +471,7 @@ describe("increase_liquidity", () => {
         tokenMaxA: tokenAmount.tokenA,
         tokenMaxB: tokenAmount.tokenB,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         positionAuthority: provider.wallet.publicKey,
         position: positionPda.publicKey,
         positionTokenAccount: positionTokenAccountAddress,
@@ -499,6 +505,7 @@ describe("increase_liquidity", () => {
           tokenMaxA: new u64(0),
           tokenMaxB: new u64(1_000_000),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positionInitInfo.publicKey,
           positionTokenAccount: positionInitInfo.tokenAccount,
@@ -534,6 +541,7 @@ describe("increase_liquidity", () => {
           tokenMaxA: new u64(0),
           tokenMaxB: new u64(999_999_999),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positionInitInfo.publicKey,
           positionTokenAccount: positionInitInfo.tokenAccount,
@@ -568,6 +576,7 @@ describe("increase_liquidity", () => {
           tokenMaxA: new u64(999_999_999),
           tokenMaxB: new u64(0),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positionInitInfo.publicKey,
           positionTokenAccount: positionInitInfo.tokenAccount,
@@ -609,6 +618,7 @@ describe("increase_liquidity", () => {
           tokenMaxA: new u64(0),
           tokenMaxB: new u64(1_000_000),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positionInitInfo.publicKey,
           positionTokenAccount: newPositionTokenAccount,
@@ -634,6 +644,7 @@ describe("increase_liquidity", () => {
           tokenMaxA: new u64(0),
           tokenMaxB: new u64(1_000_000),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positionInitInfo.publicKey,
           positionTokenAccount: positionInitInfo.tokenAccount,
@@ -671,6 +682,7 @@ describe("increase_liquidity", () => {
           tokenMaxA: new u64(0),
           tokenMaxB: new u64(1_000_000),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positionInitInfo.publicKey,
           positionTokenAccount: invalidPositionTokenAccount,
@@ -720,6 +732,7 @@ describe("increase_liquidity", () => {
           tokenMaxA: new u64(0),
           tokenMaxB: new u64(1_000_000),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positionPda.publicKey,
           positionTokenAccount: positionTokenAccountAddress,
@@ -756,6 +769,7 @@ describe("increase_liquidity", () => {
           tokenMaxA: new u64(0),
           tokenMaxB: new u64(1_000_000),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positionInitInfo.publicKey,
           positionTokenAccount: positionInitInfo.tokenAccount,
@@ -778,6 +792,7 @@ describe("increase_liquidity", () => {
           tokenMaxA: new u64(0),
           tokenMaxB: new u64(1_000_000),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positionInitInfo.publicKey,
           positionTokenAccount: positionInitInfo.tokenAccount,
@@ -814,6 +829,7 @@ describe("increase_liquidity", () => {
           tokenMaxA: new u64(0),
           tokenMaxB: new u64(1_000_000),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positionInitInfo.publicKey,
           positionTokenAccount: positionInitInfo.tokenAccount,
@@ -836,6 +852,7 @@ describe("increase_liquidity", () => {
           tokenMaxA: new u64(0),
           tokenMaxB: new u64(1_000_000),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positionInitInfo.publicKey,
           positionTokenAccount: positionInitInfo.tokenAccount,
@@ -875,6 +892,7 @@ describe("increase_liquidity", () => {
           tokenMaxA: new u64(0),
           tokenMaxB: new u64(167_000),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: delegate.publicKey,
           position: positionInitInfo.publicKey,
           positionTokenAccount: positionInitInfo.tokenAccount,
@@ -917,6 +935,7 @@ describe("increase_liquidity", () => {
           tokenMaxA: new u64(0),
           tokenMaxB: new u64(167_000),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: delegate.publicKey,
           position: positionInitInfo.publicKey,
           positionTokenAccount: positionInitInfo.tokenAccount,
@@ -959,6 +978,7 @@ describe("increase_liquidity", () => {
           tokenMaxA: new u64(0),
           tokenMaxB: new u64(167_000),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: delegate.publicKey,
           position: positionInitInfo.publicKey,
           positionTokenAccount: positionInitInfo.tokenAccount,
@@ -997,6 +1017,7 @@ describe("increase_liquidity", () => {
           tokenMaxA: new u64(0),
           tokenMaxB: new u64(167_000),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: delegate.publicKey,
           position: positionInitInfo.publicKey,
           positionTokenAccount: positionInitInfo.tokenAccount,
@@ -1041,6 +1062,7 @@ describe("increase_liquidity", () => {
           tokenMaxA: new u64(0),
           tokenMaxB: new u64(167_000),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positionInitInfo.publicKey,
           positionTokenAccount: positionInitInfo.tokenAccount,
@@ -1085,6 +1107,7 @@ describe("increase_liquidity", () => {
           tokenMaxA: new u64(0),
           tokenMaxB: new u64(167_000),
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           positionAuthority: provider.wallet.publicKey,
           position: positionInitInfo.publicKey,
           positionTokenAccount: positionInitInfo.tokenAccount,
@@ -91,6 +91,36 @@ describe("initialize_fee_tier", () => {
     );
   });
 
+  it("fails when the tick spacing is not allowed by the fee tier policy", async () => {
+    const { configInitInfo, configKeypairs } = generateDefaultConfigParams(ctx);
+    const whirlpoolsConfig = configInitInfo.whirlpoolsConfigKeypair.publicKey;
+    const feeAuthorityKeypair = configKeypairs.feeAuthorityKeypair;
+    await toTx(ctx, WhirlpoolIx.initializeConfigIx(ctx.program, configInitInfo))
+      .addInstruction(
+        WhirlpoolIx.initializeFeeTierPolicyIx(ctx.program, {
+          whirlpoolsConfig,
+          feeAuthority: feeAuthorityKeypair.publicKey,
+          funder: provider.wallet.publicKey,
+        })
+      )
+      .addInstruction(
+        WhirlpoolIx.setAllowedTickSpacingsIx(ctx.program, {
+          whirlpoolsConfig,
+          feeAuthority: feeAuthorityKeypair.publicKey,
+          allowedTickSpacings: [TickSpacing.Standard],
+        })
+      )
+      .addSigner(feeAuthorityKeypair)
+      .buildAndExecute();
+
+    await assert.rejects(
+      initFeeTier(ctx, configInitInfo, feeAuthorityKeypair, TickSpacing.Stable, 800),
+      /0x1774/ // InvalidTickSpacing
+    );
+
+    await initFeeTier(ctx, configInitInfo, feeAuthorityKeypair, TickSpacing.Standard, 3000);
+  });
+
   it("fails when fee authority is not a signer", async () => {
     const { configInitInfo } = generateDefaultConfigParams(ctx);
     await toTx(ctx, WhirlpoolIx.initializeConfigIx(ctx.program, configInitInfo)).buildAndExecute();
@@ -7,6 +7,8 @@ import {
   MAX_SQRT_PRICE,
   MIN_SQRT_PRICE,
   PDAUtil,
+  POOL_CREATION_ALLOWLISTED_MINTS,
+  POOL_CREATION_CREATOR_ONLY,
   PriceMath,
   toTx,
   WhirlpoolContext,
@@ -151,6 +153,171 @@ describe("initialize_pool", () => {
     await initTestPool(ctx, TickSpacing.Standard, MathUtil.toX64(new Decimal(5)), funderKeypair);
   });
 
+  it("fails when the pool creation policy only allows the pool creator authority", async () => {
+    const { poolInitInfo, configInitInfo } = await buildTestPoolParams(ctx, TickSpacing.Standard);
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializePoolCreationPolicyIx(ctx.program, {
+        whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+        poolCreatorAuthority: configInitInfo.poolCreatorAuthority,
+        mode: POOL_CREATION_CREATOR_ONLY,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
+
+    const funderKeypair = anchor.web3.Keypair.generate();
+    await systemTransferTx(provider, funderKeypair.publicKey, ONE_SOL).buildAndExecute();
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.initializePoolIx(ctx.program, {
+          ...poolInitInfo,
+          funder: funderKeypair.publicKey,
+        })
+      )
+        .addSigner(funderKeypair)
+        .buildAndExecute(),
+      /custom program error: 0x17a6/ // PoolCreationNotAllowed
+    );
+
+    // The pool creator authority is always allowed
+    await toTx(ctx, WhirlpoolIx.initializePoolIx(ctx.program, poolInitInfo)).buildAndExecute();
+  });
+
+  it("checks the pool creation policy against the pool creator instead of the funder", async () => {
+    const { poolInitInfo, configInitInfo } = await buildTestPoolParams(ctx, TickSpacing.Standard);
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializePoolCreationPolicyIx(ctx.program, {
+        whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+        poolCreatorAuthority: configInitInfo.poolCreatorAuthority,
+        mode: POOL_CREATION_CREATOR_ONLY,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
+
+    // Funded by the pool creator authority, but created by someone else
+    const poolCreatorKeypair = anchor.web3.Keypair.generate();
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.initializePoolIx(ctx.program, {
+          ...poolInitInfo,
+          poolCreator: poolCreatorKeypair.publicKey,
+        })
+      )
+        .addSigner(poolCreatorKeypair)
+        .buildAndExecute(),
+      /custom program error: 0x17a6/ // PoolCreationNotAllowed
+    );
+
+    // Created by the pool creator authority and funded by someone else
+    const funderKeypair = anchor.web3.Keypair.generate();
+    await systemTransferTx(provider, funderKeypair.publicKey, ONE_SOL).buildAndExecute();
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializePoolIx(ctx.program, {
+        ...poolInitInfo,
+        funder: funderKeypair.publicKey,
+        poolCreator: configInitInfo.poolCreatorAuthority,
+      })
+    )
+      .addSigner(funderKeypair)
+      .buildAndExecute();
+  });
+
+  it("succeeds when both token mints are allowlisted by the pool creation policy", async () => {
+    const { poolInitInfo, configInitInfo } = await buildTestPoolParams(ctx, TickSpacing.Standard);
+    const whirlpoolsConfig = configInitInfo.whirlpoolsConfigKeypair.publicKey;
+    const poolCreatorAuthority = configInitInfo.poolCreatorAuthority;
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializePoolCreationPolicyIx(ctx.program, {
+        whirlpoolsConfig,
+        poolCreatorAuthority,
+        mode: POOL_CREATION_ALLOWLISTED_MINTS,
+        funder: provider.wallet.publicKey,
+      })
+    )
+      .addInstruction(
+        WhirlpoolIx.addAllowedMintIx(ctx.program, {
+          whirlpoolsConfig,
+          poolCreatorAuthority,
+          mint: poolInitInfo.tokenMintA,
+        })
+      )
+      .buildAndExecute();
+
+    const funderKeypair = anchor.web3.Keypair.generate();
+    await systemTransferTx(provider, funderKeypair.publicKey, ONE_SOL).buildAndExecute();
+    const funderPoolInitInfo = { ...poolInitInfo, funder: funderKeypair.publicKey };
+
+    await assert.rejects(
+      toTx(ctx, WhirlpoolIx.initializePoolIx(ctx.program, funderPoolInitInfo))
+        .addSigner(funderKeypair)
+        .buildAndExecute(),
+      /custom program error: 0x17a6/ // PoolCreationNotAllowed
+    );
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.addAllowedMintIx(ctx.program, {
+        whirlpoolsConfig,
+        poolCreatorAuthority,
+        mint: poolInitInfo.tokenMintB,
+      })
+    ).buildAndExecute();
+    await toTx(ctx, WhirlpoolIx.initializePoolIx(ctx.program, funderPoolInitInfo))
+      .addSigner(funderKeypair)
+      .buildAndExecute();
+  });
+
+  it("fails when the fee tier has been disabled", async () => {
+    const { poolInitInfo, configInitInfo, configKeypairs } = await buildTestPoolParams(
+      ctx,
+      TickSpacing.Standard
+    );
+    const whirlpoolsConfig = configInitInfo.whirlpoolsConfigKeypair.publicKey;
+    const feeAuthorityKeypair = configKeypairs.feeAuthorityKeypair;
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializeFeeTierPolicyIx(ctx.program, {
+        whirlpoolsConfig,
+        feeAuthority: feeAuthorityKeypair.publicKey,
+        funder: provider.wallet.publicKey,
+      })
+    )
+      .addInstruction(
+        WhirlpoolIx.setFeeTierEnabledIx(ctx.program, {
+          whirlpoolsConfig,
+          feeAuthority: feeAuthorityKeypair.publicKey,
+          tickSpacing: TickSpacing.Standard,
+          isEnabled: false,
+        })
+      )
+      .addSigner(feeAuthorityKeypair)
+      .buildAndExecute();
+
+    await assert.rejects(
+      toTx(ctx, WhirlpoolIx.initializePoolIx(ctx.program, poolInitInfo)).buildAndExecute(),
+      /custom program error: 0x17a8/ // FeeTierDisabled
+    );
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.setFeeTierEnabledIx(ctx.program, {
+        whirlpoolsConfig,
+        feeAuthority: feeAuthorityKeypair.publicKey,
+        tickSpacing: TickSpacing.Standard,
+        isEnabled: true,
+      })
+    )
+      .addSigner(feeAuthorityKeypair)
+      .buildAndExecute();
+    await toTx(ctx, WhirlpoolIx.initializePoolIx(ctx.program, poolInitInfo)).buildAndExecute();
+  });
+
   it("fails when tokenVaultA mint does not match tokenA mint", async () => {
     const { poolInitInfo } = await buildTestPoolParams(ctx, TickSpacing.Standard);
     const otherTokenPublicKey = await createMint(provider);
@@ -189,13 +356,13 @@ describe("initialize_pool", () => {
       configInitInfo.whirlpoolsConfigKeypair.publicKey,
       poolInitInfo.tokenMintB,
       poolInitInfo.tokenMintA,
-      TickSpacing.Stable
+      TickSpacing.Standard
     );
 
     const modifiedPoolInitInfo: InitPoolParams = {
       ...poolInitInfo,
       whirlpoolPda,
-      tickSpacing: TickSpacing.Stable,
+      tickSpacing: TickSpacing.Standard,
       tokenMintA: poolInitInfo.tokenMintB,
       tokenMintB: poolInitInfo.tokenMintA,
     };
@@ -214,13 +381,13 @@ describe("initialize_pool", () => {
       configInitInfo.whirlpoolsConfigKeypair.publicKey,
       poolInitInfo.tokenMintA,
       poolInitInfo.tokenMintA,
-      TickSpacing.Stable
+      TickSpacing.Standard
     );
 
     const modifiedPoolInitInfo: InitPoolParams = {
       ...poolInitInfo,
       whirlpoolPda,
-      tickSpacing: TickSpacing.Stable,
+      tickSpacing: TickSpacing.Standard,
       tokenMintB: poolInitInfo.tokenMintA,
     };
 
@@ -230,6 +397,29 @@ describe("initialize_pool", () => {
     );
   });
 
+  it("fails when the fee tier does not match the tick spacing", async () => {
+    const { poolInitInfo, configInitInfo } = await buildTestPoolParams(ctx, TickSpacing.Standard);
+
+    const whirlpoolPda = PDAUtil.getWhirlpool(
+      ctx.program.programId,
+      configInitInfo.whirlpoolsConfigKeypair.publicKey,
+      poolInitInfo.tokenMintA,
+      poolInitInfo.tokenMintB,
+      TickSpacing.Stable
+    );
+
+    const modifiedPoolInitInfo: InitPoolParams = {
+      ...poolInitInfo,
+      whirlpoolPda,
+      tickSpacing: TickSpacing.Stable,
+    };
+
+    await assert.rejects(
+      toTx(ctx, WhirlpoolIx.initializePoolIx(ctx.program, modifiedPoolInitInfo)).buildAndExecute(),
+      /custom program error: 0x7d6/ // ConstraintSeeds
+    );
+  });
+
   it("fails when sqrt-price exceeds max", async () => {
     const { poolInitInfo } = await buildTestPoolParams(ctx, TickSpacing.Standard);
     const otherTokenPublicKey = await createMint(provider);
@@ -0,0 +1,338 @@
+import { deriveATA } from "@orca-so/common-sdk";
+import * as anchor from "@project-serum/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import * as assert from "assert";
+import { PDAUtil, toTx, WhirlpoolContext, WhirlpoolIx } from "../../src";
+import { TickSpacing } from "../utils";
+import { initTestPool } from "../utils/init-utils";
+import { generateDefaultOpenPositionParams } from "../utils/test-builders";
+
+describe("metadata_settings", () => {
+  const provider = anchor.AnchorProvider.local();
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.Whirlpool;
+  const ctx = WhirlpoolContext.fromWorkspace(provider, program);
+
+  const uriTemplate = "https://nft.renec.foundation/position/{position_mint}.json";
+
+  async function initMetadataSettings(
+    whirlpoolsConfig: PublicKey,
+    feeAuthorityKeypair: Keypair,
+    updateAuthority = Keypair.generate().publicKey
+  ) {
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializeMetadataSettingsIx(ctx.program, {
+        whirlpoolsConfig,
+        feeAuthority: feeAuthorityKeypair.publicKey,
+        updateAuthority,
+        name: "RENEC Position",
+        symbol: "RNP",
+        uriTemplate,
+        funder: provider.wallet.publicKey,
+      })
+    )
+      .addSigner(feeAuthorityKeypair)
+      .buildAndExecute();
+  }
+
+  async function openPositionWithMetadataSettings(
+    whirlpool: PublicKey,
+    updateAuthorityKeypair: Keypair
+  ) {
+    const { params, mint } = await generateDefaultOpenPositionParams(
+      ctx,
+      whirlpool,
+      0,
+      128,
+      provider.wallet.publicKey
+    );
+    await toTx(
+      ctx,
+      WhirlpoolIx.openPositionWithMetadataIx(ctx.program, {
+        ...params,
+        metadataUpdateAuth: updateAuthorityKeypair.publicKey,
+      })
+    )
+      .addSigner(mint)
+      .addSigner(updateAuthorityKeypair)
+      .buildAndExecute();
+    return params;
+  }
+
+  async function initPositionCollectionTx(whirlpoolsConfig: PublicKey, feeAuthority: PublicKey) {
+    const metadataSettingsPda = PDAUtil.getMetadataSettings(
+      ctx.program.programId,
+      whirlpoolsConfig
+    );
+    const collectionMintPda = PDAUtil.getPositionCollection(
+      ctx.program.programId,
+      whirlpoolsConfig
+    );
+    const collectionTokenAccount = await deriveATA(
+      metadataSettingsPda.publicKey,
+      collectionMintPda.publicKey
+    );
+    return toTx(
+      ctx,
+      WhirlpoolIx.initializePositionCollectionIx(ctx.program, {
+        whirlpoolsConfig,
+        feeAuthority,
+        collectionTokenAccount,
+        name: "RENEC Positions",
+        symbol: "RNP",
+        uri: "https://nft.renec.foundation/collection.json",
+        funder: provider.wallet.publicKey,
+      })
+    );
+  }
+
+  it("successfully initializes and sets the metadata settings", async () => {
+    const { configInitInfo, configKeypairs } = await initTestPool(ctx, TickSpacing.Standard);
+    const whirlpoolsConfig = configInitInfo.whirlpoolsConfigKeypair.publicKey;
+    const feeAuthorityKeypair = configKeypairs.feeAuthorityKeypair;
+    const updateAuthority = Keypair.generate().publicKey;
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializeMetadataSettingsIx(ctx.program, {
+        whirlpoolsConfig,
+        feeAuthority: feeAuthorityKeypair.publicKey,
+        updateAuthority,
+        name: "RENEC Position",
+        symbol: "RNP",
+        uriTemplate,
+        funder: provider.wallet.publicKey,
+      })
+    )
+      .addSigner(feeAuthorityKeypair)
+      .buildAndExecute();
+
+    const metadataSettingsPda = PDAUtil.getMetadataSettings(
+      ctx.program.programId,
+      whirlpoolsConfig
+    );
+    let metadataSettings = await ctx.program.account.metadataSettings.fetch(
+      metadataSettingsPda.publicKey
+    );
+    assert.ok(metadataSettings.whirlpoolsConfig.equals(whirlpoolsConfig));
+    assert.ok(metadataSettings.updateAuthority.equals(updateAuthority));
+    assert.equal(metadataSettings.name, "RENEC Position");
+    assert.equal(metadataSettings.symbol, "RNP");
+    assert.equal(metadataSettings.uriTemplate, uriTemplate);
+
+    const newUpdateAuthority = Keypair.generate().publicKey;
+    await toTx(
+      ctx,
+      WhirlpoolIx.setMetadataSettingsIx(ctx.program, {
+        whirlpoolsConfig,
+        feeAuthority: feeAuthorityKeypair.publicKey,
+        updateAuthority: newUpdateAuthority,
+        name: "RENEC LP",
+        symbol: "RLP",
+        uriTemplate: "https://nft.renec.foundation/position.json",
+      })
+    )
+      .addSigner(feeAuthorityKeypair)
+      .buildAndExecute();
+
+    metadataSettings = await ctx.program.account.metadataSettings.fetch(
+      metadataSettingsPda.publicKey
+    );
+    assert.ok(metadataSettings.updateAuthority.equals(newUpdateAuthority));
+    assert.equal(metadataSettings.name, "RENEC LP");
+    assert.equal(metadataSettings.symbol, "RLP");
+    assert.equal(metadataSettings.uriTemplate, "https://nft.renec.foundation/position.json");
+  });
+
+  it("fails when the signer is not the fee authority", async () => {
+    const { configInitInfo } = await initTestPool(ctx, TickSpacing.Standard);
+    const fakeAuthorityKeypair = Keypair.generate();
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.initializeMetadataSettingsIx(ctx.program, {
+          whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+          feeAuthority: fakeAuthorityKeypair.publicKey,
+          updateAuthority: fakeAuthorityKeypair.publicKey,
+          name: "RENEC Position",
+          symbol: "RNP",
+          uriTemplate,
+          funder: provider.wallet.publicKey,
+        })
+      )
+        .addSigner(fakeAuthorityKeypair)
+        .buildAndExecute(),
+      /0x7dc/ // ConstraintAddress
+    );
+  });
+
+  it("fails when the symbol is too long", async () => {
+    const { configInitInfo, configKeypairs } = await initTestPool(ctx, TickSpacing.Standard);
+    const feeAuthorityKeypair = configKeypairs.feeAuthorityKeypair;
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.initializeMetadataSettingsIx(ctx.program, {
+          whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+          feeAuthority: feeAuthorityKeypair.publicKey,
+          updateAuthority: feeAuthorityKeypair.publicKey,
+          name: "RENEC Position",
+          symbol: "RENECPOSITION",
+          uriTemplate,
+          funder: provider.wallet.publicKey,
+        })
+      )
+        .addSigner(feeAuthorityKeypair)
+        .buildAndExecute(),
+      /0x17b4/ // InvalidPositionMetadata
+    );
+  });
+
+  it("fails when the expanded URI template is too long", async () => {
+    const { configInitInfo, configKeypairs } = await initTestPool(ctx, TickSpacing.Standard);
+    const feeAuthorityKeypair = configKeypairs.feeAuthorityKeypair;
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.initializeMetadataSettingsIx(ctx.program, {
+          whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+          feeAuthority: feeAuthorityKeypair.publicKey,
+          updateAuthority: feeAuthorityKeypair.publicKey,
+          name: "RENEC Position",
+          symbol: "RNP",
+          uriTemplate:
+            "https://nft.renec.foundation/{position_mint}/{whirlpool}/{token_mint_a}/{token_mint_b}",
+          funder: provider.wallet.publicKey,
+        })
+      )
+        .addSigner(feeAuthorityKeypair)
+        .buildAndExecute(),
+      /0x17b4/ // InvalidPositionMetadata
+    );
+  });
+
+  // Metaplex is not loaded in the local validator
+  it.skip("fails to update the position metadata without the update authority", async () => {
+    const { poolInitInfo, configInitInfo, configKeypairs } = await initTestPool(
+      ctx,
+      TickSpacing.Standard
+    );
+    const whirlpoolsConfig = configInitInfo.whirlpoolsConfigKeypair.publicKey;
+    const updateAuthorityKeypair = Keypair.generate();
+    await initMetadataSettings(
+      whirlpoolsConfig,
+      configKeypairs.feeAuthorityKeypair,
+      updateAuthorityKeypair.publicKey
+    );
+    const whirlpool = poolInitInfo.whirlpoolPda.publicKey;
+    const params = await openPositionWithMetadataSettings(whirlpool, updateAuthorityKeypair);
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.updatePositionMetadataIx(ctx.program, {
+          whirlpool,
+          whirlpoolsConfig,
+          position: params.positionPda.publicKey,
+          positionMint: params.positionMintAddress,
+          metadataUpdateAuth: provider.wallet.publicKey,
+        })
+      ).buildAndExecute(),
+      /0x17b5/ // InvalidMetadataUpdateAuthority
+    );
+  });
+
+  // Metaplex is not loaded in the local validator
+  it.skip("updates the position metadata after the update authority is rotated", async () => {
+    const { poolInitInfo, configInitInfo, configKeypairs } = await initTestPool(
+      ctx,
+      TickSpacing.Standard
+    );
+    const whirlpoolsConfig = configInitInfo.whirlpoolsConfigKeypair.publicKey;
+    const feeAuthorityKeypair = configKeypairs.feeAuthorityKeypair;
+    const previousAuthorityKeypair = Keypair.generate();
+    const newAuthorityKeypair = Keypair.generate();
+    await initMetadataSettings(
+      whirlpoolsConfig,
+      feeAuthorityKeypair,
+      previousAuthorityKeypair.publicKey
+    );
+    const whirlpool = poolInitInfo.whirlpoolPda.publicKey;
+    const params = await openPositionWithMetadataSettings(whirlpool, previousAuthorityKeypair);
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.setMetadataSettingsIx(ctx.program, {
+        whirlpoolsConfig,
+        feeAuthority: feeAuthorityKeypair.publicKey,
+        updateAuthority: newAuthorityKeypair.publicKey,
+        name: "RENEC Position",
+        symbol: "RNP",
+        uriTemplate,
+      })
+    )
+      .addSigner(feeAuthorityKeypair)
+      .buildAndExecute();
+
+    const updateTx = (metadataUpdateAuthKeypair: Keypair) =>
+      toTx(
+        ctx,
+        WhirlpoolIx.updatePositionMetadataIx(ctx.program, {
+          whirlpool,
+          whirlpoolsConfig,
+          position: params.positionPda.publicKey,
+          positionMint: params.positionMintAddress,
+          metadataUpdateAuth: metadataUpdateAuthKeypair.publicKey,
+        })
+      ).addSigner(metadataUpdateAuthKeypair);
+
+    // The metadata is still held by the previous update authority, which hands it over
+    await assert.rejects(
+      updateTx(newAuthorityKeypair).buildAndExecute(),
+      /0x17b5/ // InvalidMetadataUpdateAuthority
+    );
+    await updateTx(previousAuthorityKeypair).buildAndExecute();
+    await updateTx(newAuthorityKeypair).buildAndExecute();
+    await assert.rejects(
+      updateTx(previousAuthorityKeypair).buildAndExecute(),
+      /0x17b5/ // InvalidMetadataUpdateAuthority
+    );
+  });
+
+  // Metaplex is not loaded in the local validator
+  it.skip("successfully initializes the position collection", async () => {
+    const { configInitInfo, configKeypairs } = await initTestPool(ctx, TickSpacing.Standard);
+    const whirlpoolsConfig = configInitInfo.whirlpoolsConfigKeypair.publicKey;
+    const feeAuthorityKeypair = configKeypairs.feeAuthorityKeypair;
+    await initMetadataSettings(whirlpoolsConfig, feeAuthorityKeypair);
+
+    const tx = await initPositionCollectionTx(whirlpoolsConfig, feeAuthorityKeypair.publicKey);
+    await tx.addSigner(feeAuthorityKeypair).buildAndExecute();
+
+    const metadataSettings = await ctx.program.account.metadataSettings.fetch(
+      PDAUtil.getMetadataSettings(ctx.program.programId, whirlpoolsConfig).publicKey
+    );
+    assert.ok(
+      metadataSettings.collectionMint.equals(
+        PDAUtil.getPositionCollection(ctx.program.programId, whirlpoolsConfig).publicKey
+      )
+    );
+  });
+
+  it("fails to initialize the position collection when the signer is not the fee authority", async () => {
+    const { configInitInfo, configKeypairs } = await initTestPool(ctx, TickSpacing.Standard);
+    const whirlpoolsConfig = configInitInfo.whirlpoolsConfigKeypair.publicKey;
+    await initMetadataSettings(whirlpoolsConfig, configKeypairs.feeAuthorityKeypair);
+    const fakeAuthorityKeypair = Keypair.generate();
+
+    const tx = await initPositionCollectionTx(whirlpoolsConfig, fakeAuthorityKeypair.publicKey);
+    await assert.rejects(
+      tx.addSigner(fakeAuthorityKeypair).buildAndExecute(),
+      /0x7dc/ // ConstraintAddress
+    );
+  });
+});
@@ -0,0 +1,265 @@
+import { MathUtil, Percentage } from "@orca-so/common-sdk";
+import * as anchor from "@project-serum/anchor";
+import { u64 } from "@solana/spl-token";
+import { Keypair } from "@solana/web3.js";
+import * as assert from "assert";
+import Decimal from "decimal.js";
+import {
+  buildWhirlpoolClient,
+  PDAUtil,
+  PositionData,
+  swapQuoteByInputToken,
+  toTx,
+  WhirlpoolContext,
+  WhirlpoolIx,
+} from "../../src";
+import { getTokenBalance, replaceNestedAuthority, TickSpacing, ZERO_BN } from "../utils";
+import { WhirlpoolTestFixture } from "../utils/fixture";
+
+describe("native_wrap", () => {
+  const provider = anchor.AnchorProvider.local();
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.Whirlpool;
+  const ctx = WhirlpoolContext.fromWorkspace(provider, program);
+  const fetcher = ctx.fetcher;
+  const client = buildWhirlpoolClient(ctx);
+
+  const nativeWrapPda = PDAUtil.getNativeWrap(ctx.program.programId, provider.wallet.publicKey);
+
+  async function initFixture(tokenAIsNative: boolean) {
+    return new WhirlpoolTestFixture(ctx).init({
+      tickSpacing: TickSpacing.Standard,
+      initialSqrtPrice: MathUtil.toX64(new Decimal(1)),
+      positions: [
+        { tickLowerIndex: -1280, tickUpperIndex: 1280, liquidityAmount: new anchor.BN(10_000_000) },
+      ],
+      tokenAIsNative,
+    });
+  }
+
+  async function getSwapParams(fixture: WhirlpoolTestFixture) {
+    const { poolInitInfo, tokenAccountB } = fixture.getInfos();
+    const whirlpoolKey = poolInitInfo.whirlpoolPda.publicKey;
+    const whirlpool = await client.getPool(whirlpoolKey, true);
+    const quote = await swapQuoteByInputToken(
+      whirlpool,
+      whirlpool.getData().tokenMintA,
+      new u64(100_000),
+      Percentage.fromFraction(1, 100),
+      ctx.program.programId,
+      fetcher,
+      true
+    );
+
+    return {
+      quote,
+      params: {
+        ...quote,
+        whirlpool: whirlpoolKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+        tokenAuthority: provider.wallet.publicKey,
+        tokenOwnerAccountA: nativeWrapPda.publicKey,
+        tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
+        tokenOwnerAccountB: tokenAccountB,
+        tokenVaultB: poolInitInfo.tokenVaultBKeypair.publicKey,
+        oracle: PDAUtil.getOracle(ctx.program.programId, whirlpoolKey).publicKey,
+      },
+    };
+  }
+
+  function getModifyLiquidityParams(fixture: WhirlpoolTestFixture) {
+    const { poolInitInfo, positions, tokenAccountB } = fixture.getInfos();
+    const position = positions[0];
+    return {
+      whirlpool: poolInitInfo.whirlpoolPda.publicKey,
+      whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+      positionAuthority: provider.wallet.publicKey,
+      position: position.publicKey,
+      positionTokenAccount: position.tokenAccount,
+      tokenOwnerAccountA: nativeWrapPda.publicKey,
+      tokenOwnerAccountB: tokenAccountB,
+      tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
+      tokenVaultB: poolInitInfo.tokenVaultBKeypair.publicKey,
+      tickArrayLower: position.tickArrayLower,
+      tickArrayUpper: position.tickArrayUpper,
+    };
+  }
+
+  it("successfully swaps from the lamport balance of the token authority", async () => {
+    const fixture = await initFixture(true);
+    const { poolInitInfo, tokenAccountB } = fixture.getInfos();
+    const { quote, params } = await getSwapParams(fixture);
+
+    const tokenVaultABefore = new anchor.BN(
+      await getTokenBalance(provider, poolInitInfo.tokenVaultAKeypair.publicKey)
+    );
+    const tokenAccountBBefore = new anchor.BN(await getTokenBalance(provider, tokenAccountB));
+
+    await toTx(ctx, WhirlpoolIx.swapWithNativeIx(ctx.program, params)).buildAndExecute();
+
+    assert.equal(
+      await getTokenBalance(provider, poolInitInfo.tokenVaultAKeypair.publicKey),
+      tokenVaultABefore.add(quote.estimatedAmountIn).toString()
+    );
+    assert.equal(
+      await getTokenBalance(provider, tokenAccountB),
+      tokenAccountBBefore.add(quote.estimatedAmountOut).toString()
+    );
+    assert.equal(await provider.connection.getAccountInfo(nativeWrapPda.publicKey), null);
+  });
+
+  it("successfully increases and decreases liquidity with the lamport balance", async () => {
+    const fixture = await initFixture(true);
+    const position = fixture.getInfos().positions[0];
+    const liquidityAmount = new anchor.BN(1_000_000);
+    const params = getModifyLiquidityParams(fixture);
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.increaseLiquidityWithNativeIx(ctx.program, {
+        ...params,
+        liquidityAmount,
+        tokenMaxA: new u64(1_000_000),
+        tokenMaxB: new u64(1_000_000),
+      })
+    ).buildAndExecute();
+
+    let positionData = (await fetcher.getPosition(position.publicKey, true)) as PositionData;
+    assert.ok(positionData.liquidity.eq(new anchor.BN(11_000_000)));
+    assert.equal(await provider.connection.getAccountInfo(nativeWrapPda.publicKey), null);
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.decreaseLiquidityWithNativeIx(ctx.program, {
+        ...params,
+        liquidityAmount,
+        tokenMinA: ZERO_BN,
+        tokenMinB: ZERO_BN,
+      })
+    ).buildAndExecute();
+
+    positionData = (await fetcher.getPosition(position.publicKey, true)) as PositionData;
+    assert.ok(positionData.liquidity.eq(new anchor.BN(10_000_000)));
+    assert.equal(await provider.connection.getAccountInfo(nativeWrapPda.publicKey), null);
+  });
+
+  it("fails when the whirlpool does not trade the native mint", async () => {
+    const fixture = await initFixture(false);
+    const { params } = await getSwapParams(fixture);
+
+    await assert.rejects(
+      toTx(ctx, WhirlpoolIx.swapWithNativeIx(ctx.program, params)).buildAndExecute(),
+      /0x7d3/ // ConstraintRaw
+    );
+  });
+
+  it("fails to swap when the native token owner account is not the native wrap account", async () => {
+    const fixture = await initFixture(true);
+    const { tokenAccountA } = fixture.getInfos();
+    const { params } = await getSwapParams(fixture);
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.swapWithNativeIx(ctx.program, { ...params, tokenOwnerAccountA: tokenAccountA })
+      ).buildAndExecute(),
+      /0x7d3/ // ConstraintRaw
+    );
+  });
+
+  it("fails to swap when the nested token authority is not the token authority", async () => {
+    const fixture = await initFixture(true);
+    const { params } = await getSwapParams(fixture);
+    const otherAuthorityKeypair = Keypair.generate();
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        replaceNestedAuthority(
+          WhirlpoolIx.swapWithNativeIx(ctx.program, params),
+          provider.wallet.publicKey,
+          otherAuthorityKeypair.publicKey
+        )
+      )
+        .addSigner(otherAuthorityKeypair)
+        .buildAndExecute(),
+      /0x7d3/ // ConstraintRaw
+    );
+  });
+
+  it("fails to modify liquidity when the native token owner account is not the native wrap account", async () => {
+    const fixture = await initFixture(true);
+    const { tokenAccountA } = fixture.getInfos();
+    const params = { ...getModifyLiquidityParams(fixture), tokenOwnerAccountA: tokenAccountA };
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.increaseLiquidityWithNativeIx(ctx.program, {
+          ...params,
+          liquidityAmount: new anchor.BN(1_000_000),
+          tokenMaxA: new u64(1_000_000),
+          tokenMaxB: new u64(1_000_000),
+        })
+      ).buildAndExecute(),
+      /0x7d3/ // ConstraintRaw
+    );
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.decreaseLiquidityWithNativeIx(ctx.program, {
+          ...params,
+          liquidityAmount: new anchor.BN(1_000_000),
+          tokenMinA: ZERO_BN,
+          tokenMinB: ZERO_BN,
+        })
+      ).buildAndExecute(),
+      /0x7d3/ // ConstraintRaw
+    );
+  });
+
+  it("fails to modify liquidity when the nested position authority is not the position authority", async () => {
+    const fixture = await initFixture(true);
+    const params = getModifyLiquidityParams(fixture);
+    const otherAuthorityKeypair = Keypair.generate();
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        replaceNestedAuthority(
+          WhirlpoolIx.increaseLiquidityWithNativeIx(ctx.program, {
+            ...params,
+            liquidityAmount: new anchor.BN(1_000_000),
+            tokenMaxA: new u64(1_000_000),
+            tokenMaxB: new u64(1_000_000),
+          }),
+          provider.wallet.publicKey,
+          otherAuthorityKeypair.publicKey
+        )
+      )
+        .addSigner(otherAuthorityKeypair)
+        .buildAndExecute(),
+      /0x7d3/ // ConstraintRaw
+    );
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        replaceNestedAuthority(
+          WhirlpoolIx.decreaseLiquidityWithNativeIx(ctx.program, {
+            ...params,
+            liquidityAmount: new anchor.BN(1_000_000),
+            tokenMinA: ZERO_BN,
+            tokenMinB: ZERO_BN,
+          }),
+          provider.wallet.publicKey,
+          otherAuthorityKeypair.publicKey
+        )
+      )
+        .addSigner(otherAuthorityKeypair)
+        .buildAndExecute(),
+      /0x7d3/ // ConstraintRaw
+    );
+  });
+});
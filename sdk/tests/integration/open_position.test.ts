@@ -218,6 +218,7 @@ describe("open_position", () => {
           positionMintAddress: positionMintKeypair.publicKey,
           positionTokenAccount: positionTokenAccountAddress,
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           tickLowerIndex: 0,
           tickUpperIndex: 128,
         })
@@ -227,4 +228,46 @@ describe("open_position", () => {
       /0x0/
     );
   });
+
+  it("fails when the fee tier policy does not allow the tick spacing of the whirlpool", async () => {
+    const { poolInitInfo, configKeypairs } = await initTestPool(ctx, TickSpacing.Standard);
+    const { whirlpoolsConfig, whirlpoolPda } = poolInitInfo;
+    const feeAuthorityKeypair = configKeypairs.feeAuthorityKeypair;
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializeFeeTierPolicyIx(ctx.program, {
+        whirlpoolsConfig,
+        feeAuthority: feeAuthorityKeypair.publicKey,
+        funder: provider.wallet.publicKey,
+      })
+    )
+      .addInstruction(
+        WhirlpoolIx.setAllowedTickSpacingsIx(ctx.program, {
+          whirlpoolsConfig,
+          feeAuthority: feeAuthorityKeypair.publicKey,
+          allowedTickSpacings: [TickSpacing.Stable],
+        })
+      )
+      .addSigner(feeAuthorityKeypair)
+      .buildAndExecute();
+
+    await assert.rejects(
+      openPosition(ctx, whirlpoolPda.publicKey, tickLowerIndex, tickUpperIndex),
+      /0x1774/ // InvalidTickSpacing
+    );
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.setAllowedTickSpacingsIx(ctx.program, {
+        whirlpoolsConfig,
+        feeAuthority: feeAuthorityKeypair.publicKey,
+        allowedTickSpacings: [TickSpacing.Stable, TickSpacing.Standard],
+      })
+    )
+      .addSigner(feeAuthorityKeypair)
+      .buildAndExecute();
+
+    await openPosition(ctx, whirlpoolPda.publicKey, tickLowerIndex, tickUpperIndex);
+  });
 });
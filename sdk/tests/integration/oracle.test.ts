@@ -0,0 +1,296 @@
+import { MathUtil, Percentage } from "@orca-so/common-sdk";
+import * as anchor from "@project-serum/anchor";
+import { u64 } from "@solana/spl-token";
+import { Transaction } from "@solana/web3.js";
+import * as assert from "assert";
+import Decimal from "decimal.js";
+import {
+  buildWhirlpoolClient,
+  EXTENSION_ORACLE,
+  PDAUtil,
+  swapQuoteByInputToken,
+  toTx,
+  WhirlpoolContext,
+  WhirlpoolData,
+  WhirlpoolIx,
+} from "../../src";
+import { sleep, TickSpacing } from "../utils";
+import { WhirlpoolTestFixture } from "../utils/fixture";
+
+// Sizes of the account discriminator & oracle, and of an observation
+const ORACLE_LEN = 8 + 38;
+const OBSERVATION_LEN = 33;
+
+describe("oracle", () => {
+  const provider = anchor.AnchorProvider.local();
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.Whirlpool;
+  const ctx = WhirlpoolContext.fromWorkspace(provider, program);
+  const fetcher = ctx.fetcher;
+  const client = buildWhirlpoolClient(ctx);
+
+  async function initFixtureWithOracle() {
+    const fixture = await new WhirlpoolTestFixture(ctx).init({
+      tickSpacing: TickSpacing.Standard,
+      initialSqrtPrice: MathUtil.toX64(new Decimal(1)),
+      positions: [
+        { tickLowerIndex: -1280, tickUpperIndex: 1280, liquidityAmount: new anchor.BN(10_000_000) },
+      ],
+    });
+    const whirlpool = fixture.getInfos().poolInitInfo.whirlpoolPda.publicKey;
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializeOracleIx(ctx.program, {
+        whirlpool,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
+
+    return fixture;
+  }
+
+  async function swapAToB(fixture: WhirlpoolTestFixture) {
+    const { poolInitInfo, tokenAccountA, tokenAccountB } = fixture.getInfos();
+    const whirlpoolKey = poolInitInfo.whirlpoolPda.publicKey;
+    const whirlpool = await client.getPool(whirlpoolKey, true);
+    const quote = await swapQuoteByInputToken(
+      whirlpool,
+      whirlpool.getData().tokenMintA,
+      new u64(100_000),
+      Percentage.fromFraction(1, 100),
+      ctx.program.programId,
+      fetcher,
+      true
+    );
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.swapIx(ctx.program, {
+        ...quote,
+        whirlpool: whirlpoolKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+        tokenAuthority: provider.wallet.publicKey,
+        tokenOwnerAccountA: tokenAccountA,
+        tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
+        tokenOwnerAccountB: tokenAccountB,
+        tokenVaultB: poolInitInfo.tokenVaultBKeypair.publicKey,
+        oracle: PDAUtil.getOracle(ctx.program.programId, whirlpoolKey).publicKey,
+        extensions: whirlpool.getData().extensions,
+      })
+    ).buildAndExecute();
+  }
+
+  // The observations are stored after the oracle in the account data
+  async function getObservations(oracle: anchor.web3.PublicKey) {
+    const data = (await provider.connection.getAccountInfo(oracle))!.data;
+    const observations = [];
+    for (let offset = ORACLE_LEN; offset < data.length; offset += OBSERVATION_LEN) {
+      observations.push({
+        timestamp: new anchor.BN(data.subarray(offset, offset + 8), "le"),
+        tickCumulative: new anchor.BN(data.subarray(offset + 8, offset + 16), "le").fromTwos(64),
+        secondsPerLiquidityCumulativeX64: new anchor.BN(
+          data.subarray(offset + 16, offset + 32),
+          "le"
+        ),
+        initialized: data[offset + 32] !== 0,
+      });
+    }
+    return observations;
+  }
+
+  // The ObserveResult is only available as the return data of the observe instruction
+  async function simulateObserve(whirlpool: anchor.web3.PublicKey, secondsAgos: number[]) {
+    const tx = new Transaction().add(
+      ...WhirlpoolIx.observeIx(ctx.program, { whirlpool, secondsAgos }).instructions
+    );
+    tx.feePayer = provider.wallet.publicKey;
+    tx.recentBlockhash = (await provider.connection.getLatestBlockhash()).blockhash;
+    return (await provider.connection.simulateTransaction(tx)).value;
+  }
+
+  async function observe(whirlpool: anchor.web3.PublicKey, secondsAgos: number[]) {
+    const value = await simulateObserve(whirlpool, secondsAgos);
+    assert.equal(value.err, null);
+    assert.ok(value.returnData);
+    return ctx.program.coder.types.decode(
+      "ObserveResult",
+      Buffer.from(value.returnData.data[0], "base64")
+    );
+  }
+
+  it("successfully records an observation on swap and observes the average tick", async () => {
+    const fixture = await initFixtureWithOracle();
+    const whirlpoolKey = fixture.getInfos().poolInitInfo.whirlpoolPda.publicKey;
+    const oraclePda = PDAUtil.getOracle(ctx.program.programId, whirlpoolKey);
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.increaseObservationCardinalityIx(ctx.program, {
+        whirlpool: whirlpoolKey,
+        observationCardinalityNext: 4,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
+
+    assert.equal((await getObservations(oraclePda.publicKey)).length, 4);
+
+    const poolBefore = (await fetcher.getPool(whirlpoolKey, true)) as WhirlpoolData;
+    assert.equal(poolBefore.extensions, EXTENSION_ORACLE);
+
+    let oracle = await ctx.program.account.oracle.fetch(oraclePda.publicKey);
+    assert.ok(oracle.whirlpool.equals(whirlpoolKey));
+    assert.equal(oracle.observationIndex, 0);
+    assert.equal(oracle.observationCardinality, 1);
+    assert.equal(oracle.observationCardinalityNext, 4);
+    assert.ok((await getObservations(oraclePda.publicKey))[0].initialized);
+
+    // Observations are written at most once per timestamp
+    await sleep(2000);
+    await swapAToB(fixture);
+
+    oracle = await ctx.program.account.oracle.fetch(oraclePda.publicKey);
+    assert.equal(oracle.observationIndex, 1);
+    assert.equal(oracle.observationCardinality, 4);
+    const [first, second] = await getObservations(oraclePda.publicKey);
+    assert.ok(second.initialized);
+    assert.ok(second.timestamp.gt(first.timestamp));
+    // The pool was at tick 0 until the swap
+    assert.ok(second.tickCumulative.isZero());
+    assert.ok(second.secondsPerLiquidityCumulativeX64.gt(first.secondsPerLiquidityCumulativeX64));
+
+    await sleep(2000);
+    const pool = (await fetcher.getPool(whirlpoolKey, true)) as WhirlpoolData;
+    const result = await observe(whirlpoolKey, [1, 0]);
+    assert.equal(result.tickCumulatives.length, 2);
+    assert.deepEqual(result.averageTicks, [pool.tickCurrentIndex]);
+  });
+
+  it("successfully records an observation before the in-range liquidity changes", async () => {
+    const fixture = await initFixtureWithOracle();
+    const { poolInitInfo, positions, tokenAccountA, tokenAccountB } = fixture.getInfos();
+    const whirlpoolKey = poolInitInfo.whirlpoolPda.publicKey;
+    const oraclePda = PDAUtil.getOracle(ctx.program.programId, whirlpoolKey);
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.increaseObservationCardinalityIx(ctx.program, {
+        whirlpool: whirlpoolKey,
+        observationCardinalityNext: 4,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
+
+    await sleep(2000);
+    await swapAToB(fixture);
+    const poolBefore = (await fetcher.getPool(whirlpoolKey, true)) as WhirlpoolData;
+
+    await sleep(2000);
+    const position = positions[0];
+    await toTx(
+      ctx,
+      WhirlpoolIx.increaseLiquidityIx(ctx.program, {
+        whirlpool: whirlpoolKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+        positionAuthority: provider.wallet.publicKey,
+        position: position.publicKey,
+        positionTokenAccount: position.tokenAccount,
+        tokenOwnerAccountA: tokenAccountA,
+        tokenOwnerAccountB: tokenAccountB,
+        tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
+        tokenVaultB: poolInitInfo.tokenVaultBKeypair.publicKey,
+        tickArrayLower: position.tickArrayLower,
+        tickArrayUpper: position.tickArrayUpper,
+        liquidityAmount: new anchor.BN(10_000_000),
+        tokenMaxA: new u64(1_000_000),
+        tokenMaxB: new u64(1_000_000),
+        extensions: EXTENSION_ORACLE,
+      })
+    ).buildAndExecute();
+    const poolAfter = (await fetcher.getPool(whirlpoolKey, true)) as WhirlpoolData;
+    assert.ok(poolAfter.liquidity.eq(poolBefore.liquidity.addn(10_000_000)));
+
+    await sleep(2000);
+    await swapAToB(fixture);
+
+    const oracle = await ctx.program.account.oracle.fetch(oraclePda.publicKey);
+    assert.equal(oracle.observationIndex, 3);
+    const [, swapOne, increase, swapTwo] = await getObservations(oraclePda.publicKey);
+
+    // Each observation accumulates the liquidity that was in range since the previous one
+    const secondsPerLiquidity = (
+      start: { timestamp: anchor.BN },
+      end: { timestamp: anchor.BN },
+      liquidity: anchor.BN
+    ) => end.timestamp.sub(start.timestamp).shln(64).div(liquidity);
+    assert.ok(
+      increase.secondsPerLiquidityCumulativeX64
+        .sub(swapOne.secondsPerLiquidityCumulativeX64)
+        .eq(secondsPerLiquidity(swapOne, increase, poolBefore.liquidity))
+    );
+    assert.ok(
+      swapTwo.secondsPerLiquidityCumulativeX64
+        .sub(increase.secondsPerLiquidityCumulativeX64)
+        .eq(secondsPerLiquidity(increase, swapTwo, poolAfter.liquidity))
+    );
+  });
+
+  it("successfully grows the oracle account and its rent with the cardinality", async () => {
+    const fixture = await initFixtureWithOracle();
+    const whirlpoolKey = fixture.getInfos().poolInitInfo.whirlpoolPda.publicKey;
+    const oraclePda = PDAUtil.getOracle(ctx.program.programId, whirlpoolKey);
+
+    const oracleBefore = (await provider.connection.getAccountInfo(oraclePda.publicKey))!;
+    assert.equal(oracleBefore.data.length, ORACLE_LEN + OBSERVATION_LEN);
+    assert.equal(
+      oracleBefore.lamports,
+      await provider.connection.getMinimumBalanceForRentExemption(oracleBefore.data.length)
+    );
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.increaseObservationCardinalityIx(ctx.program, {
+        whirlpool: whirlpoolKey,
+        observationCardinalityNext: 10,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
+
+    const oracleAfter = (await provider.connection.getAccountInfo(oraclePda.publicKey))!;
+    assert.equal(oracleAfter.data.length, ORACLE_LEN + 10 * OBSERVATION_LEN);
+    assert.equal(
+      oracleAfter.lamports,
+      await provider.connection.getMinimumBalanceForRentExemption(oracleAfter.data.length)
+    );
+    assert.ok(oracleAfter.lamports > oracleBefore.lamports);
+
+    // The existing observation is kept and the new slots are empty
+    const observations = await getObservations(oraclePda.publicKey);
+    assert.ok(observations[0].initialized);
+    assert.ok(observations.slice(1).every((observation) => !observation.initialized));
+  });
+
+  it("fails to observe before the oldest observation", async () => {
+    const fixture = await initFixtureWithOracle();
+    const whirlpoolKey = fixture.getInfos().poolInitInfo.whirlpoolPda.publicKey;
+
+    const value = await simulateObserve(whirlpoolKey, [3600, 0]);
+    assert.ok(value.logs?.some((log) => /0x179c/.test(log))); // ObservationTooOld
+  });
+
+  it("fails to grow the oracle past the maximum cardinality", async () => {
+    const fixture = await initFixtureWithOracle();
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.increaseObservationCardinalityIx(ctx.program, {
+          whirlpool: fixture.getInfos().poolInitInfo.whirlpoolPda.publicKey,
+          observationCardinalityNext: 301,
+          funder: provider.wallet.publicKey,
+        })
+      ).buildAndExecute(),
+      /0x179e/ // InvalidObservationCardinality
+    );
+  });
+});
@@ -0,0 +1,215 @@
+import { MathUtil } from "@orca-so/common-sdk";
+import * as anchor from "@project-serum/anchor";
+import * as assert from "assert";
+import Decimal from "decimal.js";
+import {
+  EXTENSION_ORACLE,
+  EXTENSION_RANGE_ORDER_BOOK,
+  PDAUtil,
+  PositionData,
+  toTx,
+  WhirlpoolContext,
+  WhirlpoolData,
+  WhirlpoolIx,
+} from "../../src";
+import { getTokenBalance, TickSpacing, ZERO_BN } from "../utils";
+import { WhirlpoolTestFixture } from "../utils/fixture";
+
+describe("range_order", () => {
+  const provider = anchor.AnchorProvider.local();
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.Whirlpool;
+  const ctx = WhirlpoolContext.fromWorkspace(provider, program);
+  const fetcher = ctx.fetcher;
+
+  async function initFixture(
+    tickLowerIndex: number,
+    tickUpperIndex: number,
+    liquidityAmount = new anchor.BN(1_000_000)
+  ) {
+    const fixture = await new WhirlpoolTestFixture(ctx).init({
+      tickSpacing: TickSpacing.Standard,
+      initialSqrtPrice: MathUtil.toX64(new Decimal(1)),
+      positions: [{ tickLowerIndex, tickUpperIndex, liquidityAmount }],
+    });
+    const { poolInitInfo } = fixture.getInfos();
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializeRangeOrderBookIx(ctx.program, {
+        whirlpool: poolInitInfo.whirlpoolPda.publicKey,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
+
+    return fixture;
+  }
+
+  function placeRangeOrderTx(fixture: WhirlpoolTestFixture) {
+    const { poolInitInfo, positions } = fixture.getInfos();
+    return toTx(
+      ctx,
+      WhirlpoolIx.placeRangeOrderIx(ctx.program, {
+        whirlpool: poolInitInfo.whirlpoolPda.publicKey,
+        position: positions[0].publicKey,
+        positionMint: positions[0].mintKeypair.publicKey,
+        positionTokenAccount: positions[0].tokenAccount,
+        positionAuthority: provider.wallet.publicKey,
+      })
+    );
+  }
+
+  it("successfully places and withdraws an unfilled range order", async () => {
+    const fixture = await initFixture(128, 192);
+    const { poolInitInfo, positions, tokenAccountA, tokenAccountB } = fixture.getInfos();
+    const { whirlpoolPda, tokenVaultAKeypair, tokenVaultBKeypair } = poolInitInfo;
+    const position = positions[0];
+    const rangeOrderBookPda = PDAUtil.getRangeOrderBook(
+      ctx.program.programId,
+      whirlpoolPda.publicKey
+    );
+    const rangeOrderEscrowPda = PDAUtil.getRangeOrderEscrow(
+      ctx.program.programId,
+      position.publicKey
+    );
+
+    const pool = (await fetcher.getPool(whirlpoolPda.publicKey, true)) as WhirlpoolData;
+    assert.equal(pool.extensions, EXTENSION_RANGE_ORDER_BOOK);
+
+    await placeRangeOrderTx(fixture).buildAndExecute();
+
+    const bookAfterPlace = await ctx.program.account.rangeOrderBook.fetch(
+      rangeOrderBookPda.publicKey
+    );
+    assert.equal(bookAfterPlace.rangeOrders.length, 1);
+    assert.ok(bookAfterPlace.rangeOrders[0].position.equals(position.publicKey));
+    assert.ok(bookAfterPlace.rangeOrders[0].sellsTokenA);
+    assert.ok(!bookAfterPlace.rangeOrders[0].filled);
+    assert.equal(await getTokenBalance(provider, position.tokenAccount), "0");
+    assert.equal(await getTokenBalance(provider, rangeOrderEscrowPda.publicKey), "1");
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.withdrawRangeOrderIx(ctx.program, {
+        tokenMinA: ZERO_BN,
+        tokenMinB: ZERO_BN,
+        whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+        extensions: EXTENSION_RANGE_ORDER_BOOK,
+        owner: provider.wallet.publicKey,
+        position: position.publicKey,
+        positionTokenAccount: position.tokenAccount,
+        tokenOwnerAccountA: tokenAccountA,
+        tokenOwnerAccountB: tokenAccountB,
+        tokenVaultA: tokenVaultAKeypair.publicKey,
+        tokenVaultB: tokenVaultBKeypair.publicKey,
+        tickArrayLower: position.tickArrayLower,
+        tickArrayUpper: position.tickArrayUpper,
+      })
+    ).buildAndExecute();
+
+    const bookAfterWithdraw = await ctx.program.account.rangeOrderBook.fetch(
+      rangeOrderBookPda.publicKey
+    );
+    assert.equal(bookAfterWithdraw.rangeOrders.length, 0);
+    assert.equal(await getTokenBalance(provider, position.tokenAccount), "1");
+    assert.equal(await provider.connection.getAccountInfo(rangeOrderEscrowPda.publicKey), null);
+
+    const positionAfter = (await fetcher.getPosition(position.publicKey, true)) as PositionData;
+    assert.ok(positionAfter.liquidity.isZero());
+  });
+
+  it("fails to withdraw without the side accounts of the extensions enabled on the whirlpool", async () => {
+    const fixture = await initFixture(128, 192);
+    const { poolInitInfo, positions, tokenAccountA, tokenAccountB } = fixture.getInfos();
+    const position = positions[0];
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializeOracleIx(ctx.program, {
+        whirlpool: poolInitInfo.whirlpoolPda.publicKey,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
+    await placeRangeOrderTx(fixture).buildAndExecute();
+
+    const withdrawRangeOrderTx = (extensions: number) =>
+      toTx(
+        ctx,
+        WhirlpoolIx.withdrawRangeOrderIx(ctx.program, {
+          tokenMinA: ZERO_BN,
+          tokenMinB: ZERO_BN,
+          whirlpool: poolInitInfo.whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+          extensions,
+          owner: provider.wallet.publicKey,
+          position: position.publicKey,
+          positionTokenAccount: position.tokenAccount,
+          tokenOwnerAccountA: tokenAccountA,
+          tokenOwnerAccountB: tokenAccountB,
+          tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
+          tokenVaultB: poolInitInfo.tokenVaultBKeypair.publicKey,
+          tickArrayLower: position.tickArrayLower,
+          tickArrayUpper: position.tickArrayUpper,
+        })
+      );
+
+    await assert.rejects(
+      withdrawRangeOrderTx(EXTENSION_RANGE_ORDER_BOOK).buildAndExecute(),
+      /0x17b7/ // InvalidExtensionAccount
+    );
+    await withdrawRangeOrderTx(EXTENSION_ORACLE | EXTENSION_RANGE_ORDER_BOOK).buildAndExecute();
+  });
+
+  it("fails when the position spans more than one tick-spacing", async () => {
+    const fixture = await initFixture(128, 256);
+    await assert.rejects(
+      placeRangeOrderTx(fixture).buildAndExecute(),
+      /0x17ae/ // InvalidRangeOrderTickRange
+    );
+  });
+
+  it("fails when the liquidity of the position is below the minimum", async () => {
+    const fixture = await initFixture(128, 192, new anchor.BN(999_999));
+    await assert.rejects(
+      placeRangeOrderTx(fixture).buildAndExecute(),
+      /0x17bb/ // RangeOrderLiquidityTooLow
+    );
+  });
+
+  it("fails when the current price is inside the range of the position", async () => {
+    const fixture = await initFixture(-64, 0);
+    await assert.rejects(
+      placeRangeOrderTx(fixture).buildAndExecute(),
+      /0x17af/ // RangeOrderInRange
+    );
+  });
+
+  it("fails to withdraw a position without a range order", async () => {
+    const fixture = await initFixture(128, 192);
+    const { poolInitInfo, positions, tokenAccountA, tokenAccountB } = fixture.getInfos();
+    const position = positions[0];
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.withdrawRangeOrderIx(ctx.program, {
+          tokenMinA: ZERO_BN,
+          tokenMinB: ZERO_BN,
+          whirlpool: poolInitInfo.whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+          owner: provider.wallet.publicKey,
+          position: position.publicKey,
+          positionTokenAccount: position.tokenAccount,
+          tokenOwnerAccountA: tokenAccountA,
+          tokenOwnerAccountB: tokenAccountB,
+          tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
+          tokenVaultB: poolInitInfo.tokenVaultBKeypair.publicKey,
+          tickArrayLower: position.tickArrayLower,
+          tickArrayUpper: position.tickArrayUpper,
+        })
+      ).buildAndExecute(),
+      /0xbc4/ // AccountNotInitialized
+    );
+  });
+});
@@ -0,0 +1,200 @@
+import { MathUtil } from "@orca-so/common-sdk";
+import * as anchor from "@project-serum/anchor";
+import { u64 } from "@solana/spl-token";
+import * as assert from "assert";
+import Decimal from "decimal.js";
+import {
+  EXTENSION_ORACLE,
+  PositionData,
+  TickArrayData,
+  toTx,
+  WhirlpoolContext,
+  WhirlpoolData,
+  WhirlpoolIx,
+} from "../../src";
+import { RebalancePositionParams } from "../../src/instructions";
+import { assertTick, TickSpacing, ZERO_BN } from "../utils";
+import { WhirlpoolTestFixture } from "../utils/fixture";
+
+describe("rebalance_position", () => {
+  const provider = anchor.AnchorProvider.local();
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.Whirlpool;
+  const ctx = WhirlpoolContext.fromWorkspace(provider, program);
+  const fetcher = ctx.fetcher;
+
+  const liquidityAmount = new anchor.BN(1_250_000);
+
+  async function initFixture(positionLiquidityAmount = liquidityAmount) {
+    return new WhirlpoolTestFixture(ctx).init({
+      tickSpacing: TickSpacing.Standard,
+      initialSqrtPrice: MathUtil.toX64(new Decimal(1)),
+      positions: [
+        { tickLowerIndex: -1280, tickUpperIndex: 1280, liquidityAmount: positionLiquidityAmount },
+      ],
+    });
+  }
+
+  function getRebalanceParams(
+    fixture: WhirlpoolTestFixture,
+    tickLowerIndex: number,
+    tickUpperIndex: number
+  ): RebalancePositionParams {
+    const { poolInitInfo, positions, tokenAccountA, tokenAccountB } = fixture.getInfos();
+    const position = positions[0];
+    return {
+      tickLowerIndex,
+      tickUpperIndex,
+      liquidityAmount,
+      tokenMinA: ZERO_BN,
+      tokenMinB: ZERO_BN,
+      tokenMaxA: new u64(1_000_000),
+      tokenMaxB: new u64(1_000_000),
+      whirlpool: poolInitInfo.whirlpoolPda.publicKey,
+      whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+      position: position.publicKey,
+      positionTokenAccount: position.tokenAccount,
+      tokenOwnerAccountA: tokenAccountA,
+      tokenOwnerAccountB: tokenAccountB,
+      tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
+      tokenVaultB: poolInitInfo.tokenVaultBKeypair.publicKey,
+      tickArrayLower: position.tickArrayLower,
+      tickArrayUpper: position.tickArrayUpper,
+      // The new range is hosted by the tick-array starting at 0
+      newTickArrayLower: position.tickArrayUpper,
+      newTickArrayUpper: position.tickArrayUpper,
+      positionAuthority: provider.wallet.publicKey,
+    };
+  }
+
+  it("successfully moves the liquidity of a position to a new tick range", async () => {
+    const fixture = await initFixture();
+    const { poolInitInfo, positions } = fixture.getInfos();
+    const whirlpoolKey = poolInitInfo.whirlpoolPda.publicKey;
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.rebalancePositionIx(ctx.program, getRebalanceParams(fixture, 128, 1920))
+    ).buildAndExecute();
+
+    const position = (await fetcher.getPosition(positions[0].publicKey, true)) as PositionData;
+    assert.equal(position.tickLowerIndex, 128);
+    assert.equal(position.tickUpperIndex, 1920);
+    assert.ok(position.liquidity.eq(liquidityAmount));
+    assert.ok(position.feeOwedA.isZero());
+    assert.ok(position.feeOwedB.isZero());
+
+    // The new range is above the current price
+    const pool = (await fetcher.getPool(whirlpoolKey, true)) as WhirlpoolData;
+    assert.ok(pool.liquidity.isZero());
+
+    const tickArrayLower = (await fetcher.getTickArray(
+      positions[0].tickArrayLower,
+      true
+    )) as TickArrayData;
+    const tickArrayUpper = (await fetcher.getTickArray(
+      positions[0].tickArrayUpper,
+      true
+    )) as TickArrayData;
+    assertTick(tickArrayLower.ticks[68], false, ZERO_BN, ZERO_BN);
+    assertTick(tickArrayUpper.ticks[20], false, ZERO_BN, ZERO_BN);
+    assertTick(tickArrayUpper.ticks[2], true, liquidityAmount, liquidityAmount);
+    assertTick(tickArrayUpper.ticks[30], true, liquidityAmount, liquidityAmount.neg());
+  });
+
+  it("successfully moves a position without liquidity to a new tick range", async () => {
+    const fixture = await initFixture(ZERO_BN);
+    const { poolInitInfo, positions } = fixture.getInfos();
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.rebalancePositionIx(ctx.program, getRebalanceParams(fixture, 128, 1920))
+    ).buildAndExecute();
+
+    const position = (await fetcher.getPosition(positions[0].publicKey, true)) as PositionData;
+    assert.equal(position.tickLowerIndex, 128);
+    assert.equal(position.tickUpperIndex, 1920);
+    assert.ok(position.liquidity.eq(liquidityAmount));
+
+    const pool = (await fetcher.getPool(
+      poolInitInfo.whirlpoolPda.publicKey,
+      true
+    )) as WhirlpoolData;
+    assert.ok(pool.liquidity.isZero());
+
+    const tickArrayUpper = (await fetcher.getTickArray(
+      positions[0].tickArrayUpper,
+      true
+    )) as TickArrayData;
+    assertTick(tickArrayUpper.ticks[20], false, ZERO_BN, ZERO_BN);
+    assertTick(tickArrayUpper.ticks[2], true, liquidityAmount, liquidityAmount);
+    assertTick(tickArrayUpper.ticks[30], true, liquidityAmount, liquidityAmount.neg());
+  });
+
+  it("fails without the side accounts of the extensions enabled on the whirlpool", async () => {
+    const fixture = await initFixture();
+    const { poolInitInfo } = fixture.getInfos();
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializeOracleIx(ctx.program, {
+        whirlpool: poolInitInfo.whirlpoolPda.publicKey,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.rebalancePositionIx(ctx.program, getRebalanceParams(fixture, 128, 1920))
+      ).buildAndExecute(),
+      /0x17b7/ // InvalidExtensionAccount
+    );
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.rebalancePositionIx(ctx.program, {
+        ...getRebalanceParams(fixture, 128, 1920),
+        extensions: EXTENSION_ORACLE,
+      })
+    ).buildAndExecute();
+  });
+
+  it("fails when the new tick range is invalid", async () => {
+    const fixture = await initFixture();
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.rebalancePositionIx(ctx.program, getRebalanceParams(fixture, 1920, 128))
+      ).buildAndExecute(),
+      /0x177a/ // InvalidTickIndex
+    );
+  });
+
+  it("fails when the deposit exceeds the token max", async () => {
+    const fixture = await initFixture();
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.rebalancePositionIx(ctx.program, {
+          ...getRebalanceParams(fixture, 128, 1920),
+          tokenMaxA: new u64(1),
+        })
+      ).buildAndExecute(),
+      /0x1781/ // TokenMaxExceeded
+    );
+  });
+
+  it("fails when the withdrawal subceeds the token min", async () => {
+    const fixture = await initFixture();
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.rebalancePositionIx(ctx.program, {
+          ...getRebalanceParams(fixture, 128, 1920),
+          tokenMinA: new u64(1_000_000),
+        })
+      ).buildAndExecute(),
+      /0x1782/ // TokenMinSubceeded
+    );
+  });
+});
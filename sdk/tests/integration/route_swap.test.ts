@@ -0,0 +1,349 @@
+import { Percentage } from "@orca-so/common-sdk";
+import * as anchor from "@project-serum/anchor";
+import { u64 } from "@solana/spl-token";
+import { PublicKey } from "@solana/web3.js";
+import * as assert from "assert";
+import {
+  buildWhirlpoolClient,
+  EXTENSION_ORACLE,
+  InitPoolParams,
+  PDAUtil,
+  SwapQuote,
+  swapQuoteByInputToken,
+  swapQuoteByOutputToken,
+  toTx,
+  WhirlpoolContext,
+  WhirlpoolData,
+  WhirlpoolIx,
+} from "../../src";
+import { RouteSwapHop } from "../../src/instructions";
+import { getTokenBalance, sleep, TickSpacing } from "../utils";
+import {
+  buildTestAquariums,
+  FundedPositionParams,
+  getDefaultAquarium,
+  getTokenAccsForPools,
+  InitAquariumParams,
+} from "../utils/init-utils";
+
+describe("route swap", () => {
+  const provider = anchor.AnchorProvider.local();
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.Whirlpool;
+  const ctx = WhirlpoolContext.fromWorkspace(provider, program);
+  const fetcher = ctx.fetcher;
+  const client = buildWhirlpoolClient(ctx);
+
+  let aqConfig: InitAquariumParams;
+  beforeEach(async () => {
+    aqConfig = getDefaultAquarium();
+    // Add two more tokens and accounts and two more pools, for a route of three hops
+    aqConfig.initMintParams.push({}, {});
+    aqConfig.initTokenAccParams.push({ mintIndex: 2 }, { mintIndex: 3 });
+    aqConfig.initPoolParams.push(
+      { mintIndices: [1, 2], tickSpacing: TickSpacing.Standard },
+      { mintIndices: [2, 3], tickSpacing: TickSpacing.Standard }
+    );
+
+    // Add tick arrays and positions
+    const fundParams: FundedPositionParams[] = [
+      {
+        liquidityAmount: new anchor.BN(10_000_000),
+        tickLowerIndex: 29440,
+        tickUpperIndex: 33536,
+      },
+    ];
+    for (const poolIndex of [0, 1, 2]) {
+      aqConfig.initTickArrayRangeParams.push({
+        poolIndex,
+        startTickIndex: 22528,
+        arrayCount: 3,
+        aToB: false,
+      });
+      aqConfig.initPositionParams.push({ poolIndex, fundParams });
+    }
+  });
+
+  it("swaps [3] with route swap, amountSpecifiedIsInput=true", async () => {
+    const aquarium = (await buildTestAquariums(ctx, [aqConfig]))[0];
+    const { tokenAccounts, mintKeys, pools } = aquarium;
+
+    const tokenBalances = await getTokenBalances(tokenAccounts.map((acc) => acc.account));
+    const tokenVaultBalances = await getTokenBalancesForVaults(pools);
+
+    const quotes = await getExactInQuotes(pools, mintKeys, new u64(1000));
+    const lastQuote = quotes[quotes.length - 1];
+
+    const routeSwap = WhirlpoolIx.routeSwapIx(ctx.program, {
+      amount: new u64(1000),
+      otherAmountThreshold: lastQuote.otherAmountThreshold,
+      amountSpecifiedIsInput: true,
+      hops: getHops(pools, tokenAccounts, quotes),
+      tokenAuthority: ctx.wallet.publicKey,
+    });
+    // The token program, the token authority, eight accounts per hop without extensions and the
+    // pause controls of the config of the hops
+    assert.equal(routeSwap.instructions[0].keys.length, 2 + 3 * 8 + 1);
+    await toTx(ctx, routeSwap).buildAndExecute();
+
+    assert.deepEqual(await getTokenBalancesForVaults(pools), [
+      tokenVaultBalances[0].add(quotes[0].estimatedAmountIn),
+      tokenVaultBalances[1].sub(quotes[0].estimatedAmountOut),
+      tokenVaultBalances[2].add(quotes[1].estimatedAmountIn),
+      tokenVaultBalances[3].sub(quotes[1].estimatedAmountOut),
+      tokenVaultBalances[4].add(quotes[2].estimatedAmountIn),
+      tokenVaultBalances[5].sub(quotes[2].estimatedAmountOut),
+    ]);
+
+    // The intermediate tokens pass straight from one hop to the next
+    assert.deepEqual(await getTokenBalances(tokenAccounts.map((acc) => acc.account)), [
+      tokenBalances[0].sub(quotes[0].estimatedAmountIn),
+      tokenBalances[1],
+      tokenBalances[2],
+      tokenBalances[3].add(lastQuote.estimatedAmountOut),
+    ]);
+  });
+
+  it("swaps [3] with route swap, amountSpecifiedIsInput=false", async () => {
+    const aquarium = (await buildTestAquariums(ctx, [aqConfig]))[0];
+    const { tokenAccounts, mintKeys, pools } = aquarium;
+
+    const tokenBalances = await getTokenBalances(tokenAccounts.map((acc) => acc.account));
+    const tokenVaultBalances = await getTokenBalancesForVaults(pools);
+
+    // The quotes of an exact-out route run from the last to the first hop
+    const quotes: SwapQuote[] = [];
+    let amountOut = new u64(1000);
+    for (let i = pools.length - 1; i >= 0; i--) {
+      const whirlpool = await client.getPool(pools[i].whirlpoolPda.publicKey, true);
+      const quote = await swapQuoteByOutputToken(
+        whirlpool,
+        mintKeys[i + 1],
+        amountOut,
+        Percentage.fromFraction(1, 100),
+        ctx.program.programId,
+        fetcher,
+        true
+      );
+      quotes.unshift(quote);
+      amountOut = quote.estimatedAmountIn;
+    }
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.routeSwapIx(ctx.program, {
+        amount: new u64(1000),
+        otherAmountThreshold: quotes[0].otherAmountThreshold,
+        amountSpecifiedIsInput: false,
+        hops: getHops(pools, tokenAccounts, quotes),
+        tokenAuthority: ctx.wallet.publicKey,
+      })
+    ).buildAndExecute();
+
+    assert.deepEqual(await getTokenBalancesForVaults(pools), [
+      tokenVaultBalances[0].add(quotes[0].estimatedAmountIn),
+      tokenVaultBalances[1].sub(quotes[0].estimatedAmountOut),
+      tokenVaultBalances[2].add(quotes[1].estimatedAmountIn),
+      tokenVaultBalances[3].sub(quotes[1].estimatedAmountOut),
+      tokenVaultBalances[4].add(quotes[2].estimatedAmountIn),
+      tokenVaultBalances[5].sub(quotes[2].estimatedAmountOut),
+    ]);
+
+    assert.deepEqual(await getTokenBalances(tokenAccounts.map((acc) => acc.account)), [
+      tokenBalances[0].sub(quotes[0].estimatedAmountIn),
+      tokenBalances[1],
+      tokenBalances[2],
+      tokenBalances[3].add(new u64(1000)),
+    ]);
+  });
+
+  it("fails swaps [3] with route swap, amountSpecifiedIsInput=true, slippage", async () => {
+    const aquarium = (await buildTestAquariums(ctx, [aqConfig]))[0];
+    const { tokenAccounts, mintKeys, pools } = aquarium;
+
+    const quotes = await getExactInQuotes(pools, mintKeys, new u64(1000));
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.routeSwapIx(ctx.program, {
+          amount: new u64(1000),
+          otherAmountThreshold: new u64(quotes[quotes.length - 1].estimatedAmountOut.addn(1)),
+          amountSpecifiedIsInput: true,
+          hops: getHops(pools, tokenAccounts, quotes),
+          tokenAuthority: ctx.wallet.publicKey,
+        })
+      ).buildAndExecute(),
+      /0x1794/ // AmountOutBelowMinimum
+    );
+  });
+
+  it("fails swaps with route swap, no overlapping mints", async () => {
+    const aquarium = (await buildTestAquariums(ctx, [aqConfig]))[0];
+    const { tokenAccounts, mintKeys, pools } = aquarium;
+
+    const quotes = await getExactInQuotes(pools, mintKeys, new u64(1000));
+    const hops = getHops(pools, tokenAccounts, quotes);
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.routeSwapIx(ctx.program, {
+          amount: new u64(1000),
+          otherAmountThreshold: new u64(0),
+          amountSpecifiedIsInput: true,
+          hops: [hops[0], hops[2]],
+          tokenAuthority: ctx.wallet.publicKey,
+        })
+      ).buildAndExecute(),
+      /0x179a/ // InvalidIntermediaryMint
+    );
+  });
+
+  it("fails swaps with route swap, duplicate pool", async () => {
+    const aquarium = (await buildTestAquariums(ctx, [aqConfig]))[0];
+    const { tokenAccounts, mintKeys, pools } = aquarium;
+
+    const quotes = await getExactInQuotes(pools, mintKeys, new u64(1000));
+    const hops = getHops(pools, tokenAccounts, quotes);
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.routeSwapIx(ctx.program, {
+          amount: new u64(1000),
+          otherAmountThreshold: new u64(0),
+          amountSpecifiedIsInput: true,
+          hops: [hops[0], hops[0]],
+          tokenAuthority: ctx.wallet.publicKey,
+        })
+      ).buildAndExecute(),
+      /0x17a0/ // DuplicateRoutePool
+    );
+  });
+
+  it("fails swaps with route swap, missing pause controls", async () => {
+    const aquarium = (await buildTestAquariums(ctx, [aqConfig]))[0];
+    const { tokenAccounts, mintKeys, pools } = aquarium;
+
+    const quotes = await getExactInQuotes(pools, mintKeys, new u64(1000));
+    const routeSwap = WhirlpoolIx.routeSwapIx(ctx.program, {
+      amount: new u64(1000),
+      otherAmountThreshold: new u64(0),
+      amountSpecifiedIsInput: true,
+      hops: getHops(pools, tokenAccounts, quotes),
+      tokenAuthority: ctx.wallet.publicKey,
+    });
+    // The pause controls are required even though they have not been initialized
+    routeSwap.instructions[0].keys.pop();
+
+    await assert.rejects(
+      toTx(ctx, routeSwap).buildAndExecute(),
+      /0x17b7/ // InvalidExtensionAccount
+    );
+  });
+
+  it("swaps [3] with route swap, records an observation on a hop with an oracle", async () => {
+    const aquarium = (await buildTestAquariums(ctx, [aqConfig]))[0];
+    const { tokenAccounts, mintKeys, pools } = aquarium;
+    const whirlpoolKey = pools[1].whirlpoolPda.publicKey;
+    const oraclePda = PDAUtil.getOracle(ctx.program.programId, whirlpoolKey);
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializeOracleIx(ctx.program, {
+        whirlpool: whirlpoolKey,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
+    const pool = (await fetcher.getPool(whirlpoolKey, true)) as WhirlpoolData;
+    assert.equal(pool.extensions, EXTENSION_ORACLE);
+    const oracleBefore = await getObservationTimestamp(oraclePda.publicKey);
+
+    const quotes = await getExactInQuotes(pools, mintKeys, new u64(1000));
+    const hops = getHops(pools, tokenAccounts, quotes);
+    const params = {
+      amount: new u64(1000),
+      otherAmountThreshold: quotes[quotes.length - 1].otherAmountThreshold,
+      amountSpecifiedIsInput: true,
+      hops,
+      tokenAuthority: ctx.wallet.publicKey,
+    };
+
+    // The oracle of the hop has to be passed once the extension is enabled
+    await assert.rejects(
+      toTx(ctx, WhirlpoolIx.routeSwapIx(ctx.program, params)).buildAndExecute(),
+      /0x17b7/ // InvalidExtensionAccount
+    );
+
+    // Observations are written at most once per timestamp
+    await sleep(2000);
+    hops[1].extensions = pool.extensions;
+    await toTx(ctx, WhirlpoolIx.routeSwapIx(ctx.program, params)).buildAndExecute();
+
+    assert.ok((await getObservationTimestamp(oraclePda.publicKey)).gt(oracleBefore));
+  });
+
+  // The single observation of the oracle is stored after the oracle in the account data
+  async function getObservationTimestamp(oracle: PublicKey) {
+    const data = (await provider.connection.getAccountInfo(oracle))!.data;
+    return new anchor.BN(data.subarray(8 + 38, 8 + 38 + 8), "le");
+  }
+
+  async function getExactInQuotes(pools: InitPoolParams[], mintKeys: PublicKey[], amount: u64) {
+    const quotes: SwapQuote[] = [];
+    let amountIn = amount;
+    for (let i = 0; i < pools.length; i++) {
+      const whirlpool = await client.getPool(pools[i].whirlpoolPda.publicKey, true);
+      const quote = await swapQuoteByInputToken(
+        whirlpool,
+        mintKeys[i],
+        amountIn,
+        Percentage.fromFraction(1, 100),
+        ctx.program.programId,
+        fetcher,
+        true
+      );
+      quotes.push(quote);
+      amountIn = quote.estimatedAmountOut;
+    }
+    return quotes;
+  }
+
+  function getHops(
+    pools: InitPoolParams[],
+    tokenAccounts: { mint: PublicKey; account: PublicKey }[],
+    quotes: SwapQuote[]
+  ): RouteSwapHop[] {
+    const tokenAccKeys = getTokenAccsForPools(pools, tokenAccounts);
+
+    return pools.map((pool, i) => ({
+      whirlpool: pool.whirlpoolPda.publicKey,
+      whirlpoolsConfig: pool.whirlpoolsConfig,
+      tokenOwnerAccountA: tokenAccKeys[2 * i],
+      tokenVaultA: pool.tokenVaultAKeypair.publicKey,
+      tokenOwnerAccountB: tokenAccKeys[2 * i + 1],
+      tokenVaultB: pool.tokenVaultBKeypair.publicKey,
+      tickArray0: quotes[i].tickArray0,
+      tickArray1: quotes[i].tickArray1,
+      tickArray2: quotes[i].tickArray2,
+      aToB: quotes[i].aToB,
+      sqrtPriceLimit: quotes[i].sqrtPriceLimit,
+    }));
+  }
+
+  async function getTokenBalancesForVaults(pools: InitPoolParams[]) {
+    const accs = [];
+    for (const pool of pools) {
+      accs.push(pool.tokenVaultAKeypair.publicKey);
+      accs.push(pool.tokenVaultBKeypair.publicKey);
+    }
+    return getTokenBalances(accs);
+  }
+
+  async function getTokenBalances(keys: PublicKey[]) {
+    return Promise.all(
+      keys.map(async (key) => new anchor.BN(await getTokenBalance(provider, key)))
+    );
+  }
+});
@@ -16,6 +16,13 @@ describe("set_collect_protocol_fee_authority", () => {
       configKeypairs: { collectProtocolFeesAuthorityKeypair },
     } = generateDefaultConfigParams(ctx);
     await toTx(ctx, WhirlpoolIx.initializeConfigIx(ctx.program, configInitInfo)).buildAndExecute();
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializePendingAuthoritiesIx(ctx.program, {
+        whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
     const newAuthorityKeypair = anchor.web3.Keypair.generate();
     await toTx(
       ctx,
@@ -27,6 +34,17 @@ describe("set_collect_protocol_fee_authority", () => {
     )
       .addSigner(collectProtocolFeesAuthorityKeypair)
       .buildAndExecute();
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.acceptCollectProtocolFeesAuthorityIx(ctx.program, {
+        whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+        newCollectProtocolFeesAuthority: newAuthorityKeypair.publicKey,
+      })
+    )
+      .addSigner(newAuthorityKeypair)
+      .buildAndExecute();
+
     const config = (await fetcher.getConfig(
       configInitInfo.whirlpoolsConfigKeypair.publicKey
     )) as WhirlpoolsConfigData;
@@ -39,6 +57,13 @@ describe("set_collect_protocol_fee_authority", () => {
       configKeypairs: { collectProtocolFeesAuthorityKeypair },
     } = generateDefaultConfigParams(ctx);
     await toTx(ctx, WhirlpoolIx.initializeConfigIx(ctx.program, configInitInfo)).buildAndExecute();
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializePendingAuthoritiesIx(ctx.program, {
+        whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
 
     await assert.rejects(
       toTx(
@@ -56,6 +81,13 @@ describe("set_collect_protocol_fee_authority", () => {
   it("fails if invalid collect_protocol_fee_authority provided", async () => {
     const { configInitInfo } = generateDefaultConfigParams(ctx);
     await toTx(ctx, WhirlpoolIx.initializeConfigIx(ctx.program, configInitInfo)).buildAndExecute();
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializePendingAuthoritiesIx(ctx.program, {
+        whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
 
     await assert.rejects(
       toTx(
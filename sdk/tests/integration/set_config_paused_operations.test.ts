@@ -0,0 +1,207 @@
+import { MathUtil } from "@orca-so/common-sdk";
+import * as anchor from "@project-serum/anchor";
+import { u64 } from "@solana/spl-token";
+import * as assert from "assert";
+import Decimal from "decimal.js";
+import {
+  PAUSE_ALL,
+  PAUSE_OPEN_POSITION,
+  PAUSE_SWAP,
+  PDAUtil,
+  toTx,
+  WhirlpoolContext,
+  WhirlpoolIx,
+} from "../../src";
+import { TickSpacing, ZERO_BN } from "../utils";
+import { WhirlpoolTestFixture } from "../utils/fixture";
+import { buildTestPoolParams, initTestPoolFromParams, openPosition } from "../utils/init-utils";
+
+describe("set_config_paused_operations", () => {
+  const provider = anchor.AnchorProvider.local();
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.Whirlpool;
+  const ctx = WhirlpoolContext.fromWorkspace(provider, program);
+
+  const tickLowerIndex = 29440;
+  const tickUpperIndex = 33536;
+
+  async function initFixtureWithPauseControls() {
+    const fixture = await new WhirlpoolTestFixture(ctx).init({
+      tickSpacing: TickSpacing.Standard,
+      positions: [{ tickLowerIndex, tickUpperIndex, liquidityAmount: new anchor.BN(10_000_000) }],
+    });
+    const { poolInitInfo } = fixture.getInfos();
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializePauseControlsIx(ctx.program, {
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+        poolCreatorAuthority: provider.wallet.publicKey,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
+
+    return fixture;
+  }
+
+  function setConfigPausedOperationsIx(fixture: WhirlpoolTestFixture, pausedOperations: number) {
+    const { poolInitInfo } = fixture.getInfos();
+    return WhirlpoolIx.setConfigPausedOperationsIx(ctx.program, {
+      whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+      poolCreatorAuthority: provider.wallet.publicKey,
+      pausedOperations,
+    });
+  }
+
+  function swapIx(fixture: WhirlpoolTestFixture) {
+    const { poolInitInfo, tokenAccountA, tokenAccountB } = fixture.getInfos();
+    const whirlpool = poolInitInfo.whirlpoolPda.publicKey;
+    const tickArrayPda = PDAUtil.getTickArray(ctx.program.programId, whirlpool, 22528);
+
+    return WhirlpoolIx.swapIx(ctx.program, {
+      amount: new u64(200_000),
+      otherAmountThreshold: ZERO_BN,
+      sqrtPriceLimit: MathUtil.toX64(new Decimal(4)),
+      amountSpecifiedIsInput: true,
+      aToB: true,
+      whirlpool,
+      whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+      tokenAuthority: ctx.wallet.publicKey,
+      tokenOwnerAccountA: tokenAccountA,
+      tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
+      tokenOwnerAccountB: tokenAccountB,
+      tokenVaultB: poolInitInfo.tokenVaultBKeypair.publicKey,
+      tickArray0: tickArrayPda.publicKey,
+      tickArray1: tickArrayPda.publicKey,
+      tickArray2: tickArrayPda.publicKey,
+      oracle: PDAUtil.getOracle(ctx.program.programId, whirlpool).publicKey,
+    });
+  }
+
+  it("successfully pauses every operation of the config with PAUSE_ALL", async () => {
+    const fixture = await initFixtureWithPauseControls();
+    const { poolInitInfo, positions, tokenAccountA, tokenAccountB } = fixture.getInfos();
+    const whirlpool = poolInitInfo.whirlpoolPda.publicKey;
+
+    await toTx(ctx, setConfigPausedOperationsIx(fixture, PAUSE_ALL)).buildAndExecute();
+
+    await assert.rejects(
+      toTx(ctx, swapIx(fixture)).buildAndExecute(),
+      /0x17aa/ // OperationPaused
+    );
+    await assert.rejects(
+      openPosition(ctx, whirlpool, tickLowerIndex, tickUpperIndex),
+      /0x17aa/ // OperationPaused
+    );
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.decreaseLiquidityIx(ctx.program, {
+          liquidityAmount: new anchor.BN(1_000_000),
+          tokenMinA: ZERO_BN,
+          tokenMinB: ZERO_BN,
+          whirlpool,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+          positionAuthority: provider.wallet.publicKey,
+          position: positions[0].publicKey,
+          positionTokenAccount: positions[0].tokenAccount,
+          tokenOwnerAccountA: tokenAccountA,
+          tokenOwnerAccountB: tokenAccountB,
+          tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
+          tokenVaultB: poolInitInfo.tokenVaultBKeypair.publicKey,
+          tickArrayLower: positions[0].tickArrayLower,
+          tickArrayUpper: positions[0].tickArrayUpper,
+        })
+      ).buildAndExecute(),
+      /0x17aa/ // OperationPaused
+    );
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.collectFeesIx(ctx.program, {
+          whirlpool,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+          positionAuthority: provider.wallet.publicKey,
+          position: positions[0].publicKey,
+          positionTokenAccount: positions[0].tokenAccount,
+          tokenOwnerAccountA: tokenAccountA,
+          tokenOwnerAccountB: tokenAccountB,
+          tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
+          tokenVaultB: poolInitInfo.tokenVaultBKeypair.publicKey,
+        })
+      ).buildAndExecute(),
+      /0x17aa/ // OperationPaused
+    );
+  });
+
+  it("successfully resumes the operations of the config", async () => {
+    const fixture = await initFixtureWithPauseControls();
+
+    await toTx(ctx, setConfigPausedOperationsIx(fixture, PAUSE_SWAP)).buildAndExecute();
+    await assert.rejects(
+      toTx(ctx, swapIx(fixture)).buildAndExecute(),
+      /0x17aa/ // OperationPaused
+    );
+
+    await toTx(ctx, setConfigPausedOperationsIx(fixture, 0)).buildAndExecute();
+    await toTx(ctx, swapIx(fixture)).buildAndExecute();
+  });
+
+  it("successfully pauses the pools created after the pause controls", async () => {
+    const poolParams = await buildTestPoolParams(ctx, TickSpacing.Standard);
+    const { poolInitInfo } = poolParams;
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializePauseControlsIx(ctx.program, {
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+        poolCreatorAuthority: provider.wallet.publicKey,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
+    await initTestPoolFromParams(ctx, poolParams);
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.setConfigPausedOperationsIx(ctx.program, {
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+        poolCreatorAuthority: provider.wallet.publicKey,
+        pausedOperations: PAUSE_OPEN_POSITION,
+      })
+    ).buildAndExecute();
+
+    await assert.rejects(
+      openPosition(ctx, poolInitInfo.whirlpoolPda.publicKey, tickLowerIndex, tickUpperIndex),
+      /0x17aa/ // OperationPaused
+    );
+  });
+
+  it("fails with unknown paused operations", async () => {
+    const fixture = await initFixtureWithPauseControls();
+
+    await assert.rejects(
+      toTx(ctx, setConfigPausedOperationsIx(fixture, PAUSE_ALL + 1)).buildAndExecute(),
+      /0x17ab/ // InvalidPausedOperations
+    );
+  });
+
+  it("fails when invalid pool_creator_authority provided", async () => {
+    const fixture = await initFixtureWithPauseControls();
+    const { poolInitInfo } = fixture.getInfos();
+    const fakeAuthorityKeypair = anchor.web3.Keypair.generate();
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.setConfigPausedOperationsIx(ctx.program, {
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+          poolCreatorAuthority: fakeAuthorityKeypair.publicKey,
+          pausedOperations: PAUSE_ALL,
+        })
+      )
+        .addSigner(fakeAuthorityKeypair)
+        .buildAndExecute(),
+      /0x7dc/ // ConstraintAddress
+    );
+  });
+});
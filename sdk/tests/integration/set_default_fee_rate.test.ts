@@ -57,7 +57,7 @@ describe("set_default_fee_rate", () => {
       whirlpoolsConfigKey,
       tokenMintA,
       tokenMintB,
-      TickSpacing.Stable
+      TickSpacing.Standard
     );
     const tokenVaultAKeypair = anchor.web3.Keypair.generate();
     const tokenVaultBKeypair = anchor.web3.Keypair.generate();
@@ -69,7 +69,7 @@ describe("set_default_fee_rate", () => {
       whirlpoolPda,
       tokenVaultAKeypair,
       tokenVaultBKeypair,
-      tickSpacing: TickSpacing.Stable,
+      tickSpacing: TickSpacing.Standard,
     };
     await toTx(ctx, WhirlpoolIx.initializePoolIx(ctx.program, newPoolInitInfo)).buildAndExecute();
 
@@ -181,6 +181,7 @@ describe("set_enable_flag", () => {
           tokenMaxA: tokenAmount.tokenA,
           tokenMaxB: tokenAmount.tokenB,
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: whirlpoolsConfigKey,
           positionAuthority: provider.wallet.publicKey,
           position: positionInitInfo.publicKey,
           positionTokenAccount: positionInitInfo.tokenAccount,
@@ -235,6 +236,7 @@ describe("set_enable_flag", () => {
         WhirlpoolIx.decreaseLiquidityIx(ctx.program, {
           ...removalQuote,
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: whirlpoolsConfigKey,
           positionAuthority: provider.wallet.publicKey,
           position: positions[0].publicKey,
           positionTokenAccount: positions[0].tokenAccount,
@@ -306,6 +308,7 @@ describe("set_enable_flag", () => {
         WhirlpoolIx.swapIx(ctx.program, {
           ...quote,
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: whirlpoolsConfigKey,
           tokenAuthority: ctx.wallet.publicKey,
           tokenOwnerAccountA: tokenAccountA,
           tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -16,6 +16,13 @@ describe("set_fee_authority", () => {
       configKeypairs: { feeAuthorityKeypair },
     } = generateDefaultConfigParams(ctx);
     await toTx(ctx, WhirlpoolIx.initializeConfigIx(ctx.program, configInitInfo)).buildAndExecute();
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializePendingAuthoritiesIx(ctx.program, {
+        whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
     const newAuthorityKeypair = anchor.web3.Keypair.generate();
     await toTx(
       ctx,
@@ -27,18 +34,116 @@ describe("set_fee_authority", () => {
     )
       .addSigner(feeAuthorityKeypair)
       .buildAndExecute();
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.acceptFeeAuthorityIx(ctx.program, {
+        whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+        newFeeAuthority: newAuthorityKeypair.publicKey,
+      })
+    )
+      .addSigner(newAuthorityKeypair)
+      .buildAndExecute();
+
     const config = (await fetcher.getConfig(
       configInitInfo.whirlpoolsConfigKeypair.publicKey
     )) as WhirlpoolsConfigData;
     assert.ok(config.feeAuthority.equals(newAuthorityKeypair.publicKey));
   });
 
+  it("does not transfer the fee_authority until it is accepted", async () => {
+    const {
+      configInitInfo,
+      configKeypairs: { feeAuthorityKeypair },
+    } = generateDefaultConfigParams(ctx);
+    await toTx(ctx, WhirlpoolIx.initializeConfigIx(ctx.program, configInitInfo)).buildAndExecute();
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializePendingAuthoritiesIx(ctx.program, {
+        whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
+    const newAuthorityKeypair = anchor.web3.Keypair.generate();
+    await toTx(
+      ctx,
+      WhirlpoolIx.setFeeAuthorityIx(ctx.program, {
+        whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+        feeAuthority: feeAuthorityKeypair.publicKey,
+        newFeeAuthority: newAuthorityKeypair.publicKey,
+      })
+    )
+      .addSigner(feeAuthorityKeypair)
+      .buildAndExecute();
+
+    const config = (await fetcher.getConfig(
+      configInitInfo.whirlpoolsConfigKeypair.publicKey,
+      true
+    )) as WhirlpoolsConfigData;
+    assert.ok(config.feeAuthority.equals(feeAuthorityKeypair.publicKey));
+  });
+
+  it("fails to accept a cancelled fee_authority transfer", async () => {
+    const {
+      configInitInfo,
+      configKeypairs: { feeAuthorityKeypair },
+    } = generateDefaultConfigParams(ctx);
+    await toTx(ctx, WhirlpoolIx.initializeConfigIx(ctx.program, configInitInfo)).buildAndExecute();
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializePendingAuthoritiesIx(ctx.program, {
+        whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
+    const newAuthorityKeypair = anchor.web3.Keypair.generate();
+    await toTx(
+      ctx,
+      WhirlpoolIx.setFeeAuthorityIx(ctx.program, {
+        whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+        feeAuthority: feeAuthorityKeypair.publicKey,
+        newFeeAuthority: newAuthorityKeypair.publicKey,
+      })
+    )
+      .addSigner(feeAuthorityKeypair)
+      .buildAndExecute();
+    await toTx(
+      ctx,
+      WhirlpoolIx.cancelFeeAuthorityTransferIx(ctx.program, {
+        whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+        feeAuthority: feeAuthorityKeypair.publicKey,
+      })
+    )
+      .addSigner(feeAuthorityKeypair)
+      .buildAndExecute();
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.acceptFeeAuthorityIx(ctx.program, {
+          whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+          newFeeAuthority: newAuthorityKeypair.publicKey,
+        })
+      )
+        .addSigner(newAuthorityKeypair)
+        .buildAndExecute(),
+      /0x7dc/ // An address constraint was violated
+    );
+  });
+
   it("fails if current fee_authority is not a signer", async () => {
     const {
       configInitInfo,
       configKeypairs: { feeAuthorityKeypair },
     } = generateDefaultConfigParams(ctx);
     await toTx(ctx, WhirlpoolIx.initializeConfigIx(ctx.program, configInitInfo)).buildAndExecute();
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializePendingAuthoritiesIx(ctx.program, {
+        whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
 
     await assert.rejects(
       toTx(
@@ -56,6 +161,13 @@ describe("set_fee_authority", () => {
   it("fails if invalid fee_authority provided", async () => {
     const { configInitInfo } = generateDefaultConfigParams(ctx);
     await toTx(ctx, WhirlpoolIx.initializeConfigIx(ctx.program, configInitInfo)).buildAndExecute();
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializePendingAuthoritiesIx(ctx.program, {
+        whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
 
     await assert.rejects(
       toTx(
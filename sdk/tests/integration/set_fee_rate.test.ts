@@ -1,8 +1,8 @@
 import * as anchor from "@project-serum/anchor";
 import * as assert from "assert";
-import { toTx, WhirlpoolContext, WhirlpoolData, WhirlpoolIx } from "../../src";
-import { TickSpacing } from "../utils";
-import { initTestPool } from "../utils/init-utils";
+import { PDAUtil, toTx, WhirlpoolContext, WhirlpoolData, WhirlpoolIx } from "../../src";
+import { getChainTimestamp, TickSpacing } from "../utils";
+import { initFeeRateSchedule, initTestPool } from "../utils/init-utils";
 import { generateDefaultConfigParams } from "../utils/test-builders";
 
 describe("set_fee_rate", () => {
@@ -23,21 +23,29 @@ describe("set_fee_rate", () => {
 
     const newFeeRate = 50;
 
-    let whirlpool = (await fetcher.getPool(whirlpoolKey, true)) as WhirlpoolData;
-
-    assert.equal(whirlpool.feeRate, feeTierParams.defaultFeeRate);
+    const feeRateSchedulePda = PDAUtil.getFeeRateSchedule(program.programId, whirlpoolKey);
+    await initFeeRateSchedule(ctx, whirlpoolsConfigKey, whirlpoolKey, feeAuthorityKeypair);
 
+    const timestamp = await getChainTimestamp(provider);
     await program.rpc.setFeeRate(newFeeRate, {
       accounts: {
         whirlpoolsConfig: whirlpoolsConfigKey,
         whirlpool: whirlpoolKey,
         feeAuthority: feeAuthorityKeypair.publicKey,
+        feeRateSchedule: feeRateSchedulePda.publicKey,
       },
       signers: [feeAuthorityKeypair],
     });
 
-    whirlpool = (await fetcher.getPool(poolInitInfo.whirlpoolPda.publicKey, true)) as WhirlpoolData;
-    assert.equal(whirlpool.feeRate, newFeeRate);
+    // The change is scheduled at the minimum delay, the other rate is kept
+    const whirlpool = (await fetcher.getPool(whirlpoolKey, true)) as WhirlpoolData;
+    assert.equal(whirlpool.feeRate, feeTierParams.defaultFeeRate);
+    const feeRateSchedule = await program.account.feeRateSchedule.fetch(
+      feeRateSchedulePda.publicKey
+    );
+    assert.equal(feeRateSchedule.pendingFeeRate, newFeeRate);
+    assert.equal(feeRateSchedule.pendingProtocolFeeRate, whirlpool.protocolFeeRate);
+    assert.ok(feeRateSchedule.effectiveAt.gtn(timestamp));
   });
 
   it("fails when fee rate exceeds max", async () => {
@@ -48,6 +56,7 @@ describe("set_fee_rate", () => {
     const whirlpoolKey = poolInitInfo.whirlpoolPda.publicKey;
     const whirlpoolsConfigKey = configInitInfo.whirlpoolsConfigKeypair.publicKey;
     const feeAuthorityKeypair = configKeypairs.feeAuthorityKeypair;
+    await initFeeRateSchedule(ctx, whirlpoolsConfigKey, whirlpoolKey, feeAuthorityKeypair);
 
     const newFeeRate = 20_000;
     await assert.rejects(
@@ -66,6 +75,29 @@ describe("set_fee_rate", () => {
     );
   });
 
+  it("fails when the fee rate schedule is not initialized", async () => {
+    const { poolInitInfo, configInitInfo, configKeypairs } = await initTestPool(
+      ctx,
+      TickSpacing.Standard
+    );
+    const feeAuthorityKeypair = configKeypairs.feeAuthorityKeypair;
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.setFeeRateIx(ctx.program, {
+          whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+          whirlpool: poolInitInfo.whirlpoolPda.publicKey,
+          feeAuthority: feeAuthorityKeypair.publicKey,
+          feeRate: 1000,
+        })
+      )
+        .addSigner(feeAuthorityKeypair)
+        .buildAndExecute(),
+      /0xbc4/ // AccountNotInitialized
+    );
+  });
+
   it("fails when fee authority is not signer", async () => {
     const { poolInitInfo, configInitInfo, configKeypairs } = await initTestPool(
       ctx,
@@ -103,6 +135,13 @@ describe("set_fee_rate", () => {
       ctx,
       WhirlpoolIx.initializeConfigIx(ctx.program, otherConfigInitInfo)
     ).buildAndExecute();
+    const feeRateSchedulePda = PDAUtil.getFeeRateSchedule(program.programId, whirlpoolKey);
+    await initFeeRateSchedule(
+      ctx,
+      poolInitInfo.whirlpoolsConfig,
+      whirlpoolKey,
+      configKeypairs.feeAuthorityKeypair
+    );
 
     const newFeeRate = 1000;
     await assert.rejects(
@@ -111,6 +150,7 @@ describe("set_fee_rate", () => {
           whirlpoolsConfig: otherConfigInitInfo.whirlpoolsConfigKeypair.publicKey,
           whirlpool: whirlpoolKey,
           feeAuthority: feeAuthorityKeypair.publicKey,
+          feeRateSchedule: feeRateSchedulePda.publicKey,
         },
         signers: [configKeypairs.feeAuthorityKeypair],
       }),
@@ -119,10 +159,20 @@ describe("set_fee_rate", () => {
   });
 
   it("fails when fee authority is invalid", async () => {
-    const { poolInitInfo, configInitInfo } = await initTestPool(ctx, TickSpacing.Standard);
+    const { poolInitInfo, configInitInfo, configKeypairs } = await initTestPool(
+      ctx,
+      TickSpacing.Standard
+    );
     const whirlpoolKey = poolInitInfo.whirlpoolPda.publicKey;
 
     const fakeAuthorityKeypair = anchor.web3.Keypair.generate();
+    const feeRateSchedulePda = PDAUtil.getFeeRateSchedule(program.programId, whirlpoolKey);
+    await initFeeRateSchedule(
+      ctx,
+      configInitInfo.whirlpoolsConfigKeypair.publicKey,
+      whirlpoolKey,
+      configKeypairs.feeAuthorityKeypair
+    );
 
     const newFeeRate = 1000;
     await assert.rejects(
@@ -131,6 +181,7 @@ describe("set_fee_rate", () => {
           whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
           whirlpool: whirlpoolKey,
           feeAuthority: fakeAuthorityKeypair.publicKey,
+          feeRateSchedule: feeRateSchedulePda.publicKey,
         },
         signers: [fakeAuthorityKeypair],
       }),
@@ -16,6 +16,13 @@ describe("set_pool_creator_authority", () => {
     } = generateDefaultConfigParams(ctx);
     const defaultPoolCreatorWallet = ctx.wallet
     await toTx(ctx, WhirlpoolIx.initializeConfigIx(ctx.program, configInitInfo)).buildAndExecute();
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializePendingAuthoritiesIx(ctx.program, {
+        whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
     const newAuthorityKeypair = anchor.web3.Keypair.generate();
     await toTx(
       ctx,
@@ -26,7 +33,17 @@ describe("set_pool_creator_authority", () => {
       })
     )
       .buildAndExecute();
-      
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.acceptPoolCreatorAuthorityIx(ctx.program, {
+        whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+        newPoolCreatorAuthority: newAuthorityKeypair.publicKey,
+      })
+    )
+      .addSigner(newAuthorityKeypair)
+      .buildAndExecute();
+
     const config = (await fetcher.getConfig(
       configInitInfo.whirlpoolsConfigKeypair.publicKey
     )) as WhirlpoolsConfigData;
@@ -36,6 +53,13 @@ describe("set_pool_creator_authority", () => {
   it("fails if invalid pool_creator_authority provided", async () => {
     const { configInitInfo } = generateDefaultConfigParams(ctx);
     await toTx(ctx, WhirlpoolIx.initializeConfigIx(ctx.program, configInitInfo)).buildAndExecute();
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializePendingAuthoritiesIx(ctx.program, {
+        whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
     const otherAuthorityKeypair = anchor.web3.Keypair.generate();
 
     await assert.rejects(
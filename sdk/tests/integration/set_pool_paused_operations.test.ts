@@ -0,0 +1,214 @@
+import { MathUtil } from "@orca-so/common-sdk";
+import * as anchor from "@project-serum/anchor";
+import { u64 } from "@solana/spl-token";
+import * as assert from "assert";
+import Decimal from "decimal.js";
+import {
+  PAUSE_DECREASE_LIQUIDITY,
+  PAUSE_SWAP,
+  PDAUtil,
+  toTx,
+  WhirlpoolContext,
+  WhirlpoolIx,
+} from "../../src";
+import { TickSpacing, ZERO_BN } from "../utils";
+import { WhirlpoolTestFixture } from "../utils/fixture";
+import { initTestPool } from "../utils/init-utils";
+
+describe("set_pool_paused_operations", () => {
+  const provider = anchor.AnchorProvider.local();
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.Whirlpool;
+  const ctx = WhirlpoolContext.fromWorkspace(provider, program);
+  const fetcher = ctx.fetcher;
+
+  const tickLowerIndex = 29440;
+  const tickUpperIndex = 33536;
+
+  async function initFixtureWithPauseControls() {
+    const fixture = await new WhirlpoolTestFixture(ctx).init({
+      tickSpacing: TickSpacing.Standard,
+      positions: [{ tickLowerIndex, tickUpperIndex, liquidityAmount: new anchor.BN(10_000_000) }],
+    });
+    const { poolInitInfo } = fixture.getInfos();
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializePauseControlsIx(ctx.program, {
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+        poolCreatorAuthority: provider.wallet.publicKey,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
+
+    return fixture;
+  }
+
+  function swapIx(fixture: WhirlpoolTestFixture) {
+    const { poolInitInfo, tokenAccountA, tokenAccountB } = fixture.getInfos();
+    const whirlpool = poolInitInfo.whirlpoolPda.publicKey;
+    const tickArrayPda = PDAUtil.getTickArray(ctx.program.programId, whirlpool, 22528);
+
+    return WhirlpoolIx.swapIx(ctx.program, {
+      amount: new u64(200_000),
+      otherAmountThreshold: ZERO_BN,
+      sqrtPriceLimit: MathUtil.toX64(new Decimal(4)),
+      amountSpecifiedIsInput: true,
+      aToB: true,
+      whirlpool,
+      whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+      tokenAuthority: ctx.wallet.publicKey,
+      tokenOwnerAccountA: tokenAccountA,
+      tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
+      tokenOwnerAccountB: tokenAccountB,
+      tokenVaultB: poolInitInfo.tokenVaultBKeypair.publicKey,
+      tickArray0: tickArrayPda.publicKey,
+      tickArray1: tickArrayPda.publicKey,
+      tickArray2: tickArrayPda.publicKey,
+      oracle: PDAUtil.getOracle(ctx.program.programId, whirlpool).publicKey,
+    });
+  }
+
+  function setPoolPausedOperationsIx(fixture: WhirlpoolTestFixture, pausedOperations: number) {
+    const { poolInitInfo } = fixture.getInfos();
+    return WhirlpoolIx.setPoolPausedOperationsIx(ctx.program, {
+      whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+      whirlpool: poolInitInfo.whirlpoolPda.publicKey,
+      poolCreatorAuthority: provider.wallet.publicKey,
+      pausedOperations,
+    });
+  }
+
+  it("successfully pauses swaps while positions can still be withdrawn", async () => {
+    const fixture = await initFixtureWithPauseControls();
+    const { poolInitInfo, positions, tokenAccountA, tokenAccountB } = fixture.getInfos();
+
+    await toTx(ctx, setPoolPausedOperationsIx(fixture, PAUSE_SWAP)).buildAndExecute();
+
+    const pauseControlsPda = PDAUtil.getPauseControls(
+      ctx.program.programId,
+      poolInitInfo.whirlpoolsConfig
+    );
+    const pauseControls = await ctx.program.account.pauseControls.fetch(
+      pauseControlsPda.publicKey
+    );
+    assert.equal(pauseControls.pausedOperations, 0);
+    assert.equal(pauseControls.pausedPools.length, 1);
+    assert.ok(pauseControls.pausedPools[0].whirlpool.equals(poolInitInfo.whirlpoolPda.publicKey));
+    assert.equal(pauseControls.pausedPools[0].pausedOperations, PAUSE_SWAP);
+
+    await assert.rejects(
+      toTx(ctx, swapIx(fixture)).buildAndExecute(),
+      /0x17aa/ // OperationPaused
+    );
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.decreaseLiquidityIx(ctx.program, {
+        liquidityAmount: new anchor.BN(10_000_000),
+        tokenMinA: ZERO_BN,
+        tokenMinB: ZERO_BN,
+        whirlpool: poolInitInfo.whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+        positionAuthority: provider.wallet.publicKey,
+        position: positions[0].publicKey,
+        positionTokenAccount: positions[0].tokenAccount,
+        tokenOwnerAccountA: tokenAccountA,
+        tokenOwnerAccountB: tokenAccountB,
+        tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
+        tokenVaultB: poolInitInfo.tokenVaultBKeypair.publicKey,
+        tickArrayLower: positions[0].tickArrayLower,
+        tickArrayUpper: positions[0].tickArrayUpper,
+      })
+    ).buildAndExecute();
+
+    const position = await fetcher.getPosition(positions[0].publicKey, true);
+    assert.ok(position?.liquidity.eq(ZERO_BN));
+  });
+
+  it("successfully resumes the paused operations of a pool", async () => {
+    const fixture = await initFixtureWithPauseControls();
+    const { poolInitInfo } = fixture.getInfos();
+
+    await toTx(
+      ctx,
+      setPoolPausedOperationsIx(fixture, PAUSE_SWAP | PAUSE_DECREASE_LIQUIDITY)
+    ).buildAndExecute();
+    await toTx(ctx, setPoolPausedOperationsIx(fixture, 0)).buildAndExecute();
+
+    const pauseControlsPda = PDAUtil.getPauseControls(
+      ctx.program.programId,
+      poolInitInfo.whirlpoolsConfig
+    );
+    const pauseControls = await ctx.program.account.pauseControls.fetch(
+      pauseControlsPda.publicKey
+    );
+    assert.equal(pauseControls.pausedPools.length, 0);
+
+    await toTx(ctx, swapIx(fixture)).buildAndExecute();
+  });
+
+  it("fails to swap with the pause controls of another config", async () => {
+    const fixture = await initFixtureWithPauseControls();
+    await toTx(ctx, setPoolPausedOperationsIx(fixture, PAUSE_SWAP)).buildAndExecute();
+    const { poolInitInfo: otherPoolInitInfo } = await initTestPool(ctx, TickSpacing.Standard);
+
+    const ix = swapIx(fixture);
+    const pauseControls = PDAUtil.getPauseControls(
+      ctx.program.programId,
+      fixture.getInfos().poolInitInfo.whirlpoolsConfig
+    ).publicKey;
+    const otherPauseControls = PDAUtil.getPauseControls(
+      ctx.program.programId,
+      otherPoolInitInfo.whirlpoolsConfig
+    ).publicKey;
+    ix.instructions[0].keys
+      .filter((key) => key.pubkey.equals(pauseControls))
+      .forEach((key) => (key.pubkey = otherPauseControls));
+
+    await assert.rejects(
+      toTx(ctx, ix).buildAndExecute(),
+      /0x7d6/ // ConstraintSeeds
+    );
+  });
+
+  it("fails when the whirlpool is not in the whirlpools config", async () => {
+    const fixture = await initFixtureWithPauseControls();
+    const { poolInitInfo } = fixture.getInfos();
+    const { poolInitInfo: otherPoolInitInfo } = await initTestPool(ctx, TickSpacing.Standard);
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.setPoolPausedOperationsIx(ctx.program, {
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+          whirlpool: otherPoolInitInfo.whirlpoolPda.publicKey,
+          poolCreatorAuthority: provider.wallet.publicKey,
+          pausedOperations: PAUSE_SWAP,
+        })
+      ).buildAndExecute(),
+      /0x7d1/ // ConstraintHasOne
+    );
+  });
+
+  it("fails when invalid pool_creator_authority provided", async () => {
+    const fixture = await initFixtureWithPauseControls();
+    const { poolInitInfo } = fixture.getInfos();
+    const fakeAuthorityKeypair = anchor.web3.Keypair.generate();
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.setPoolPausedOperationsIx(ctx.program, {
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+          whirlpool: poolInitInfo.whirlpoolPda.publicKey,
+          poolCreatorAuthority: fakeAuthorityKeypair.publicKey,
+          pausedOperations: PAUSE_SWAP,
+        })
+      )
+        .addSigner(fakeAuthorityKeypair)
+        .buildAndExecute(),
+      /0x7dc/ // ConstraintAddress
+    );
+  });
+});
@@ -17,6 +17,13 @@ describe("set_reward_emissions_super_authority", () => {
     } = generateDefaultConfigParams(ctx);
 
     await toTx(ctx, WhirlpoolIx.initializeConfigIx(ctx.program, configInitInfo)).buildAndExecute();
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializePendingAuthoritiesIx(ctx.program, {
+        whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
     const newAuthorityKeypair = anchor.web3.Keypair.generate();
 
     await toTx(
@@ -30,6 +37,16 @@ describe("set_reward_emissions_super_authority", () => {
       .addSigner(rewardEmissionsSuperAuthorityKeypair)
       .buildAndExecute();
 
+    await toTx(
+      ctx,
+      WhirlpoolIx.acceptRewardEmissionsSuperAuthorityIx(ctx.program, {
+        whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+        newRewardEmissionsSuperAuthority: newAuthorityKeypair.publicKey,
+      })
+    )
+      .addSigner(newAuthorityKeypair)
+      .buildAndExecute();
+
     const config = (await fetcher.getConfig(
       configInitInfo.whirlpoolsConfigKeypair.publicKey
     )) as WhirlpoolsConfigData;
@@ -42,6 +59,13 @@ describe("set_reward_emissions_super_authority", () => {
       configKeypairs: { rewardEmissionsSuperAuthorityKeypair },
     } = generateDefaultConfigParams(ctx);
     await toTx(ctx, WhirlpoolIx.initializeConfigIx(ctx.program, configInitInfo)).buildAndExecute();
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializePendingAuthoritiesIx(ctx.program, {
+        whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
 
     await assert.rejects(
       ctx.program.rpc.setRewardEmissionsSuperAuthority({
@@ -58,6 +82,13 @@ describe("set_reward_emissions_super_authority", () => {
   it("fails if incorrect reward_emissions_super_authority is passed in", async () => {
     const { configInitInfo } = generateDefaultConfigParams(ctx);
     await toTx(ctx, WhirlpoolIx.initializeConfigIx(ctx.program, configInitInfo)).buildAndExecute();
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializePendingAuthoritiesIx(ctx.program, {
+        whirlpoolsConfig: configInitInfo.whirlpoolsConfigKeypair.publicKey,
+        funder: provider.wallet.publicKey,
+      })
+    ).buildAndExecute();
 
     await assert.rejects(
       toTx(
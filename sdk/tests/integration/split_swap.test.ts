@@ -0,0 +1,290 @@
+import { Percentage } from "@orca-so/common-sdk";
+import * as anchor from "@project-serum/anchor";
+import { u64 } from "@solana/spl-token";
+import { PublicKey } from "@solana/web3.js";
+import * as assert from "assert";
+import {
+  buildWhirlpoolClient,
+  InitPoolParams,
+  SwapQuote,
+  swapQuoteByInputToken,
+  swapQuoteByOutputToken,
+  toTx,
+  WhirlpoolContext,
+  WhirlpoolIx,
+} from "../../src";
+import { SplitSwapLeg } from "../../src/instructions";
+import { getTokenBalance, TickSpacing } from "../utils";
+import {
+  buildTestAquariums,
+  FundedPositionParams,
+  getDefaultAquarium,
+  getTokenAccsForPools,
+  InitAquariumParams,
+  TestAquarium,
+} from "../utils/init-utils";
+
+describe("split swap", () => {
+  const provider = anchor.AnchorProvider.local();
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.Whirlpool;
+  const ctx = WhirlpoolContext.fromWorkspace(provider, program);
+  const fetcher = ctx.fetcher;
+  const client = buildWhirlpoolClient(ctx);
+
+  let aqConfig: InitAquariumParams;
+  beforeEach(async () => {
+    aqConfig = getDefaultAquarium();
+    // Add a second pool of the same pair on another fee tier
+    aqConfig.initFeeTierParams.push({ tickSpacing: TickSpacing.ThirtyTwo });
+    aqConfig.initPoolParams.push({
+      mintIndices: [0, 1],
+      tickSpacing: TickSpacing.ThirtyTwo,
+      feeTierIndex: 1,
+    });
+
+    // Add tick arrays and positions
+    aqConfig.initTickArrayRangeParams.push({
+      poolIndex: 0,
+      startTickIndex: 22528,
+      arrayCount: 3,
+      aToB: false,
+    });
+    aqConfig.initTickArrayRangeParams.push({
+      poolIndex: 1,
+      startTickIndex: 22528,
+      arrayCount: 12,
+      aToB: true,
+    });
+    aqConfig.initTickArrayRangeParams.push({
+      poolIndex: 1,
+      startTickIndex: 22528,
+      arrayCount: 12,
+      aToB: false,
+    });
+    const fundParams: FundedPositionParams[] = [
+      {
+        liquidityAmount: new anchor.BN(10_000_000),
+        tickLowerIndex: 29440,
+        tickUpperIndex: 33536,
+      },
+    ];
+    aqConfig.initPositionParams.push({ poolIndex: 0, fundParams });
+    aqConfig.initPositionParams.push({ poolIndex: 1, fundParams });
+  });
+
+  it("swaps [2] with split swap, amountSpecifiedIsInput=true", async () => {
+    const aquarium = (await buildTestAquariums(ctx, [aqConfig]))[0];
+    const { tokenAccounts, mintKeys, pools } = aquarium;
+
+    const tokenBalances = await getTokenBalances(tokenAccounts.map((acc) => acc.account));
+    const tokenVaultBalances = await getTokenBalancesForVaults(pools);
+
+    // 60% of the amount goes through the first pool and 40% through the second one
+    const quotes = [
+      await getExactInQuote(pools[0], mintKeys[0], new u64(600)),
+      await getExactInQuote(pools[1], mintKeys[0], new u64(400)),
+    ];
+    const totalAmountOut = quotes[0].estimatedAmountOut.add(quotes[1].estimatedAmountOut);
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.splitSwapIx(ctx.program, {
+        ...getParamsFromAquarium(aquarium, quotes, [6000, 4000]),
+        amount: new u64(1000),
+        otherAmountThreshold: new u64(totalAmountOut),
+        amountSpecifiedIsInput: true,
+      })
+    ).buildAndExecute();
+
+    assert.deepEqual(await getTokenBalancesForVaults(pools), [
+      tokenVaultBalances[0].add(quotes[0].estimatedAmountIn),
+      tokenVaultBalances[1].sub(quotes[0].estimatedAmountOut),
+      tokenVaultBalances[2].add(quotes[1].estimatedAmountIn),
+      tokenVaultBalances[3].sub(quotes[1].estimatedAmountOut),
+    ]);
+
+    assert.deepEqual(await getTokenBalances(tokenAccounts.map((acc) => acc.account)), [
+      tokenBalances[0].sub(new u64(1000)),
+      tokenBalances[1].add(totalAmountOut),
+    ]);
+  });
+
+  it("swaps [2] with split swap, amountSpecifiedIsInput=false", async () => {
+    const aquarium = (await buildTestAquariums(ctx, [aqConfig]))[0];
+    const { tokenAccounts, mintKeys, pools } = aquarium;
+
+    const tokenBalances = await getTokenBalances(tokenAccounts.map((acc) => acc.account));
+    const tokenVaultBalances = await getTokenBalancesForVaults(pools);
+
+    const quotes = await Promise.all([
+      getExactOutQuote(pools[0], mintKeys[1], new u64(500)),
+      getExactOutQuote(pools[1], mintKeys[1], new u64(500)),
+    ]);
+    const totalAmountIn = quotes[0].estimatedAmountIn.add(quotes[1].estimatedAmountIn);
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.splitSwapIx(ctx.program, {
+        ...getParamsFromAquarium(aquarium, quotes, [5000, 5000]),
+        amount: new u64(1000),
+        otherAmountThreshold: new u64(totalAmountIn),
+        amountSpecifiedIsInput: false,
+      })
+    ).buildAndExecute();
+
+    assert.deepEqual(await getTokenBalancesForVaults(pools), [
+      tokenVaultBalances[0].add(quotes[0].estimatedAmountIn),
+      tokenVaultBalances[1].sub(new u64(500)),
+      tokenVaultBalances[2].add(quotes[1].estimatedAmountIn),
+      tokenVaultBalances[3].sub(new u64(500)),
+    ]);
+
+    assert.deepEqual(await getTokenBalances(tokenAccounts.map((acc) => acc.account)), [
+      tokenBalances[0].sub(totalAmountIn),
+      tokenBalances[1].add(new u64(1000)),
+    ]);
+  });
+
+  it("fails swaps [2] with split swap, amountSpecifiedIsInput=true, slippage", async () => {
+    const aquarium = (await buildTestAquariums(ctx, [aqConfig]))[0];
+    const { mintKeys, pools } = aquarium;
+
+    const quotes = [
+      await getExactInQuote(pools[0], mintKeys[0], new u64(600)),
+      await getExactInQuote(pools[1], mintKeys[0], new u64(400)),
+    ];
+    // The threshold applies to the combined output of both legs
+    const totalAmountOut = quotes[0].estimatedAmountOut.add(quotes[1].estimatedAmountOut);
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.splitSwapIx(ctx.program, {
+          ...getParamsFromAquarium(aquarium, quotes, [6000, 4000]),
+          amount: new u64(1000),
+          otherAmountThreshold: new u64(totalAmountOut.addn(1)),
+          amountSpecifiedIsInput: true,
+        })
+      ).buildAndExecute(),
+      /0x1794/ // AmountOutBelowMinimum
+    );
+  });
+
+  it("fails swaps [2] with split swap, amountSpecifiedIsInput=false, slippage", async () => {
+    const aquarium = (await buildTestAquariums(ctx, [aqConfig]))[0];
+    const { mintKeys, pools } = aquarium;
+
+    const quotes = await Promise.all([
+      getExactOutQuote(pools[0], mintKeys[1], new u64(500)),
+      getExactOutQuote(pools[1], mintKeys[1], new u64(500)),
+    ]);
+    const totalAmountIn = quotes[0].estimatedAmountIn.add(quotes[1].estimatedAmountIn);
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.splitSwapIx(ctx.program, {
+          ...getParamsFromAquarium(aquarium, quotes, [5000, 5000]),
+          amount: new u64(1000),
+          otherAmountThreshold: new u64(totalAmountIn.subn(1)),
+          amountSpecifiedIsInput: false,
+        })
+      ).buildAndExecute(),
+      /0x1795/ // AmountInAboveMaximum
+    );
+  });
+
+  it("fails swaps with split swap, split not summing up to 100%", async () => {
+    const aquarium = (await buildTestAquariums(ctx, [aqConfig]))[0];
+    const { mintKeys, pools } = aquarium;
+
+    const quotes = [
+      await getExactInQuote(pools[0], mintKeys[0], new u64(600)),
+      await getExactInQuote(pools[1], mintKeys[0], new u64(400)),
+    ];
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.splitSwapIx(ctx.program, {
+          ...getParamsFromAquarium(aquarium, quotes, [6000, 3999]),
+          amount: new u64(1000),
+          otherAmountThreshold: new u64(0),
+          amountSpecifiedIsInput: true,
+        })
+      ).buildAndExecute(),
+      /0x17a2/ // InvalidSplitSwap
+    );
+  });
+
+  async function getExactInQuote(pool: InitPoolParams, inputMint: PublicKey, amount: u64) {
+    const whirlpool = await client.getPool(pool.whirlpoolPda.publicKey, true);
+    return swapQuoteByInputToken(
+      whirlpool,
+      inputMint,
+      amount,
+      Percentage.fromFraction(1, 100),
+      ctx.program.programId,
+      fetcher,
+      true
+    );
+  }
+
+  async function getExactOutQuote(pool: InitPoolParams, outputMint: PublicKey, amount: u64) {
+    const whirlpool = await client.getPool(pool.whirlpoolPda.publicKey, true);
+    return swapQuoteByOutputToken(
+      whirlpool,
+      outputMint,
+      amount,
+      Percentage.fromFraction(1, 100),
+      ctx.program.programId,
+      fetcher,
+      true
+    );
+  }
+
+  function getParamsFromAquarium(
+    aquarium: TestAquarium,
+    quotes: SwapQuote[],
+    splitBps: number[]
+  ) {
+    const { tokenAccounts, pools } = aquarium;
+    const tokenAccKeys = getTokenAccsForPools([pools[0]], tokenAccounts);
+
+    const legs: SplitSwapLeg[] = pools.map((pool, i) => ({
+      whirlpool: pool.whirlpoolPda.publicKey,
+      whirlpoolsConfig: pool.whirlpoolsConfig,
+      tokenVaultA: pool.tokenVaultAKeypair.publicKey,
+      tokenVaultB: pool.tokenVaultBKeypair.publicKey,
+      tickArray0: quotes[i].tickArray0,
+      tickArray1: quotes[i].tickArray1,
+      tickArray2: quotes[i].tickArray2,
+      splitBps: splitBps[i],
+      sqrtPriceLimit: quotes[i].sqrtPriceLimit,
+    }));
+
+    return {
+      aToB: quotes[0].aToB,
+      legs,
+      tokenOwnerAccountA: tokenAccKeys[0],
+      tokenOwnerAccountB: tokenAccKeys[1],
+      tokenAuthority: ctx.wallet.publicKey,
+    };
+  }
+
+  async function getTokenBalancesForVaults(pools: InitPoolParams[]) {
+    const accs = [];
+    for (const pool of pools) {
+      accs.push(pool.tokenVaultAKeypair.publicKey);
+      accs.push(pool.tokenVaultBKeypair.publicKey);
+    }
+    return getTokenBalances(accs);
+  }
+
+  async function getTokenBalances(keys: PublicKey[]) {
+    return Promise.all(
+      keys.map(async (key) => new anchor.BN(await getTokenBalance(provider, key)))
+    );
+  }
+});
@@ -69,6 +69,7 @@ describe("swap", () => {
           amountSpecifiedIsInput: true,
           aToB: true,
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           tokenAuthority: ctx.wallet.publicKey,
           tokenOwnerAccountA: tokenAccountA,
           tokenVaultA: anotherPoolInitInfo.tokenVaultAKeypair.publicKey,
@@ -114,6 +115,7 @@ describe("swap", () => {
           amountSpecifiedIsInput: true,
           aToB: true,
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           tokenAuthority: ctx.wallet.publicKey,
           tokenOwnerAccountA: tokenAccountA,
           tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -161,6 +163,7 @@ describe("swap", () => {
           amountSpecifiedIsInput: true,
           aToB: true,
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           tokenAuthority: ctx.wallet.publicKey,
           tokenOwnerAccountA: anotherTokenAccountA,
           tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -208,6 +211,7 @@ describe("swap", () => {
           amountSpecifiedIsInput: true,
           aToB: true,
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           tokenAuthority: ctx.wallet.publicKey,
           tokenOwnerAccountA: tokenAccountA,
           tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -250,6 +254,7 @@ describe("swap", () => {
           amountSpecifiedIsInput: true,
           aToB: true,
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           tokenAuthority: otherTokenAuthority.publicKey,
           tokenOwnerAccountA: tokenAccountA,
           tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -296,6 +301,7 @@ describe("swap", () => {
           amountSpecifiedIsInput: true,
           aToB: true,
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           tokenAuthority: ctx.wallet.publicKey,
           tokenOwnerAccountA: tokenAccountA,
           tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -338,6 +344,7 @@ describe("swap", () => {
           amountSpecifiedIsInput: true,
           aToB: true,
           whirlpool: anotherPoolInitInfo.whirlpoolPda.publicKey,
+          whirlpoolsConfig: anotherPoolInitInfo.whirlpoolsConfig,
           tokenAuthority: ctx.wallet.publicKey,
           tokenOwnerAccountA: tokenAccountA,
           tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -380,6 +387,7 @@ describe("swap", () => {
           amountSpecifiedIsInput: true,
           aToB: true,
           whirlpool: anotherPoolInitInfo.whirlpoolPda.publicKey,
+          whirlpoolsConfig: anotherPoolInitInfo.whirlpoolsConfig,
           tokenAuthority: ctx.wallet.publicKey,
           tokenOwnerAccountA: tokenAccountA,
           tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -418,6 +426,7 @@ describe("swap", () => {
           amountSpecifiedIsInput: true,
           aToB: true,
           whirlpool: poolInitInfo.whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           tokenAuthority: ctx.wallet.publicKey,
           tokenOwnerAccountA: tokenAccountA,
           tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -463,6 +472,7 @@ describe("swap", () => {
           amountSpecifiedIsInput: true,
           aToB: true,
           whirlpool: poolInitInfo.whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           tokenAuthority: ctx.wallet.publicKey,
           tokenOwnerAccountA: tokenAccountA,
           tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -503,6 +513,7 @@ describe("swap", () => {
           amountSpecifiedIsInput: true,
           aToB: true,
           whirlpool: whirlpoolPda.publicKey,
+          whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
           tokenAuthority: ctx.wallet.publicKey,
           tokenOwnerAccountA: tokenAccountA,
           tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -568,6 +579,7 @@ describe("swap", () => {
       WhirlpoolIx.swapIx(ctx.program, {
         ...quote,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -659,6 +671,7 @@ describe("swap", () => {
       WhirlpoolIx.swapIx(ctx.program, {
         ...quote,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -694,6 +707,7 @@ describe("swap", () => {
       WhirlpoolIx.swapIx(ctx.program, {
         ...quote2,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -786,6 +800,7 @@ describe("swap", () => {
       WhirlpoolIx.swapIx(ctx.program, {
         ...quote,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -820,6 +835,7 @@ describe("swap", () => {
       WhirlpoolIx.swapIx(ctx.program, {
         ...quote2,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -900,6 +916,7 @@ describe("swap", () => {
         amountSpecifiedIsInput: true,
         aToB: aToB,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -940,6 +957,7 @@ describe("swap", () => {
       amountSpecifiedIsInput: true,
       aToB: true,
       whirlpool: whirlpool,
+      whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
       tokenAuthority: ctx.wallet.publicKey,
       tokenOwnerAccountA: tokenAccountA,
       tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -974,6 +992,7 @@ describe("swap", () => {
       amountSpecifiedIsInput: true,
       aToB: true,
       whirlpool: whirlpool,
+      whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
       tokenAuthority: ctx.wallet.publicKey,
       tokenOwnerAccountA: tokenAccountA,
       tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -1008,6 +1027,7 @@ describe("swap", () => {
       amountSpecifiedIsInput: true,
       aToB: true,
       whirlpool: whirlpool,
+      whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
       tokenAuthority: ctx.wallet.publicKey,
       tokenOwnerAccountA: tokenAccountA,
       tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -1060,6 +1080,7 @@ describe("swap", () => {
       amountSpecifiedIsInput: true,
       aToB: true,
       whirlpool: whirlpoolPda.publicKey,
+      whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
       tokenAuthority: ctx.wallet.publicKey,
       tokenOwnerAccountA: tokenAccountA,
       tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -1112,6 +1133,7 @@ describe("swap", () => {
       amountSpecifiedIsInput: true,
       aToB: false,
       whirlpool: whirlpoolPda.publicKey,
+      whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
       tokenAuthority: ctx.wallet.publicKey,
       tokenOwnerAccountA: tokenAccountA,
       tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -1164,6 +1186,7 @@ describe("swap", () => {
       amountSpecifiedIsInput: false,
       aToB: true,
       whirlpool: whirlpoolPda.publicKey,
+      whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
       tokenAuthority: ctx.wallet.publicKey,
       tokenOwnerAccountA: tokenAccountA,
       tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -1216,6 +1239,7 @@ describe("swap", () => {
       amountSpecifiedIsInput: false,
       aToB: false,
       whirlpool: whirlpoolPda.publicKey,
+      whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
       tokenAuthority: ctx.wallet.publicKey,
       tokenOwnerAccountA: tokenAccountA,
       tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -1349,6 +1373,7 @@ describe("swap", () => {
         amountSpecifiedIsInput: false,
         aToB,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -1390,6 +1415,7 @@ describe("swap", () => {
         amountSpecifiedIsInput: false,
         aToB: true,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -1431,6 +1457,7 @@ describe("swap", () => {
         amountSpecifiedIsInput: false,
         aToB,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -1472,6 +1499,7 @@ describe("swap", () => {
         amountSpecifiedIsInput: false,
         aToB: true,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -1513,6 +1541,7 @@ describe("swap", () => {
         amountSpecifiedIsInput: false,
         aToB,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
@@ -1554,6 +1583,7 @@ describe("swap", () => {
         amountSpecifiedIsInput: false,
         aToB: true,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
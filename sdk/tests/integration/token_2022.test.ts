@@ -0,0 +1,457 @@
+import * as anchor from "@project-serum/anchor";
+import { TOKEN_PROGRAM_ID, u64 } from "@solana/spl-token";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import * as assert from "assert";
+import { AddressUtil } from "@orca-so/common-sdk";
+import {
+  InitPoolV2Params,
+  MAX_SQRT_PRICE,
+  MIN_SQRT_PRICE,
+  PDAUtil,
+  PositionData,
+  PriceMath,
+  TickUtil,
+  TOKEN_2022_PROGRAM_ID,
+  toTx,
+  WhirlpoolContext,
+  WhirlpoolData,
+  WhirlpoolIx,
+} from "../../src";
+import { PoolUtil, toTokenAmount } from "../../src/utils/public/pool-utils";
+import {
+  createAndMintToTokenAccountWithTransferFee,
+  createMintWithTransferFee,
+  getTokenBalance,
+  isToken2022Deployed,
+  TickSpacing,
+  ZERO_BN,
+} from "../utils";
+import { WhirlpoolTestFixture } from "../utils/fixture";
+import { buildTestPoolParams, initTickArray, openPosition } from "../utils/init-utils";
+
+describe("token_2022", () => {
+  const provider = anchor.AnchorProvider.local();
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.Whirlpool;
+  const ctx = WhirlpoolContext.fromWorkspace(provider, program);
+  const fetcher = ctx.fetcher;
+
+  const tickLowerIndex = -1280;
+  const tickUpperIndex = 1280;
+
+  async function getBalanceBN(tokenAccount: PublicKey) {
+    return new anchor.BN(await getTokenBalance(provider, tokenAccount));
+  }
+
+  it("initializes a pool with the mints of the token program", async () => {
+    const { poolInitInfo } = await buildTestPoolParams(ctx, TickSpacing.Standard);
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.initializePoolV2Ix(ctx.program, {
+        ...poolInitInfo,
+        tokenProgramA: TOKEN_PROGRAM_ID,
+        tokenProgramB: TOKEN_PROGRAM_ID,
+      })
+    ).buildAndExecute();
+
+    const whirlpool = (await fetcher.getPool(
+      poolInitInfo.whirlpoolPda.publicKey,
+      true
+    )) as WhirlpoolData;
+    assert.ok(whirlpool.tokenMintA.equals(poolInitInfo.tokenMintA));
+    assert.ok(whirlpool.tokenMintB.equals(poolInitInfo.tokenMintB));
+    assert.ok(whirlpool.tokenVaultA.equals(poolInitInfo.tokenVaultAKeypair.publicKey));
+    assert.ok(whirlpool.tokenVaultB.equals(poolInitInfo.tokenVaultBKeypair.publicKey));
+
+    const vaultA = await provider.connection.getAccountInfo(whirlpool.tokenVaultA);
+    assert.ok(vaultA?.owner.equals(TOKEN_PROGRAM_ID));
+    assert.equal(await getTokenBalance(provider, whirlpool.tokenVaultA), "0");
+    assert.equal(await getTokenBalance(provider, whirlpool.tokenVaultB), "0");
+  });
+
+  it("fails to initialize a pool with a mint that does not belong to a token program", async () => {
+    const { poolInitInfo } = await buildTestPoolParams(ctx, TickSpacing.Standard);
+    const [tokenMintA, tokenMintB] = PoolUtil.orderMints(
+      poolInitInfo.tokenMintA,
+      poolInitInfo.whirlpoolsConfig
+    ).map(AddressUtil.toPubKey);
+    const tokenProgram = (mint: PublicKey) =>
+      mint.equals(poolInitInfo.whirlpoolsConfig) ? ctx.program.programId : TOKEN_PROGRAM_ID;
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.initializePoolV2Ix(ctx.program, {
+          ...poolInitInfo,
+          tokenMintA,
+          tokenMintB,
+          whirlpoolPda: PDAUtil.getWhirlpool(
+            ctx.program.programId,
+            poolInitInfo.whirlpoolsConfig,
+            tokenMintA,
+            tokenMintB,
+            TickSpacing.Standard
+          ),
+          tokenProgramA: tokenProgram(tokenMintA),
+          tokenProgramB: tokenProgram(tokenMintB),
+        })
+      ).buildAndExecute(),
+      /custom program error: 0x17bc/ // UnsupportedTokenMint
+    );
+  });
+
+  it("modifies liquidity, swaps and collects fees of a pool of the token program", async () => {
+    const fixture = await new WhirlpoolTestFixture(ctx).init({
+      tickSpacing: TickSpacing.Standard,
+      positions: [{ tickLowerIndex, tickUpperIndex, liquidityAmount: ZERO_BN }],
+      initialSqrtPrice: PriceMath.tickIndexToSqrtPriceX64(0),
+    });
+    const { poolInitInfo, configKeypairs, positions, tokenAccountA, tokenAccountB } =
+      fixture.getInfos();
+    const { whirlpoolPda, whirlpoolsConfig, tokenVaultAKeypair, tokenVaultBKeypair } =
+      poolInitInfo;
+    const positionInitInfo = positions[0];
+    const tokenProgramParams = {
+      tokenMintA: poolInitInfo.tokenMintA,
+      tokenMintB: poolInitInfo.tokenMintB,
+      tokenProgramA: TOKEN_PROGRAM_ID,
+      tokenProgramB: TOKEN_PROGRAM_ID,
+    };
+    const modifyLiquidityParams = {
+      whirlpool: whirlpoolPda.publicKey,
+      whirlpoolsConfig,
+      positionAuthority: provider.wallet.publicKey,
+      position: positionInitInfo.publicKey,
+      positionTokenAccount: positionInitInfo.tokenAccount,
+      tokenOwnerAccountA: tokenAccountA,
+      tokenOwnerAccountB: tokenAccountB,
+      tokenVaultA: tokenVaultAKeypair.publicKey,
+      tokenVaultB: tokenVaultBKeypair.publicKey,
+      tickArrayLower: positionInitInfo.tickArrayLower,
+      tickArrayUpper: positionInitInfo.tickArrayUpper,
+      ...tokenProgramParams,
+    };
+
+    const tokenAmount = toTokenAmount(1_000_000, 1_000_000);
+    const liquidityAmount = PoolUtil.estimateLiquidityFromTokenAmounts(
+      0,
+      tickLowerIndex,
+      tickUpperIndex,
+      tokenAmount
+    );
+    await toTx(
+      ctx,
+      WhirlpoolIx.increaseLiquidityV2Ix(ctx.program, {
+        ...modifyLiquidityParams,
+        liquidityAmount,
+        tokenMaxA: tokenAmount.tokenA,
+        tokenMaxB: tokenAmount.tokenB,
+      })
+    ).buildAndExecute();
+
+    const vaultABefore = await getBalanceBN(tokenVaultAKeypair.publicKey);
+    const vaultBBefore = await getBalanceBN(tokenVaultBKeypair.publicKey);
+    assert.ok(vaultABefore.gt(ZERO_BN) && vaultABefore.lte(tokenAmount.tokenA));
+    assert.ok(vaultBBefore.gt(ZERO_BN) && vaultBBefore.lte(tokenAmount.tokenB));
+
+    const userBBefore = await getBalanceBN(tokenAccountB);
+    await toTx(
+      ctx,
+      WhirlpoolIx.swapV2Ix(ctx.program, {
+        amount: new u64(100_000),
+        otherAmountThreshold: ZERO_BN,
+        sqrtPriceLimit: new anchor.BN(MIN_SQRT_PRICE),
+        amountSpecifiedIsInput: true,
+        aToB: true,
+        whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig,
+        tokenAuthority: provider.wallet.publicKey,
+        tokenOwnerAccountA: tokenAccountA,
+        tokenVaultA: tokenVaultAKeypair.publicKey,
+        tokenOwnerAccountB: tokenAccountB,
+        tokenVaultB: tokenVaultBKeypair.publicKey,
+        tickArray0: positionInitInfo.tickArrayUpper,
+        tickArray1: positionInitInfo.tickArrayLower,
+        tickArray2: positionInitInfo.tickArrayLower,
+        oracle: PDAUtil.getOracle(ctx.program.programId, whirlpoolPda.publicKey).publicKey,
+        ...tokenProgramParams,
+      })
+    ).buildAndExecute();
+
+    const vaultAAfterSwap = await getBalanceBN(tokenVaultAKeypair.publicKey);
+    const vaultBAfterSwap = await getBalanceBN(tokenVaultBKeypair.publicKey);
+    assert.ok(vaultAAfterSwap.sub(vaultABefore).eq(new anchor.BN(100_000)));
+    assert.ok(
+      (await getBalanceBN(tokenAccountB)).sub(userBBefore).eq(vaultBBefore.sub(vaultBAfterSwap))
+    );
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.decreaseLiquidityV2Ix(ctx.program, {
+        ...modifyLiquidityParams,
+        liquidityAmount,
+        tokenMinA: ZERO_BN,
+        tokenMinB: ZERO_BN,
+      })
+    ).buildAndExecute();
+    const position = (await fetcher.getPosition(positionInitInfo.publicKey, true)) as PositionData;
+    const whirlpoolBefore = (await fetcher.getPool(whirlpoolPda.publicKey, true)) as WhirlpoolData;
+    assert.ok(position.liquidity.eq(ZERO_BN));
+    assert.ok(position.feeOwedA.gt(ZERO_BN));
+    assert.ok(whirlpoolBefore.protocolFeeOwedA.gt(ZERO_BN));
+    const vaultABeforeCollect = await getBalanceBN(tokenVaultAKeypair.publicKey);
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.collectFeesV2Ix(ctx.program, {
+        whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig,
+        positionAuthority: provider.wallet.publicKey,
+        position: positionInitInfo.publicKey,
+        positionTokenAccount: positionInitInfo.tokenAccount,
+        tokenOwnerAccountA: tokenAccountA,
+        tokenOwnerAccountB: tokenAccountB,
+        tokenVaultA: tokenVaultAKeypair.publicKey,
+        tokenVaultB: tokenVaultBKeypair.publicKey,
+        ...tokenProgramParams,
+      })
+    ).buildAndExecute();
+
+    await toTx(
+      ctx,
+      WhirlpoolIx.collectProtocolFeesV2Ix(ctx.program, {
+        whirlpoolsConfig,
+        whirlpool: whirlpoolPda.publicKey,
+        collectProtocolFeesAuthority: configKeypairs.collectProtocolFeesAuthorityKeypair.publicKey,
+        tokenVaultA: tokenVaultAKeypair.publicKey,
+        tokenVaultB: tokenVaultBKeypair.publicKey,
+        tokenOwnerAccountA: tokenAccountA,
+        tokenOwnerAccountB: tokenAccountB,
+        ...tokenProgramParams,
+      })
+    )
+      .addSigner(configKeypairs.collectProtocolFeesAuthorityKeypair)
+      .buildAndExecute();
+
+    const whirlpool = (await fetcher.getPool(whirlpoolPda.publicKey, true)) as WhirlpoolData;
+    assert.ok(whirlpool.protocolFeeOwedA.eq(ZERO_BN));
+    assert.ok(
+      vaultABeforeCollect
+        .sub(await getBalanceBN(tokenVaultAKeypair.publicKey))
+        .eq(position.feeOwedA.add(whirlpoolBefore.protocolFeeOwedA))
+    );
+  });
+
+  describe("with transfer fees", () => {
+    // The share of every transfer withheld by the mints of the pool, 1%
+    const transferFeeBasisPoints = 100;
+    const maximumFee = new anchor.BN(1_000_000);
+
+    function calculateTransferFee(amount: anchor.BN) {
+      const fee = amount
+        .muln(transferFeeBasisPoints)
+        .addn(10_000 - 1)
+        .divn(10_000);
+      return anchor.BN.min(fee, maximumFee);
+    }
+
+    before(async function () {
+      if (!(await isToken2022Deployed(provider))) {
+        this.skip();
+      }
+    });
+
+    async function initPoolWithTransferFees() {
+      const { poolInitInfo: classicPoolInitInfo } = await buildTestPoolParams(
+        ctx,
+        TickSpacing.Standard
+      );
+      const [tokenMintA, tokenMintB] = PoolUtil.orderMints(
+        await createMintWithTransferFee(provider, transferFeeBasisPoints, maximumFee),
+        await createMintWithTransferFee(provider, transferFeeBasisPoints, maximumFee)
+      ).map(AddressUtil.toPubKey);
+      const poolInitInfo: InitPoolV2Params = {
+        ...classicPoolInitInfo,
+        initSqrtPrice: PriceMath.tickIndexToSqrtPriceX64(0),
+        tokenMintA,
+        tokenMintB,
+        whirlpoolPda: PDAUtil.getWhirlpool(
+          ctx.program.programId,
+          classicPoolInitInfo.whirlpoolsConfig,
+          tokenMintA,
+          tokenMintB,
+          TickSpacing.Standard
+        ),
+        tokenVaultAKeypair: Keypair.generate(),
+        tokenVaultBKeypair: Keypair.generate(),
+        tokenProgramA: TOKEN_2022_PROGRAM_ID,
+        tokenProgramB: TOKEN_2022_PROGRAM_ID,
+      };
+      await toTx(ctx, WhirlpoolIx.initializePoolV2Ix(ctx.program, poolInitInfo)).buildAndExecute();
+
+      const whirlpool = poolInitInfo.whirlpoolPda.publicKey;
+      const tickArrays = [tickLowerIndex, tickUpperIndex].map((tickIndex) =>
+        TickUtil.getStartTickIndex(tickIndex, TickSpacing.Standard)
+      );
+      for (const startTickIndex of tickArrays) {
+        await initTickArray(ctx, whirlpool, startTickIndex);
+      }
+      const { params: positionParams } = await openPosition(
+        ctx,
+        whirlpool,
+        tickLowerIndex,
+        tickUpperIndex
+      );
+
+      const mintAmount = new anchor.BN(10_000_000_000);
+      const tokenAccountA = await createAndMintToTokenAccountWithTransferFee(
+        provider,
+        tokenMintA,
+        mintAmount
+      );
+      const tokenAccountB = await createAndMintToTokenAccountWithTransferFee(
+        provider,
+        tokenMintB,
+        mintAmount
+      );
+
+      const tickArrayLower = PDAUtil.getTickArray(ctx.program.programId, whirlpool, tickArrays[0]);
+      const tickArrayUpper = PDAUtil.getTickArray(ctx.program.programId, whirlpool, tickArrays[1]);
+      const accounts = {
+        whirlpool,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
+        tokenOwnerAccountA: tokenAccountA,
+        tokenOwnerAccountB: tokenAccountB,
+        tokenVaultA: poolInitInfo.tokenVaultAKeypair.publicKey,
+        tokenVaultB: poolInitInfo.tokenVaultBKeypair.publicKey,
+        tokenMintA,
+        tokenMintB,
+        tokenProgramA: TOKEN_2022_PROGRAM_ID,
+        tokenProgramB: TOKEN_2022_PROGRAM_ID,
+      };
+      const modifyLiquidityAccounts = {
+        ...accounts,
+        positionAuthority: provider.wallet.publicKey,
+        position: positionParams.positionPda.publicKey,
+        positionTokenAccount: positionParams.positionTokenAccount,
+        tickArrayLower: tickArrayLower.publicKey,
+        tickArrayUpper: tickArrayUpper.publicKey,
+      };
+      const swapAccounts = {
+        ...accounts,
+        tokenAuthority: provider.wallet.publicKey,
+        oracle: PDAUtil.getOracle(ctx.program.programId, whirlpool).publicKey,
+      };
+      return { accounts, modifyLiquidityAccounts, swapAccounts, tickArrayLower, tickArrayUpper };
+    }
+
+    it("charges the transfer fees to the liquidity provider and the swapper", async () => {
+      const { accounts, modifyLiquidityAccounts, swapAccounts, tickArrayLower, tickArrayUpper } =
+        await initPoolWithTransferFees();
+      const { tokenOwnerAccountA, tokenOwnerAccountB, tokenVaultA, tokenVaultB } = accounts;
+
+      const tokenAmount = toTokenAmount(1_000_000, 1_000_000);
+      const liquidityAmount = PoolUtil.estimateLiquidityFromTokenAmounts(
+        0,
+        tickLowerIndex,
+        tickUpperIndex,
+        tokenAmount
+      );
+
+      // The token maxes have to cover the transfer fees of the deposits
+      await assert.rejects(
+        toTx(
+          ctx,
+          WhirlpoolIx.increaseLiquidityV2Ix(ctx.program, {
+            ...modifyLiquidityAccounts,
+            liquidityAmount,
+            tokenMaxA: tokenAmount.tokenA,
+            tokenMaxB: tokenAmount.tokenB,
+          })
+        ).buildAndExecute(),
+        /custom program error: 0x1781/ // TokenMaxExceeded
+      );
+
+      const userABefore = await getBalanceBN(tokenOwnerAccountA);
+      await toTx(
+        ctx,
+        WhirlpoolIx.increaseLiquidityV2Ix(ctx.program, {
+          ...modifyLiquidityAccounts,
+          liquidityAmount,
+          tokenMaxA: new u64(tokenAmount.tokenA.muln(2)),
+          tokenMaxB: new u64(tokenAmount.tokenB.muln(2)),
+        })
+      ).buildAndExecute();
+      const depositA = userABefore.sub(await getBalanceBN(tokenOwnerAccountA));
+      const vaultA = await getBalanceBN(tokenVaultA);
+      assert.ok(vaultA.eq(depositA.sub(calculateTransferFee(depositA))));
+
+      // The exact input is what the swapper sends, the pool swaps it net of the transfer fee
+      const vaultBBeforeSwap = await getBalanceBN(tokenVaultB);
+      const userBBeforeSwap = await getBalanceBN(tokenOwnerAccountB);
+      const amountIn = new anchor.BN(100_000);
+      await toTx(
+        ctx,
+        WhirlpoolIx.swapV2Ix(ctx.program, {
+          ...swapAccounts,
+          amount: new u64(amountIn),
+          otherAmountThreshold: ZERO_BN,
+          sqrtPriceLimit: new anchor.BN(MIN_SQRT_PRICE),
+          amountSpecifiedIsInput: true,
+          aToB: true,
+          tickArray0: tickArrayUpper.publicKey,
+          tickArray1: tickArrayLower.publicKey,
+          tickArray2: tickArrayLower.publicKey,
+        })
+      ).buildAndExecute();
+      const swappedA = (await getBalanceBN(tokenVaultA)).sub(vaultA);
+      assert.ok(swappedA.eq(amountIn.sub(calculateTransferFee(amountIn))));
+      const amountOut = vaultBBeforeSwap.sub(await getBalanceBN(tokenVaultB));
+      const receivedB = (await getBalanceBN(tokenOwnerAccountB)).sub(userBBeforeSwap);
+      assert.ok(receivedB.eq(amountOut.sub(calculateTransferFee(amountOut))));
+
+      // The exact output is what the swapper receives, the maximum input includes the transfer fee
+      const exactOutput = new anchor.BN(10_000);
+      const exactOutputSwap = (otherAmountThreshold: anchor.BN) =>
+        toTx(
+          ctx,
+          WhirlpoolIx.swapV2Ix(ctx.program, {
+            ...swapAccounts,
+            amount: new u64(exactOutput),
+            otherAmountThreshold: new u64(otherAmountThreshold),
+            sqrtPriceLimit: new anchor.BN(MAX_SQRT_PRICE),
+            amountSpecifiedIsInput: false,
+            aToB: false,
+            tickArray0: tickArrayLower.publicKey,
+            tickArray1: tickArrayUpper.publicKey,
+            tickArray2: tickArrayUpper.publicKey,
+          })
+        ).buildAndExecute();
+      await assert.rejects(
+        exactOutputSwap(exactOutput.divn(2)),
+        /custom program error: 0x1795/ // AmountInAboveMaximum
+      );
+
+      const userABeforeSwap = await getBalanceBN(tokenOwnerAccountA);
+      await exactOutputSwap(exactOutput.muln(2));
+      assert.ok((await getBalanceBN(tokenOwnerAccountA)).sub(userABeforeSwap).eq(exactOutput));
+
+      // The token mins are what the liquidity provider receives, net of the transfer fees
+      const vaultABeforeWithdrawal = await getBalanceBN(tokenVaultA);
+      const userABeforeWithdrawal = await getBalanceBN(tokenOwnerAccountA);
+      await toTx(
+        ctx,
+        WhirlpoolIx.decreaseLiquidityV2Ix(ctx.program, {
+          ...modifyLiquidityAccounts,
+          liquidityAmount,
+          tokenMinA: ZERO_BN,
+          tokenMinB: ZERO_BN,
+        })
+      ).buildAndExecute();
+      const withdrawalA = vaultABeforeWithdrawal.sub(await getBalanceBN(tokenVaultA));
+      const receivedA = (await getBalanceBN(tokenOwnerAccountA)).sub(userABeforeWithdrawal);
+      assert.ok(receivedA.eq(withdrawalA.sub(calculateTransferFee(withdrawalA))));
+    });
+  });
+});
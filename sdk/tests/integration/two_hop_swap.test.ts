@@ -1,7 +1,7 @@
 import { Percentage } from "@orca-so/common-sdk";
 import * as anchor from "@project-serum/anchor";
-import { u64 } from "@solana/spl-token";
-import { PublicKey } from "@solana/web3.js";
+import { NATIVE_MINT, u64 } from "@solana/spl-token";
+import { Keypair, PublicKey } from "@solana/web3.js";
 import * as assert from "assert";
 import {
   buildWhirlpoolClient,
@@ -15,7 +15,7 @@ import {
   WhirlpoolIx,
 } from "../../src";
 import { TwoHopSwapParams } from "../../src/instructions";
-import { getTokenBalance, TickSpacing } from "../utils";
+import { getTokenBalance, replaceNestedAuthority, TickSpacing } from "../utils";
 import {
   buildTestAquariums,
   FundedPositionParams,
@@ -213,6 +213,95 @@ describe("two-hop swap", () => {
     }
   });
 
+  describe("fails [2] with two-hop swap with native, invalid accounts", () => {
+    const nativeWrapPda = PDAUtil.getNativeWrap(ctx.program.programId, ctx.wallet.publicKey);
+    let baseIxParams: TwoHopSwapParams;
+    let tokenAccountNative: PublicKey;
+    beforeEach(async () => {
+      aqConfig.initMintParams[0] = { isNative: true };
+      const aquarium = (await buildTestAquariums(ctx, [aqConfig]))[0];
+      const { tokenAccounts, mintKeys, pools } = aquarium;
+      tokenAccountNative = tokenAccounts.find((acc) => acc.mint.equals(NATIVE_MINT))!.account;
+
+      const whirlpoolOne = await client.getPool(pools[0].whirlpoolPda.publicKey, true);
+      const whirlpoolTwo = await client.getPool(pools[1].whirlpoolPda.publicKey, true);
+
+      const [inputToken, intermediaryToken, _outputToken] = mintKeys;
+
+      const quote = await swapQuoteByInputToken(
+        whirlpoolOne,
+        inputToken,
+        new u64(1000),
+        Percentage.fromFraction(1, 100),
+        ctx.program.programId,
+        fetcher,
+        true
+      );
+
+      const quote2 = await swapQuoteByInputToken(
+        whirlpoolTwo,
+        intermediaryToken,
+        quote.estimatedAmountOut,
+        Percentage.fromFraction(1, 100),
+        ctx.program.programId,
+        fetcher,
+        true
+      );
+
+      const twoHopQuote = twoHopSwapQuoteFromSwapQuotes(quote, quote2);
+      const params = getParamsFromPools([pools[0], pools[1]], tokenAccounts);
+      // The native wrap account is the token owner account of every native side
+      const native = (tokenOwnerAccount: PublicKey) =>
+        tokenOwnerAccount.equals(tokenAccountNative) ? nativeWrapPda.publicKey : tokenOwnerAccount;
+      baseIxParams = {
+        ...twoHopQuote,
+        ...params,
+        tokenOwnerAccountOneA: native(params.tokenOwnerAccountOneA),
+        tokenOwnerAccountOneB: native(params.tokenOwnerAccountOneB),
+        tokenOwnerAccountTwoA: native(params.tokenOwnerAccountTwoA),
+        tokenOwnerAccountTwoB: native(params.tokenOwnerAccountTwoB),
+        tokenAuthority: ctx.wallet.publicKey,
+      };
+    });
+
+    it("fails when the native token owner account is not the native wrap account", async () => {
+      const unwrap = (tokenOwnerAccount: PublicKey) =>
+        tokenOwnerAccount.equals(nativeWrapPda.publicKey) ? tokenAccountNative : tokenOwnerAccount;
+
+      await assert.rejects(
+        toTx(
+          ctx,
+          WhirlpoolIx.twoHopSwapWithNativeIx(ctx.program, {
+            ...baseIxParams,
+            tokenOwnerAccountOneA: unwrap(baseIxParams.tokenOwnerAccountOneA),
+            tokenOwnerAccountOneB: unwrap(baseIxParams.tokenOwnerAccountOneB),
+            tokenOwnerAccountTwoA: unwrap(baseIxParams.tokenOwnerAccountTwoA),
+            tokenOwnerAccountTwoB: unwrap(baseIxParams.tokenOwnerAccountTwoB),
+          })
+        ).buildAndExecute(),
+        /0x7d3/ // ConstraintRaw
+      );
+    });
+
+    it("fails when the nested token authority is not the token authority", async () => {
+      const otherAuthorityKeypair = Keypair.generate();
+
+      await assert.rejects(
+        toTx(
+          ctx,
+          replaceNestedAuthority(
+            WhirlpoolIx.twoHopSwapWithNativeIx(ctx.program, baseIxParams),
+            ctx.wallet.publicKey,
+            otherAuthorityKeypair.publicKey
+          )
+        )
+          .addSigner(otherAuthorityKeypair)
+          .buildAndExecute(),
+        /0x7d3/ // ConstraintRaw
+      );
+    });
+  });
+
   it("swaps [2] with two-hop swap, amountSpecifiedIsInput=true", async () => {
     const aquarium = (await buildTestAquariums(ctx, [aqConfig]))[0];
     const { tokenAccounts, mintKeys, pools } = aquarium;
@@ -576,6 +665,106 @@ describe("two-hop swap", () => {
     );
   });
 
+  it("fails swaps [2] with two-hop swap, amountSpecifiedIsInput=false, partial fill on hop one", async () => {
+    const aquarium = (await buildTestAquariums(ctx, [aqConfig]))[0];
+    const { tokenAccounts, mintKeys, pools } = aquarium;
+
+    const whirlpoolOne = await client.getPool(pools[0].whirlpoolPda.publicKey, true);
+    const whirlpoolTwo = await client.getPool(pools[1].whirlpoolPda.publicKey, true);
+
+    const [_inputToken, intermediaryToken, outputToken] = mintKeys;
+
+    const quote2 = await swapQuoteByOutputToken(
+      whirlpoolTwo,
+      outputToken,
+      new u64(1000),
+      Percentage.fromFraction(1, 100),
+      ctx.program.programId,
+      fetcher,
+      true
+    );
+
+    const quote = await swapQuoteByOutputToken(
+      whirlpoolOne,
+      intermediaryToken,
+      quote2.estimatedAmountIn,
+      Percentage.fromFraction(1, 100),
+      ctx.program.programId,
+      fetcher,
+      true
+    );
+
+    // Set a price limit halfway to the end price of swap-one, so that swap-one can only provide
+    // part of the input of swap-two
+    quote.sqrtPriceLimit = quote.estimatedEndSqrtPrice.add(
+      whirlpoolOne.getData().sqrtPrice.sub(quote.estimatedEndSqrtPrice).div(new anchor.BN("2"))
+    );
+
+    const twoHopQuote = twoHopSwapQuoteFromSwapQuotes(quote, quote2);
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.twoHopSwapIx(ctx.program, {
+          ...twoHopQuote,
+          ...getParamsFromPools([pools[0], pools[1]], tokenAccounts),
+          tokenAuthority: ctx.wallet.publicKey,
+        })
+      ).buildAndExecute(),
+      /0x17a1/ // IntermediateTokenAmountMismatch
+    );
+  });
+
+  it("fails swaps [2] with two-hop swap, amountSpecifiedIsInput=false, partial fill on hop two", async () => {
+    const aquarium = (await buildTestAquariums(ctx, [aqConfig]))[0];
+    const { tokenAccounts, mintKeys, pools } = aquarium;
+
+    const whirlpoolOne = await client.getPool(pools[0].whirlpoolPda.publicKey, true);
+    const whirlpoolTwo = await client.getPool(pools[1].whirlpoolPda.publicKey, true);
+
+    const [_inputToken, intermediaryToken, outputToken] = mintKeys;
+
+    const quote2 = await swapQuoteByOutputToken(
+      whirlpoolTwo,
+      outputToken,
+      new u64(1000),
+      Percentage.fromFraction(1, 100),
+      ctx.program.programId,
+      fetcher,
+      true
+    );
+
+    const quote = await swapQuoteByOutputToken(
+      whirlpoolOne,
+      intermediaryToken,
+      quote2.estimatedAmountIn,
+      Percentage.fromFraction(1, 100),
+      ctx.program.programId,
+      fetcher,
+      true
+    );
+
+    // Set a price limit halfway to the end price of swap-two, so that swap-two can only provide
+    // part of the specified output
+    quote2.sqrtPriceLimit = quote2.estimatedEndSqrtPrice.add(
+      whirlpoolTwo.getData().sqrtPrice.sub(quote2.estimatedEndSqrtPrice).div(new anchor.BN("2"))
+    );
+
+    const twoHopQuote = twoHopSwapQuoteFromSwapQuotes(quote, quote2);
+
+    await assert.rejects(
+      toTx(
+        ctx,
+        WhirlpoolIx.twoHopSwapIx(ctx.program, {
+          ...twoHopQuote,
+          ...getParamsFromPools([pools[0], pools[1]], tokenAccounts),
+          tokenAuthority: ctx.wallet.publicKey,
+        })
+      ).buildAndExecute(),
+      /0x1794/ // AmountOutBelowMinimum
+    );
+  });
+
   it("fails swaps [2] with two-hop swap, no overlapping mints", async () => {
     // Add another mint and update pool so there is no overlapping mint
     aqConfig.initMintParams.push({});
@@ -865,6 +1054,8 @@ describe("two-hop swap", () => {
     return {
       whirlpoolOne: pools[0].whirlpoolPda.publicKey,
       whirlpoolTwo: pools[1].whirlpoolPda.publicKey,
+      whirlpoolsConfigOne: pools[0].whirlpoolsConfig,
+      whirlpoolsConfigTwo: pools[1].whirlpoolsConfig,
       tokenOwnerAccountOneA: tokenAccKeys[0],
       tokenVaultOneA: pools[0].tokenVaultAKeypair.publicKey,
       tokenOwnerAccountOneB: tokenAccKeys[1],
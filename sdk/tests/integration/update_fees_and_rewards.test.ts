@@ -29,7 +29,7 @@ describe("update_fees_and_rewards", () => {
       ],
     });
     const {
-      poolInitInfo: { whirlpoolPda, tokenVaultAKeypair, tokenVaultBKeypair },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig, tokenVaultAKeypair, tokenVaultBKeypair },
       tokenAccountA,
       tokenAccountB,
       positions,
@@ -57,6 +57,7 @@ describe("update_fees_and_rewards", () => {
         amountSpecifiedIsInput: true,
         aToB: true,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: tokenVaultAKeypair.publicKey,
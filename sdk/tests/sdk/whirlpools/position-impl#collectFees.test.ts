@@ -76,6 +76,7 @@ describe("PositionImpl#collectFees()", () => {
         amountSpecifiedIsInput: true,
         aToB: true,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: tokenVaultAKeypair.publicKey,
@@ -98,6 +99,7 @@ describe("PositionImpl#collectFees()", () => {
         amountSpecifiedIsInput: true,
         aToB: false,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: tokenVaultAKeypair.publicKey,
@@ -77,6 +77,7 @@ describe("WhirlpoolImpl#collectFeesAndRewardsForPositions()", () => {
         amountSpecifiedIsInput: true,
         aToB: true,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: tokenVaultAKeypair.publicKey,
@@ -99,6 +100,7 @@ describe("WhirlpoolImpl#collectFeesAndRewardsForPositions()", () => {
         amountSpecifiedIsInput: true,
         aToB: false,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig: poolInitInfo.whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: tokenVaultAKeypair.publicKey,
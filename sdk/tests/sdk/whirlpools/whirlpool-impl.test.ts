@@ -333,7 +333,7 @@ describe("whirlpool-impl", () => {
       ],
     });
     const {
-      poolInitInfo: { whirlpoolPda, tokenVaultAKeypair, tokenVaultBKeypair },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig, tokenVaultAKeypair, tokenVaultBKeypair },
       tokenAccountA,
       tokenAccountB,
       positions,
@@ -352,6 +352,7 @@ describe("whirlpool-impl", () => {
         amountSpecifiedIsInput: true,
         aToB: true,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: tokenVaultAKeypair.publicKey,
@@ -374,6 +375,7 @@ describe("whirlpool-impl", () => {
         amountSpecifiedIsInput: true,
         aToB: false,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: tokenVaultAKeypair.publicKey,
@@ -501,7 +503,7 @@ describe("whirlpool-impl", () => {
       tokenAIsNative: true,
     });
     const {
-      poolInitInfo: { whirlpoolPda, tokenVaultAKeypair, tokenVaultBKeypair },
+      poolInitInfo: { whirlpoolPda, whirlpoolsConfig, tokenVaultAKeypair, tokenVaultBKeypair },
       tokenAccountA,
       tokenAccountB,
       positions,
@@ -520,6 +522,7 @@ describe("whirlpool-impl", () => {
         amountSpecifiedIsInput: true,
         aToB: true,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: tokenVaultAKeypair.publicKey,
@@ -542,6 +545,7 @@ describe("whirlpool-impl", () => {
         amountSpecifiedIsInput: true,
         aToB: false,
         whirlpool: whirlpoolPda.publicKey,
+        whirlpoolsConfig,
         tokenAuthority: ctx.wallet.publicKey,
         tokenOwnerAccountA: tokenAccountA,
         tokenVaultA: tokenVaultAKeypair.publicKey,
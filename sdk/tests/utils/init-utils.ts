@@ -382,6 +382,25 @@ export async function initFeeTier(
   };
 }
 
+export async function initFeeRateSchedule(
+  ctx: WhirlpoolContext,
+  whirlpoolsConfig: PublicKey,
+  whirlpool: PublicKey,
+  feeAuthorityKeypair: Keypair
+) {
+  return toTx(
+    ctx,
+    WhirlpoolIx.initializeFeeRateScheduleIx(ctx.program, {
+      whirlpoolsConfig,
+      whirlpool,
+      feeAuthority: feeAuthorityKeypair.publicKey,
+      funder: ctx.wallet.publicKey,
+    })
+  )
+    .addSigner(feeAuthorityKeypair)
+    .buildAndExecute();
+}
+
 export async function initializeReward(
   ctx: WhirlpoolContext,
   rewardAuthorityKeypair: anchor.web3.Keypair,
@@ -664,6 +683,7 @@ export async function withdrawPositions(
           tokenMinA: tokenA,
           tokenMinB: tokenB,
           whirlpool: info.initParams.whirlpool,
+          whirlpoolsConfig: pool.whirlpoolsConfig,
           positionAuthority: ctx.provider.wallet.publicKey,
           position: info.initParams.positionPda.publicKey,
           positionTokenAccount: info.initParams.positionTokenAccount,
@@ -680,6 +700,7 @@ export async function withdrawPositions(
         ctx,
         WhirlpoolIx.collectFeesIx(ctx.program, {
           whirlpool: info.initParams.whirlpool,
+          whirlpoolsConfig: pool.whirlpoolsConfig,
           positionAuthority: ctx.provider.wallet.publicKey,
           position: info.initParams.positionPda.publicKey,
           positionTokenAccount: info.initParams.positionTokenAccount,
@@ -738,6 +759,7 @@ export async function fundPositions(
 ): Promise<FundedPositionInfo[]> {
   const {
     whirlpoolPda: { publicKey: whirlpool },
+    whirlpoolsConfig,
     tickSpacing,
     tokenVaultAKeypair,
     tokenVaultBKeypair,
@@ -780,6 +802,7 @@ export async function fundPositions(
             tokenMaxA: tokenA,
             tokenMaxB: tokenB,
             whirlpool: whirlpool,
+            whirlpoolsConfig,
             positionAuthority: ctx.provider.wallet.publicKey,
             position: positionInfo.positionPda.publicKey,
             positionTokenAccount: positionInfo.positionTokenAccount,
@@ -139,6 +139,7 @@ export async function generateDefaultOpenPositionParams(
 ): Promise<{ params: Required<OpenPositionParams & { metadataPda: PDA }>; mint: Keypair }> {
   const positionMintKeypair = Keypair.generate();
   const positionPda = PDAUtil.getPosition(context.program.programId, positionMintKeypair.publicKey);
+  const whirlpoolData = await context.fetcher.getPool(whirlpool, true);
 
   const metadataPda = PDAUtil.getPositionMetadata(positionMintKeypair.publicKey);
 
@@ -157,6 +158,8 @@ export async function generateDefaultOpenPositionParams(
     positionMintAddress: positionMintKeypair.publicKey,
     positionTokenAccount: positionTokenAccountAddress,
     whirlpool: whirlpool,
+    // Invalid whirlpools are rejected before the config is used
+    whirlpoolsConfig: whirlpoolData?.whirlpoolsConfig ?? PublicKey.default,
     tickLowerIndex,
     tickUpperIndex,
   };
@@ -33,6 +33,7 @@ export const testWhirlpoolData = {
   rewardInfos: [],
   tickSpacing: 64,
   isEnabled: true,
+  extensions: 0,
 };
 
 export const testInitializedTickData: TickData = {
@@ -10,6 +10,7 @@ import {
   TOKEN_PROGRAM_ID,
   u64,
 } from "@solana/spl-token";
+import { TOKEN_2022_PROGRAM_ID } from "../../src";
 import { TEST_TOKEN_PROGRAM_ID } from "./test-consts";
 
 export async function createMint(
@@ -251,3 +252,117 @@ export async function transfer(
   );
   return provider.sendAndConfirm(tx, [], { commitment: "confirmed" });
 }
+
+// Token-2022 instructions are encoded by hand, the installed spl-token package predates it
+const MINT_WITH_TRANSFER_FEE_SIZE = 278;
+const ACCOUNT_WITH_TRANSFER_FEE_SIZE = 178;
+
+/**
+ * Creates a Token-2022 mint with a transfer fee, whose authority is the wallet of the provider.
+ * @param provider An anchor AnchorProvider object used to send transactions
+ * @param transferFeeBasisPoints The share of every transfer withheld by the mint
+ * @param maximumFee The maximum fee withheld from a transfer
+ */
+export async function createMintWithTransferFee(
+  provider: AnchorProvider,
+  transferFeeBasisPoints: number,
+  maximumFee: BN
+): Promise<web3.PublicKey> {
+  const mint = web3.Keypair.generate();
+  const authority = provider.wallet.publicKey;
+
+  const initTransferFeeConfigData = Buffer.concat([
+    Buffer.from([26, 0, 1]),
+    authority.toBuffer(),
+    Buffer.from([1]),
+    authority.toBuffer(),
+    new BN(transferFeeBasisPoints).toArrayLike(Buffer, "le", 2),
+    maximumFee.toArrayLike(Buffer, "le", 8),
+  ]);
+  const initMintData = Buffer.concat([
+    Buffer.from([20, 0]),
+    authority.toBuffer(),
+    Buffer.from([0]),
+  ]);
+
+  const tx = new web3.Transaction();
+  tx.add(
+    web3.SystemProgram.createAccount({
+      fromPubkey: authority,
+      newAccountPubkey: mint.publicKey,
+      space: MINT_WITH_TRANSFER_FEE_SIZE,
+      lamports: await provider.connection.getMinimumBalanceForRentExemption(
+        MINT_WITH_TRANSFER_FEE_SIZE
+      ),
+      programId: TOKEN_2022_PROGRAM_ID,
+    }),
+    new web3.TransactionInstruction({
+      programId: TOKEN_2022_PROGRAM_ID,
+      keys: [{ pubkey: mint.publicKey, isSigner: false, isWritable: true }],
+      data: initTransferFeeConfigData,
+    }),
+    new web3.TransactionInstruction({
+      programId: TOKEN_2022_PROGRAM_ID,
+      keys: [{ pubkey: mint.publicKey, isSigner: false, isWritable: true }],
+      data: initMintData,
+    })
+  );
+  await provider.sendAndConfirm(tx, [mint], { commitment: "confirmed" });
+  return mint.publicKey;
+}
+
+/**
+ * Creates a token account for a Token-2022 mint with a transfer fee and mints the specified amount
+ * of tokens into it. The caller is assumed to be the mint authority.
+ * @param provider An anchor AnchorProvider object used to send transactions
+ * @param mint The mint address of the token
+ * @param amount Number of tokens to mint to the newly created token account
+ */
+export async function createAndMintToTokenAccountWithTransferFee(
+  provider: AnchorProvider,
+  mint: web3.PublicKey,
+  amount: BN
+): Promise<web3.PublicKey> {
+  const tokenAccount = web3.Keypair.generate();
+  const owner = provider.wallet.publicKey;
+
+  const tx = new web3.Transaction();
+  tx.add(
+    web3.SystemProgram.createAccount({
+      fromPubkey: owner,
+      newAccountPubkey: tokenAccount.publicKey,
+      space: ACCOUNT_WITH_TRANSFER_FEE_SIZE,
+      lamports: await provider.connection.getMinimumBalanceForRentExemption(
+        ACCOUNT_WITH_TRANSFER_FEE_SIZE
+      ),
+      programId: TOKEN_2022_PROGRAM_ID,
+    }),
+    new web3.TransactionInstruction({
+      programId: TOKEN_2022_PROGRAM_ID,
+      keys: [
+        { pubkey: tokenAccount.publicKey, isSigner: false, isWritable: true },
+        { pubkey: mint, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.concat([Buffer.from([18]), owner.toBuffer()]),
+    }),
+    new web3.TransactionInstruction({
+      programId: TOKEN_2022_PROGRAM_ID,
+      keys: [
+        { pubkey: mint, isSigner: false, isWritable: true },
+        { pubkey: tokenAccount.publicKey, isSigner: false, isWritable: true },
+        { pubkey: owner, isSigner: true, isWritable: false },
+      ],
+      data: Buffer.concat([Buffer.from([7]), amount.toArrayLike(Buffer, "le", 8)]),
+    })
+  );
+  await provider.sendAndConfirm(tx, [tokenAccount], { commitment: "confirmed" });
+  return tokenAccount.publicKey;
+}
+
+/**
+ * Whether the Token-2022 program is deployed on the cluster of the provider.
+ */
+export async function isToken2022Deployed(provider: AnchorProvider) {
+  const program = await provider.connection.getAccountInfo(TOKEN_2022_PROGRAM_ID);
+  return !!program?.executable;
+}
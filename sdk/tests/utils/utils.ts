@@ -1,5 +1,5 @@
-import { TransactionBuilder } from "@orca-so/common-sdk";
-import { web3, AnchorProvider } from "@project-serum/anchor";
+import { Instruction, TransactionBuilder } from "@orca-so/common-sdk";
+import { web3, AnchorProvider, BN } from "@project-serum/anchor";
 
 export function systemTransferTx(
   provider: AnchorProvider,
@@ -22,3 +22,45 @@ export function systemTransferTx(
 export function sleep(ms: number): Promise<unknown> {
   return new Promise((resolve) => setTimeout(resolve, ms));
 }
+
+export async function getChainTimestamp(provider: AnchorProvider): Promise<number> {
+  const blockTime = await provider.connection.getBlockTime(await provider.connection.getSlot());
+  if (blockTime === null) {
+    throw new Error("The block time of the current slot is not available");
+  }
+  return blockTime;
+}
+
+/**
+ * Waits until the chain has moved past `timestamp`, e.g. until a scheduled fee rate change is due.
+ */
+export async function waitUntilTimestamp(provider: AnchorProvider, timestamp: BN) {
+  while ((await getChainTimestamp(provider)) <= timestamp.toNumber()) {
+    await sleep(1000);
+  }
+}
+
+/**
+ * Replaces the authority of the nested accounts of an instruction, i.e. every account after the
+ * first one that is the authority of the instruction.
+ */
+export function replaceNestedAuthority(
+  ix: Instruction,
+  authority: web3.PublicKey,
+  nestedAuthority: web3.PublicKey
+): Instruction {
+  const [instruction] = ix.instructions;
+  const keys = instruction.keys.map((key, index) =>
+    index > 0 && key.pubkey.equals(authority) ? { ...key, pubkey: nestedAuthority } : key
+  );
+  return {
+    ...ix,
+    instructions: [
+      new web3.TransactionInstruction({
+        keys,
+        programId: instruction.programId,
+        data: instruction.data,
+      }),
+    ],
+  };
+}